@@ -1,12 +1,62 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20260102_204116_create_users_table;
+mod m20261019_090000_create_oauth_clients_table;
+mod m20261019_090100_create_revoked_tokens_table;
+mod m20261019_090200_create_user_totp_table;
+mod m20261019_090300_create_passkeys_table;
+mod m20261019_090400_create_external_identities_table;
+mod m20261019_090500_create_login_attempts_table;
+mod m20261019_090600_create_password_reset_tokens_table;
+mod m20261019_090700_create_user_token_cutoffs_table;
+mod m20261019_090800_create_password_history_table;
+mod m20261019_090900_add_password_changed_at_to_users;
+mod m20261019_091000_create_sessions_table;
+mod m20261019_091100_create_invites_table;
+mod m20261019_091200_add_email_to_users;
+mod m20261019_091300_create_email_verification_tokens_table;
+mod m20261019_091400_add_profile_to_users;
+mod m20261019_091500_create_user_avatars_table;
+mod m20261019_091600_create_roles_tables;
+mod m20261019_091700_create_organizations_tables;
+mod m20261019_091800_create_groups_tables;
+mod m20261019_091900_create_audit_log_table;
+mod m20261019_092000_add_deleted_at_to_users;
+mod m20261019_092100_add_organization_id_to_invites;
+mod m20261019_092200_create_passkey_challenges_table;
+mod m20261019_092300_add_admin_scope_to_oauth_clients;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20260102_204116_create_users_table::Migration)]
+        vec![
+            Box::new(m20260102_204116_create_users_table::Migration),
+            Box::new(m20261019_090000_create_oauth_clients_table::Migration),
+            Box::new(m20261019_090100_create_revoked_tokens_table::Migration),
+            Box::new(m20261019_090200_create_user_totp_table::Migration),
+            Box::new(m20261019_090300_create_passkeys_table::Migration),
+            Box::new(m20261019_090400_create_external_identities_table::Migration),
+            Box::new(m20261019_090500_create_login_attempts_table::Migration),
+            Box::new(m20261019_090600_create_password_reset_tokens_table::Migration),
+            Box::new(m20261019_090700_create_user_token_cutoffs_table::Migration),
+            Box::new(m20261019_090800_create_password_history_table::Migration),
+            Box::new(m20261019_090900_add_password_changed_at_to_users::Migration),
+            Box::new(m20261019_091000_create_sessions_table::Migration),
+            Box::new(m20261019_091100_create_invites_table::Migration),
+            Box::new(m20261019_091200_add_email_to_users::Migration),
+            Box::new(m20261019_091300_create_email_verification_tokens_table::Migration),
+            Box::new(m20261019_091400_add_profile_to_users::Migration),
+            Box::new(m20261019_091500_create_user_avatars_table::Migration),
+            Box::new(m20261019_091600_create_roles_tables::Migration),
+            Box::new(m20261019_091700_create_organizations_tables::Migration),
+            Box::new(m20261019_091800_create_groups_tables::Migration),
+            Box::new(m20261019_091900_create_audit_log_table::Migration),
+            Box::new(m20261019_092000_add_deleted_at_to_users::Migration),
+            Box::new(m20261019_092100_add_organization_id_to_invites::Migration),
+            Box::new(m20261019_092200_create_passkey_challenges_table::Migration),
+            Box::new(m20261019_092300_add_admin_scope_to_oauth_clients::Migration),
+        ]
     }
 }
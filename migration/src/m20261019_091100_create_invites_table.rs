@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Invites::Table)
+                    .col(ColumnDef::new(Invites::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Invites::SecretHash).string().not_null())
+                    .col(ColumnDef::new(Invites::CreatedBy).uuid().not_null())
+                    .col(ColumnDef::new(Invites::MaxUses).integer().not_null())
+                    .col(
+                        ColumnDef::new(Invites::Uses)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Invites::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Invites::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_invites_created_by")
+                            .from(Invites::Table, Invites::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Invites::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Invites {
+    Table,
+    Id,
+    SecretHash,
+    CreatedBy,
+    MaxUses,
+    Uses,
+    ExpiresAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
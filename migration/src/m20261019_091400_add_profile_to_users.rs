@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(ColumnDef::new(Users::AvatarUpdatedAt).timestamp_with_time_zone())
+                    .add_column(ColumnDef::new(Users::StatusText).string_len(100))
+                    .add_column(ColumnDef::new(Users::StatusEmoji).string_len(64))
+                    .add_column(ColumnDef::new(Users::Bio).text())
+                    .add_column(ColumnDef::new(Users::Timezone).string_len(64))
+                    .add_column(ColumnDef::new(Users::Locale).string_len(16))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::AvatarUpdatedAt)
+                    .drop_column(Users::StatusText)
+                    .drop_column(Users::StatusEmoji)
+                    .drop_column(Users::Bio)
+                    .drop_column(Users::Timezone)
+                    .drop_column(Users::Locale)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    AvatarUpdatedAt,
+    StatusText,
+    StatusEmoji,
+    Bio,
+    Timezone,
+    Locale,
+}
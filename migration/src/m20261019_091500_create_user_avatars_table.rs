@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserAvatars::Table)
+                    .col(ColumnDef::new(UserAvatars::UserId).uuid().not_null())
+                    .col(ColumnDef::new(UserAvatars::Size).small_integer().not_null())
+                    .col(
+                        ColumnDef::new(UserAvatars::ContentType)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(UserAvatars::Data).binary().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(UserAvatars::UserId)
+                            .col(UserAvatars::Size),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_user_avatars_user_id")
+                            .from(UserAvatars::Table, UserAvatars::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserAvatars::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserAvatars {
+    Table,
+    UserId,
+    Size,
+    ContentType,
+    Data,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,191 @@
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Roles::Table)
+                    .col(
+                        ColumnDef::new(Roles::Name)
+                            .string_len(32)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Roles::Description)
+                            .string_len(255)
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(Roles::Builtin)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(Roles::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RolePermissions::Table)
+                    .col(
+                        ColumnDef::new(RolePermissions::Role)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RolePermissions::Permission)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(RolePermissions::Role)
+                            .col(RolePermissions::Permission),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_role_permissions_role")
+                            .from(RolePermissions::Table, RolePermissions::Role)
+                            .to(Roles::Table, Roles::Name)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(Roles::Table)
+                    .columns([Roles::Name, Roles::Description, Roles::Builtin])
+                    .values_panic([
+                        "administrator".into(),
+                        "Holds every permission".into(),
+                        true.into(),
+                    ])
+                    .values_panic(["user".into(), "Given to new users".into(), true.into()])
+                    .to_owned(),
+            )
+            .await?;
+
+        // The enum becomes a reference to the roles table, keeping the stored values.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE users ALTER COLUMN role DROP DEFAULT, \
+                 ALTER COLUMN role TYPE varchar(32) USING role::text, \
+                 ALTER COLUMN role SET DEFAULT 'user'",
+            )
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name("user_roles").to_owned())
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("FK_users_role")
+                    .from(Users::Table, Users::Role)
+                    .to(Roles::Table, Roles::Name)
+                    .on_delete(ForeignKeyAction::Restrict)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_users_role")
+                    .table(Users::Table)
+                    .col(Users::Role)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("IDX_users_role")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("FK_users_role")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum("user_roles")
+                    .values(["administrator", "user"])
+                    .to_owned(),
+            )
+            .await?;
+
+        // Holders of custom roles fall back to the user role.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE users ALTER COLUMN role DROP DEFAULT, \
+                 ALTER COLUMN role TYPE user_roles USING \
+                 (CASE WHEN role = 'administrator' THEN 'administrator' ELSE 'user' END)::user_roles, \
+                 ALTER COLUMN role SET DEFAULT 'user'",
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RolePermissions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Roles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Roles {
+    Table,
+    Name,
+    Description,
+    Builtin,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum RolePermissions {
+    Table,
+    Role,
+    Permission,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Role,
+}
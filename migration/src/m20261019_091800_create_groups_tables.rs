@@ -0,0 +1,191 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Groups::Table)
+                    .col(ColumnDef::new(Groups::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Groups::OrganizationId).uuid().not_null())
+                    .col(ColumnDef::new(Groups::Name).string_len(32).not_null())
+                    .col(
+                        ColumnDef::new(Groups::Description)
+                            .string_len(255)
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(Groups::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_groups_organization_id")
+                            .from(Groups::Table, Groups::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_groups_organization_id_name_unique")
+                    .table(Groups::Table)
+                    .col(Groups::OrganizationId)
+                    .col(Groups::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupPermissions::Table)
+                    .col(ColumnDef::new(GroupPermissions::GroupId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(GroupPermissions::Permission)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(GroupPermissions::GroupId)
+                            .col(GroupPermissions::Permission),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_group_permissions_group_id")
+                            .from(GroupPermissions::Table, GroupPermissions::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Members leaving the organization leave its groups along with it.
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMembers::Table)
+                    .col(ColumnDef::new(GroupMembers::GroupId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(GroupMembers::OrganizationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GroupMembers::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(GroupMembers::AddedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(GroupMembers::GroupId)
+                            .col(GroupMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_group_members_group_id")
+                            .from(GroupMembers::Table, GroupMembers::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("FK_group_members_organization_member")
+                            .from(
+                                GroupMembers::Table,
+                                (GroupMembers::OrganizationId, GroupMembers::UserId),
+                            )
+                            .to(
+                                OrganizationMembers::Table,
+                                (
+                                    OrganizationMembers::OrganizationId,
+                                    OrganizationMembers::UserId,
+                                ),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_group_members_organization_id_user_id")
+                    .table(GroupMembers::Table)
+                    .col(GroupMembers::OrganizationId)
+                    .col(GroupMembers::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupMembers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GroupPermissions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Groups::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    OrganizationId,
+    Name,
+    Description,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupPermissions {
+    Table,
+    GroupId,
+    Permission,
+}
+
+#[derive(DeriveIden)]
+enum GroupMembers {
+    Table,
+    GroupId,
+    OrganizationId,
+    UserId,
+    AddedAt,
+}
+
+#[derive(DeriveIden)]
+enum Organizations {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationMembers {
+    Table,
+    OrganizationId,
+    UserId,
+}
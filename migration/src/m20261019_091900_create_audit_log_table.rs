@@ -0,0 +1,129 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // No foreign keys: entries outlive the users and organizations they name.
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .col(ColumnDef::new(AuditLog::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(AuditLog::OrganizationId).uuid().null())
+                    .col(ColumnDef::new(AuditLog::ActorId).uuid().null())
+                    .col(
+                        ColumnDef::new(AuditLog::ActorUsername)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Action).string_len(64).not_null())
+                    .col(ColumnDef::new(AuditLog::Target).string_len(255).null())
+                    .col(ColumnDef::new(AuditLog::IpAddress).string_len(45).null())
+                    .col(
+                        ColumnDef::new(AuditLog::Changes)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .col(ColumnDef::new(AuditLog::Reason).string_len(500).null())
+                    .col(
+                        ColumnDef::new(AuditLog::OccurredAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_audit_log_organization_id_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::OrganizationId)
+                    .col(AuditLog::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_audit_log_actor_id")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::ActorId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_audit_log_target")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::Target)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Append-only, whoever connects: rows can be inserted and read, nothing else.
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE FUNCTION audit_log_append_only() RETURNS trigger AS $$ \
+             BEGIN \
+                 RAISE EXCEPTION 'audit_log is append-only'; \
+             END; \
+             $$ LANGUAGE plpgsql",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE TRIGGER audit_log_no_update_delete \
+             BEFORE UPDATE OR DELETE ON audit_log \
+             FOR EACH ROW EXECUTE FUNCTION audit_log_append_only()",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE TRIGGER audit_log_no_truncate \
+             BEFORE TRUNCATE ON audit_log \
+             FOR EACH STATEMENT EXECUTE FUNCTION audit_log_append_only()",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP FUNCTION audit_log_append_only()")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    Id,
+    OrganizationId,
+    ActorId,
+    ActorUsername,
+    Action,
+    Target,
+    IpAddress,
+    Changes,
+    Reason,
+    OccurredAt,
+}
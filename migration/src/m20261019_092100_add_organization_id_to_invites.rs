@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+const DEFAULT_ORGANIZATION_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing invites were created for the default organization.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Invites::Table)
+                    .add_column(
+                        ColumnDef::new(Invites::OrganizationId)
+                            .uuid()
+                            .not_null()
+                            .default(Expr::cust(format!("'{}'::uuid", DEFAULT_ORGANIZATION_ID))),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("FK_invites_organization_id")
+                            .from_tbl(Invites::Table)
+                            .from_col(Invites::OrganizationId)
+                            .to_tbl(Organizations::Table)
+                            .to_col(Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_invites_organization_id")
+                    .table(Invites::Table)
+                    .col(Invites::OrganizationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("IDX_invites_organization_id")
+                    .table(Invites::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Invites::Table)
+                    .drop_foreign_key("FK_invites_organization_id")
+                    .drop_column(Invites::OrganizationId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Invites {
+    Table,
+    OrganizationId,
+}
+
+#[derive(DeriveIden)]
+enum Organizations {
+    Table,
+    Id,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PasskeyChallenges::Table)
+                    .col(
+                        ColumnDef::new(PasskeyChallenges::Challenge)
+                            .string_len(128)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PasskeyChallenges::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("IDX_passkey_challenges_expires_at")
+                    .table(PasskeyChallenges::Table)
+                    .col(PasskeyChallenges::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PasskeyChallenges::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PasskeyChallenges {
+    Table,
+    Challenge,
+    ExpiresAt,
+}
@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+/// Permissions only apply to tokens with the admin scope from now on, so clients registered
+/// before it existed are allowed it to keep working as they did.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE oauth_clients SET scope = trim(scope || ' admin') \
+                 WHERE NOT ('admin' = ANY(string_to_array(scope, ' ')))",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE oauth_clients \
+                 SET scope = array_to_string(array_remove(string_to_array(scope, ' '), 'admin'), ' ')",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
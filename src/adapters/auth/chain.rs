@@ -0,0 +1,54 @@
+use crate::application::auth::{
+    authenticated_user::AuthenticatedUser, authenticator::Authenticator, credentials::Credentials,
+    error::AuthenticationError,
+};
+
+/// Combines two authenticators. The secondary one is tried when the primary one does not
+/// support the credentials, does not know them, or is unavailable; e.g. local accounts keep
+/// working while a directory is down.
+#[derive(Clone)]
+pub struct ChainAuthenticator<A, B>
+where
+    A: Authenticator,
+    B: Authenticator,
+{
+    primary: A,
+    secondary: B,
+}
+
+impl<A, B> ChainAuthenticator<A, B>
+where
+    A: Authenticator,
+    B: Authenticator,
+{
+    pub fn new(primary: A, secondary: B) -> Self {
+        Self { primary, secondary }
+    }
+}
+
+impl<A, B> Authenticator for ChainAuthenticator<A, B>
+where
+    A: Authenticator,
+    B: Authenticator,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let error: AuthenticationError = match self.primary.authenticate(credentials.clone()).await
+        {
+            Err(
+                error @ (AuthenticationError::UnsupportedCredentials
+                | AuthenticationError::InvalidCredentials
+                | AuthenticationError::UserNotFound
+                | AuthenticationError::ProviderUnavailable),
+            ) => error,
+            result => return result,
+        };
+
+        match self.secondary.authenticate(credentials).await {
+            Err(AuthenticationError::UnsupportedCredentials) => Err(error),
+            result => result,
+        }
+    }
+}
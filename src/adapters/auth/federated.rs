@@ -0,0 +1,329 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_user::AuthenticatedUser, authenticator::Authenticator,
+            credentials::Credentials, error::AuthenticationError,
+        },
+        federation::identity_provider::{ExternalClaims, FederationError, IdentityProviders},
+        registration::register::RegistrationMode,
+    },
+    domain::{
+        federation::{entity::ExternalIdentity, repository::ExternalIdentityRepository},
+        organization::entity::Organization,
+        user::{
+            entity::User,
+            password_hasher::PasswordHasher,
+            repository::UserRepository,
+            value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        },
+    },
+};
+use log::warn;
+use rand::{RngCore, rngs::OsRng};
+use uuid::Uuid;
+
+const MAX_USERNAME_LENGTH: usize = 32;
+const FALLBACK_USERNAME: &str = "user";
+const NUMBERED_ATTEMPTS: u32 = 5;
+
+/// Authenticates users returning from an upstream identity provider.
+///
+/// Accounts are matched on the provider's subject only, never on a username or email the
+/// provider asserts. Unknown accounts get a new local user in the default organization, with an
+/// unusable local password, but only while registration is open: logging in through a provider
+/// is no way around closed or invite-only registration.
+#[derive(Clone)]
+pub struct FederatedAuthenticator<U, E, P, H>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    P: IdentityProviders,
+    H: PasswordHasher,
+{
+    user_repository: U,
+    identity_repository: E,
+    providers: P,
+    hasher: H,
+    registration_mode: RegistrationMode,
+}
+
+impl<U, E, P, H> FederatedAuthenticator<U, E, P, H>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    P: IdentityProviders,
+    H: PasswordHasher,
+{
+    pub fn new(
+        user_repository: U,
+        identity_repository: E,
+        providers: P,
+        hasher: H,
+        registration_mode: RegistrationMode,
+    ) -> Self {
+        Self {
+            user_repository,
+            identity_repository,
+            providers,
+            hasher,
+            registration_mode,
+        }
+    }
+
+    async fn provision(
+        &self,
+        provider: String,
+        claims: ExternalClaims,
+    ) -> Result<User, AuthenticationError> {
+        if self.registration_mode != RegistrationMode::Open {
+            return Err(AuthenticationError::UserNotFound);
+        }
+
+        let username: Username = self.available_username(&claims).await?;
+        let name: Name = claims
+            .name
+            .and_then(|name| Name::new(name).ok())
+            .or_else(|| Name::new(username.as_str().to_owned()).ok())
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        let mut secret: [u8; 32] = [0; 32];
+        OsRng.fill_bytes(&mut secret);
+        let password_hash: PasswordHash = PasswordHash::new(self.hasher.hash(&hex::encode(secret)))
+            .map_err(|_| AuthenticationError::ProviderUnavailable)?;
+
+        let user: User = self
+            .user_repository
+            .create(User::new(
+                Uuid::now_v7(),
+                name,
+                username,
+                password_hash,
+                None,
+                None,
+            ))
+            .await?;
+
+        let identity: ExternalIdentity =
+            ExternalIdentity::new(Uuid::now_v7(), user.id, provider, claims.subject);
+
+        if let Err(error) = self.identity_repository.create(identity).await {
+            // Do not leave behind a user nobody can log in as.
+            self.user_repository.delete(&user.id).await?;
+
+            return Err(error.into());
+        }
+
+        Ok(user)
+    }
+
+    /// Derives a free local username from what the provider knows about the user.
+    async fn available_username(
+        &self,
+        claims: &ExternalClaims,
+    ) -> Result<Username, AuthenticationError> {
+        let base: String = [
+            claims.preferred_username.as_deref(),
+            claims
+                .email
+                .as_deref()
+                .and_then(|email| email.split('@').next()),
+            claims.name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(sanitize_username)
+        .find(|candidate| candidate.len() >= 3)
+        .unwrap_or_else(|| FALLBACK_USERNAME.to_owned());
+
+        let numbered = (2..=NUMBERED_ATTEMPTS + 1).map(|n| n.to_string());
+        let random = std::iter::repeat_with(|| {
+            let mut suffix: [u8; 4] = [0; 4];
+            OsRng.fill_bytes(&mut suffix);
+            hex::encode(suffix)
+        })
+        .take(3);
+
+        for suffix in std::iter::once(String::new()).chain(numbered).chain(random) {
+            let candidate: String = if suffix.is_empty() {
+                base.clone()
+            } else {
+                let length: usize = MAX_USERNAME_LENGTH - suffix.len() - 1;
+                format!("{}-{}", &base[..base.len().min(length)], suffix)
+            };
+
+            let Ok(username) = Username::new(candidate) else {
+                continue;
+            };
+
+            if !self
+                .user_repository
+                .is_username_taken(&Organization::DEFAULT_ID, &username)
+                .await?
+            {
+                return Ok(username);
+            }
+        }
+
+        Err(AuthenticationError::ProviderUnavailable)
+    }
+}
+
+impl<U, E, P, H> Authenticator for FederatedAuthenticator<U, E, P, H>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    P: IdentityProviders,
+    H: PasswordHasher,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let Credentials::AuthorizationCode {
+            provider,
+            code,
+            state,
+        } = credentials
+        else {
+            return Err(AuthenticationError::UnsupportedCredentials);
+        };
+
+        let claims: ExternalClaims = self.providers.complete(&provider, &code, &state).await?;
+
+        let user: User = match self
+            .identity_repository
+            .find(&provider, &claims.subject)
+            .await?
+        {
+            Some(identity) => self
+                .user_repository
+                .find_by_id(&identity.user_id)
+                .await?
+                .ok_or(AuthenticationError::UserNotFound)?,
+            None => self.provision(provider, claims).await?,
+        };
+
+        if !user.is_active() {
+            return Err(AuthenticationError::UserInactive);
+        }
+
+        Ok(AuthenticatedUser::from(user))
+    }
+}
+
+impl From<FederationError> for AuthenticationError {
+    fn from(error: FederationError) -> Self {
+        match error {
+            FederationError::Unavailable => AuthenticationError::ProviderUnavailable,
+            FederationError::UnknownProvider
+            | FederationError::InvalidState
+            | FederationError::InvalidResponse => {
+                warn!("Federated login failed: {:?}", error);
+
+                AuthenticationError::InvalidCredentials
+            }
+        }
+    }
+}
+
+/// Keeps the characters usernames allow, truncated to the maximum length.
+fn sanitize_username(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+        .take(MAX_USERNAME_LENGTH)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::persistence::memory::{
+            InMemoryExternalIdentityRepository, InMemoryUserRepository, PlainPasswordHasher,
+        },
+        application::federation::identity_provider::AuthorizationRequest,
+    };
+
+    /// A provider that vouches for one subject, whatever the code.
+    #[derive(Clone)]
+    struct StandInProvider;
+
+    #[async_trait::async_trait]
+    impl IdentityProviders for StandInProvider {
+        async fn authorization_request(
+            &self,
+            _provider: &str,
+        ) -> Result<AuthorizationRequest, FederationError> {
+            Err(FederationError::Unavailable)
+        }
+
+        async fn complete(
+            &self,
+            _provider: &str,
+            _code: &str,
+            _state: &str,
+        ) -> Result<ExternalClaims, FederationError> {
+            Ok(ExternalClaims {
+                subject: "subject".into(),
+                preferred_username: Some("alice".into()),
+                name: Some("Alice".into()),
+                email: None,
+            })
+        }
+    }
+
+    type TestAuthenticator = FederatedAuthenticator<
+        InMemoryUserRepository,
+        InMemoryExternalIdentityRepository,
+        StandInProvider,
+        PlainPasswordHasher,
+    >;
+
+    fn authenticator(mode: RegistrationMode) -> TestAuthenticator {
+        FederatedAuthenticator::new(
+            InMemoryUserRepository::default(),
+            InMemoryExternalIdentityRepository::default(),
+            StandInProvider,
+            PlainPasswordHasher,
+            mode,
+        )
+    }
+
+    fn credentials() -> Credentials {
+        Credentials::AuthorizationCode {
+            provider: "upstream".into(),
+            code: "code".into(),
+            state: "state".into(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn provisions_unknown_accounts_while_registration_is_open() {
+        let authenticator: TestAuthenticator = authenticator(RegistrationMode::Open);
+
+        let user: AuthenticatedUser = authenticator.authenticate(credentials()).await.unwrap();
+
+        assert_eq!(user.username, "alice");
+        assert_eq!(authenticator.user_repository.all().len(), 1);
+
+        // The identity is linked, so the next login finds the same user.
+        let again: AuthenticatedUser = authenticator.authenticate(credentials()).await.unwrap();
+
+        assert_eq!(again.id, user.id);
+        assert_eq!(authenticator.user_repository.all().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_provision_accounts_unless_registration_is_open() {
+        for mode in [RegistrationMode::Closed, RegistrationMode::InviteOnly] {
+            let authenticator: TestAuthenticator = authenticator(mode);
+
+            let result: Result<AuthenticatedUser, AuthenticationError> =
+                authenticator.authenticate(credentials()).await;
+
+            assert!(matches!(result, Err(AuthenticationError::UserNotFound)));
+            assert!(authenticator.user_repository.all().is_empty());
+        }
+    }
+}
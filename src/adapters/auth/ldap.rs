@@ -0,0 +1,737 @@
+use crate::{
+    adapters::ldap::connection::{INVALID_CREDENTIALS, LdapConnection, LdapError, SearchEntry},
+    application::{
+        auth::{
+            authenticated_user::AuthenticatedUser, authenticator::Authenticator,
+            credentials::Credentials, error::AuthenticationError,
+        },
+        security::revocation_store::RevocationStore,
+    },
+    config::ldap::ports::{LdapConfig, USERNAME_PLACEHOLDER},
+    domain::{
+        federation::{entity::ExternalIdentity, repository::ExternalIdentityRepository},
+        organization::entity::Organization,
+        role::entity::RoleName,
+        user::{
+            entity::User,
+            password_hasher::PasswordHasher,
+            patch::UserPatch,
+            repository::UserRepository,
+            value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        },
+    },
+};
+use actix_web::rt::task::spawn_blocking;
+use log::warn;
+use rand::{RngCore, rngs::OsRng};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+use uuid::Uuid;
+
+/// Provider name of the links between directory accounts and local users, whose subject is
+/// the organization and the username.
+const LDAP_PROVIDER: &str = "ldap";
+
+/// Authenticates users by binding as them against an LDAP directory.
+///
+/// On first login a local user is provisioned in the organization signed in to and linked to
+/// the directory account. A local user with the same username is only linked when the
+/// configuration allows it, otherwise they keep their own password. The role of linked users
+/// follows the directory groups on every login, unless they were given a role the directory
+/// does not hand out, and tokens issued under the former role are revoked when it changes.
+/// Provisioned users get an unusable local password, so they cannot log in while the directory
+/// is down. Their password expires in the directory rather than here, but like local users they
+/// are asked for their second factor if they enrolled one.
+///
+/// Only the organizations configured for the directory can be signed in to, others are left
+/// to the next authenticator.
+#[derive(Clone)]
+pub struct LdapAuthenticator<U, E, H, S>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+{
+    user_repository: U,
+    identity_repository: E,
+    hasher: H,
+    revocation_store: S,
+    config: Arc<LdapConfig>,
+}
+
+/// What the directory knows about an authenticated user.
+struct DirectoryUser {
+    name: Option<String>,
+    role: RoleName,
+}
+
+impl<U, E, H, S> LdapAuthenticator<U, E, H, S>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+{
+    pub fn new(
+        user_repository: U,
+        identity_repository: E,
+        hasher: H,
+        revocation_store: S,
+        config: LdapConfig,
+    ) -> Self {
+        Self {
+            user_repository,
+            identity_repository,
+            hasher,
+            revocation_store,
+            config: Arc::new(config),
+        }
+    }
+
+    /// Whether directory users may sign in to the organization.
+    fn serves(&self, organization_id: &Uuid) -> bool {
+        match self.config.organizations.is_empty() {
+            true => organization_id == &Organization::DEFAULT_ID,
+            false => self.config.organizations.contains(organization_id),
+        }
+    }
+
+    async fn provision(
+        &self,
+        organization_id: Uuid,
+        username: Username,
+        directory_user: DirectoryUser,
+    ) -> Result<User, AuthenticationError> {
+        let subject: String = format!("{}:{}", organization_id, username.as_str());
+
+        if let Some(identity) = self
+            .identity_repository
+            .find(LDAP_PROVIDER, &subject)
+            .await?
+        {
+            let user: User = self
+                .user_repository
+                .find_by_id(&identity.user_id)
+                .await?
+                .ok_or(AuthenticationError::UserNotFound)?;
+
+            return self.sync_role(user, directory_user.role).await;
+        }
+
+        if let Some(user) = self
+            .user_repository
+            .find_by_username(&organization_id, &username)
+            .await?
+        {
+            // The local user keeps signing in with their own password.
+            if !self.config.link_local_users {
+                return Err(AuthenticationError::UnsupportedCredentials);
+            }
+
+            self.link(&user, subject).await?;
+
+            return self.sync_role(user, directory_user.role).await;
+        }
+
+        // The username of a deleted user stays reserved until the user is purged.
+        if self
+            .user_repository
+            .is_username_taken(&organization_id, &username)
+            .await?
+        {
+            return Err(AuthenticationError::UserInactive);
+        }
+
+        let name: Name = directory_user
+            .name
+            .and_then(|name| Name::new(name).ok())
+            .or_else(|| Name::new(username.as_str().to_owned()).ok())
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        let mut secret: [u8; 32] = [0; 32];
+        OsRng.fill_bytes(&mut secret);
+        let password_hash: PasswordHash = PasswordHash::new(self.hasher.hash(&hex::encode(secret)))
+            .map_err(|_| AuthenticationError::ProviderUnavailable)?;
+
+        let mut user: User = User::new(
+            Uuid::now_v7(),
+            name,
+            username,
+            password_hash,
+            Some(directory_user.role),
+            None,
+        );
+        user.organization_id = organization_id;
+
+        let user: User = self.user_repository.create(user).await?;
+
+        if let Err(error) = self.link(&user, subject).await {
+            // Do not leave behind a user the directory account is not linked to.
+            self.user_repository.delete(&user.id).await?;
+
+            return Err(error);
+        }
+
+        Ok(user)
+    }
+
+    async fn link(&self, user: &User, subject: String) -> Result<(), AuthenticationError> {
+        self.identity_repository
+            .create(ExternalIdentity::new(
+                Uuid::now_v7(),
+                user.id,
+                LDAP_PROVIDER.into(),
+                subject,
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gives the user the role of their directory groups, unless an administrator gave them a
+    /// role the directory does not hand out. Tokens issued under the former role are revoked.
+    async fn sync_role(&self, user: User, role: RoleName) -> Result<User, AuthenticationError> {
+        if !user.is_active() {
+            return Err(AuthenticationError::UserInactive);
+        }
+
+        if user.role == role || !is_directory_role(&user.role) {
+            return Ok(user);
+        }
+
+        let patch: UserPatch = UserPatch::new(None, None, None, Some(role), None, None);
+        let user: User = self.user_repository.update(&user.id, patch).await?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| AuthenticationError::ProviderUnavailable)?
+            .as_millis() as u64;
+        self.revocation_store.revoke_all(&user.id, now).await?;
+
+        Ok(user)
+    }
+}
+
+impl<U, E, H, S> Authenticator for LdapAuthenticator<U, E, H, S>
+where
+    U: UserRepository,
+    E: ExternalIdentityRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let Credentials::UsernamePassword {
+            organization_id,
+            username,
+            password,
+        } = credentials
+        else {
+            return Err(AuthenticationError::UnsupportedCredentials);
+        };
+
+        if !self.serves(&organization_id) {
+            return Err(AuthenticationError::UnsupportedCredentials);
+        }
+
+        // An empty password would be an unauthenticated bind, which servers accept.
+        if password.as_str().is_empty() {
+            return Err(AuthenticationError::InvalidCredentials);
+        }
+
+        let config: Arc<LdapConfig> = self.config.clone();
+        let login: String = username.as_str().to_owned();
+        let password: String = password.as_str().to_owned();
+
+        let directory_user: DirectoryUser =
+            spawn_blocking(move || lookup(&config, &login, &password))
+                .await
+                .map_err(|_| AuthenticationError::ProviderUnavailable)??;
+
+        let user: User = self
+            .provision(organization_id, username, directory_user)
+            .await?;
+
+        Ok(AuthenticatedUser::from(user))
+    }
+}
+
+fn lookup(
+    config: &LdapConfig,
+    username: &str,
+    password: &str,
+) -> Result<DirectoryUser, AuthenticationError> {
+    let url: Url = Url::parse(&config.url).map_err(|_| AuthenticationError::ProviderUnavailable)?;
+    let timeout: Duration = Duration::from_secs(config.timeout);
+    let bind_dn: String = config
+        .bind_dn
+        .replace(USERNAME_PLACEHOLDER, &escape_dn_value(username));
+
+    let mut connection: LdapConnection =
+        LdapConnection::connect(&url, timeout).map_err(unavailable)?;
+
+    match connection.simple_bind(&bind_dn, password) {
+        Ok(()) => {}
+        Err(LdapError::Result(INVALID_CREDENTIALS)) => {
+            return Err(AuthenticationError::InvalidCredentials);
+        }
+        Err(error) => return Err(unavailable(error)),
+    }
+
+    let mut entries: Vec<SearchEntry> = connection
+        .search(
+            &config.base_dn,
+            &config.username_attribute,
+            username,
+            &[&config.name_attribute, &config.group_attribute],
+            timeout,
+        )
+        .map_err(unavailable)?;
+
+    connection.unbind();
+
+    // The bind succeeded, but the account is not one of the users this server serves.
+    if entries.len() != 1 {
+        return Err(AuthenticationError::InvalidCredentials);
+    }
+
+    let entry: SearchEntry = entries.remove(0);
+    let groups: &[String] = entry.values(&config.group_attribute);
+    let is_member = |group: &String| groups.iter().any(|g| g.eq_ignore_ascii_case(group));
+
+    if config
+        .user_group
+        .as_ref()
+        .is_some_and(|group| !is_member(group))
+    {
+        return Err(AuthenticationError::InvalidCredentials);
+    }
+
+    let role: RoleName = match &config.admin_group {
+        Some(group) if is_member(group) => RoleName::administrator(),
+        _ => RoleName::user(),
+    };
+
+    Ok(DirectoryUser {
+        name: entry.values(&config.name_attribute).first().cloned(),
+        role,
+    })
+}
+
+/// The roles directory groups map to, the only ones logins change.
+fn is_directory_role(role: &RoleName) -> bool {
+    role == &RoleName::administrator() || role == &RoleName::user()
+}
+
+fn unavailable(error: LdapError) -> AuthenticationError {
+    warn!("LDAP directory unavailable: {}", error);
+
+    AuthenticationError::ProviderUnavailable
+}
+
+/// Escapes an attribute value for use in a DN (RFC 4514, section 2.4).
+fn escape_dn_value(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+    let last: usize = value.chars().count().saturating_sub(1);
+
+    for (index, c) in value.chars().enumerate() {
+        match c {
+            '"' | '+' | ',' | ';' | '<' | '>' | '\\' | '=' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '#' | ' ' if index == 0 => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' ' if index == last => escaped.push_str("\\ "),
+            '\0' => escaped.push_str("\\00"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::{
+            ldap::stand_in::{Entry, Request, StandInLdap},
+            persistence::memory::{
+                InMemoryExternalIdentityRepository, InMemoryRevocationStore,
+                InMemoryUserRepository, PlainPasswordHasher,
+            },
+        },
+        application::security::revocation_store::RevocationStore,
+        config::ldap::ports::{
+            DEFAULT_GROUP_ATTRIBUTE, DEFAULT_NAME_ATTRIBUTE, DEFAULT_TIMEOUT,
+            DEFAULT_USERNAME_ATTRIBUTE,
+        },
+        domain::user::value_objects::password_plain::PasswordPlain,
+    };
+
+    const ADMINS: &str = "cn=admins,dc=example,dc=org";
+    const STAFF: &str = "cn=staff,dc=example,dc=org";
+
+    type TestAuthenticator = LdapAuthenticator<
+        InMemoryUserRepository,
+        InMemoryExternalIdentityRepository,
+        PlainPasswordHasher,
+        InMemoryRevocationStore,
+    >;
+
+    struct Fixture {
+        directory: StandInLdap,
+        users: InMemoryUserRepository,
+        identities: InMemoryExternalIdentityRepository,
+        revocation_store: InMemoryRevocationStore,
+    }
+
+    impl Fixture {
+        fn new(users: Vec<User>) -> Self {
+            Self {
+                directory: StandInLdap::start(vec![
+                    Entry::new(
+                        "uid=alice,ou=people,dc=example,dc=org",
+                        "alice-secret",
+                        &[
+                            ("uid", &["alice"]),
+                            ("cn", &["Alice Example"]),
+                            ("memberOf", &[ADMINS, STAFF]),
+                        ],
+                    ),
+                    Entry::new(
+                        "uid=bob,ou=people,dc=example,dc=org",
+                        "bob-secret",
+                        &[("uid", &["bob"]), ("memberOf", &[STAFF])],
+                    ),
+                    Entry::new(
+                        "uid=carol,ou=people,dc=example,dc=org",
+                        "carol-secret",
+                        &[("uid", &["carol"]), ("cn", &["Carol Example"])],
+                    ),
+                ]),
+                users: InMemoryUserRepository::with(users),
+                identities: InMemoryExternalIdentityRepository::default(),
+                revocation_store: InMemoryRevocationStore::default(),
+            }
+        }
+
+        fn config(&self) -> LdapConfig {
+            LdapConfig {
+                url: self.directory.url(),
+                bind_dn: format!("uid={},ou=people,dc=example,dc=org", USERNAME_PLACEHOLDER),
+                base_dn: "dc=example,dc=org".into(),
+                username_attribute: DEFAULT_USERNAME_ATTRIBUTE.into(),
+                name_attribute: DEFAULT_NAME_ATTRIBUTE.into(),
+                group_attribute: DEFAULT_GROUP_ATTRIBUTE.into(),
+                admin_group: Some(ADMINS.into()),
+                user_group: Some(STAFF.into()),
+                timeout: DEFAULT_TIMEOUT,
+                organizations: Vec::new(),
+                link_local_users: false,
+            }
+        }
+
+        fn authenticator(&self, config: LdapConfig) -> TestAuthenticator {
+            LdapAuthenticator::new(
+                self.users.clone(),
+                self.identities.clone(),
+                PlainPasswordHasher,
+                self.revocation_store.clone(),
+                config,
+            )
+        }
+    }
+
+    fn credentials(organization_id: Uuid, username: &str, password: &str) -> Credentials {
+        Credentials::UsernamePassword {
+            organization_id,
+            username: Username::new(username.into()).unwrap(),
+            password: PasswordPlain::new(password.into()).unwrap(),
+        }
+    }
+
+    fn local_user(username: &str, role: RoleName) -> User {
+        User::new(
+            Uuid::now_v7(),
+            Name::new(format!("Local {}", username)).unwrap(),
+            Username::new(username.into()).unwrap(),
+            PasswordHash::new(PlainPasswordHasher.hash("local-secret")).unwrap(),
+            Some(role),
+            None,
+        )
+    }
+
+    #[actix_web::test]
+    async fn provisions_and_links_a_user_on_first_login() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let authenticator: TestAuthenticator = fixture.authenticator(fixture.config());
+
+        let user: AuthenticatedUser = authenticator
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(user.username, "alice");
+        assert_eq!(user.roles, vec![RoleName::administrator()]);
+
+        let stored: Vec<User> = fixture.users.all();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].name.as_str(), "Alice Example");
+        assert_eq!(
+            fixture
+                .identities
+                .links(LDAP_PROVIDER)
+                .get(&format!("{}:alice", Organization::DEFAULT_ID)),
+            Some(&user.id)
+        );
+        assert_eq!(
+            fixture.directory.requests(),
+            vec![
+                Request::Bind {
+                    dn: "uid=alice,ou=people,dc=example,dc=org".into(),
+                    password: "alice-secret".into(),
+                },
+                Request::Search {
+                    base: "dc=example,dc=org".into(),
+                    attribute: "uid".into(),
+                    value: "alice".into(),
+                },
+                Request::Unbind,
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn reuses_the_linked_user_on_later_logins() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let authenticator: TestAuthenticator = fixture.authenticator(fixture.config());
+
+        let first: AuthenticatedUser = authenticator
+            .authenticate(credentials(Organization::DEFAULT_ID, "bob", "bob-secret"))
+            .await
+            .unwrap();
+        let second: AuthenticatedUser = authenticator
+            .authenticate(credentials(Organization::DEFAULT_ID, "bob", "bob-secret"))
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.roles, vec![RoleName::user()]);
+        // Without a name in the directory, the username is used.
+        assert_eq!(fixture.users.all()[0].name.as_str(), "bob");
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_wrong_password() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let authenticator: TestAuthenticator = fixture.authenticator(fixture.config());
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = authenticator
+            .authenticate(credentials(Organization::DEFAULT_ID, "alice", "wrong"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+        assert!(fixture.users.all().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn rejects_directory_users_outside_the_user_group() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let authenticator: TestAuthenticator = fixture.authenticator(fixture.config());
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = authenticator
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "carol",
+                "carol-secret",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn reports_an_unreachable_directory_as_unavailable() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let closed: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url: String = format!("ldap://{}", closed.local_addr().unwrap());
+        drop(closed);
+
+        let authenticator: TestAuthenticator = fixture.authenticator(LdapConfig {
+            url,
+            ..fixture.config()
+        });
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = authenticator
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::ProviderUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn leaves_organizations_it_does_not_serve_to_the_next_authenticator() {
+        let fixture: Fixture = Fixture::new(Vec::new());
+        let authenticator: TestAuthenticator = fixture.authenticator(fixture.config());
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = authenticator
+            .authenticate(credentials(Uuid::now_v7(), "alice", "alice-secret"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::UnsupportedCredentials)
+        ));
+        assert!(fixture.directory.requests().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn does_not_take_over_local_users_unless_configured() {
+        let local: User = local_user("alice", RoleName::user());
+        let fixture: Fixture = Fixture::new(vec![local.clone()]);
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = fixture
+            .authenticator(fixture.config())
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::UnsupportedCredentials)
+        ));
+        assert!(fixture.identities.links(LDAP_PROVIDER).is_empty());
+
+        let user: AuthenticatedUser = fixture
+            .authenticator(LdapConfig {
+                link_local_users: true,
+                ..fixture.config()
+            })
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(user.id, local.id);
+        assert_eq!(user.roles, vec![RoleName::administrator()]);
+        assert_eq!(fixture.identities.links(LDAP_PROVIDER).len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn revokes_tokens_when_the_directory_role_changes() {
+        let local: User = local_user("alice", RoleName::user());
+        let fixture: Fixture = Fixture::new(vec![local.clone()]);
+        let config: LdapConfig = LdapConfig {
+            link_local_users: true,
+            ..fixture.config()
+        };
+
+        fixture
+            .authenticator(config)
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await
+            .unwrap();
+
+        assert!(
+            fixture
+                .revocation_store
+                .not_before(&local.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[actix_web::test]
+    async fn keeps_roles_the_directory_does_not_hand_out() {
+        let auditor: RoleName = RoleName::new("auditor".into()).unwrap();
+        let local: User = local_user("bob", auditor.clone());
+        let fixture: Fixture = Fixture::new(vec![local.clone()]);
+        let config: LdapConfig = LdapConfig {
+            link_local_users: true,
+            ..fixture.config()
+        };
+
+        let user: AuthenticatedUser = fixture
+            .authenticator(config)
+            .authenticate(credentials(Organization::DEFAULT_ID, "bob", "bob-secret"))
+            .await
+            .unwrap();
+
+        assert_eq!(user.roles, vec![auditor]);
+        assert!(
+            fixture
+                .revocation_store
+                .not_before(&local.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[actix_web::test]
+    async fn refuses_usernames_of_deleted_users() {
+        let mut deleted: User = local_user("alice", RoleName::user());
+        deleted.deleted_at = Some(1);
+        let fixture: Fixture = Fixture::new(vec![deleted]);
+
+        let result: Result<AuthenticatedUser, AuthenticationError> = fixture
+            .authenticator(fixture.config())
+            .authenticate(credentials(
+                Organization::DEFAULT_ID,
+                "alice",
+                "alice-secret",
+            ))
+            .await;
+
+        assert!(matches!(result, Err(AuthenticationError::UserInactive)));
+        assert_eq!(fixture.users.all().len(), 1);
+    }
+
+    #[test]
+    fn escapes_special_characters_in_dn_values() {
+        assert_eq!(escape_dn_value("alice"), "alice");
+        assert_eq!(escape_dn_value("a,b=c+d"), "a\\,b\\=c\\+d");
+        assert_eq!(escape_dn_value("#admin"), "\\#admin");
+        assert_eq!(escape_dn_value(" alice "), "\\ alice\\ ");
+        assert_eq!(escape_dn_value("a\\b\0"), "a\\\\b\\00");
+        assert_eq!(escape_dn_value("\"<x>;\""), r#"\"\<x\>\;\""#);
+    }
+}
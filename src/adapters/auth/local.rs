@@ -1,61 +1,239 @@
-use uuid::Uuid;
-
 use crate::{
     application::{
         auth::{
             authenticated_user::AuthenticatedUser, authenticator::Authenticator,
             credentials::Credentials, error::AuthenticationError,
         },
-        security::{error::TokenError, token_service::TokenService},
+        security::{
+            error::TokenError, revocation_store::RevocationStore, token::RefreshGrant,
+            token_service::TokenService,
+        },
     },
     domain::{
         errors::repository::RepositoryError,
-        user::{entity::User, password_hasher::PasswordHasher, repository::UserRepository},
+        mfa::{entity::TotpEnrollment, repository::TotpRepository, totp::TotpProvider},
+        user::{
+            entity::User,
+            password_hasher::{HashError, PasswordHasher, PasswordMatch},
+            repository::UserRepository,
+            value_objects::{password_hash::PasswordHash, password_plain::PasswordPlain},
+        },
     },
 };
+use log::warn;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
 
 #[derive(Clone)]
-pub struct LocalAuthenticator<U, H, T>
+pub struct LocalAuthenticator<U, H, T, M, P, R>
 where
     U: UserRepository,
     H: PasswordHasher,
     T: TokenService,
+    M: TotpRepository,
+    P: TotpProvider,
+    R: RevocationStore,
 {
     user_repository: U,
     hasher: H,
     token_service: T,
+    totp_repository: M,
+    totp_provider: P,
+    revocation_store: R,
+    /// Seconds after which passwords expire, if they do.
+    password_max_age: Option<u64>,
+    /// Verified against when the user does not exist, so that unknown usernames take as long
+    /// to reject as wrong passwords.
+    dummy_hash: String,
 }
 
-impl<U, H, T> LocalAuthenticator<U, H, T>
+impl<U, H, T, M, P, R> LocalAuthenticator<U, H, T, M, P, R>
 where
     U: UserRepository,
     H: PasswordHasher,
     T: TokenService,
+    M: TotpRepository,
+    P: TotpProvider,
+    R: RevocationStore,
 {
-    pub fn new(user_repository: U, hasher: H, token_service: T) -> Self {
+    pub fn new(
+        user_repository: U,
+        hasher: H,
+        token_service: T,
+        totp_repository: M,
+        totp_provider: P,
+        revocation_store: R,
+        password_max_age: Option<u64>,
+    ) -> Self {
+        let dummy_hash: String = hasher.hash("windwatcher-dummy-password");
+
         Self {
             user_repository,
             hasher,
             token_service,
+            totp_repository,
+            totp_provider,
+            revocation_store,
+            password_max_age,
+            dummy_hash,
+        }
+    }
+
+    /// Replaces an outdated password hash. Failing to is no reason to refuse the login, the
+    /// next one tries again.
+    async fn rehash(&self, user: &User, password: &str) {
+        let rehashed: Result<(), RepositoryError> =
+            match PasswordHash::new(self.hasher.hash(password)) {
+                Ok(password_hash) => {
+                    self.user_repository
+                        .rehash_password(&user.id, &password_hash)
+                        .await
+                }
+                Err(_) => Err(RepositoryError::InvariantViolation),
+            };
+
+        if rehashed.is_err() {
+            warn!("Failed to upgrade the password hash of user {}", user.id);
+        }
+    }
+
+    /// Checks the password of a local user, who then still has to pass the second factor if
+    /// they enrolled one, see `SecondFactorAuthenticator`.
+    async fn authenticate_password(
+        &self,
+        user: Option<User>,
+        password: &PasswordPlain,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let Some(user) = user else {
+            let _ = self.hasher.verify(password.as_str(), &self.dummy_hash);
+
+            return Err(AuthenticationError::UserNotFound);
+        };
+
+        match self
+            .hasher
+            .verify(password.as_str(), user.password_hash.as_str())?
+        {
+            PasswordMatch::Mismatch => return Err(AuthenticationError::InvalidCredentials),
+            PasswordMatch::Match => {}
+            PasswordMatch::Outdated => self.rehash(&user, password.as_str()).await,
+        }
+
+        // Only told once the password is known, so it reveals nothing to guessers.
+        if !user.is_active() {
+            return Err(AuthenticationError::UserInactive);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| AuthenticationError::ProviderUnavailable)?
+            .as_secs();
+
+        if user.is_password_expired(self.password_max_age, now) {
+            return Err(AuthenticationError::PasswordExpired);
+        }
+
+        Ok(AuthenticatedUser::from(user))
+    }
+
+    /// Accepts either a TOTP code or one of the user's unused recovery codes. Recovery codes
+    /// are consumed on use, and TOTP codes cannot be replayed.
+    async fn verify_second_factor(
+        &self,
+        user_id: &Uuid,
+        code: &str,
+    ) -> Result<(), AuthenticationError> {
+        let mut enrollment: TotpEnrollment = self
+            .totp_repository
+            .find_by_user_id(user_id)
+            .await?
+            .filter(TotpEnrollment::is_active)
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        if let Some(step) =
+            self.totp_provider
+                .verify(&enrollment.secret, code, enrollment.last_used_step)
+        {
+            enrollment.last_used_step = Some(step);
+            self.totp_repository.save(enrollment).await?;
+
+            return Ok(());
+        }
+
+        let code: String = code.trim().to_ascii_lowercase();
+        let mut position: Option<usize> = None;
+
+        for (index, hash) in enrollment.recovery_codes.iter().enumerate() {
+            if self.hasher.verify(&code, hash.as_str())?.is_match() {
+                position = Some(index);
+                break;
+            }
+        }
+
+        match position {
+            Some(position) => {
+                enrollment.recovery_codes.remove(position);
+                self.totp_repository.save(enrollment).await?;
+
+                Ok(())
+            }
+            None => Err(AuthenticationError::InvalidCredentials),
         }
     }
 }
 
-impl<U, H, T> Authenticator for LocalAuthenticator<U, H, T>
+impl<U, H, T, M, P, R> Authenticator for LocalAuthenticator<U, H, T, M, P, R>
 where
     U: UserRepository,
     H: PasswordHasher,
     T: TokenService,
+    M: TotpRepository,
+    P: TotpProvider,
+    R: RevocationStore,
 {
     async fn authenticate(
         &self,
         credentials: Credentials,
     ) -> Result<AuthenticatedUser, AuthenticationError> {
         match credentials {
-            Credentials::UsernamePassword { username, password } => {
+            Credentials::UsernamePassword {
+                organization_id,
+                username,
+                password,
+            } => {
+                let user: Option<User> = self
+                    .user_repository
+                    .find_by_username(&organization_id, &username)
+                    .await?;
+
+                self.authenticate_password(user, &password).await
+            }
+            Credentials::EmailPassword { email, password } => {
+                // Only a verified address identifies a user, anyone can claim an address.
+                let user: Option<User> = self
+                    .user_repository
+                    .find_by_email(&email)
+                    .await?
+                    .filter(|user| user.verified_email().is_some());
+
+                self.authenticate_password(user, &password).await
+            }
+            Credentials::RefreshToken(refresh_token) => {
+                let grant: RefreshGrant = self.token_service.verify_refresh(&refresh_token)?;
+
+                if self.revocation_store.is_revoked(&grant.token_id).await?
+                    || self
+                        .revocation_store
+                        .not_before(&grant.user_id)
+                        .await?
+                        .is_some_and(|not_before| grant.issued_at < not_before)
+                {
+                    return Err(AuthenticationError::InvalidCredentials);
+                }
+
                 let user: User = self
                     .user_repository
-                    .find_by_username(&username)
+                    .find_by_id(&grant.user_id)
                     .await?
                     .ok_or(AuthenticationError::UserNotFound)?;
 
@@ -63,17 +241,16 @@ where
                     return Err(AuthenticationError::UserInactive);
                 }
 
-                if !self
-                    .hasher
-                    .verify(password.as_str(), user.password_hash.as_str())
-                {
-                    return Err(AuthenticationError::InvalidCredentials);
-                }
+                // Left in the home organization, the login activates the one of the grant.
+                let mut authenticated: AuthenticatedUser = AuthenticatedUser::from(user);
+                authenticated.restrict_scopes(&grant.scopes);
+                authenticated.client_id = grant.client_id;
+                authenticated.session_id = grant.session_id;
 
-                Ok(AuthenticatedUser::from(user))
+                Ok(authenticated)
             }
-            Credentials::RefreshToken(refresh_token) => {
-                let id: Uuid = self.token_service.verify_refresh(&refresh_token)?;
+            Credentials::MfaOtp { mfa_token, code } => {
+                let id: Uuid = self.token_service.verify_mfa(&mfa_token)?;
                 let user: User = self
                     .user_repository
                     .find_by_id(&id)
@@ -84,8 +261,13 @@ where
                     return Err(AuthenticationError::UserInactive);
                 }
 
+                self.verify_second_factor(&user.id, &code).await?;
+
                 Ok(AuthenticatedUser::from(user))
             }
+            Credentials::Passkey { .. } | Credentials::AuthorizationCode { .. } => {
+                Err(AuthenticationError::UnsupportedCredentials)
+            }
         }
     }
 }
@@ -96,6 +278,12 @@ impl From<RepositoryError> for AuthenticationError {
     }
 }
 
+impl From<HashError> for AuthenticationError {
+    fn from(_: HashError) -> Self {
+        AuthenticationError::ProviderUnavailable
+    }
+}
+
 impl From<TokenError> for AuthenticationError {
     fn from(value: TokenError) -> Self {
         match value {
@@ -1 +1,6 @@
+pub mod chain;
+pub mod federated;
+pub mod ldap;
 pub mod local;
+pub mod passkey;
+pub mod second_factor;
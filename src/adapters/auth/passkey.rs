@@ -0,0 +1,359 @@
+use crate::{
+    application::auth::{
+        authenticated_user::AuthenticatedUser, authenticator::Authenticator,
+        credentials::Credentials, error::AuthenticationError,
+    },
+    domain::{
+        passkey::{
+            entity::Passkey,
+            repository::PasskeyRepository,
+            verifier::{PasskeyAssertion, PasskeyError, PasskeyVerifier},
+        },
+        user::{entity::User, repository::UserRepository},
+    },
+};
+
+/// Authenticates WebAuthn assertions against the passkeys users registered.
+#[derive(Clone)]
+pub struct PasskeyAuthenticator<U, K, V>
+where
+    U: UserRepository,
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    user_repository: U,
+    passkey_repository: K,
+    verifier: V,
+}
+
+impl<U, K, V> PasskeyAuthenticator<U, K, V>
+where
+    U: UserRepository,
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    pub fn new(user_repository: U, passkey_repository: K, verifier: V) -> Self {
+        Self {
+            user_repository,
+            passkey_repository,
+            verifier,
+        }
+    }
+}
+
+impl<U, K, V> Authenticator for PasskeyAuthenticator<U, K, V>
+where
+    U: UserRepository,
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let Credentials::Passkey {
+            credential_id,
+            client_data_json,
+            authenticator_data,
+            signature,
+        } = credentials
+        else {
+            return Err(AuthenticationError::UnsupportedCredentials);
+        };
+
+        let passkey: Passkey = self
+            .passkey_repository
+            .find_by_credential_id(&credential_id)
+            .await?
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        let assertion: PasskeyAssertion = self.verifier.verify_assertion(
+            &passkey,
+            &client_data_json,
+            &authenticator_data,
+            &signature,
+        )?;
+
+        // A replayed assertion carries a challenge that was already consumed.
+        if !self
+            .passkey_repository
+            .consume_challenge(&assertion.challenge)
+            .await?
+        {
+            return Err(AuthenticationError::InvalidCredentials);
+        }
+
+        self.passkey_repository
+            .update_sign_count(&passkey.id, assertion.sign_count)
+            .await?;
+
+        let user: User = self
+            .user_repository
+            .find_by_id(&passkey.user_id)
+            .await?
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        if !user.is_active() {
+            return Err(AuthenticationError::UserInactive);
+        }
+
+        Ok(AuthenticatedUser::from(user))
+    }
+}
+
+impl From<PasskeyError> for AuthenticationError {
+    fn from(_: PasskeyError) -> Self {
+        AuthenticationError::InvalidCredentials
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::{
+            persistence::memory::{InMemoryPasskeyRepository, InMemoryUserRepository},
+            webauthn::{
+                software::{Assertion, Attestation, SoftwareAuthenticator},
+                verifier::WebAuthnVerifier,
+            },
+        },
+        application::passkey::{
+            register_passkey::{
+                RegisterPasskeyError, RegisterPasskeyInput, RegisterPasskeyService,
+            },
+            start_authentication::StartPasskeyAuthenticationService,
+            start_registration::StartPasskeyRegistrationService,
+        },
+        domain::user::{
+            entity::UserStatus,
+            patch::UserPatch,
+            value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        },
+    };
+    use uuid::Uuid;
+
+    const ORIGIN: &str = "https://login.example.org";
+    const RP_ID: &str = "example.org";
+
+    struct Fixture {
+        users: InMemoryUserRepository,
+        passkeys: InMemoryPasskeyRepository,
+        verifier: WebAuthnVerifier,
+        user: User,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let user: User = User::new(
+                Uuid::now_v7(),
+                Name::new("Alice".into()).unwrap(),
+                Username::new("alice".into()).unwrap(),
+                PasswordHash::new("hash".into()).unwrap(),
+                None,
+                None,
+            );
+
+            Self {
+                users: InMemoryUserRepository::with(vec![user.clone()]),
+                passkeys: InMemoryPasskeyRepository::default(),
+                verifier: WebAuthnVerifier::new("test-secret", ORIGIN, RP_ID, "Example"),
+                user,
+            }
+        }
+
+        async fn register(
+            &self,
+            authenticator: &mut SoftwareAuthenticator,
+        ) -> Result<Passkey, RegisterPasskeyError> {
+            let actor: AuthenticatedUser = AuthenticatedUser::from(self.user.clone());
+            let Ok(options) =
+                StartPasskeyRegistrationService::new(self.passkeys.clone(), self.verifier.clone())
+                    .execute(&actor)
+                    .await
+            else {
+                panic!("registration options could not be issued");
+            };
+            let attestation: Attestation = authenticator.register(&options.challenge);
+
+            self.register_with(attestation, &actor).await
+        }
+
+        async fn register_with(
+            &self,
+            attestation: Attestation,
+            actor: &AuthenticatedUser,
+        ) -> Result<Passkey, RegisterPasskeyError> {
+            RegisterPasskeyService::new(self.passkeys.clone(), self.verifier.clone())
+                .execute(
+                    RegisterPasskeyInput {
+                        name: "Laptop".into(),
+                        client_data_json: attestation.client_data_json,
+                        attestation_object: attestation.attestation_object,
+                    },
+                    actor,
+                )
+                .await
+        }
+
+        async fn challenge(&self) -> String {
+            let Ok(options) = StartPasskeyAuthenticationService::new(
+                self.passkeys.clone(),
+                self.verifier.clone(),
+            )
+            .execute()
+            .await
+            else {
+                panic!("authentication options could not be issued");
+            };
+
+            options.challenge
+        }
+
+        async fn sign_in(
+            &self,
+            authenticator: &SoftwareAuthenticator,
+            assertion: Assertion,
+        ) -> Result<AuthenticatedUser, AuthenticationError> {
+            PasskeyAuthenticator::new(
+                self.users.clone(),
+                self.passkeys.clone(),
+                self.verifier.clone(),
+            )
+            .authenticate(Credentials::Passkey {
+                credential_id: authenticator.credential_id.clone(),
+                client_data_json: assertion.client_data_json,
+                authenticator_data: assertion.authenticator_data,
+                signature: assertion.signature,
+            })
+            .await
+        }
+    }
+
+    fn clone_assertion(assertion: &Assertion) -> Assertion {
+        Assertion {
+            client_data_json: assertion.client_data_json.clone(),
+            authenticator_data: assertion.authenticator_data.clone(),
+            signature: assertion.signature.clone(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn registers_a_passkey_and_signs_in_with_it() {
+        let fixture: Fixture = Fixture::new();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+
+        let Ok(passkey) = fixture.register(&mut authenticator).await else {
+            panic!("the passkey was not registered");
+        };
+        assert_eq!(passkey.user_id, fixture.user.id);
+
+        let assertion: Assertion = authenticator.assert(&fixture.challenge().await);
+        let user: AuthenticatedUser = fixture.sign_in(&authenticator, assertion).await.unwrap();
+
+        assert_eq!(user.id, fixture.user.id);
+        assert_eq!(fixture.passkeys.all()[0].sign_count, 1);
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_replayed_attestation() {
+        let fixture: Fixture = Fixture::new();
+        let actor: AuthenticatedUser = AuthenticatedUser::from(fixture.user.clone());
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let Ok(options) = StartPasskeyRegistrationService::new(
+            fixture.passkeys.clone(),
+            fixture.verifier.clone(),
+        )
+        .execute(&actor)
+        .await
+        else {
+            panic!("registration options could not be issued");
+        };
+
+        let attestation: Attestation = authenticator.register(&options.challenge);
+        let replayed: Attestation = Attestation {
+            client_data_json: attestation.client_data_json.clone(),
+            attestation_object: attestation.attestation_object.clone(),
+        };
+
+        assert!(fixture.register_with(attestation, &actor).await.is_ok());
+        fixture
+            .passkeys
+            .delete(&fixture.passkeys.all()[0].id)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            fixture.register_with(replayed, &actor).await,
+            Err(RegisterPasskeyError::InvalidAttestation)
+        ));
+        assert!(fixture.passkeys.all().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_replayed_assertion_from_an_authenticator_without_a_counter() {
+        let fixture: Fixture = Fixture::new();
+        let mut authenticator: SoftwareAuthenticator =
+            SoftwareAuthenticator::without_counter(RP_ID, ORIGIN);
+        assert!(fixture.register(&mut authenticator).await.is_ok());
+
+        let assertion: Assertion = authenticator.assert(&fixture.challenge().await);
+        let replayed: Assertion = clone_assertion(&assertion);
+
+        assert!(fixture.sign_in(&authenticator, assertion).await.is_ok());
+        assert!(matches!(
+            fixture.sign_in(&authenticator, replayed).await,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn rejects_challenges_that_were_not_issued() {
+        let fixture: Fixture = Fixture::new();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        assert!(fixture.register(&mut authenticator).await.is_ok());
+
+        // Validly signed by the relying party, but never handed out.
+        let assertion: Assertion = authenticator.assert(&fixture.verifier.challenge(None).value);
+
+        assert!(matches!(
+            fixture.sign_in(&authenticator, assertion).await,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn rejects_inactive_users() {
+        let fixture: Fixture = Fixture::new();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        assert!(fixture.register(&mut authenticator).await.is_ok());
+        fixture
+            .users
+            .update(
+                &fixture.user.id,
+                UserPatch::new(None, None, None, None, Some(UserStatus::Banned), None),
+            )
+            .await
+            .unwrap();
+
+        let assertion: Assertion = authenticator.assert(&fixture.challenge().await);
+
+        assert!(matches!(
+            fixture.sign_in(&authenticator, assertion).await,
+            Err(AuthenticationError::UserInactive)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn rejects_unknown_credentials() {
+        let fixture: Fixture = Fixture::new();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+
+        let assertion: Assertion = authenticator.assert(&fixture.challenge().await);
+
+        assert!(matches!(
+            fixture.sign_in(&authenticator, assertion).await,
+            Err(AuthenticationError::InvalidCredentials)
+        ));
+    }
+}
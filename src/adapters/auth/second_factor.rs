@@ -0,0 +1,76 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_user::AuthenticatedUser, authenticator::Authenticator,
+            credentials::Credentials, error::AuthenticationError,
+        },
+        security::token_service::TokenService,
+    },
+    domain::mfa::repository::TotpRepository,
+};
+
+/// Asks users who enrolled a second factor for it after any password authenticator accepted
+/// their password, whether the password was checked locally or against a directory, and after
+/// an upstream provider vouched for them, as what the provider checked is unknown. The code is
+/// then redeemed with the MFA token the error carries.
+#[derive(Clone)]
+pub struct SecondFactorAuthenticator<A, M, T>
+where
+    A: Authenticator,
+    M: TotpRepository,
+    T: TokenService,
+{
+    inner: A,
+    totp_repository: M,
+    token_service: T,
+}
+
+impl<A, M, T> SecondFactorAuthenticator<A, M, T>
+where
+    A: Authenticator,
+    M: TotpRepository,
+    T: TokenService,
+{
+    pub fn new(inner: A, totp_repository: M, token_service: T) -> Self {
+        Self {
+            inner,
+            totp_repository,
+            token_service,
+        }
+    }
+}
+
+impl<A, M, T> Authenticator for SecondFactorAuthenticator<A, M, T>
+where
+    A: Authenticator,
+    M: TotpRepository,
+    T: TokenService,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        let is_first_factor: bool = matches!(
+            credentials,
+            Credentials::UsernamePassword { .. }
+                | Credentials::EmailPassword { .. }
+                | Credentials::AuthorizationCode { .. }
+        );
+
+        let user: AuthenticatedUser = self.inner.authenticate(credentials).await?;
+
+        if is_first_factor
+            && self
+                .totp_repository
+                .find_by_user_id(&user.id)
+                .await?
+                .is_some_and(|enrollment| enrollment.is_active())
+        {
+            return Err(AuthenticationError::MfaRequired(
+                self.token_service.issue_mfa(&user.id)?,
+            ));
+        }
+
+        Ok(user)
+    }
+}
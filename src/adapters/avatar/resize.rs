@@ -0,0 +1,129 @@
+//! Decodes PNG, JPEG and WebP uploads and resizes them to every avatar size with the `image`
+//! crate.
+
+use crate::{
+    application::avatar::processor::{AvatarProcessor, AvatarProcessorError},
+    domain::avatar::entity::{AvatarImage, AvatarSize, AvatarUpload},
+};
+use actix_web::rt::task::spawn_blocking;
+use image::{
+    DynamicImage, ImageDecoder, ImageError, ImageFormat, ImageReader, Limits, imageops::FilterType,
+};
+use std::{io::Cursor, sync::Arc};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Larger images are refused before they are decoded, to bound memory use.
+const MAX_DIMENSION: u32 = 4096;
+/// Memory one upload may take: the decoded image and its square crop, such as both of an
+/// 8-bit RGBA image of the largest size.
+const MAX_ALLOCATION: u64 = 128 * 1024 * 1024;
+/// Uploads decoded at once, the others wait for their turn.
+const MAX_CONCURRENT_DECODES: usize = 2;
+
+#[derive(Clone)]
+pub struct ResizingAvatarProcessor {
+    permits: Arc<Semaphore>,
+}
+
+impl Default for ResizingAvatarProcessor {
+    fn default() -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_DECODES)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AvatarProcessor for ResizingAvatarProcessor {
+    async fn process(
+        &self,
+        upload: AvatarUpload,
+    ) -> Result<Vec<AvatarImage>, AvatarProcessorError> {
+        let permit: OwnedSemaphorePermit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| AvatarProcessorError::Unavailable)?;
+
+        // The permit goes with the work, which goes on when the request is dropped.
+        spawn_blocking(move || {
+            let images = resize(upload.as_bytes());
+            drop(permit);
+
+            images
+        })
+        .await
+        .map_err(|_| AvatarProcessorError::Unavailable)?
+    }
+}
+
+/// Crops the image to a centered square and stores each size as PNG. Re-encoding also drops
+/// metadata such as the location a photo was taken at.
+fn resize(data: &[u8]) -> Result<Vec<AvatarImage>, AvatarProcessorError> {
+    let mut reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|_| AvatarProcessorError::Unsupported)?;
+
+    if !matches!(
+        reader.format(),
+        Some(ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP)
+    ) {
+        return Err(AvatarProcessorError::Unsupported);
+    }
+
+    let mut limits: Limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_ALLOCATION);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(decode_error)?;
+    let (width, height): (u32, u32) = decoder.dimensions();
+    let side: u64 = u64::from(width.min(height));
+
+    // The decoder only accounts for the image, the crop copies up to all of it once more.
+    if (u64::from(width) * u64::from(height) + side * side)
+        * u64::from(decoder.color_type().bytes_per_pixel())
+        > MAX_ALLOCATION
+    {
+        return Err(AvatarProcessorError::TooLarge);
+    }
+
+    let orientation = decoder.orientation().map_err(decode_error)?;
+    let mut image: DynamicImage = DynamicImage::from_decoder(decoder).map_err(decode_error)?;
+    image.apply_orientation(orientation);
+
+    let side: u32 = image.width().min(image.height());
+    let square: DynamicImage = image.crop_imm(
+        (image.width() - side) / 2,
+        (image.height() - side) / 2,
+        side,
+        side,
+    );
+
+    AvatarSize::ALL
+        .into_iter()
+        .map(|size| {
+            let mut data: Vec<u8> = Vec::new();
+
+            square
+                .resize_exact(size.pixels(), size.pixels(), FilterType::Lanczos3)
+                .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+                .map_err(|_| AvatarProcessorError::Unavailable)?;
+
+            Ok(AvatarImage {
+                size,
+                content_type: "image/png".into(),
+                data,
+            })
+        })
+        .collect()
+}
+
+fn decode_error(error: ImageError) -> AvatarProcessorError {
+    match error {
+        ImageError::Limits(_) => AvatarProcessorError::TooLarge,
+        _ => AvatarProcessorError::Unsupported,
+    }
+}
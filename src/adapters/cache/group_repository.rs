@@ -0,0 +1,170 @@
+use crate::domain::{
+    errors::repository::RepositoryError,
+    group::{
+        entity::{Group, GroupName},
+        member::GroupMember,
+        repository::GroupRepository,
+    },
+    role::permission::Permission,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+struct CachedGroups {
+    groups: Vec<Group>,
+    cached_at: Instant,
+}
+
+/// Remembers the groups of each member looked up through a [`GroupRepository`] for `ttl`, so
+/// that resolving the permissions of every request does not hit the database.
+///
+/// Changes made through this instance apply at once. Ones made by other server instances apply
+/// once the cached entry expires.
+#[derive(Clone)]
+pub struct CachedGroupRepository<G>
+where
+    G: GroupRepository,
+{
+    inner: G,
+    ttl: Duration,
+    /// Keyed by organization and user.
+    members: Arc<Mutex<HashMap<(Uuid, Uuid), CachedGroups>>>,
+}
+
+impl<G> CachedGroupRepository<G>
+where
+    G: GroupRepository,
+{
+    pub fn new(inner: G, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            members: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn cached(&self, key: &(Uuid, Uuid)) -> Option<Vec<Group>> {
+        let members = self.members.lock().ok()?;
+
+        members
+            .get(key)
+            .filter(|groups| groups.cached_at.elapsed() < self.ttl)
+            .map(|groups| groups.groups.clone())
+    }
+
+    fn remember(&self, key: (Uuid, Uuid), groups: Vec<Group>) {
+        let Ok(mut members) = self.members.lock() else {
+            return;
+        };
+
+        members.insert(
+            key,
+            CachedGroups {
+                groups,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    fn forget(&self, organization_id: &Uuid, user_id: &Uuid) {
+        if let Ok(mut members) = self.members.lock() {
+            members.remove(&(*organization_id, *user_id));
+        }
+    }
+
+    /// Forgets the cached members of the group.
+    fn forget_group(&self, group_id: &Uuid) {
+        if let Ok(mut members) = self.members.lock() {
+            members.retain(|_, groups| groups.groups.iter().all(|group| &group.id != group_id));
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<G> GroupRepository for CachedGroupRepository<G>
+where
+    G: GroupRepository + Send + Sync,
+{
+    async fn find_by_name(
+        &self,
+        organization_id: &Uuid,
+        name: &GroupName,
+    ) -> Result<Option<Group>, RepositoryError> {
+        self.inner.find_by_name(organization_id, name).await
+    }
+
+    async fn find_all(&self, organization_id: &Uuid) -> Result<Vec<Group>, RepositoryError> {
+        self.inner.find_all(organization_id).await
+    }
+
+    async fn find_by_member(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Vec<Group>, RepositoryError> {
+        let key: (Uuid, Uuid) = (*organization_id, *user_id);
+
+        if let Some(groups) = self.cached(&key) {
+            return Ok(groups);
+        }
+
+        let groups: Vec<Group> = self.inner.find_by_member(organization_id, user_id).await?;
+        self.remember(key, groups.clone());
+
+        Ok(groups)
+    }
+
+    async fn create(&self, group: Group) -> Result<Group, RepositoryError> {
+        self.inner.create(group).await
+    }
+
+    async fn update(
+        &self,
+        id: &Uuid,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Group, RepositoryError> {
+        let group: Group = self.inner.update(id, description, permissions).await?;
+        self.forget_group(id);
+
+        Ok(group)
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let deleted: bool = self.inner.delete(id).await?;
+        self.forget_group(id);
+
+        Ok(deleted)
+    }
+
+    async fn find_members(&self, group_id: &Uuid) -> Result<Vec<GroupMember>, RepositoryError> {
+        self.inner.find_members(group_id).await
+    }
+
+    async fn add_member(&self, member: GroupMember) -> Result<GroupMember, RepositoryError> {
+        let member: GroupMember = self.inner.add_member(member).await?;
+        self.forget(&member.organization_id, &member.user_id);
+
+        Ok(member)
+    }
+
+    async fn remove_member(
+        &self,
+        group_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let removed: bool = self.inner.remove_member(group_id, user_id).await?;
+
+        if let Ok(mut members) = self.members.lock() {
+            members.retain(|(_, user), groups| {
+                user != user_id || groups.groups.iter().all(|group| &group.id != group_id)
+            });
+        }
+
+        Ok(removed)
+    }
+}
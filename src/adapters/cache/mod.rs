@@ -0,0 +1,3 @@
+pub mod group_repository;
+pub mod revocation_store;
+pub mod role_repository;
@@ -0,0 +1,105 @@
+use crate::{
+    application::security::revocation_store::RevocationStore,
+    domain::errors::repository::RepositoryError,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+/// Entries kept before expired ones are swept out.
+const SWEEP_THRESHOLD: usize = 10_000;
+
+struct CachedCutoff {
+    not_before: Option<u64>,
+    cached_at: Instant,
+}
+
+/// Remembers the per-user cutoffs of a [`RevocationStore`] for `ttl`, so that checking access
+/// tokens does not hit the store on every request.
+///
+/// Cutoffs set through this instance apply at once. Ones set by other server instances apply
+/// once the cached entry expires.
+#[derive(Clone)]
+pub struct CachedRevocationStore<S>
+where
+    S: RevocationStore,
+{
+    inner: S,
+    ttl: Duration,
+    cutoffs: Arc<Mutex<HashMap<Uuid, CachedCutoff>>>,
+}
+
+impl<S> CachedRevocationStore<S>
+where
+    S: RevocationStore,
+{
+    pub fn new(inner: S, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cutoffs: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn cached(&self, user_id: &Uuid) -> Option<Option<u64>> {
+        let cutoffs = self.cutoffs.lock().ok()?;
+
+        cutoffs
+            .get(user_id)
+            .filter(|cutoff| cutoff.cached_at.elapsed() < self.ttl)
+            .map(|cutoff| cutoff.not_before)
+    }
+
+    fn remember(&self, user_id: &Uuid, not_before: Option<u64>) {
+        let Ok(mut cutoffs) = self.cutoffs.lock() else {
+            return;
+        };
+
+        if cutoffs.len() >= SWEEP_THRESHOLD {
+            cutoffs.retain(|_, cutoff| cutoff.cached_at.elapsed() < self.ttl);
+        }
+
+        cutoffs.insert(
+            user_id.to_owned(),
+            CachedCutoff {
+                not_before,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> RevocationStore for CachedRevocationStore<S>
+where
+    S: RevocationStore + Send + Sync,
+{
+    async fn revoke(&self, token_id: &Uuid, expires_at: u64) -> Result<(), RepositoryError> {
+        self.inner.revoke(token_id, expires_at).await
+    }
+
+    async fn is_revoked(&self, token_id: &Uuid) -> Result<bool, RepositoryError> {
+        self.inner.is_revoked(token_id).await
+    }
+
+    async fn revoke_all(&self, user_id: &Uuid, not_before: u64) -> Result<(), RepositoryError> {
+        self.inner.revoke_all(user_id, not_before).await?;
+        self.remember(user_id, Some(not_before));
+
+        Ok(())
+    }
+
+    async fn not_before(&self, user_id: &Uuid) -> Result<Option<u64>, RepositoryError> {
+        if let Some(not_before) = self.cached(user_id) {
+            return Ok(not_before);
+        }
+
+        let not_before: Option<u64> = self.inner.not_before(user_id).await?;
+        self.remember(user_id, not_before);
+
+        Ok(not_before)
+    }
+}
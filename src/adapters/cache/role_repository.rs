@@ -0,0 +1,123 @@
+use crate::domain::{
+    errors::repository::RepositoryError,
+    role::{
+        entity::{Role, RoleName},
+        permission::Permission,
+        repository::RoleRepository,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+struct CachedRole {
+    role: Option<Role>,
+    cached_at: Instant,
+}
+
+/// Remembers the roles looked up through a [`RoleRepository`] for `ttl`, so that resolving the
+/// permissions of every request does not hit the database.
+///
+/// Changes made through this instance apply at once. Ones made by other server instances apply
+/// once the cached entry expires.
+#[derive(Clone)]
+pub struct CachedRoleRepository<R>
+where
+    R: RoleRepository,
+{
+    inner: R,
+    ttl: Duration,
+    roles: Arc<Mutex<HashMap<RoleName, CachedRole>>>,
+}
+
+impl<R> CachedRoleRepository<R>
+where
+    R: RoleRepository,
+{
+    pub fn new(inner: R, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            roles: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn cached(&self, name: &RoleName) -> Option<Option<Role>> {
+        let roles = self.roles.lock().ok()?;
+
+        roles
+            .get(name)
+            .filter(|role| role.cached_at.elapsed() < self.ttl)
+            .map(|role| role.role.clone())
+    }
+
+    fn remember(&self, name: &RoleName, role: Option<Role>) {
+        let Ok(mut roles) = self.roles.lock() else {
+            return;
+        };
+
+        roles.insert(
+            name.clone(),
+            CachedRole {
+                role,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    fn forget(&self, name: &RoleName) {
+        if let Ok(mut roles) = self.roles.lock() {
+            roles.remove(name);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<R> RoleRepository for CachedRoleRepository<R>
+where
+    R: RoleRepository + Send + Sync,
+{
+    async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError> {
+        if let Some(role) = self.cached(name) {
+            return Ok(role);
+        }
+
+        let role: Option<Role> = self.inner.find_by_name(name).await?;
+        self.remember(name, role.clone());
+
+        Ok(role)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Role>, RepositoryError> {
+        self.inner.find_all().await
+    }
+
+    async fn create(&self, role: Role) -> Result<Role, RepositoryError> {
+        let role: Role = self.inner.create(role).await?;
+        self.remember(&role.name, Some(role.clone()));
+
+        Ok(role)
+    }
+
+    async fn update(
+        &self,
+        name: &RoleName,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Role, RepositoryError> {
+        self.forget(name);
+        let role: Role = self.inner.update(name, description, permissions).await?;
+        self.remember(name, Some(role.clone()));
+
+        Ok(role)
+    }
+
+    async fn delete(&self, name: &RoleName) -> Result<bool, RepositoryError> {
+        let deleted: bool = self.inner.delete(name).await?;
+        self.forget(name);
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,27 @@
+pub mod site_verify;
+
+use self::site_verify::SiteVerifyCaptcha;
+use crate::application::registration::captcha::{CaptchaError, CaptchaVerifier};
+use std::net::IpAddr;
+
+/// Verifies CAPTCHA responses when a CAPTCHA service is configured, and lets every
+/// registration through otherwise.
+#[derive(Clone)]
+pub enum AppCaptcha {
+    SiteVerify(SiteVerifyCaptcha),
+    Disabled,
+}
+
+#[async_trait::async_trait]
+impl CaptchaVerifier for AppCaptcha {
+    async fn verify(
+        &self,
+        response: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<bool, CaptchaError> {
+        match self {
+            AppCaptcha::SiteVerify(captcha) => captcha.verify(response, ip_address).await,
+            AppCaptcha::Disabled => Ok(true),
+        }
+    }
+}
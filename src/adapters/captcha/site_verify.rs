@@ -0,0 +1,76 @@
+//! CAPTCHA services sharing the `siteverify` API: hCaptcha, reCAPTCHA and Turnstile.
+
+use crate::{
+    adapters::federation::http::{self, Response},
+    application::registration::captcha::{CaptchaError, CaptchaVerifier},
+};
+use actix_web::rt::task::spawn_blocking;
+use log::warn;
+use serde::Deserialize;
+use std::{net::IpAddr, sync::Arc, time::Duration};
+use url::Url;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub struct SiteVerifyCaptcha {
+    verify_url: Arc<Url>,
+    secret: Arc<str>,
+}
+
+#[derive(Deserialize)]
+struct VerifyResponse {
+    success: bool,
+}
+
+impl SiteVerifyCaptcha {
+    pub fn new(verify_url: Url, secret: impl Into<Arc<str>>) -> Self {
+        Self {
+            verify_url: Arc::new(verify_url),
+            secret: secret.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptchaVerifier for SiteVerifyCaptcha {
+    async fn verify(
+        &self,
+        response: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<bool, CaptchaError> {
+        if response.is_empty() {
+            return Ok(false);
+        }
+
+        let (verify_url, secret) = (self.verify_url.clone(), self.secret.clone());
+        let response: String = response.to_owned();
+        let ip_address: String = ip_address.map(|ip| ip.to_string()).unwrap_or_default();
+
+        let answer: Response = spawn_blocking(move || {
+            let mut form: Vec<(&str, &str)> = vec![("secret", &secret), ("response", &response)];
+
+            if !ip_address.is_empty() {
+                form.push(("remoteip", &ip_address));
+            }
+
+            http::post_form(&verify_url, &[], &form, TIMEOUT)
+        })
+        .await
+        .map_err(|_| CaptchaError::Unavailable)?
+        .map_err(|error| {
+            warn!("CAPTCHA service unreachable: {}", error);
+            CaptchaError::Unavailable
+        })?;
+
+        if answer.status != 200 {
+            warn!("CAPTCHA service answered with status {}", answer.status);
+            return Err(CaptchaError::Unavailable);
+        }
+
+        let answer: VerifyResponse =
+            serde_json::from_slice(&answer.body).map_err(|_| CaptchaError::Unavailable)?;
+
+        Ok(answer.success)
+    }
+}
@@ -0,0 +1,14 @@
+use super::payload;
+use crate::application::event::publisher::{Event, EventPublisher};
+use log::info;
+
+/// Writes events to the log instead of delivering them, when no webhook is configured.
+#[derive(Clone)]
+pub struct LogEventPublisher;
+
+#[async_trait::async_trait]
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, event: Event) {
+        info!(target: "events", "{}", payload::render(&event));
+    }
+}
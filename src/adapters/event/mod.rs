@@ -0,0 +1,23 @@
+pub mod log;
+mod payload;
+pub mod webhook;
+
+use self::{log::LogEventPublisher, webhook::WebhookEventPublisher};
+use crate::application::event::publisher::{Event, EventPublisher};
+
+/// Posts events to a webhook when one is configured, and logs them otherwise.
+#[derive(Clone)]
+pub enum AppEventPublisher {
+    Webhook(WebhookEventPublisher),
+    Log(LogEventPublisher),
+}
+
+#[async_trait::async_trait]
+impl EventPublisher for AppEventPublisher {
+    async fn publish(&self, event: Event) {
+        match self {
+            AppEventPublisher::Webhook(publisher) => publisher.publish(event).await,
+            AppEventPublisher::Log(publisher) => publisher.publish(event).await,
+        }
+    }
+}
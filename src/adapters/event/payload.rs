@@ -0,0 +1,39 @@
+use crate::{application::event::publisher::Event, domain::user::profile::Profile};
+use serde_json::{Value, json};
+
+/// The name of the event, also sent as the `X-Windwatcher-Event` header.
+pub fn name(event: &Event) -> &'static str {
+    match event {
+        Event::ProfileUpdated(_) => "user.profile_updated",
+    }
+}
+
+/// The JSON document delivered for an event. Cleared profile fields are `null`, so receivers
+/// can replace what they stored.
+pub fn render(event: &Event) -> Value {
+    match event {
+        Event::ProfileUpdated(update) => json!({
+            "type": name(event),
+            "occurred_at": update.occurred_at,
+            "data": {
+                "id": update.user_id.to_string(),
+                "username": update.username,
+                "name": update.name,
+                "profile": profile(&update.user_id.to_string(), &update.profile),
+            },
+        }),
+    }
+}
+
+fn profile(user_id: &str, profile: &Profile) -> Value {
+    json!({
+        "avatar_url": profile
+            .avatar_updated_at
+            .map(|updated_at| format!("/users/{}/avatar?v={}", user_id, updated_at)),
+        "status_text": profile.status_text.as_ref().map(|v| v.as_str()),
+        "status_emoji": profile.status_emoji.as_ref().map(|v| v.as_str()),
+        "bio": profile.bio.as_ref().map(|v| v.as_str()),
+        "timezone": profile.timezone.as_ref().map(|v| v.as_str()),
+        "locale": profile.locale.as_ref().map(|v| v.as_str()),
+    })
+}
@@ -0,0 +1,70 @@
+use super::payload;
+use crate::{
+    adapters::federation::http::{self, Response},
+    application::event::publisher::{Event, EventPublisher},
+};
+use actix_web::rt::task::spawn_blocking;
+use hmac::{Hmac, Mac};
+use log::warn;
+use sha2::Sha256;
+use std::{sync::Arc, time::Duration};
+use url::Url;
+
+/// Posts events to an HTTP endpoint. With a secret, the `X-Windwatcher-Signature` header
+/// holds `sha256=` and the hex HMAC-SHA256 of the body, so the receiver can tell the events
+/// are genuine.
+#[derive(Clone)]
+pub struct WebhookEventPublisher {
+    url: Arc<Url>,
+    secret: Option<Arc<str>>,
+    timeout: Duration,
+}
+
+impl WebhookEventPublisher {
+    pub fn new(url: Url, secret: Option<String>, timeout: Duration) -> Self {
+        Self {
+            url: Arc::new(url),
+            secret: secret.map(Arc::from),
+            timeout,
+        }
+    }
+
+    fn sign(&self, body: &[u8]) -> Option<String> {
+        let secret: &str = self.secret.as_deref()?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()?;
+        mac.update(body);
+
+        Some(format!(
+            "sha256={}",
+            hex::encode(mac.finalize().into_bytes())
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPublisher for WebhookEventPublisher {
+    /// Delivery runs in the background, so a slow receiver never holds up the request that
+    /// caused the event.
+    async fn publish(&self, event: Event) {
+        let name: &'static str = payload::name(&event);
+        let body: Vec<u8> = payload::render(&event).to_string().into_bytes();
+        let signature: Option<String> = self.sign(&body);
+        let (url, timeout) = (self.url.clone(), self.timeout);
+
+        spawn_blocking(move || {
+            let mut headers: Vec<(&str, &str)> = vec![("X-Windwatcher-Event", name)];
+
+            if let Some(signature) = &signature {
+                headers.push(("X-Windwatcher-Signature", signature));
+            }
+
+            match http::post_json(&url, &headers, &body, timeout) {
+                Ok(Response { status, .. }) if (200..300).contains(&status) => {}
+                Ok(Response { status, .. }) => {
+                    warn!("Webhook answered {} with status {}", name, status)
+                }
+                Err(error) => warn!("Webhook unreachable for {}: {}", name, error),
+            }
+        });
+    }
+}
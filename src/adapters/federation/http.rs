@@ -0,0 +1,354 @@
+//! A minimal blocking HTTP/1.1 client, enough to talk JSON to identity providers, CAPTCHA
+//! services and webhooks.
+
+use crate::adapters::net::{self, Stream};
+use std::{
+    io::{Error, ErrorKind, Write},
+    time::Duration,
+};
+use url::Url;
+
+/// Identity provider documents and token responses are small.
+const MAX_RESPONSE_SIZE: usize = 1 << 20;
+
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+pub fn get(url: &Url, timeout: Duration) -> Result<Response, Error> {
+    request("GET", url, &[], None, timeout)
+}
+
+/// Posts an `application/x-www-form-urlencoded` body.
+pub fn post_form(
+    url: &Url,
+    headers: &[(&str, &str)],
+    form: &[(&str, &str)],
+    timeout: Duration,
+) -> Result<Response, Error> {
+    let body: String = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(form)
+        .finish();
+
+    let mut headers: Vec<(&str, &str)> = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+
+    request("POST", url, &headers, Some(body.as_bytes()), timeout)
+}
+
+/// Posts an `application/json` body.
+pub fn post_json(
+    url: &Url,
+    headers: &[(&str, &str)],
+    body: &[u8],
+    timeout: Duration,
+) -> Result<Response, Error> {
+    let mut headers: Vec<(&str, &str)> = headers.to_vec();
+    headers.push(("Content-Type", "application/json"));
+
+    request("POST", url, &headers, Some(body), timeout)
+}
+
+fn request(
+    method: &str,
+    url: &Url,
+    headers: &[(&str, &str)],
+    body: Option<&[u8]>,
+    timeout: Duration,
+) -> Result<Response, Error> {
+    let host: &str = url
+        .host_str()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "URL without host"))?;
+    let secure: bool = match url.scheme() {
+        "https" => true,
+        "http" => false,
+        _ => return Err(Error::new(ErrorKind::InvalidInput, "unsupported scheme")),
+    };
+    let port: u16 = url
+        .port_or_known_default()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "URL without port"))?;
+
+    let mut target: String = url.path().to_owned();
+
+    if let Some(query) = url.query() {
+        target.push('?');
+        target.push_str(query);
+    }
+
+    let host_header: String = match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_owned(),
+    };
+
+    let mut head: String = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nAccept: application/json\r\nConnection: close\r\nUser-Agent: windwatcher\r\n",
+        method, target, host_header
+    );
+
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+
+    if let Some(body) = body {
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+
+    head.push_str("\r\n");
+
+    let mut stream: Box<dyn Stream> = net::connect(host, port, secure, timeout)?;
+    stream.write_all(head.as_bytes())?;
+
+    if let Some(body) = body {
+        stream.write_all(body)?;
+    }
+
+    stream.flush()?;
+
+    parse_response(&read_to_end(stream.as_mut())?)
+}
+
+fn read_to_end(stream: &mut dyn Stream) -> Result<Vec<u8>, Error> {
+    let mut response: Vec<u8> = Vec::new();
+    let mut chunk: [u8; 8192] = [0; 8192];
+
+    loop {
+        match stream.read(&mut chunk) {
+            Ok(0) => return Ok(response),
+            Ok(read) => response.extend_from_slice(&chunk[..read]),
+            // Servers commonly close TLS connections without a close_notify alert.
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof && !response.is_empty() => {
+                return Ok(response);
+            }
+            Err(error) => return Err(error),
+        }
+
+        if response.len() > MAX_RESPONSE_SIZE {
+            return Err(Error::new(ErrorKind::InvalidData, "response too large"));
+        }
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, Error> {
+    let invalid = || Error::new(ErrorKind::InvalidData, "malformed HTTP response");
+
+    let separator: usize = raw
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(invalid)?;
+    let head: &str = std::str::from_utf8(&raw[..separator]).map_err(|_| invalid())?;
+    let body: &[u8] = &raw[separator + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status: u16 = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+
+    let mut chunked: bool = false;
+    let mut content_length: Option<usize> = None;
+
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.trim().eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse().ok();
+        }
+    }
+
+    let body: Vec<u8> = if chunked {
+        decode_chunked(body).ok_or_else(invalid)?
+    } else {
+        match content_length {
+            Some(length) => body.get(..length).ok_or_else(invalid)?.to_vec(),
+            None => body.to_vec(),
+        }
+    };
+
+    Ok(Response { status, body })
+}
+
+fn decode_chunked(mut input: &[u8]) -> Option<Vec<u8>> {
+    let mut body: Vec<u8> = Vec::new();
+
+    loop {
+        let line_end: usize = input.windows(2).position(|window| window == b"\r\n")?;
+        let size_line: &str = std::str::from_utf8(&input[..line_end]).ok()?;
+        let size: usize = usize::from_str_radix(size_line.split(';').next()?.trim(), 16).ok()?;
+        input = &input[line_end + 2..];
+
+        if size == 0 {
+            return Some(body);
+        }
+
+        body.extend_from_slice(input.get(..size)?);
+        input = input.get(size + 2..)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::federation::stand_in::{Request, response, serve_http};
+    use std::{
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Serves `reply` to every request, recording the requests received.
+    fn stub(reply: Vec<u8>) -> (Url, Arc<Mutex<Vec<Request>>>) {
+        let requests: Arc<Mutex<Vec<Request>>> = Arc::default();
+        let recorded: Arc<Mutex<Vec<Request>>> = requests.clone();
+
+        let address: SocketAddr = serve_http(move |request| {
+            recorded.lock().unwrap().push(request);
+            reply.clone()
+        });
+
+        (
+            Url::parse(&format!("http://{}", address)).unwrap(),
+            requests,
+        )
+    }
+
+    #[test]
+    fn parses_a_response_with_a_content_length() {
+        let parsed: Response =
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}trailing").unwrap();
+
+        assert_eq!(parsed.status, 200);
+        assert_eq!(parsed.body, b"{}");
+    }
+
+    #[test]
+    fn reads_the_body_to_the_end_without_a_content_length() {
+        let parsed: Response = parse_response(b"HTTP/1.0 404 Not Found\r\n\r\nmissing").unwrap();
+
+        assert_eq!(parsed.status, 404);
+        assert_eq!(parsed.body, b"missing");
+    }
+
+    #[test]
+    fn decodes_chunked_bodies() {
+        let parsed: Response = parse_response(
+            b"HTTP/1.1 200 OK\r\ntransfer-encoding: Chunked\r\n\r\n4;ext=1\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.body, b"Wikipedia");
+    }
+
+    #[test]
+    fn rejects_malformed_responses() {
+        let malformed: [&[u8]; 5] = [
+            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n",
+            b"HTTP/1.1 abc OK\r\n\r\n",
+            b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort",
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nzz\r\nWiki\r\n0\r\n\r\n",
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n9\r\nWiki\r\n",
+        ];
+
+        for raw in malformed {
+            assert_eq!(
+                parse_response(raw).err().map(|error| error.kind()),
+                Some(ErrorKind::InvalidData)
+            );
+        }
+    }
+
+    #[test]
+    fn sends_get_requests_with_the_query() {
+        let (url, requests) = stub(response(200, "application/json", b"{\"ok\":true}"));
+
+        let fetched: Response = get(&url.join("/.well-known/jwks?x=1").unwrap(), TIMEOUT).unwrap();
+
+        assert_eq!(fetched.status, 200);
+        assert_eq!(fetched.body, b"{\"ok\":true}");
+
+        let request: Request = requests.lock().unwrap().remove(0);
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.target, "/.well-known/jwks?x=1");
+        let host: String = format!("127.0.0.1:{}", url.port().unwrap());
+        assert_eq!(request.header("Host"), Some(host.as_str()));
+        assert_eq!(request.header("Accept"), Some("application/json"));
+        assert_eq!(request.header("Connection"), Some("close"));
+    }
+
+    #[test]
+    fn posts_url_encoded_forms() {
+        let (url, requests) = stub(response(200, "application/json", b"{}"));
+
+        post_form(
+            &url.join("/token").unwrap(),
+            &[("Authorization", "Basic abc")],
+            &[("code", "a b&c"), ("grant_type", "authorization_code")],
+            TIMEOUT,
+        )
+        .unwrap();
+
+        let request: Request = requests.lock().unwrap().remove(0);
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.header("Authorization"), Some("Basic abc"));
+        assert_eq!(
+            request.header("Content-Type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(request.body, b"code=a+b%26c&grant_type=authorization_code");
+        assert_eq!(request.form()["code"], "a b&c");
+    }
+
+    #[test]
+    fn posts_json_bodies() {
+        let (url, requests) = stub(response(202, "application/json", b""));
+
+        let posted: Response =
+            post_json(&url.join("/hook").unwrap(), &[], b"{\"event\":1}", TIMEOUT).unwrap();
+
+        assert_eq!(posted.status, 202);
+
+        let request: Request = requests.lock().unwrap().remove(0);
+        assert_eq!(request.header("Content-Type"), Some("application/json"));
+        assert_eq!(request.header("Content-Length"), Some("11"));
+        assert_eq!(request.body, b"{\"event\":1}");
+    }
+
+    #[test]
+    fn reads_chunked_responses_from_the_network() {
+        let (url, _) = stub(
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n{}\r\n0\r\n\r\n".to_vec(),
+        );
+
+        assert_eq!(get(&url, TIMEOUT).unwrap().body, b"{}");
+    }
+
+    #[test]
+    fn refuses_oversized_responses() {
+        let (url, _) = stub(response(
+            200,
+            "text/plain",
+            &vec![b'a'; MAX_RESPONSE_SIZE + 1],
+        ));
+
+        assert_eq!(
+            get(&url, TIMEOUT).err().map(|error| error.kind()),
+            Some(ErrorKind::InvalidData)
+        );
+    }
+
+    #[test]
+    fn refuses_other_schemes() {
+        let url: Url = Url::parse("ftp://127.0.0.1/file").unwrap();
+
+        assert_eq!(
+            get(&url, TIMEOUT).err().map(|error| error.kind()),
+            Some(ErrorKind::InvalidInput)
+        );
+    }
+}
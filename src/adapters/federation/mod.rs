@@ -0,0 +1,5 @@
+pub(crate) mod http;
+pub mod oidc;
+
+#[cfg(test)]
+pub mod stand_in;
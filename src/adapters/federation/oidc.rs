@@ -0,0 +1,620 @@
+use super::http::{self, Response};
+use crate::{
+    application::federation::identity_provider::{
+        AuthorizationRequest, ExternalClaims, FederationError, IdentityProviders,
+    },
+    config::oidc::ports::OidcProviderConfig,
+};
+use actix_web::rt::task::spawn_blocking;
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
+use hmac::{Hmac, Mac};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Header, Validation, decode, decode_header,
+    jwk::{Jwk, JwkSet},
+};
+use log::warn;
+use rand::{RngCore, rngs::OsRng};
+use serde::{Deserialize, de::DeserializeOwned};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+
+const STATE_TTL_SECONDS: u64 = 600;
+const NONCE_BYTES: usize = 16;
+const PROVIDER_HASH_BYTES: usize = 8;
+const TAG_BYTES: usize = 16;
+const PAYLOAD_BYTES: usize = NONCE_BYTES + 8 + PROVIDER_HASH_BYTES;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Federates login to upstream OpenID Connect providers with the authorization-code flow.
+///
+/// Provider metadata and keys are discovered lazily and cached. The state is stateless: it
+/// carries its expiry and provider and is authenticated with an HMAC; the id_token nonce and
+/// the PKCE verifier are both derived from it.
+#[derive(Clone)]
+pub struct OidcFederation {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    providers: HashMap<String, Provider>,
+    secret: Vec<u8>,
+    /// Public base URL of this server, callbacks are served under it.
+    public_url: String,
+}
+
+struct Provider {
+    config: OidcProviderConfig,
+    metadata: RwLock<Option<Arc<Metadata>>>,
+    jwks: RwLock<Option<Arc<JwkSet>>>,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    preferred_username: Option<String>,
+    name: Option<String>,
+    email: Option<String>,
+}
+
+impl OidcFederation {
+    pub fn new(
+        providers: Vec<OidcProviderConfig>,
+        secret: impl AsRef<[u8]>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        let providers: HashMap<String, Provider> = providers
+            .into_iter()
+            .map(|config| {
+                (
+                    config.name.clone(),
+                    Provider {
+                        config,
+                        metadata: RwLock::new(None),
+                        jwks: RwLock::new(None),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(Inner {
+                providers,
+                secret: secret.as_ref().to_vec(),
+                public_url: public_url.into(),
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdentityProviders for OidcFederation {
+    async fn authorization_request(
+        &self,
+        provider: &str,
+    ) -> Result<AuthorizationRequest, FederationError> {
+        let inner: Arc<Inner> = self.inner.clone();
+        let provider: String = provider.to_owned();
+
+        spawn_blocking(move || inner.authorization_request(&provider))
+            .await
+            .map_err(|_| FederationError::Unavailable)?
+    }
+
+    async fn complete(
+        &self,
+        provider: &str,
+        code: &str,
+        state: &str,
+    ) -> Result<ExternalClaims, FederationError> {
+        let inner: Arc<Inner> = self.inner.clone();
+        let (provider, code, state) = (provider.to_owned(), code.to_owned(), state.to_owned());
+
+        spawn_blocking(move || inner.complete(&provider, &code, &state))
+            .await
+            .map_err(|_| FederationError::Unavailable)?
+    }
+}
+
+impl Inner {
+    fn provider(&self, name: &str) -> Result<&Provider, FederationError> {
+        self.providers
+            .get(name)
+            .ok_or(FederationError::UnknownProvider)
+    }
+
+    fn redirect_uri(&self, provider: &str) -> String {
+        format!("{}/auth/{}/callback", self.public_url, provider)
+    }
+
+    fn authorization_request(&self, name: &str) -> Result<AuthorizationRequest, FederationError> {
+        let provider: &Provider = self.provider(name)?;
+        let metadata: Arc<Metadata> = provider.metadata()?;
+        let state: String = self.issue_state(name);
+
+        let mut url: Url = Url::parse(&metadata.authorization_endpoint)
+            .map_err(|_| FederationError::InvalidResponse)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &provider.config.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri(name))
+            .append_pair("scope", &provider.config.scope)
+            .append_pair("state", &state)
+            .append_pair("nonce", &self.derive(b"oidc-nonce", &state))
+            .append_pair(
+                "code_challenge",
+                &URL_SAFE_NO_PAD.encode(Sha256::digest(self.derive(b"oidc-pkce", &state))),
+            )
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(AuthorizationRequest {
+            url: url.into(),
+            state,
+        })
+    }
+
+    fn complete(
+        &self,
+        name: &str,
+        code: &str,
+        state: &str,
+    ) -> Result<ExternalClaims, FederationError> {
+        let provider: &Provider = self.provider(name)?;
+        self.verify_state(name, state)?;
+
+        let metadata: Arc<Metadata> = provider.metadata()?;
+        let token_endpoint: Url =
+            Url::parse(&metadata.token_endpoint).map_err(|_| FederationError::InvalidResponse)?;
+
+        let encode = |value: &str| -> String {
+            url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+        };
+        let credentials: String = format!(
+            "Basic {}",
+            STANDARD.encode(format!(
+                "{}:{}",
+                encode(&provider.config.client_id),
+                encode(&provider.config.client_secret)
+            ))
+        );
+
+        let response: Response = http::post_form(
+            &token_endpoint,
+            &[("Authorization", &credentials)],
+            &[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &self.redirect_uri(name)),
+                ("code_verifier", &self.derive(b"oidc-pkce", state)),
+            ],
+            TIMEOUT,
+        )
+        .map_err(unavailable)?;
+
+        if response.status != 200 {
+            warn!(
+                "OIDC provider {} rejected the authorization code ({})",
+                name, response.status
+            );
+            return Err(FederationError::InvalidResponse);
+        }
+
+        let token: TokenResponse =
+            serde_json::from_slice(&response.body).map_err(|_| FederationError::InvalidResponse)?;
+        let claims: IdTokenClaims = provider.verify_id_token(&token.id_token, &metadata)?;
+
+        if claims.nonce.as_deref() != Some(self.derive(b"oidc-nonce", state).as_str()) {
+            return Err(FederationError::InvalidResponse);
+        }
+
+        Ok(ExternalClaims {
+            subject: claims.sub,
+            preferred_username: claims.preferred_username,
+            name: claims.name,
+            email: claims.email,
+        })
+    }
+
+    fn mac(&self, purpose: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut mac: Hmac<Sha256> =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any size");
+        mac.update(purpose);
+        mac.update(data);
+
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// Derives a per-flow secret from the state.
+    fn derive(&self, purpose: &[u8], state: &str) -> String {
+        URL_SAFE_NO_PAD.encode(self.mac(purpose, state.as_bytes()))
+    }
+
+    fn issue_state(&self, provider: &str) -> String {
+        let mut payload: Vec<u8> = vec![0; NONCE_BYTES];
+        OsRng.fill_bytes(&mut payload);
+        payload.extend_from_slice(&(now() + STATE_TTL_SECONDS).to_be_bytes());
+        payload.extend_from_slice(&Sha256::digest(provider.as_bytes())[..PROVIDER_HASH_BYTES]);
+
+        let tag: Vec<u8> = self.mac(b"oidc-state", &payload);
+        payload.extend_from_slice(&tag[..TAG_BYTES]);
+
+        URL_SAFE_NO_PAD.encode(payload)
+    }
+
+    fn verify_state(&self, provider: &str, state: &str) -> Result<(), FederationError> {
+        let state: Vec<u8> = URL_SAFE_NO_PAD
+            .decode(state)
+            .map_err(|_| FederationError::InvalidState)?;
+
+        if state.len() != PAYLOAD_BYTES + TAG_BYTES {
+            return Err(FederationError::InvalidState);
+        }
+
+        let (payload, tag) = state.split_at(PAYLOAD_BYTES);
+        let mut mac: Hmac<Sha256> =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any size");
+        mac.update(b"oidc-state");
+        mac.update(payload);
+        mac.verify_truncated_left(tag)
+            .map_err(|_| FederationError::InvalidState)?;
+
+        let expires_at: u64 = u64::from_be_bytes(
+            payload[NONCE_BYTES..NONCE_BYTES + 8]
+                .try_into()
+                .map_err(|_| FederationError::InvalidState)?,
+        );
+
+        if expires_at < now()
+            || payload[NONCE_BYTES + 8..]
+                != Sha256::digest(provider.as_bytes())[..PROVIDER_HASH_BYTES]
+        {
+            return Err(FederationError::InvalidState);
+        }
+
+        Ok(())
+    }
+}
+
+impl Provider {
+    fn metadata(&self) -> Result<Arc<Metadata>, FederationError> {
+        if let Some(metadata) = self.metadata.read().ok().and_then(|cached| cached.clone()) {
+            return Ok(metadata);
+        }
+
+        let url: Url = Url::parse(&format!(
+            "{}/.well-known/openid-configuration",
+            self.config.issuer
+        ))
+        .map_err(|_| FederationError::Unavailable)?;
+        let metadata: Metadata = fetch_json(&url)?;
+
+        // OpenID Connect Discovery, section 4.3.
+        if metadata.issuer.trim_end_matches('/') != self.config.issuer {
+            warn!(
+                "OIDC provider {} advertises issuer {}",
+                self.config.name, metadata.issuer
+            );
+            return Err(FederationError::InvalidResponse);
+        }
+
+        let metadata: Arc<Metadata> = Arc::new(metadata);
+
+        if let Ok(mut cached) = self.metadata.write() {
+            *cached = Some(metadata.clone());
+        }
+
+        Ok(metadata)
+    }
+
+    /// Finds the key an id_token was signed with, refreshing the key set once when the key is
+    /// unknown, since providers rotate keys.
+    fn key(&self, kid: Option<&str>, metadata: &Metadata) -> Result<Jwk, FederationError> {
+        let find = |jwks: &JwkSet| -> Option<Jwk> {
+            match kid {
+                Some(kid) => jwks.find(kid).cloned(),
+                None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+                None => None,
+            }
+        };
+
+        if let Some(key) = self
+            .jwks
+            .read()
+            .ok()
+            .and_then(|cached| cached.as_deref().and_then(find))
+        {
+            return Ok(key);
+        }
+
+        let url: Url =
+            Url::parse(&metadata.jwks_uri).map_err(|_| FederationError::InvalidResponse)?;
+        let jwks: Arc<JwkSet> = Arc::new(fetch_json(&url)?);
+        let key: Option<Jwk> = find(&jwks);
+
+        if let Ok(mut cached) = self.jwks.write() {
+            *cached = Some(jwks);
+        }
+
+        key.ok_or(FederationError::InvalidResponse)
+    }
+
+    fn verify_id_token(
+        &self,
+        id_token: &str,
+        metadata: &Metadata,
+    ) -> Result<IdTokenClaims, FederationError> {
+        let header: Header =
+            decode_header(id_token).map_err(|_| FederationError::InvalidResponse)?;
+
+        // Only asymmetric signatures: the key must come from the provider's key set.
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(FederationError::InvalidResponse);
+        }
+
+        let key: Jwk = self.key(header.kid.as_deref(), metadata)?;
+        let key: DecodingKey =
+            DecodingKey::from_jwk(&key).map_err(|_| FederationError::InvalidResponse)?;
+
+        let mut validation: Validation = Validation::new(header.alg);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims: IdTokenClaims = decode::<IdTokenClaims>(id_token, &key, &validation)
+            .map_err(|_| FederationError::InvalidResponse)?
+            .claims;
+
+        Ok(claims)
+    }
+}
+
+fn fetch_json<T: DeserializeOwned>(url: &Url) -> Result<T, FederationError> {
+    let response: Response = http::get(url, TIMEOUT).map_err(unavailable)?;
+
+    if response.status != 200 {
+        return Err(FederationError::Unavailable);
+    }
+
+    serde_json::from_slice(&response.body).map_err(|_| FederationError::InvalidResponse)
+}
+
+fn unavailable(error: std::io::Error) -> FederationError {
+    warn!("OIDC provider unavailable: {}", error);
+
+    FederationError::Unavailable
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::federation::stand_in::MockIdp;
+    use serde_json::{Value, json};
+
+    const CLIENT_ID: &str = "windwatcher";
+    const CLIENT_SECRET: &str = "s3cret";
+    const PUBLIC_URL: &str = "https://login.example.org";
+
+    fn federation(idp: &MockIdp, client_secret: &str) -> OidcFederation {
+        OidcFederation::new(
+            vec![OidcProviderConfig {
+                name: "corp".into(),
+                issuer: idp.issuer().into(),
+                client_id: CLIENT_ID.into(),
+                client_secret: client_secret.into(),
+                scope: "openid profile email".into(),
+            }],
+            "test-secret",
+            PUBLIC_URL,
+        )
+    }
+
+    /// Runs the flow up to the callback, returning the code and state it carries.
+    async fn sign_in(federation: &OidcFederation, idp: &MockIdp) -> (String, String) {
+        let request: AuthorizationRequest = federation.authorization_request("corp").await.unwrap();
+
+        (idp.authorize(&request.url, "user-123"), request.state)
+    }
+
+    #[actix_web::test]
+    async fn completes_the_authorization_code_flow() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+
+        let request: AuthorizationRequest = federation.authorization_request("corp").await.unwrap();
+        let query: HashMap<String, String> = Url::parse(&request.url)
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect();
+
+        assert_eq!(
+            query["redirect_uri"],
+            "https://login.example.org/auth/corp/callback"
+        );
+        assert_eq!(query["scope"], "openid profile email");
+        assert_eq!(query["state"], request.state);
+
+        let code: String = idp.authorize(&request.url, "user-123");
+        let claims: ExternalClaims = federation
+            .complete("corp", &code, &request.state)
+            .await
+            .unwrap();
+
+        assert_eq!(claims.subject, "user-123");
+        assert_eq!(claims.preferred_username.as_deref(), Some("alice"));
+        assert_eq!(claims.name.as_deref(), Some("Alice Example"));
+        assert_eq!(claims.email.as_deref(), Some("alice@example.org"));
+    }
+
+    #[actix_web::test]
+    async fn caches_the_provider_metadata_and_keys() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+
+        for _ in 0..2 {
+            let (code, state) = sign_in(&federation, &idp).await;
+            federation.complete("corp", &code, &state).await.unwrap();
+        }
+
+        assert_eq!(
+            idp.requests(),
+            vec![
+                "GET /.well-known/openid-configuration",
+                "POST /token",
+                "GET /jwks",
+                "POST /token",
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn refetches_the_keys_when_the_provider_rotates_them() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+
+        let (code, state) = sign_in(&federation, &idp).await;
+        federation.complete("corp", &code, &state).await.unwrap();
+
+        idp.rotate_key();
+
+        let (code, state) = sign_in(&federation, &idp).await;
+        assert!(federation.complete("corp", &code, &state).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn rejects_states_that_were_tampered_with_or_issued_for_another_provider() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+        let (code, state) = sign_in(&federation, &idp).await;
+
+        let mut tampered: Vec<u8> = URL_SAFE_NO_PAD.decode(&state).unwrap();
+        tampered[0] ^= 0x01;
+        let foreign: String = federation.inner.issue_state("other");
+
+        for state in [URL_SAFE_NO_PAD.encode(tampered), foreign, "abc".into()] {
+            assert!(matches!(
+                federation.complete("corp", &code, &state).await,
+                Err(FederationError::InvalidState)
+            ));
+        }
+    }
+
+    #[actix_web::test]
+    async fn rejects_codes_the_provider_refuses() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+        let (code, state) = sign_in(&federation, &idp).await;
+
+        federation.complete("corp", &code, &state).await.unwrap();
+
+        // Codes are single use.
+        assert!(matches!(
+            federation.complete("corp", &code, &state).await,
+            Err(FederationError::InvalidResponse)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_wrong_client_secret() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, "wrong");
+        let (code, state) = sign_in(&federation, &idp).await;
+
+        assert!(matches!(
+            federation.complete("corp", &code, &state).await,
+            Err(FederationError::InvalidResponse)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn rejects_id_tokens_with_unexpected_claims() {
+        let overrides: [Value; 4] = [
+            json!({ "aud": "someone-else" }),
+            json!({ "iss": "https://attacker.example" }),
+            json!({ "exp": 1 }),
+            json!({ "nonce": "replayed" }),
+        ];
+
+        for claims in overrides {
+            let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+            let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+            idp.override_claims(claims.clone());
+            let (code, state) = sign_in(&federation, &idp).await;
+
+            assert!(
+                matches!(
+                    federation.complete("corp", &code, &state).await,
+                    Err(FederationError::InvalidResponse)
+                ),
+                "{}",
+                claims
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn rejects_unknown_providers() {
+        let idp: MockIdp = MockIdp::start(CLIENT_ID, CLIENT_SECRET);
+        let federation: OidcFederation = federation(&idp, CLIENT_SECRET);
+
+        assert!(matches!(
+            federation.authorization_request("unknown").await,
+            Err(FederationError::UnknownProvider)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn reports_an_unreachable_provider_as_unavailable() {
+        let closed: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let issuer: String = format!("http://{}", closed.local_addr().unwrap());
+        drop(closed);
+
+        let federation: OidcFederation = OidcFederation::new(
+            vec![OidcProviderConfig {
+                name: "corp".into(),
+                issuer,
+                client_id: CLIENT_ID.into(),
+                client_secret: CLIENT_SECRET.into(),
+                scope: "openid".into(),
+            }],
+            "test-secret",
+            PUBLIC_URL,
+        );
+
+        assert!(matches!(
+            federation.authorization_request("corp").await,
+            Err(FederationError::Unavailable)
+        ));
+    }
+}
@@ -0,0 +1,317 @@
+//! Stand-in HTTP servers for tests: a generic one answering from a closure, and a mock OpenID
+//! Connect provider built on it.
+
+use crate::adapters::token::id_token_key::IdTokenKey;
+use base64::{
+    Engine,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
+use jsonwebtoken::{Algorithm, Header};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+
+/// A request as the stand-in received it.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn form(&self) -> HashMap<String, String> {
+        url::form_urlencoded::parse(&self.body)
+            .into_owned()
+            .collect()
+    }
+}
+
+/// Serves every connection with `handler`, which returns the raw response bytes. Each
+/// connection carries a single request, the clients under test send `Connection: close`.
+pub fn serve_http<F>(handler: F) -> SocketAddr
+where
+    F: Fn(Request) -> Vec<u8> + Send + Sync + 'static,
+{
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address: SocketAddr = listener.local_addr().unwrap();
+    let handler: Arc<F> = Arc::new(handler);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { return };
+            let handler: Arc<F> = handler.clone();
+
+            thread::spawn(move || {
+                if let Some(request) = read_request(&mut stream) {
+                    let _ = stream.write_all(&handler(request));
+                }
+            });
+        }
+    });
+
+    address
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut raw: Vec<u8> = Vec::new();
+    let mut chunk: [u8; 4096] = [0; 4096];
+
+    let separator: usize = loop {
+        if let Some(position) = raw.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position;
+        }
+
+        let read: usize = stream.read(&mut chunk).ok().filter(|read| *read > 0)?;
+        raw.extend_from_slice(&chunk[..read]);
+    };
+
+    let head: String = String::from_utf8(raw[..separator].to_vec()).ok()?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method: String = request_line.next()?.to_owned();
+    let target: String = request_line.next()?.to_owned();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .collect();
+
+    let length: usize = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+    let mut body: Vec<u8> = raw[separator + 4..].to_vec();
+
+    while body.len() < length {
+        let read: usize = stream.read(&mut chunk).ok().filter(|read| *read > 0)?;
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(Request {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+/// Builds a response with a `Content-Length`.
+pub fn response(status: u16, content_type: &str, body: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = format!(
+        "HTTP/1.1 {} Stand-in\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )
+    .into_bytes();
+    out.extend_from_slice(body);
+
+    out
+}
+
+fn json_response(status: u16, body: &Value) -> Vec<u8> {
+    response(status, "application/json", body.to_string().as_bytes())
+}
+
+/// An authorization the mock provider granted, waiting for its code to be redeemed.
+struct Grant {
+    subject: String,
+    nonce: String,
+    code_challenge: String,
+    redirect_uri: String,
+}
+
+struct State {
+    key: IdTokenKey,
+    client_id: String,
+    client_secret: String,
+    issuer: String,
+    grants: HashMap<String, Grant>,
+    /// Claims merged into the next id_tokens, to make the provider misbehave.
+    overrides: serde_json::Map<String, Value>,
+    requests: Vec<Request>,
+}
+
+/// A mock OpenID Connect provider supporting discovery, a JWKS and the authorization-code
+/// grant with PKCE. The browser leg is simulated by `authorize`.
+pub struct MockIdp {
+    state: Arc<Mutex<State>>,
+    issuer: String,
+}
+
+impl MockIdp {
+    pub fn start(client_id: &str, client_secret: &str) -> Self {
+        let state: Arc<Mutex<State>> = Arc::new(Mutex::new(State {
+            key: IdTokenKey::generate().unwrap(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            issuer: String::new(),
+            grants: HashMap::new(),
+            overrides: serde_json::Map::new(),
+            requests: Vec::new(),
+        }));
+
+        let served: Arc<Mutex<State>> = state.clone();
+        let address: SocketAddr = serve_http(move |request| {
+            let mut state = served.lock().unwrap();
+            state.requests.push(request.clone());
+
+            handle(&mut state, request)
+        });
+
+        let issuer: String = format!("http://{}", address);
+        state.lock().unwrap().issuer = issuer.clone();
+
+        Self { state, issuer }
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Plays the user signing in at the authorization endpoint, returning the code the
+    /// browser would be redirected back with.
+    pub fn authorize(&self, authorization_url: &str, subject: &str) -> String {
+        let url: Url = Url::parse(authorization_url).unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let mut state = self.state.lock().unwrap();
+
+        assert_eq!(url.path(), "/authorize");
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["client_id"], state.client_id);
+        assert_eq!(query["code_challenge_method"], "S256");
+
+        let code: String = URL_SAFE_NO_PAD.encode(Sha256::digest(query["state"].as_bytes()));
+        state.grants.insert(
+            code.clone(),
+            Grant {
+                subject: subject.into(),
+                nonce: query["nonce"].clone(),
+                code_challenge: query["code_challenge"].clone(),
+                redirect_uri: query["redirect_uri"].clone(),
+            },
+        );
+
+        code
+    }
+
+    /// Merges `claims` into the id_tokens issued from now on.
+    pub fn override_claims(&self, claims: Value) {
+        let Value::Object(claims) = claims else {
+            panic!("claims must be an object");
+        };
+
+        self.state.lock().unwrap().overrides.extend(claims);
+    }
+
+    /// Replaces the signing key, as a provider rotating keys does.
+    pub fn rotate_key(&self) {
+        self.state.lock().unwrap().key = IdTokenKey::generate().unwrap();
+    }
+
+    /// Targets of the requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.target))
+            .collect()
+    }
+}
+
+fn handle(state: &mut State, request: Request) -> Vec<u8> {
+    match (request.method.as_str(), request.target.as_str()) {
+        ("GET", "/.well-known/openid-configuration") => json_response(
+            200,
+            &json!({
+                "issuer": state.issuer,
+                "authorization_endpoint": format!("{}/authorize", state.issuer),
+                "token_endpoint": format!("{}/token", state.issuer),
+                "jwks_uri": format!("{}/jwks", state.issuer),
+            }),
+        ),
+        ("GET", "/jwks") => json_response(200, &serde_json::to_value(state.key.jwks()).unwrap()),
+        ("POST", "/token") => token(state, &request),
+        _ => json_response(404, &json!({ "error": "not_found" })),
+    }
+}
+
+fn token(state: &mut State, request: &Request) -> Vec<u8> {
+    let error = |code: &str| json_response(400, &json!({ "error": code }));
+
+    let expected: String = format!(
+        "Basic {}",
+        STANDARD.encode(format!("{}:{}", state.client_id, state.client_secret))
+    );
+
+    if request.header("Authorization") != Some(expected.as_str()) {
+        return json_response(401, &json!({ "error": "invalid_client" }));
+    }
+
+    let form: HashMap<String, String> = request.form();
+
+    if form.get("grant_type").map(String::as_str) != Some("authorization_code") {
+        return error("unsupported_grant_type");
+    }
+
+    let Some(grant) = form.get("code").and_then(|code| state.grants.remove(code)) else {
+        return error("invalid_grant");
+    };
+
+    let verifier: &str = form.get("code_verifier").map_or("", String::as_str);
+
+    if URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())) != grant.code_challenge
+        || form.get("redirect_uri") != Some(&grant.redirect_uri)
+    {
+        return error("invalid_grant");
+    }
+
+    let now: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let mut claims: serde_json::Map<String, Value> = json!({
+        "iss": state.issuer,
+        "aud": state.client_id,
+        "sub": grant.subject,
+        "iat": now,
+        "exp": now + 300,
+        "nonce": grant.nonce,
+        "preferred_username": "alice",
+        "name": "Alice Example",
+        "email": "alice@example.org",
+    })
+    .as_object()
+    .cloned()
+    .unwrap();
+    claims.extend(state.overrides.clone());
+
+    let mut header: Header = Header::new(Algorithm::ES256);
+    header.kid = state.key.key_id().map(str::to_owned);
+    let id_token: String =
+        jsonwebtoken::encode(&header, &claims, state.key.encoding_key()).unwrap();
+
+    json_response(
+        200,
+        &json!({ "access_token": "opaque", "token_type": "Bearer", "id_token": id_token }),
+    )
+}
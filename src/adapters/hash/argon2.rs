@@ -1,30 +1,205 @@
-use crate::domain::user::password_hasher::PasswordHasher as DomainPasswordHasher;
+use crate::domain::user::password_hasher::{
+    HashError, PasswordHasher as DomainPasswordHasher, PasswordMatch,
+};
 use argon2::{
-    Argon2,
-    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
+    Algorithm, Argon2, KeyId, Params, ParamsBuilder, Version,
+    password_hash::{
+        Error as PasswordHashError, PasswordHash, PasswordHasher, PasswordVerifier, SaltString,
+        rand_core::OsRng,
+    },
 };
+use std::sync::Arc;
+
+/// Marks the hashes made with the pepper, so that hashes from before it was configured can
+/// still be verified, and then upgraded.
+const PEPPER_KEY_ID: &[u8] = b"pepper";
 
+/// Argon2id hashing. An optional pepper, a secret kept out of the database, is mixed into
+/// every new hash.
 #[derive(Clone)]
-pub struct Argon2Hasher;
+pub struct Argon2Hasher {
+    params: Params,
+    pepper: Option<Arc<[u8]>>,
+}
+
+impl Argon2Hasher {
+    /// Fails when the parameters are out of the ranges Argon2 supports.
+    pub fn new(
+        memory: u32,
+        iterations: u32,
+        parallelism: u32,
+        pepper: Option<String>,
+    ) -> Result<Self, argon2::Error> {
+        let mut builder: ParamsBuilder = ParamsBuilder::new();
+        builder
+            .m_cost(memory)
+            .t_cost(iterations)
+            .p_cost(parallelism);
+
+        if pepper.is_some() {
+            builder.keyid(KeyId::new(PEPPER_KEY_ID)?);
+        }
+
+        let params: Params = builder.build()?;
+
+        // Rejects a pepper that is too long now rather than on first use.
+        if let Some(pepper) = &pepper {
+            Argon2::new_with_secret(
+                pepper.as_bytes(),
+                Algorithm::Argon2id,
+                Version::V0x13,
+                params.clone(),
+            )?;
+        }
+
+        Ok(Self {
+            params,
+            pepper: pepper.map(|pepper| Arc::from(pepper.into_bytes())),
+        })
+    }
+
+    /// Argon2 for verifying hashes made with or without the pepper, unless they were made with
+    /// a pepper that is no longer configured.
+    fn argon2(&self, peppered: bool) -> Option<Argon2<'_>> {
+        match (&self.pepper, peppered) {
+            (Some(pepper), true) => Argon2::new_with_secret(
+                pepper,
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )
+            .ok(),
+            (None, true) => None,
+            (_, false) => Some(Argon2::new(
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )),
+        }
+    }
+
+    fn is_outdated(&self, hash: &PasswordHash<'_>, params: &Params) -> bool {
+        hash.algorithm != Algorithm::Argon2id.ident()
+            || hash.version != Some(Version::V0x13.into())
+            || params.m_cost() != self.params.m_cost()
+            || params.t_cost() != self.params.t_cost()
+            || params.p_cost() != self.params.p_cost()
+            || params.keyid() != self.params.keyid()
+    }
+}
 
 impl DomainPasswordHasher for Argon2Hasher {
     fn hash(&self, plain: &str) -> String {
-        let argon2: Argon2<'_> = Argon2::default();
-
+        let argon2: Argon2<'_> = self
+            .argon2(self.pepper.is_some())
+            .expect("The pepper is validated when the hasher is built");
         let salt: SaltString = SaltString::generate(&mut OsRng);
 
+        argon2
+            .hash_password(plain.as_bytes(), &salt)
+            .expect("Argon2 parameters are validated when the hasher is built")
+            .to_string()
+    }
+
+    fn verify(&self, plain: &str, hash: &str) -> Result<PasswordMatch, HashError> {
         let password_hash: PasswordHash<'_> =
-            argon2.hash_password(plain.as_bytes(), &salt).unwrap();
+            PasswordHash::new(hash).map_err(|_| HashError::Malformed)?;
+        let params: Params = Params::try_from(&password_hash).map_err(|_| HashError::Malformed)?;
+        let peppered: bool = match params.keyid() {
+            b"" => false,
+            PEPPER_KEY_ID => true,
+            _ => return Err(HashError::Malformed),
+        };
+        let argon2: Argon2<'_> = self.argon2(peppered).ok_or(HashError::Malformed)?;
 
-        password_hash.to_string()
+        match argon2.verify_password(plain.as_bytes(), &password_hash) {
+            Ok(()) if self.is_outdated(&password_hash, &params) => Ok(PasswordMatch::Outdated),
+            Ok(()) => Ok(PasswordMatch::Match),
+            Err(PasswordHashError::Password) => Ok(PasswordMatch::Mismatch),
+            Err(_) => Err(HashError::Malformed),
+        }
     }
+}
 
-    fn verify(&self, plain: &str, hash: &str) -> bool {
-        let password_hash: PasswordHash<'_> = PasswordHash::new(hash).unwrap();
-        let argon2: Argon2<'_> = Argon2::default();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        argon2
-            .verify_password(plain.as_bytes(), &password_hash)
-            .is_ok()
+    /// Parameters far below production ones, to keep the tests fast.
+    fn hasher(memory: u32, iterations: u32, pepper: Option<&str>) -> Argon2Hasher {
+        Argon2Hasher::new(memory, iterations, 1, pepper.map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn matches_hashes_made_with_the_current_parameters() {
+        let hasher: Argon2Hasher = hasher(16, 1, None);
+        let hash: String = hasher.hash("secret");
+
+        assert_eq!(
+            hasher.verify("secret", &hash).unwrap(),
+            PasswordMatch::Match
+        );
+        assert_eq!(
+            hasher.verify("guess", &hash).unwrap(),
+            PasswordMatch::Mismatch
+        );
+    }
+
+    #[test]
+    fn reports_hashes_made_with_weaker_parameters_as_outdated() {
+        let current: Argon2Hasher = hasher(32, 2, None);
+
+        for weaker in [hasher(16, 2, None), hasher(32, 1, None)] {
+            let hash: String = weaker.hash("secret");
+
+            assert_eq!(
+                current.verify("secret", &hash).unwrap(),
+                PasswordMatch::Outdated
+            );
+            assert_eq!(
+                current.verify("guess", &hash).unwrap(),
+                PasswordMatch::Mismatch
+            );
+        }
+    }
+
+    #[test]
+    fn reports_hashes_made_before_the_pepper_as_outdated() {
+        let hash: String = hasher(16, 1, None).hash("secret");
+
+        assert_eq!(
+            hasher(16, 1, Some("pepper"))
+                .verify("secret", &hash)
+                .unwrap(),
+            PasswordMatch::Outdated
+        );
+    }
+
+    #[test]
+    fn reports_garbage_hashes_as_malformed() {
+        let hasher: Argon2Hasher = hasher(16, 1, None);
+
+        for hash in [
+            "",
+            "not a hash",
+            "$argon2id$",
+            "$argon2id$v=19$m=abc,t=1,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA",
+        ] {
+            assert!(
+                matches!(hasher.verify("secret", hash), Err(HashError::Malformed)),
+                "hash {:?}",
+                hash
+            );
+        }
+    }
+
+    #[test]
+    fn reports_hashes_made_with_a_pepper_no_longer_configured_as_malformed() {
+        let hash: String = hasher(16, 1, Some("pepper")).hash("secret");
+
+        assert!(matches!(
+            hasher(16, 1, None).verify("secret", &hash),
+            Err(HashError::Malformed)
+        ));
     }
 }
@@ -1,4 +1,7 @@
-use actix_web::{HttpResponse, ResponseError};
+use actix_web::{
+    HttpResponse, ResponseError,
+    http::header::{HeaderName, HeaderValue},
+};
 use serde::Serialize;
 use std::fmt::{Display, Formatter};
 
@@ -7,6 +10,10 @@ pub struct ApiError {
     message: String,
     #[serde(skip)]
     status: actix_web::http::StatusCode,
+    #[serde(skip)]
+    headers: Vec<(HeaderName, HeaderValue)>,
+    #[serde(skip)]
+    details: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl ApiError {
@@ -14,6 +21,8 @@ impl ApiError {
         Self {
             message: msg.into(),
             status,
+            headers: Vec::new(),
+            details: None,
         }
     }
 
@@ -21,8 +30,23 @@ impl ApiError {
         Self {
             message: "Internal server error".into(),
             status: actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
+            headers: Vec::new(),
+            details: None,
         }
     }
+
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// Adds extra fields to the JSON body, next to `message`.
+    pub fn with_details(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.details
+            .get_or_insert_with(serde_json::Map::new)
+            .insert(key.into(), value);
+        self
+    }
 }
 
 impl ResponseError for ApiError {
@@ -31,7 +55,20 @@ impl ResponseError for ApiError {
     }
 
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status).json(serde_json::json!({ "message": self.message }))
+        let mut response = HttpResponse::build(self.status);
+
+        for header in &self.headers {
+            response.insert_header(header.clone());
+        }
+
+        let mut body = serde_json::Map::new();
+        body.insert("message".into(), self.message.clone().into());
+
+        if let Some(details) = &self.details {
+            body.extend(details.clone());
+        }
+
+        response.json(body)
     }
 }
 
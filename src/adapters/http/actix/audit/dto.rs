@@ -0,0 +1,87 @@
+use crate::domain::audit::entry::{AuditChange, AuditEntry};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQueryParams {
+    /// Only entries of this actor.
+    pub actor_id: Option<String>,
+    /// Only entries of this action, such as `user.deleted`.
+    pub action: Option<String>,
+    /// Only entries about this target, such as the identifier of a user.
+    pub target: Option<String>,
+    /// Only entries at or after this time, in seconds since the Unix epoch.
+    pub since: Option<u64>,
+    /// Only entries before this time, in seconds since the Unix epoch.
+    pub until: Option<u64>,
+    /// Page size, at most 200. Defaults to 50. Ignored by exports.
+    pub limit: Option<u64>,
+    /// The `next_cursor` of the previous page. Ignored by exports.
+    pub cursor: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AuditChangeDto {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AuditEntryResponseDto {
+    pub id: String,
+    /// Who acted, absent for failed logins.
+    pub actor_id: Option<String>,
+    pub actor_username: Option<String>,
+    /// What was done, such as `user.deleted`.
+    pub action: String,
+    /// What was acted on, such as the identifier of a user or the name of a role.
+    pub target: Option<String>,
+    /// Where the request came from.
+    pub ip_address: Option<String>,
+    /// The fields the action changed.
+    pub changes: Vec<AuditChangeDto>,
+    /// Why, when the actor had to say.
+    pub reason: Option<String>,
+    /// When it happened, in seconds since the Unix epoch.
+    pub occurred_at: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AuditPageDto {
+    /// The entries of the page, newest first.
+    pub items: Vec<AuditEntryResponseDto>,
+    /// Cursor of the next page, absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl From<AuditChange> for AuditChangeDto {
+    fn from(change: AuditChange) -> Self {
+        AuditChangeDto {
+            field: change.field,
+            before: change.before,
+            after: change.after,
+        }
+    }
+}
+
+impl From<AuditEntry> for AuditEntryResponseDto {
+    fn from(entry: AuditEntry) -> Self {
+        AuditEntryResponseDto {
+            id: entry.id.to_string(),
+            actor_id: entry.actor_id.map(|id| id.to_string()),
+            actor_username: entry.actor_username,
+            action: entry.action.as_str().into(),
+            target: entry.target,
+            ip_address: entry.ip_address.map(|ip| ip.to_string()),
+            changes: entry
+                .changes
+                .into_iter()
+                .map(AuditChangeDto::from)
+                .collect(),
+            reason: entry.reason,
+            occurred_at: entry.occurred_at,
+        }
+    }
+}
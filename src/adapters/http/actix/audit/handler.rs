@@ -0,0 +1,203 @@
+use super::dto::{AuditEntryResponseDto, AuditPageDto, AuditQueryParams};
+use crate::{
+    adapters::{
+        http::actix::api_error::ApiError,
+        persistence::postgres::audit::repository::PostgresAuditLog,
+    },
+    application::{
+        audit::list_entries::{
+            DEFAULT_PAGE_SIZE, ListAuditEntriesError, ListAuditEntriesOutput,
+            ListAuditEntriesService, MAX_PAGE_SIZE,
+        },
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::audit::{
+        entry::{AuditChange, AuditEntry},
+        error::AuditError,
+        query::AuditQuery,
+    },
+};
+use actix_web::{
+    HttpResponse,
+    http::{StatusCode, header::CONTENT_DISPOSITION},
+    web,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "audit",
+    params(AuditQueryParams),
+    tag = "Audit",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "Entries of the audit log of the organization the token acts in, newest first", body = AuditPageDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the audit.read permission")
+    )
+)]
+pub async fn list_entries(
+    service: web::Data<ListAuditEntriesService<PostgresAuditLog>>,
+    params: web::Query<AuditQueryParams>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let query: AuditQuery = audit_query(params.into_inner(), &actor)?;
+
+    let page: ListAuditEntriesOutput = service.execute(query, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(AuditPageDto {
+        items: page
+            .entries
+            .into_iter()
+            .map(AuditEntryResponseDto::from)
+            .collect(),
+        next_cursor: page.next_cursor.map(|id| id.to_string()),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "audit/export",
+    params(AuditQueryParams),
+    tag = "Audit",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The matching entries as CSV, newest first, at most 10000 of them", content_type = "text/csv"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the audit.read permission")
+    )
+)]
+pub async fn export_entries(
+    service: web::Data<ListAuditEntriesService<PostgresAuditLog>>,
+    params: web::Query<AuditQueryParams>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let mut params: AuditQueryParams = params.into_inner();
+    params.limit = None;
+    params.cursor = None;
+
+    let query: AuditQuery = audit_query(params, &actor)?;
+
+    let entries: Vec<AuditEntry> = service.export(query, &actor).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((CONTENT_DISPOSITION, "attachment; filename=\"audit.csv\""))
+        .body(to_csv(&entries)))
+}
+
+fn audit_query(
+    params: AuditQueryParams,
+    actor: &AuthenticatedUser,
+) -> Result<AuditQuery, ApiError> {
+    let uuid = |value: Option<String>, message: &'static str| {
+        value
+            .map(|value| {
+                Uuid::parse_str(&value).map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, message))
+            })
+            .transpose()
+    };
+
+    Ok(AuditQuery {
+        organization_id: actor.organization_id,
+        actor_id: uuid(params.actor_id, "Invalid UUID format")?,
+        action: params.action.map(|action| action.parse()).transpose()?,
+        target: params.target.filter(|target| !target.trim().is_empty()),
+        since: params.since,
+        until: params.until,
+        before: uuid(params.cursor, "Invalid cursor")?,
+        limit: params.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+    })
+}
+
+/// One row per entry, changes written as `field: before -> after` separated by `; `.
+fn to_csv(entries: &[AuditEntry]) -> String {
+    let mut csv: String = String::from(
+        "id,occurred_at,actor_id,actor_username,action,target,ip_address,changes,reason\n",
+    );
+
+    for entry in entries {
+        let changes: String = entry
+            .changes
+            .iter()
+            .map(describe_change)
+            .collect::<Vec<String>>()
+            .join("; ");
+
+        let fields: [String; 9] = [
+            entry.id.to_string(),
+            entry.occurred_at.to_string(),
+            entry.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+            entry.actor_username.clone().unwrap_or_default(),
+            entry.action.as_str().into(),
+            entry.target.clone().unwrap_or_default(),
+            entry
+                .ip_address
+                .map(|ip| ip.to_string())
+                .unwrap_or_default(),
+            changes,
+            entry.reason.clone().unwrap_or_default(),
+        ];
+
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn describe_change(change: &AuditChange) -> String {
+    format!(
+        "{}: {} -> {}",
+        change.field,
+        change.before.as_deref().unwrap_or("-"),
+        change.after.as_deref().unwrap_or("-")
+    )
+}
+
+/// Quotes a field holding a separator, a quote or a line break (RFC 4180). Fields starting
+/// like a formula are prefixed with a quote so spreadsheets show them as text.
+fn csv_field(value: &str) -> String {
+    let value: String = match value.starts_with(['=', '+', '-', '@']) {
+        true => format!("'{}", value),
+        false => value.to_owned(),
+    };
+
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value,
+    }
+}
+
+impl From<AuditError> for ApiError {
+    fn from(err: AuditError) -> Self {
+        match err {
+            AuditError::UnknownAction(action) => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("Unknown action: {}", action),
+            ),
+        }
+    }
+}
+
+impl From<ListAuditEntriesError> for ApiError {
+    fn from(err: ListAuditEntriesError) -> Self {
+        match err {
+            ListAuditEntriesError::InvalidLimit => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("Limit must be between 1 and {}", MAX_PAGE_SIZE),
+            ),
+            ListAuditEntriesError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to the audit log",
+            ),
+            ListAuditEntriesError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(handler::list_entries, handler::export_entries),
+    components(schemas(dto::AuditEntryResponseDto, dto::AuditChangeDto, dto::AuditPageDto)),
+    tags(
+        (name = "Audit", description = "Audit log endpoints")
+    )
+)]
+pub struct AuditApiDoc;
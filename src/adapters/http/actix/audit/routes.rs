@@ -0,0 +1,26 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{export_entries, list_entries};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/audit")
+            .wrap(AuthMiddleware)
+            .route(
+                "",
+                web::get()
+                    .to(list_entries)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "/export",
+                web::get()
+                    .to(export_entries)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            ),
+    );
+}
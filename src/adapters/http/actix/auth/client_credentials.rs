@@ -0,0 +1,41 @@
+use actix_web::{HttpRequest, http::header::AUTHORIZATION};
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Client credentials sent either through HTTP Basic authentication or in the request body
+/// (RFC 6749, section 2.3.1).
+pub struct ClientCredentials {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl ClientCredentials {
+    pub fn from_request(
+        req: &HttpRequest,
+        client_id: Option<&String>,
+        client_secret: Option<&String>,
+    ) -> Option<Self> {
+        let basic: Option<ClientCredentials> = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.strip_prefix("Basic "))
+            .and_then(|encoded| STANDARD.decode(encoded.trim()).ok())
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .and_then(|decoded| {
+                decoded
+                    .split_once(':')
+                    .map(|(id, secret)| ClientCredentials {
+                        client_id: id.into(),
+                        client_secret: secret.into(),
+                    })
+            });
+
+        basic.or_else(|| match (client_id, client_secret) {
+            (Some(client_id), Some(client_secret)) => Some(ClientCredentials {
+                client_id: client_id.clone(),
+                client_secret: client_secret.clone(),
+            }),
+            _ => None,
+        })
+    }
+}
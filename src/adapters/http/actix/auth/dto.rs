@@ -1,22 +1,39 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-#[derive(Deserialize, ToSchema)]
-pub enum GrantType  {
+#[derive(Serialize, Deserialize, ToSchema)]
+pub enum GrantType {
     #[serde(rename = "password")]
     Password,
     #[serde(rename = "refresh_token")]
     RefreshToken,
+    #[serde(rename = "mfa_otp")]
+    MfaOtp,
+    #[serde(rename = "passkey")]
+    Passkey,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:token-exchange")]
+    TokenExchange,
     #[serde(other)]
     Unsupported,
 }
 
+impl GrantType {
+    /// The grant types the token endpoint accepts.
+    pub const SUPPORTED: [GrantType; 5] = [
+        GrantType::Password,
+        GrantType::RefreshToken,
+        GrantType::MfaOtp,
+        GrantType::Passkey,
+        GrantType::TokenExchange,
+    ];
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct TokenRequest {
     /// OAuth2 grant type
     pub grant_type: GrantType,
 
-    /// Username (password grant)
+    /// Username, or verified email address (password grant)
     pub username: Option<String>,
 
     /// Password (password grant)
@@ -24,4 +41,124 @@ pub struct TokenRequest {
 
     /// Refresh token (refresh_token grant)
     pub refresh_token: Option<String>,
+
+    /// Token returned by a password grant that requires a second factor (mfa_otp grant)
+    pub mfa_token: Option<String>,
+
+    /// TOTP or recovery code (mfa_otp grant)
+    pub otp: Option<String>,
+
+    /// Base64url encoded credential id (passkey grant)
+    pub credential_id: Option<String>,
+
+    /// Base64url encoded `response.clientDataJSON` (passkey grant)
+    pub client_data_json: Option<String>,
+
+    /// Base64url encoded `response.authenticatorData` (passkey grant)
+    pub authenticator_data: Option<String>,
+
+    /// Base64url encoded `response.signature` (passkey grant)
+    pub signature: Option<String>,
+
+    /// Access token of the impersonating administrator (token exchange grant)
+    pub subject_token: Option<String>,
+
+    /// Must be `urn:ietf:params:oauth:token-type:access_token` (token exchange grant)
+    pub subject_token_type: Option<String>,
+
+    /// UUID of the user to impersonate (token exchange grant)
+    pub requested_subject: Option<String>,
+
+    /// Slug of the organization to act in, defaults to the home organization of the user.
+    /// Usernames are looked up in it (password grant)
+    pub organization: Option<String>,
+
+    /// Space-delimited list of requested scopes
+    pub scope: Option<String>,
+
+    /// Client identifier (when not sent through HTTP Basic authentication)
+    pub client_id: Option<String>,
+
+    /// Client secret (when not sent through HTTP Basic authentication)
+    pub client_secret: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub enum TokenTypeHint {
+    #[serde(rename = "access_token")]
+    AccessToken,
+    #[serde(rename = "refresh_token")]
+    RefreshToken,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct IntrospectRequest {
+    /// The token to introspect
+    pub token: String,
+
+    /// Type of the token, used to speed up the lookup
+    pub token_type_hint: Option<TokenTypeHint>,
+
+    /// Client identifier (when not sent through HTTP Basic authentication)
+    pub client_id: Option<String>,
+
+    /// Client secret (when not sent through HTTP Basic authentication)
+    pub client_secret: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct RevokeRequest {
+    /// The token to revoke
+    pub token: String,
+
+    /// Type of the token, used to speed up the lookup
+    pub token_type_hint: Option<TokenTypeHint>,
+
+    /// Client identifier (when not sent through HTTP Basic authentication)
+    pub client_id: Option<String>,
+
+    /// Client secret (when not sent through HTTP Basic authentication)
+    pub client_secret: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct IntrospectionResponseDto {
+    /// Whether the token is currently valid
+    pub active: bool,
+    /// Identifier of the user the token was issued to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    /// Username of the user the token was issued to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Space-delimited list of granted scopes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Identifier of the organization the token acts in, which rooms and other resources
+    /// should be scoped to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+    /// Groups of the user in the organization, such as `backend`, which rooms can be granted to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// Permissions granted by the roles and groups of the user, such as `room.moderate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
+    /// Expiration time, in seconds since the Unix epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<u64>,
+    /// Type of the token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    /// The administrator impersonating the user (RFC 8693, section 4.1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub act: Option<ActDto>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ActDto {
+    /// Identifier of the administrator
+    pub sub: String,
+    /// Username of the administrator
+    pub username: String,
 }
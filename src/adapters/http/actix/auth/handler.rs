@@ -1,24 +1,67 @@
-use super::dto::TokenRequest;
+use super::{
+    client_credentials::ClientCredentials,
+    dto::{
+        ActDto, IntrospectRequest, IntrospectionResponseDto, RevokeRequest, TokenRequest,
+        TokenTypeHint,
+    },
+    login_context::login_context,
+};
 use crate::{
     adapters::{
-        auth::local::LocalAuthenticator,
         hash::argon2::Argon2Hasher,
-        http::actix::{api_error::ApiError, auth::dto::GrantType},
-        persistence::postgres::user::repository::PostgresUserRepository,
+        http::actix::{
+            api_error::ApiError,
+            auth::dto::GrantType,
+            passkey::handler::decode_base64url,
+            server::{
+                AppGroupRepository, AppImpersonate, AppLogin, AppRevocationStore, AppRoleRepository,
+            },
+        },
+        persistence::postgres::{
+            client::repository::PostgresClientRepository,
+            organization::repository::PostgresOrganizationRepository,
+            session::repository::PostgresSessionRepository,
+            user::repository::PostgresUserRepository,
+        },
         token::jwt::JwtService,
     },
     application::{
         auth::{
             credentials::Credentials,
             error::AuthenticationError,
-            login::{Login, LoginError},
+            impersonate::{ImpersonateError, ImpersonateInput},
+            login::LoginError,
+            scope::{Scope, ScopeError},
+        },
+        client::authenticate_client::{AuthenticateClientError, AuthenticateClientService},
+        organization::find_organization::FindOrganizationService,
+        security::{
+            introspect_token::{
+                IntrospectTokenError, IntrospectTokenInput, IntrospectTokenOutput,
+                IntrospectTokenService,
+            },
+            revoke_token::{RevokeTokenError, RevokeTokenInput, RevokeTokenService},
+            token::{IssuedToken, MfaToken, RefreshToken, Token, TokenKind},
         },
-        security::token::{IssuedToken, RefreshToken},
     },
-    domain::user::value_objects::{password_plain::PasswordPlain, username::Username},
+    domain::{
+        client::entity::Client,
+        organization::entity::{Organization, OrganizationSlug},
+        user::value_objects::{email::Email, password_plain::PasswordPlain, username::Username},
+    },
 };
-use actix_web::{HttpResponse, http::StatusCode, web};
-use serde_json::json;
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        StatusCode,
+        header::{HeaderValue, RETRY_AFTER, WWW_AUTHENTICATE},
+    },
+    web,
+};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
 
 #[utoipa::path(
     post,
@@ -30,16 +73,43 @@ use serde_json::json;
     ),
     responses(
         (status = 200, description = "User autheticated"),
-        (status = 400, description = "Invalid data provided"),
-        (status = 401, description = "Invalid credentials")
+        (status = 400, description = "Invalid data provided or invalid scope"),
+        (status = 401, description = "Invalid user or client credentials"),
+        (status = 403, description = "Second factor required, exchange the returned mfa_token with the mfa_otp grant, or password expired and to be changed at /auth/password/expired, or impersonation not allowed, or not a member of the organization"),
+        (status = 429, description = "Too many failed attempts, retry after the Retry-After delay")
     )
 )]
 pub async fn token(
+    req: HttpRequest,
     body: web::Form<TokenRequest>,
-    login: web::Data<
-        Login<LocalAuthenticator<PostgresUserRepository, Argon2Hasher, JwtService>, JwtService>,
-    >,
+    login: web::Data<AppLogin>,
+    impersonate: web::Data<AppImpersonate>,
+    client_service: web::Data<AuthenticateClientService<PostgresClientRepository, Argon2Hasher>>,
+    organization_service: web::Data<FindOrganizationService<PostgresOrganizationRepository>>,
 ) -> Result<HttpResponse, ApiError> {
+    let client: Option<Client> = match ClientCredentials::from_request(
+        &req,
+        body.client_id.as_ref(),
+        body.client_secret.as_ref(),
+    ) {
+        Some(credentials) => Some(
+            client_service
+                .execute(&credentials.client_id, &credentials.client_secret)
+                .await?,
+        ),
+        None => None,
+    };
+
+    let organization_id: Option<Uuid> = match &body.organization {
+        Some(slug) => Some(
+            organization_service
+                .find_by_slug(&OrganizationSlug::new(slug.clone())?)
+                .await?
+                .id,
+        ),
+        None => None,
+    };
+
     let credentials: Credentials =
         match body.grant_type {
             GrantType::Password => {
@@ -51,9 +121,20 @@ pub async fn token(
                     ApiError::new(StatusCode::BAD_REQUEST, "password is required")
                 })?;
 
-                Credentials::UsernamePassword {
-                    username: Username::new(username.clone())?,
-                    password: PasswordPlain::new(password.clone())?,
+                let password: PasswordPlain = PasswordPlain::new(password.clone())?;
+
+                // Usernames cannot contain `@`, so the email address is told apart by it.
+                if username.contains('@') {
+                    Credentials::EmailPassword {
+                        email: Email::new(username.clone())?,
+                        password,
+                    }
+                } else {
+                    Credentials::UsernamePassword {
+                        organization_id: organization_id.unwrap_or(Organization::DEFAULT_ID),
+                        username: Username::new(username.clone())?,
+                        password,
+                    }
                 }
             }
 
@@ -65,6 +146,44 @@ pub async fn token(
                 Credentials::RefreshToken(RefreshToken::new(refresh_token.clone()))
             }
 
+            GrantType::MfaOtp => {
+                let mfa_token: &String = body.mfa_token.as_ref().ok_or_else(|| {
+                    ApiError::new(StatusCode::BAD_REQUEST, "mfa_token is required")
+                })?;
+
+                let otp: &String = body
+                    .otp
+                    .as_ref()
+                    .ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "otp is required"))?;
+
+                Credentials::MfaOtp {
+                    mfa_token: MfaToken::new(mfa_token.clone()),
+                    code: otp.clone(),
+                }
+            }
+
+            GrantType::Passkey => {
+                let field = |value: &Option<String>, name: &str| {
+                    value
+                        .as_deref()
+                        .ok_or_else(|| {
+                            ApiError::new(StatusCode::BAD_REQUEST, format!("{} is required", name))
+                        })
+                        .and_then(|value| decode_base64url(value, name))
+                };
+
+                Credentials::Passkey {
+                    credential_id: field(&body.credential_id, "credential_id")?,
+                    client_data_json: field(&body.client_data_json, "client_data_json")?,
+                    authenticator_data: field(&body.authenticator_data, "authenticator_data")?,
+                    signature: field(&body.signature, "signature")?,
+                }
+            }
+
+            GrantType::TokenExchange => {
+                return exchange_token(&req, &body, &impersonate, client.as_ref()).await;
+            }
+
             GrantType::Unsupported => {
                 return Err(ApiError::new(
                     StatusCode::BAD_REQUEST,
@@ -73,39 +192,368 @@ pub async fn token(
             }
         };
 
+    let requested_scopes: Option<Vec<Scope>> =
+        body.scope.as_deref().map(Scope::parse_list).transpose()?;
+
+    let issued: IssuedToken = login
+        .execute(
+            credentials,
+            organization_id,
+            requested_scopes,
+            client.as_ref(),
+            &login_context(&req),
+        )
+        .await?;
+
+    Ok(token_response(issued))
+}
+
+/// Issues a token to impersonate a user (RFC 8693 token exchange). The administrator's access
+/// token is the subject token and the user to impersonate is the requested subject.
+async fn exchange_token(
+    req: &HttpRequest,
+    body: &TokenRequest,
+    service: &AppImpersonate,
+    client: Option<&Client>,
+) -> Result<HttpResponse, ApiError> {
+    let subject_token: &String = body
+        .subject_token
+        .as_ref()
+        .ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "subject_token is required"))?;
+
+    if body.subject_token_type.as_deref() != Some(ACCESS_TOKEN_TYPE) {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            format!("subject_token_type must be {}", ACCESS_TOKEN_TYPE),
+        ));
+    }
+
+    let requested_subject: Uuid = body
+        .requested_subject
+        .as_deref()
+        .ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "requested_subject is required"))
+        .and_then(|subject| {
+            Uuid::parse_str(subject)
+                .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))
+        })?;
+
+    let input: ImpersonateInput = ImpersonateInput {
+        subject_token: Token::new(subject_token.as_str()),
+        requested_subject,
+        scopes: body.scope.as_deref().map(Scope::parse_list).transpose()?,
+        ip_address: req.peer_addr().map(|address| address.ip()),
+    };
+
+    let issued: IssuedToken = service.execute(input, client).await?;
+
+    let mut response: Value = token_body(issued);
+    response["issued_token_type"] = json!(ACCESS_TOKEN_TYPE);
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// The RFC 6749 access token response for tokens issued by a login.
+pub fn token_response(issued: IssuedToken) -> HttpResponse {
+    HttpResponse::Ok().json(token_body(issued))
+}
+
+fn token_body(issued: IssuedToken) -> Value {
     let IssuedToken {
         expires_in,
         token,
         refresh_token,
-    } = login.execute(credentials).await?;
+        id_token,
+        scopes,
+        ..
+    } = issued;
 
-    Ok(HttpResponse::Ok().json(json!({
+    let mut response = json!({
         "access_token": token.as_str(),
         "token_type": "Bearer",
         "expires_in": expires_in,
-        "refresh_token": refresh_token.as_ref().map(|t| t.as_str())
-    })))
+        "refresh_token": refresh_token.as_ref().map(|t| t.as_str()),
+        "scope": Scope::join(&scopes)
+    });
+
+    if let Some(id_token) = id_token {
+        response["id_token"] = json!(id_token.as_str());
+    }
+
+    response
+}
+
+#[utoipa::path(
+    get,
+    path = "/authorize",
+    tag = "Auth",
+    security(()),
+    responses(
+        (status = 400, description = "No response type is supported, tokens are only issued by the token endpoint")
+    )
+)]
+pub async fn authorize() -> Result<HttpResponse, ApiError> {
+    // Clients have no registered redirect URIs, the error can't be redirected back safely.
+    Err(ApiError::new(
+        StatusCode::BAD_REQUEST,
+        "unsupported_response_type",
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/introspect",
+    tag = "Auth",
+    request_body(
+        content = IntrospectRequest,
+        content_type = "application/x-www-form-urlencoded"
+    ),
+    responses(
+        (status = 200, description = "Token description", body = IntrospectionResponseDto),
+        (status = 401, description = "Invalid client credentials")
+    )
+)]
+pub async fn introspect(
+    req: HttpRequest,
+    body: web::Form<IntrospectRequest>,
+    client_service: web::Data<AuthenticateClientService<PostgresClientRepository, Argon2Hasher>>,
+    service: web::Data<
+        IntrospectTokenService<
+            JwtService,
+            AppRevocationStore,
+            PostgresUserRepository,
+            AppRoleRepository,
+            AppGroupRepository,
+            PostgresOrganizationRepository,
+        >,
+    >,
+) -> Result<HttpResponse, ApiError> {
+    let IntrospectRequest {
+        token,
+        token_type_hint,
+        client_id,
+        client_secret,
+    } = body.into_inner();
+
+    authenticate_client(
+        &req,
+        &client_service,
+        client_id.as_ref(),
+        client_secret.as_ref(),
+    )
+    .await?;
+
+    let output: IntrospectTokenOutput = service
+        .execute(IntrospectTokenInput {
+            token,
+            token_type_hint: token_type_hint.map(TokenKind::from),
+        })
+        .await?;
+
+    Ok(HttpResponse::Ok().json(IntrospectionResponseDto {
+        active: output.active,
+        sub: output.subject.map(|id| id.to_string()),
+        username: output.username,
+        org: output.organization_id.map(|id| id.to_string()),
+        scope: output.active.then(|| Scope::join(&output.scopes)),
+        groups: output.active.then(|| {
+            output
+                .groups
+                .iter()
+                .map(|group| group.as_str().to_owned())
+                .collect()
+        }),
+        permissions: output.active.then(|| {
+            output
+                .permissions
+                .iter()
+                .map(|permission| permission.as_str().to_owned())
+                .collect()
+        }),
+        exp: output.expires_at,
+        token_type: output.kind.map(|kind| match kind {
+            TokenKind::Access => "Bearer".into(),
+            TokenKind::Refresh => "refresh_token".into(),
+        }),
+        act: output.actor.map(|actor| ActDto {
+            sub: actor.id.to_string(),
+            username: actor.username,
+        }),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/revoke",
+    tag = "Auth",
+    request_body(
+        content = RevokeRequest,
+        content_type = "application/x-www-form-urlencoded"
+    ),
+    responses(
+        (status = 200, description = "Token revoked, or already invalid, or issued to another client and left alone"),
+        (status = 401, description = "Invalid client credentials")
+    )
+)]
+pub async fn revoke(
+    req: HttpRequest,
+    body: web::Form<RevokeRequest>,
+    client_service: web::Data<AuthenticateClientService<PostgresClientRepository, Argon2Hasher>>,
+    service: web::Data<
+        RevokeTokenService<JwtService, AppRevocationStore, PostgresSessionRepository>,
+    >,
+) -> Result<HttpResponse, ApiError> {
+    let RevokeRequest {
+        token,
+        token_type_hint,
+        client_id,
+        client_secret,
+    } = body.into_inner();
+
+    let client: Client = authenticate_client(
+        &req,
+        &client_service,
+        client_id.as_ref(),
+        client_secret.as_ref(),
+    )
+    .await?;
+
+    service
+        .execute(
+            RevokeTokenInput {
+                token,
+                token_type_hint: token_type_hint.map(TokenKind::from),
+            },
+            &client,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn authenticate_client(
+    req: &HttpRequest,
+    service: &AuthenticateClientService<PostgresClientRepository, Argon2Hasher>,
+    client_id: Option<&String>,
+    client_secret: Option<&String>,
+) -> Result<Client, ApiError> {
+    let credentials: ClientCredentials =
+        ClientCredentials::from_request(req, client_id, client_secret)
+            .ok_or(AuthenticateClientError::InvalidClient)?;
+
+    let client: Client = service
+        .execute(&credentials.client_id, &credentials.client_secret)
+        .await?;
+
+    Ok(client)
+}
+
+impl From<TokenTypeHint> for TokenKind {
+    fn from(value: TokenTypeHint) -> Self {
+        match value {
+            TokenTypeHint::AccessToken => TokenKind::Access,
+            TokenTypeHint::RefreshToken => TokenKind::Refresh,
+        }
+    }
+}
+
+impl From<AuthenticateClientError> for ApiError {
+    fn from(value: AuthenticateClientError) -> Self {
+        match value {
+            AuthenticateClientError::InvalidClient => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "invalid_client")
+                    .with_header(WWW_AUTHENTICATE, HeaderValue::from_static("Basic"))
+            }
+            AuthenticateClientError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<IntrospectTokenError> for ApiError {
+    fn from(value: IntrospectTokenError) -> Self {
+        match value {
+            IntrospectTokenError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<RevokeTokenError> for ApiError {
+    fn from(value: RevokeTokenError) -> Self {
+        match value {
+            RevokeTokenError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
 }
 
 impl From<LoginError> for ApiError {
     fn from(value: LoginError) -> Self {
         match value {
             LoginError::Authentication(error) => match error {
-                AuthenticationError::_UnsupportedCredentials => {
+                AuthenticationError::MfaRequired(mfa_token) => {
+                    ApiError::new(StatusCode::FORBIDDEN, "mfa_required")
+                        .with_details("mfa_token", json!(mfa_token.as_str()))
+                }
+                AuthenticationError::UnsupportedCredentials => {
                     ApiError::new(StatusCode::BAD_REQUEST, "Unsupported credentials type")
                 }
                 AuthenticationError::UserInactive => {
                     ApiError::new(StatusCode::BAD_REQUEST, "User inactive")
                 }
-                AuthenticationError::InvalidCredentials => {
-                    ApiError::new(StatusCode::UNAUTHORIZED, "Invalid credentials")
+                // The expired password still proves who the user is, so it can be changed.
+                AuthenticationError::PasswordExpired => {
+                    ApiError::new(StatusCode::FORBIDDEN, "password_expired")
+                        .with_details("change_password", json!("/auth/password/expired"))
                 }
-                AuthenticationError::UserNotFound => {
-                    ApiError::new(StatusCode::NOT_FOUND, "User not found")
+                // Unknown users are not told apart, so usernames cannot be enumerated.
+                AuthenticationError::InvalidCredentials | AuthenticationError::UserNotFound => {
+                    ApiError::new(StatusCode::UNAUTHORIZED, "Invalid credentials")
                 }
                 AuthenticationError::ProviderUnavailable => ApiError::internal_server_error(),
             },
             LoginError::Token(_token_error) => ApiError::internal_server_error(),
+            LoginError::InvalidScope => ApiError::new(StatusCode::BAD_REQUEST, "invalid_scope"),
+            LoginError::InvalidClient => ApiError::new(StatusCode::BAD_REQUEST, "invalid_grant"),
+            LoginError::NotMember => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You are not a member of this organization",
+            ),
+            LoginError::Throttled(retry_after) => {
+                ApiError::new(StatusCode::TOO_MANY_REQUESTS, "Too many failed attempts")
+                    .with_header(RETRY_AFTER, HeaderValue::from(retry_after))
+            }
+        }
+    }
+}
+
+impl From<ImpersonateError> for ApiError {
+    fn from(value: ImpersonateError) -> Self {
+        match value {
+            ImpersonateError::InvalidToken => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "invalid_grant: invalid subject_token",
+            ),
+            ImpersonateError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to impersonate this user",
+            ),
+            ImpersonateError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            ImpersonateError::UserInactive => {
+                ApiError::new(StatusCode::BAD_REQUEST, "User inactive")
+            }
+            ImpersonateError::InvalidScope => {
+                ApiError::new(StatusCode::BAD_REQUEST, "invalid_scope")
+            }
+            ImpersonateError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ScopeError> for ApiError {
+    fn from(value: ScopeError) -> Self {
+        match value {
+            ScopeError::Unknown(scope) => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("invalid_scope: unknown scope '{}'", scope),
+            ),
         }
     }
 }
@@ -0,0 +1,15 @@
+use crate::application::auth::login::LoginContext;
+use actix_web::{HttpRequest, http::header::USER_AGENT};
+
+/// Builds the context of a login from the request. The address is the one of the peer:
+/// forwarding headers are not trusted, since any client can set them.
+pub fn login_context(req: &HttpRequest) -> LoginContext {
+    LoginContext {
+        ip_address: req.peer_addr().map(|address| address.ip()),
+        user_agent: req
+            .headers()
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from),
+    }
+}
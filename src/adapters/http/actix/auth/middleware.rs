@@ -1,14 +1,19 @@
 use crate::{
-    adapters::{http::actix::api_error::ApiError, token::jwt::JwtService},
+    adapters::{
+        http::actix::{api_error::ApiError, server::AppVerifyAccess},
+        persistence::postgres::audit::repository::PostgresAuditLog,
+    },
     application::{
+        audit::record::{entry, record},
         auth::authenticated_user::AuthenticatedUser,
-        security::{token::Token, token_service::TokenService},
+        security::{token::Token, verify_access::VerifyAccessError},
     },
+    domain::audit::entry::AuditAction,
 };
 use actix_web::{
     Error, HttpMessage,
     dev::{Service, ServiceRequest, ServiceResponse, Transform},
-    http::StatusCode,
+    http::{Method, StatusCode},
     web,
 };
 use std::{
@@ -66,20 +71,50 @@ where
                 .and_then(|h| h.strip_prefix("Bearer "))
                 .map(Token::new);
 
-            let jwt_service: &web::Data<JwtService> = req
-                .app_data::<web::Data<JwtService>>()
-                .expect("JwtService missing");
+            let verify_access: web::Data<AppVerifyAccess> = req
+                .app_data::<web::Data<AppVerifyAccess>>()
+                .expect("VerifyAccessService missing")
+                .clone();
 
             match token {
-                Some(token) => match jwt_service.verify(&token) {
-                    Ok(user) => {
+                Some(token) => match verify_access.execute(&token).await {
+                    Ok(mut user) => {
+                        // The peer, as for logins: forwarding headers are not trusted.
+                        user.ip_address = req.peer_addr().map(|address| address.ip());
+
+                        if user.is_impersonated() {
+                            let audit_log: web::Data<PostgresAuditLog> = req
+                                .app_data::<web::Data<PostgresAuditLog>>()
+                                .expect("PostgresAuditLog missing")
+                                .clone();
+
+                            // Put down to the administrator, on the impersonated user.
+                            record(
+                                audit_log.get_ref(),
+                                entry(&user, AuditAction::ImpersonatedRequest)
+                                    .with_target(user.id)
+                                    .with_reason(format!("{} {}", req.method(), req.path())),
+                            )
+                            .await;
+
+                            // Impersonation is for looking into issues: nothing can be changed.
+                            if !is_read_only(req.method()) {
+                                return Ok(req.into_response(actix_web::HttpResponse::from_error(
+                                    ApiError::new(
+                                        StatusCode::FORBIDDEN,
+                                        "Not allowed while impersonating",
+                                    ),
+                                )));
+                            }
+                        }
+
                         req.extensions_mut().insert::<AuthenticatedUser>(user);
 
                         service.call(req).await
                     }
-                    Err(_) => Ok(req.into_response(actix_web::HttpResponse::from_error(
-                        ApiError::new(StatusCode::UNAUTHORIZED, "Invalid token"),
-                    ))),
+                    Err(err) => Ok(
+                        req.into_response(actix_web::HttpResponse::from_error(ApiError::from(err)))
+                    ),
                 },
                 None => Ok(
                     req.into_response(actix_web::HttpResponse::from_error(ApiError::new(
@@ -91,3 +126,19 @@ where
         })
     }
 }
+
+fn is_read_only(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+impl From<VerifyAccessError> for ApiError {
+    fn from(err: VerifyAccessError) -> Self {
+        match err {
+            VerifyAccessError::InvalidToken => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "Invalid token")
+            }
+            VerifyAccessError::Revoked => ApiError::new(StatusCode::UNAUTHORIZED, "Token revoked"),
+            VerifyAccessError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -1,7 +1,10 @@
+pub mod client_credentials;
 pub mod dto;
 pub mod extractor;
 pub mod handler;
+pub mod login_context;
 pub mod middleware;
+pub mod require_scope;
 pub mod routes;
 
 use utoipa::OpenApi;
@@ -9,11 +12,18 @@ use utoipa::OpenApi;
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        handler::authorize,
         handler::token,
+        handler::introspect,
+        handler::revoke,
     ),
     components(
         schemas(
             dto::TokenRequest,
+            dto::IntrospectRequest,
+            dto::RevokeRequest,
+            dto::IntrospectionResponseDto,
+            dto::ActDto,
         )
     ),
     tags(
@@ -0,0 +1,99 @@
+use crate::{
+    adapters::http::actix::api_error::ApiError,
+    application::auth::{authenticated_user::AuthenticatedUser, scope::Scope},
+};
+use actix_web::{
+    Error, HttpMessage, HttpResponse,
+    dev::{Service, ServiceRequest, ServiceResponse, Transform},
+    http::{
+        StatusCode,
+        header::{HeaderValue, WWW_AUTHENTICATE},
+    },
+};
+use std::{
+    future::{Ready, ready},
+    pin::Pin,
+    rc::Rc,
+    task::Poll,
+};
+
+type LocalFuture = Pin<Box<dyn Future<Output = Result<ServiceResponse, Error>>>>;
+
+/// Rejects requests whose token was not granted every one of the given scopes.
+///
+/// Must run inside [`AuthMiddleware`](super::middleware::AuthMiddleware), which places the
+/// `AuthenticatedUser` in the request extensions.
+pub struct RequireScope {
+    scopes: Rc<Vec<Scope>>,
+}
+
+impl RequireScope {
+    pub fn new(scopes: impl IntoIterator<Item = Scope>) -> Self {
+        Self {
+            scopes: Rc::new(scopes.into_iter().collect()),
+        }
+    }
+}
+
+impl<S> Transform<S, ServiceRequest> for RequireScope
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error> + 'static,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequireScopeService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireScopeService {
+            service: Rc::new(service),
+            scopes: self.scopes.clone(),
+        }))
+    }
+}
+
+pub struct RequireScopeService<S> {
+    service: Rc<S>,
+    scopes: Rc<Vec<Scope>>,
+}
+
+impl<S> Service<ServiceRequest> for RequireScopeService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error> + 'static,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalFuture;
+
+    fn poll_ready(&self, ctx: &mut core::task::Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(ctx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let scopes = self.scopes.clone();
+
+        Box::pin(async move {
+            let granted: bool = match req.extensions().get::<AuthenticatedUser>() {
+                Some(user) => scopes.iter().all(|scope| user.has_scope(scope)),
+                None => false,
+            };
+
+            if granted {
+                return service.call(req).await;
+            }
+
+            let mut error: ApiError = ApiError::new(StatusCode::FORBIDDEN, "insufficient_scope");
+
+            if let Ok(challenge) = HeaderValue::from_str(&format!(
+                "Bearer error=\"insufficient_scope\", scope=\"{}\"",
+                Scope::join(&scopes)
+            )) {
+                error = error.with_header(WWW_AUTHENTICATE, challenge);
+            }
+
+            Ok(req.into_response(HttpResponse::from_error(error)))
+        })
+    }
+}
@@ -1,6 +1,14 @@
-use super::handler::token;
+use super::handler::{authorize, introspect, revoke, token};
+use crate::adapters::http::actix::passkey::handler::authentication_options;
 use actix_web::web;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(web::scope("/oauth").route("/token", web::post().to(token)));
+    cfg.service(
+        web::scope("/oauth")
+            .route("/authorize", web::get().to(authorize))
+            .route("/token", web::post().to(token))
+            .route("/introspect", web::post().to(introspect))
+            .route("/revoke", web::post().to(revoke))
+            .route("/passkey/options", web::post().to(authentication_options)),
+    );
 }
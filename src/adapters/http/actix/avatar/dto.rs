@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, ToSchema, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AvatarSizeDto {
+    /// 64 by 64 pixels.
+    Small,
+    /// 128 by 128 pixels.
+    Medium,
+    /// 256 by 256 pixels.
+    #[default]
+    Large,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AvatarQuery {
+    /// The size to fetch, large by default.
+    pub size: Option<AvatarSizeDto>,
+}
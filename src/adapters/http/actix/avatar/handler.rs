@@ -0,0 +1,173 @@
+use super::dto::{AvatarQuery, AvatarSizeDto};
+use crate::{
+    adapters::{
+        http::actix::{
+            api_error::ApiError,
+            server::{AppDeleteAvatar, AppSetAvatar},
+            user::handler::user_response,
+        },
+        persistence::postgres::avatar::repository::PostgresAvatarRepository,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        avatar::{
+            delete_avatar::DeleteAvatarError,
+            get_avatar::{GetAvatarError, GetAvatarService},
+            set_avatar::SetAvatarError,
+        },
+    },
+    domain::{
+        avatar::entity::{AvatarImage, AvatarSize},
+        user::entity::User,
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "users/{id}/avatar",
+    params(
+        ("id" = String, Path, description = "User UUID"),
+        AvatarQuery
+    ),
+    tag = "Avatars",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The avatar image", content_type = "image/png"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 404, description = "User not found, or without an avatar")
+    )
+)]
+pub async fn get_avatar(
+    service: web::Data<GetAvatarService<PostgresAvatarRepository>>,
+    params: web::Path<String>,
+    query: web::Query<AvatarQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    let size: AvatarSize = match query.size.unwrap_or_default() {
+        AvatarSizeDto::Small => AvatarSize::Small,
+        AvatarSizeDto::Medium => AvatarSize::Medium,
+        AvatarSizeDto::Large => AvatarSize::Large,
+    };
+
+    let image: AvatarImage = service.execute(&id, size).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(image.content_type)
+        .insert_header(("Cache-Control", "private, max-age=86400"))
+        .body(image.data))
+}
+
+#[utoipa::path(
+    put,
+    path = "users/{id}/avatar",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    request_body(
+        content(
+            (Vec<u8> = "image/png"),
+            (Vec<u8> = "image/jpeg"),
+            (Vec<u8> = "image/webp")
+        ),
+        description = "A PNG, JPEG or WebP image of at most 5 MiB, cropped to a square"
+    ),
+    tag = "Avatars",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Avatar replaced, the user has a new avatar URL"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without access, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found"),
+        (status = 413, description = "The image is too large"),
+        (status = 415, description = "Not a PNG, JPEG or WebP image")
+    )
+)]
+pub async fn set_avatar(
+    service: web::Data<AppSetAvatar>,
+    params: web::Path<String>,
+    body: web::Bytes,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    let user: User = service.execute(&id, body.to_vec(), &actor).await?;
+
+    Ok(HttpResponse::Ok().json(user_response(&user, &actor)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "users/{id}/avatar",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    tag = "Avatars",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Avatar removed"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without access, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn delete_avatar(
+    service: web::Data<AppDeleteAvatar>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<GetAvatarError> for ApiError {
+    fn from(err: GetAvatarError) -> Self {
+        match err {
+            GetAvatarError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Avatar not found"),
+            GetAvatarError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<SetAvatarError> for ApiError {
+    fn from(err: SetAvatarError) -> Self {
+        match err {
+            SetAvatarError::UserError(user_err) => ApiError::from(user_err),
+            SetAvatarError::UnsupportedImage => ApiError::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Avatar must be a PNG, JPEG or WebP image",
+            ),
+            SetAvatarError::ImageTooLarge => ApiError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "Avatar must be at most 8192 pixels wide and high",
+            ),
+            SetAvatarError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            SetAvatarError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to edit this user",
+            ),
+            SetAvatarError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeleteAvatarError> for ApiError {
+    fn from(err: DeleteAvatarError) -> Self {
+        match err {
+            DeleteAvatarError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            DeleteAvatarError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to edit this user",
+            ),
+            DeleteAvatarError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
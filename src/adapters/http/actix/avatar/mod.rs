@@ -0,0 +1,18 @@
+pub mod dto;
+pub mod handler;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_avatar,
+        handler::set_avatar,
+        handler::delete_avatar
+    ),
+    components(schemas(dto::AvatarSizeDto)),
+    tags(
+        (name = "Avatars", description = "Profile pictures, resized to fixed sizes")
+    )
+)]
+pub struct AvatarApiDoc;
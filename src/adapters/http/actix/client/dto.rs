@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct RegisterClientDto {
+    /// Human readable name of the client.
+    #[schema(max_length = 255)]
+    pub name: String,
+    /// Space-delimited list of scopes the client may request.
+    pub scope: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ClientResponseDto {
+    /// The client identifier.
+    pub client_id: String,
+    /// The client secret. It is only returned once, at registration.
+    pub client_secret: String,
+    /// Human readable name of the client.
+    pub name: String,
+    /// Space-delimited list of scopes the client may request.
+    pub scope: String,
+}
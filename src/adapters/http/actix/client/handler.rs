@@ -0,0 +1,71 @@
+use super::dto::{ClientResponseDto, RegisterClientDto};
+use crate::{
+    adapters::{
+        hash::argon2::Argon2Hasher,
+        http::actix::api_error::ApiError,
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog, client::repository::PostgresClientRepository,
+        },
+    },
+    application::{
+        auth::{authenticated_user::AuthenticatedUser, scope::Scope},
+        client::register_client::{
+            RegisterClientError, RegisterClientInput, RegisterClientOutput, RegisterClientService,
+        },
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = RegisterClientDto,
+    tag = "Clients",
+    responses(
+        (status = 201, description = "Client registered successfully", body = ClientResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without access")
+    )
+)]
+pub async fn register_client(
+    service: web::Data<
+        RegisterClientService<PostgresClientRepository, Argon2Hasher, PostgresAuditLog>,
+    >,
+    payload: web::Json<RegisterClientDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let scopes: Vec<Scope> = match payload.scope.as_deref() {
+        Some(scope) => Scope::parse_list(scope)?,
+        None => Scope::ALL.to_vec(),
+    };
+
+    let cmd: RegisterClientInput = RegisterClientInput {
+        name: payload.name.clone(),
+        scopes,
+    };
+
+    let client: RegisterClientOutput = service.execute(cmd, &actor).await?;
+
+    Ok(HttpResponse::Created().json(ClientResponseDto {
+        client_id: client.id.to_string(),
+        client_secret: client.secret,
+        name: client.name,
+        scope: Scope::join(&client.scopes),
+    }))
+}
+
+impl From<RegisterClientError> for ApiError {
+    fn from(err: RegisterClientError) -> Self {
+        match err {
+            RegisterClientError::InvalidName => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Name must be between 1 and 255 characters long",
+            ),
+            RegisterClientError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to register clients",
+            ),
+            RegisterClientError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
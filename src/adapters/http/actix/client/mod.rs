@@ -0,0 +1,22 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::register_client,
+    ),
+    components(
+        schemas(
+            dto::RegisterClientDto,
+            dto::ClientResponseDto
+        )
+    ),
+    tags(
+        (name = "Clients", description = "OAuth client registration endpoints")
+    )
+)]
+pub struct ClientApiDoc;
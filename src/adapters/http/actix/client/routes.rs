@@ -0,0 +1,12 @@
+use crate::adapters::http::actix::auth::middleware::AuthMiddleware;
+
+use super::handler::register_client;
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/oauth/clients")
+            .wrap(AuthMiddleware)
+            .route("", web::post().to(register_client)),
+    );
+}
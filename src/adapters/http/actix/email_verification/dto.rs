@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct VerifyEmailDto {
+    /// The verification token sent to the address.
+    pub token: String,
+}
@@ -0,0 +1,128 @@
+use super::dto::VerifyEmailDto;
+use crate::{
+    adapters::{
+        hash::argon2::Argon2Hasher,
+        http::actix::api_error::ApiError,
+        notification::AppNotifier,
+        persistence::postgres::{
+            email_verification::repository::PostgresEmailVerificationRepository,
+            user::repository::PostgresUserRepository,
+        },
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        email_verification::{
+            request_verification::{
+                RequestEmailVerificationError, RequestEmailVerificationService,
+            },
+            verify_email::{VerifyEmailError, VerifyEmailService},
+        },
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+
+type AppVerifyEmail =
+    VerifyEmailService<PostgresUserRepository, PostgresEmailVerificationRepository, Argon2Hasher>;
+
+#[utoipa::path(
+    post,
+    path = "me/email/verification",
+    tag = "Email verification",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 202, description = "A verification link is sent to the email address"),
+        (status = 409, description = "No email address is set, or it is verified already"),
+        (status = 503, description = "The email address cannot be reached")
+    )
+)]
+pub async fn request_verification(
+    service: web::Data<
+        RequestEmailVerificationService<
+            PostgresUserRepository,
+            PostgresEmailVerificationRepository,
+            Argon2Hasher,
+            AppNotifier,
+        >,
+    >,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    service.execute(&actor).await?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "auth/email/verify",
+    tag = "Email verification",
+    params(VerifyEmailDto),
+    security(()),
+    responses(
+        (status = 200, description = "Email address verified, for links opened in a browser"),
+        (status = 400, description = "Invalid, expired or used token")
+    )
+)]
+pub async fn verify_email_link(
+    service: web::Data<AppVerifyEmail>,
+    params: web::Query<VerifyEmailDto>,
+) -> Result<HttpResponse, ApiError> {
+    service.execute(&params.token).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body("Your email address is verified."))
+}
+
+#[utoipa::path(
+    post,
+    path = "auth/email/verify",
+    tag = "Email verification",
+    request_body = VerifyEmailDto,
+    security(()),
+    responses(
+        (status = 204, description = "Email address verified"),
+        (status = 400, description = "Invalid, expired or used token")
+    )
+)]
+pub async fn verify_email(
+    service: web::Data<AppVerifyEmail>,
+    payload: web::Json<VerifyEmailDto>,
+) -> Result<HttpResponse, ApiError> {
+    service.execute(&payload.token).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<RequestEmailVerificationError> for ApiError {
+    fn from(value: RequestEmailVerificationError) -> Self {
+        match value {
+            RequestEmailVerificationError::NoEmail => {
+                ApiError::new(StatusCode::CONFLICT, "No email address is set")
+            }
+            RequestEmailVerificationError::AlreadyVerified => ApiError::new(
+                StatusCode::CONFLICT,
+                "The email address is verified already",
+            ),
+            RequestEmailVerificationError::Unreachable => ApiError::new(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "The email address cannot be reached",
+            ),
+            RequestEmailVerificationError::NotFound
+            | RequestEmailVerificationError::InfrastructureError => {
+                ApiError::internal_server_error()
+            }
+        }
+    }
+}
+
+impl From<VerifyEmailError> for ApiError {
+    fn from(value: VerifyEmailError) -> Self {
+        match value {
+            VerifyEmailError::InvalidToken => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Invalid or expired verification token",
+            ),
+            VerifyEmailError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::request_verification,
+        handler::verify_email_link,
+        handler::verify_email
+    ),
+    components(schemas(dto::VerifyEmailDto)),
+    tags(
+        (name = "Email verification", description = "Proof that users receive mail at their address")
+    )
+)]
+pub struct EmailVerificationApiDoc;
@@ -0,0 +1,21 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{request_verification, verify_email, verify_email_link};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/me/email/verification")
+            .wrap(AuthMiddleware)
+            .route(
+                web::post()
+                    .to(request_verification)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    )
+    .route("/auth/email/verify", web::get().to(verify_email_link))
+    .route("/auth/email/verify", web::post().to(verify_email));
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+/// The authorization response an upstream provider redirects the browser back with.
+#[derive(Deserialize, ToSchema, IntoParams)]
+pub struct CallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    /// Set instead of `code` when the user or the provider refused the request.
+    pub error: Option<String>,
+}
@@ -0,0 +1,147 @@
+use super::dto::CallbackQuery;
+use crate::{
+    adapters::{
+        federation::oidc::OidcFederation,
+        http::actix::{
+            api_error::ApiError,
+            auth::{handler::token_response, login_context::login_context},
+            server::AppLogin,
+        },
+    },
+    application::{
+        auth::credentials::Credentials,
+        federation::{
+            identity_provider::{AuthorizationRequest, FederationError},
+            start_login::StartFederatedLoginService,
+        },
+        security::token::IssuedToken,
+    },
+};
+use actix_web::{
+    HttpRequest, HttpResponse,
+    cookie::{Cookie, SameSite, time::Duration},
+    http::{StatusCode, header::LOCATION},
+    web,
+};
+
+const STATE_COOKIE: &str = "windwatcher_federation_state";
+const STATE_COOKIE_MAX_AGE_MINUTES: i64 = 10;
+
+#[utoipa::path(
+    get,
+    path = "auth/{provider}/login",
+    tag = "Federation",
+    params(("provider" = String, Path, description = "Name of the configured provider")),
+    security(()),
+    responses(
+        (status = 302, description = "Redirect to the provider's authorization endpoint"),
+        (status = 404, description = "Unknown provider")
+    )
+)]
+pub async fn login(
+    req: HttpRequest,
+    path: web::Path<String>,
+    service: web::Data<StartFederatedLoginService<OidcFederation>>,
+) -> Result<HttpResponse, ApiError> {
+    let provider: String = path.into_inner();
+    let AuthorizationRequest { url, state } = service.execute(&provider).await?;
+
+    // Binds the flow to this browser, so a callback cannot be replayed in another one.
+    let cookie: Cookie = Cookie::build(STATE_COOKIE, state)
+        .path(format!("/auth/{}", provider))
+        .http_only(true)
+        .secure(req.connection_info().scheme() == "https")
+        .same_site(SameSite::Lax)
+        .max_age(Duration::minutes(STATE_COOKIE_MAX_AGE_MINUTES))
+        .finish();
+
+    Ok(HttpResponse::Found()
+        .insert_header((LOCATION, url))
+        .cookie(cookie)
+        .finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "auth/{provider}/callback",
+    tag = "Federation",
+    params(
+        ("provider" = String, Path, description = "Name of the configured provider"),
+        CallbackQuery
+    ),
+    security(()),
+    responses(
+        (status = 200, description = "User authenticated"),
+        (status = 400, description = "Missing code or state"),
+        (status = 401, description = "The provider refused the login, or the response is invalid"),
+        (status = 403, description = "A second factor is required, redeem it with the MFA token")
+    )
+)]
+pub async fn callback(
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<CallbackQuery>,
+    login: web::Data<AppLogin>,
+) -> Result<HttpResponse, ApiError> {
+    let provider: String = path.into_inner();
+    let CallbackQuery { code, state, error } = query.into_inner();
+
+    if error.is_some() {
+        return Err(ApiError::new(StatusCode::UNAUTHORIZED, "access_denied"));
+    }
+
+    let code: String =
+        code.ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "code is required"))?;
+    let state: String =
+        state.ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "state is required"))?;
+
+    if req
+        .cookie(STATE_COOKIE)
+        .is_none_or(|cookie| cookie.value() != state)
+    {
+        return Err(FederationError::InvalidState.into());
+    }
+
+    let issued: IssuedToken = login
+        .execute(
+            Credentials::AuthorizationCode {
+                provider: provider.clone(),
+                code,
+                state,
+            },
+            None,
+            None,
+            None,
+            &login_context(&req),
+        )
+        .await?;
+
+    let mut response: HttpResponse = token_response(issued);
+    response
+        .add_removal_cookie(
+            &Cookie::build(STATE_COOKIE, "")
+                .path(format!("/auth/{}", provider))
+                .finish(),
+        )
+        .map_err(|_| ApiError::internal_server_error())?;
+
+    Ok(response)
+}
+
+impl From<FederationError> for ApiError {
+    fn from(value: FederationError) -> Self {
+        match value {
+            FederationError::UnknownProvider => {
+                ApiError::new(StatusCode::NOT_FOUND, "Unknown identity provider")
+            }
+            FederationError::InvalidState => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "Invalid or expired state")
+            }
+            FederationError::InvalidResponse => ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "Invalid response from identity provider",
+            ),
+            FederationError::Unavailable => ApiError::internal_server_error(),
+        }
+    }
+}
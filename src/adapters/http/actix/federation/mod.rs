@@ -0,0 +1,15 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(handler::login, handler::callback),
+    components(schemas(dto::CallbackQuery)),
+    tags(
+        (name = "Federation", description = "Sign-in through upstream OpenID Connect providers")
+    )
+)]
+pub struct FederationApiDoc;
@@ -0,0 +1,7 @@
+use super::handler::{callback, login};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/auth/{provider}/login", web::get().to(login))
+        .route("/auth/{provider}/callback", web::get().to(callback));
+}
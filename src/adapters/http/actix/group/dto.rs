@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateGroupDto {
+    /// The unique name of the group in the organization, which cannot be changed. A leading
+    /// `@` is ignored.
+    #[schema(min_length = 2, max_length = 32, example = "backend")]
+    pub name: String,
+    /// What the group is for.
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// The permissions the group grants its members, such as `room.moderate`.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateGroupDto {
+    /// What the group is for.
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// The permissions the group grants its members, replacing the current ones.
+    pub permissions: Option<Vec<String>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GroupResponseDto {
+    /// The unique identifier of the group.
+    pub id: String,
+    /// The unique name of the group in the organization.
+    pub name: String,
+    /// How the group is mentioned, such as `@backend`.
+    pub mention: String,
+    /// What the group is for.
+    pub description: String,
+    /// The permissions the group grants its members.
+    pub permissions: Vec<String>,
+    /// When the group was created, in seconds since the Unix epoch.
+    pub created_at: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GroupMemberResponseDto {
+    /// The unique identifier of the user.
+    pub user_id: String,
+    /// When the user was added to the group, in seconds since the Unix epoch.
+    pub added_at: u64,
+}
@@ -0,0 +1,376 @@
+use super::dto::{CreateGroupDto, GroupMemberResponseDto, GroupResponseDto, UpdateGroupDto};
+use crate::{
+    adapters::{
+        http::actix::{
+            api_error::ApiError, role::handler::permissions, server::AppGroupRepository,
+        },
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog,
+            organization::repository::PostgresOrganizationRepository,
+        },
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        group::{
+            add_member::{AddGroupMemberError, AddGroupMemberService},
+            create_group::{CreateGroupError, CreateGroupInput, CreateGroupService},
+            delete_group::{DeleteGroupError, DeleteGroupService},
+            list_groups::{ListGroupsError, ListGroupsService},
+            list_members::{ListGroupMembersError, ListGroupMembersService},
+            remove_member::{RemoveGroupMemberError, RemoveGroupMemberService},
+            update_group::{UpdateGroupError, UpdateGroupInput, UpdateGroupService},
+        },
+    },
+    domain::group::{
+        entity::{Group, GroupName},
+        error::GroupError,
+        member::GroupMember,
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "groups",
+    tag = "Groups",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The groups of the organization the token acts in, by name", body = Vec<GroupResponseDto>)
+    )
+)]
+pub async fn list_groups(
+    service: web::Data<ListGroupsService<AppGroupRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let groups: Vec<Group> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        groups
+            .into_iter()
+            .map(GroupResponseDto::from)
+            .collect::<Vec<GroupResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "groups",
+    request_body = CreateGroupDto,
+    tag = "Groups",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Group created in the organization the token acts in", body = GroupResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the group.manage permission, or granting permissions the caller lacks"),
+        (status = 409, description = "Group already exists")
+    )
+)]
+pub async fn create_group(
+    service: web::Data<CreateGroupService<AppGroupRepository, PostgresAuditLog>>,
+    payload: web::Json<CreateGroupDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let payload: CreateGroupDto = payload.into_inner();
+
+    let input: CreateGroupInput = CreateGroupInput {
+        name: GroupName::new(payload.name)?,
+        description: payload.description.unwrap_or_default(),
+        permissions: permissions(payload.permissions)?,
+    };
+
+    let group: Group = service.execute(input, &actor).await?;
+
+    Ok(HttpResponse::Created().json(GroupResponseDto::from(group)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "groups/{name}",
+    params(
+        ("name" = String, Path, description = "Group name")
+    ),
+    request_body = UpdateGroupDto,
+    tag = "Groups",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Group updated, members get the new permissions at once", body = GroupResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the group.manage permission, or changing permissions the caller lacks"),
+        (status = 404, description = "Group not found")
+    )
+)]
+pub async fn update_group(
+    service: web::Data<UpdateGroupService<AppGroupRepository, PostgresAuditLog>>,
+    params: web::Path<String>,
+    payload: web::Json<UpdateGroupDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let name: GroupName = GroupName::new(params.into_inner())?;
+    let payload: UpdateGroupDto = payload.into_inner();
+
+    let input: UpdateGroupInput = UpdateGroupInput {
+        description: payload.description,
+        permissions: payload.permissions.map(permissions).transpose()?,
+    };
+
+    let group: Group = service.execute(&name, input, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(GroupResponseDto::from(group)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "groups/{name}",
+    params(
+        ("name" = String, Path, description = "Group name")
+    ),
+    tag = "Groups",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Group deleted"),
+        (status = 400, description = "Invalid group name"),
+        (status = 403, description = "Without the group.manage permission"),
+        (status = 404, description = "Group not found")
+    )
+)]
+pub async fn delete_group(
+    service: web::Data<DeleteGroupService<AppGroupRepository, PostgresAuditLog>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let name: GroupName = GroupName::new(params.into_inner())?;
+
+    service.execute(&name, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "groups/{name}/members",
+    params(
+        ("name" = String, Path, description = "Group name")
+    ),
+    tag = "Groups",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The members of the group, who a mention of it reaches", body = Vec<GroupMemberResponseDto>),
+        (status = 400, description = "Invalid group name"),
+        (status = 404, description = "Group not found")
+    )
+)]
+pub async fn list_members(
+    service: web::Data<ListGroupMembersService<AppGroupRepository>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let name: GroupName = GroupName::new(params.into_inner())?;
+
+    let members: Vec<GroupMember> = service.execute(&name, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        members
+            .into_iter()
+            .map(GroupMemberResponseDto::from)
+            .collect::<Vec<GroupMemberResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "groups/{name}/members/{user_id}",
+    params(
+        ("name" = String, Path, description = "Group name"),
+        ("user_id" = String, Path, description = "User UUID")
+    ),
+    tag = "Groups",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Member added, they get what the group grants at once", body = GroupMemberResponseDto),
+        (status = 400, description = "Invalid data provided, or the user is not a member of the organization"),
+        (status = 403, description = "Without the group.manage permission, or the group grants permissions the caller lacks"),
+        (status = 404, description = "Group not found")
+    )
+)]
+pub async fn add_member(
+    service: web::Data<
+        AddGroupMemberService<AppGroupRepository, PostgresOrganizationRepository, PostgresAuditLog>,
+    >,
+    params: web::Path<(String, String)>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let (name, user_id) = params.into_inner();
+    let name: GroupName = GroupName::new(name)?;
+    let user_id: Uuid = parse_id(&user_id)?;
+
+    let member: GroupMember = service.execute(&name, &user_id, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(GroupMemberResponseDto::from(member)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "groups/{name}/members/{user_id}",
+    params(
+        ("name" = String, Path, description = "Group name"),
+        ("user_id" = String, Path, description = "User UUID")
+    ),
+    tag = "Groups",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Member removed, they lose what the group granted at once"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the group.manage permission, unless leaving, or the group grants permissions the caller lacks"),
+        (status = 404, description = "Group or member not found")
+    )
+)]
+pub async fn remove_member(
+    service: web::Data<RemoveGroupMemberService<AppGroupRepository, PostgresAuditLog>>,
+    params: web::Path<(String, String)>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let (name, user_id) = params.into_inner();
+    let name: GroupName = GroupName::new(name)?;
+    let user_id: Uuid = parse_id(&user_id)?;
+
+    service.execute(&name, &user_id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+fn parse_id(raw: &str) -> Result<Uuid, ApiError> {
+    Uuid::parse_str(raw).map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))
+}
+
+impl From<Group> for GroupResponseDto {
+    fn from(group: Group) -> Self {
+        GroupResponseDto {
+            id: group.id.to_string(),
+            name: group.name.as_str().into(),
+            mention: group.name.mention(),
+            description: group.description,
+            permissions: group
+                .permissions
+                .iter()
+                .map(|permission| permission.as_str().into())
+                .collect(),
+            created_at: group.created_at,
+        }
+    }
+}
+
+impl From<GroupMember> for GroupMemberResponseDto {
+    fn from(member: GroupMember) -> Self {
+        GroupMemberResponseDto {
+            user_id: member.user_id.to_string(),
+            added_at: member.added_at,
+        }
+    }
+}
+
+impl From<GroupError> for ApiError {
+    fn from(err: GroupError) -> Self {
+        match err {
+            GroupError::InvalidName(message) | GroupError::InvalidDescription(message) => {
+                ApiError::new(StatusCode::BAD_REQUEST, message)
+            }
+        }
+    }
+}
+
+impl From<ListGroupsError> for ApiError {
+    fn from(err: ListGroupsError) -> Self {
+        match err {
+            ListGroupsError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<CreateGroupError> for ApiError {
+    fn from(err: CreateGroupError) -> Self {
+        match err {
+            CreateGroupError::InvalidGroup(error) => ApiError::from(error),
+            CreateGroupError::AlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Group already exists")
+            }
+            CreateGroupError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to create this group",
+            ),
+            CreateGroupError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<UpdateGroupError> for ApiError {
+    fn from(err: UpdateGroupError) -> Self {
+        match err {
+            UpdateGroupError::InvalidGroup(error) => ApiError::from(error),
+            UpdateGroupError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Group not found"),
+            UpdateGroupError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to change this group",
+            ),
+            UpdateGroupError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeleteGroupError> for ApiError {
+    fn from(err: DeleteGroupError) -> Self {
+        match err {
+            DeleteGroupError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Group not found"),
+            DeleteGroupError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to delete groups",
+            ),
+            DeleteGroupError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ListGroupMembersError> for ApiError {
+    fn from(err: ListGroupMembersError) -> Self {
+        match err {
+            ListGroupMembersError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Group not found")
+            }
+            ListGroupMembersError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<AddGroupMemberError> for ApiError {
+    fn from(err: AddGroupMemberError) -> Self {
+        match err {
+            AddGroupMemberError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Group not found")
+            }
+            AddGroupMemberError::NotMember => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "The user is not a member of the organization",
+            ),
+            AddGroupMemberError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to add members to this group",
+            ),
+            AddGroupMemberError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<RemoveGroupMemberError> for ApiError {
+    fn from(err: RemoveGroupMemberError) -> Self {
+        match err {
+            RemoveGroupMemberError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Group member not found")
+            }
+            RemoveGroupMemberError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to remove members from this group",
+            ),
+            RemoveGroupMemberError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
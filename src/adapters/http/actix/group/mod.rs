@@ -0,0 +1,30 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::list_groups,
+        handler::create_group,
+        handler::update_group,
+        handler::delete_group,
+        handler::list_members,
+        handler::add_member,
+        handler::remove_member,
+    ),
+    components(
+        schemas(
+            dto::CreateGroupDto,
+            dto::UpdateGroupDto,
+            dto::GroupResponseDto,
+            dto::GroupMemberResponseDto
+        )
+    ),
+    tags(
+        (name = "Groups", description = "Group and group membership endpoints")
+    )
+)]
+pub struct GroupApiDoc;
@@ -0,0 +1,58 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{
+    add_member, create_group, delete_group, list_groups, list_members, remove_member, update_group,
+};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/groups")
+            .wrap(AuthMiddleware)
+            .route(
+                "",
+                web::get()
+                    .to(list_groups)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "",
+                web::post()
+                    .to(create_group)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}",
+                web::patch()
+                    .to(update_group)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}",
+                web::delete()
+                    .to(delete_group)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}/members",
+                web::get()
+                    .to(list_members)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "/{name}/members/{user_id}",
+                web::put()
+                    .to(add_member)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}/members/{user_id}",
+                web::delete()
+                    .to(remove_member)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    );
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct TotpEnrollmentResponseDto {
+    /// Base32 encoded shared secret, for manual entry in an authenticator app.
+    pub secret: String,
+    /// `otpauth://` URI, usually rendered as a QR code.
+    pub provisioning_uri: String,
+    /// Single-use codes accepted in place of a TOTP code. They are only returned once.
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ConfirmTotpDto {
+    /// A code currently displayed by the authenticator app.
+    #[schema(example = "123456")]
+    pub code: String,
+}
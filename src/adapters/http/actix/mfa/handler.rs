@@ -0,0 +1,134 @@
+use super::dto::{ConfirmTotpDto, TotpEnrollmentResponseDto};
+use crate::{
+    adapters::{
+        hash::argon2::Argon2Hasher,
+        http::actix::{api_error::ApiError, server::AppResetTotp},
+        mfa::totp::HmacTotp,
+        persistence::postgres::totp::repository::PostgresTotpRepository,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        mfa::{
+            confirm_totp::{ConfirmTotpError, ConfirmTotpService},
+            enroll_totp::{EnrollTotpError, EnrollTotpOutput, EnrollTotpService},
+            reset_totp::ResetTotpError,
+        },
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "me/mfa/totp",
+    tag = "MFA",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Enrollment started, confirm it with a first code", body = TotpEnrollmentResponseDto),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    )
+)]
+pub async fn enroll_totp(
+    service: web::Data<EnrollTotpService<PostgresTotpRepository, HmacTotp, Argon2Hasher>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let enrollment: EnrollTotpOutput = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Created().json(TotpEnrollmentResponseDto {
+        secret: enrollment.secret,
+        provisioning_uri: enrollment.provisioning_uri,
+        recovery_codes: enrollment.recovery_codes,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "me/mfa/totp/confirm",
+    request_body = ConfirmTotpDto,
+    tag = "MFA",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Two-factor authentication enabled"),
+        (status = 400, description = "Invalid code"),
+        (status = 404, description = "No pending enrollment"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    )
+)]
+pub async fn confirm_totp(
+    service: web::Data<ConfirmTotpService<PostgresTotpRepository, HmacTotp>>,
+    payload: web::Json<ConfirmTotpDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    service.execute(&payload.code, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "users/{id}/mfa",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    tag = "MFA",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Two-factor authentication reset"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Whitout permission, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn reset_totp(
+    service: web::Data<AppResetTotp>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<EnrollTotpError> for ApiError {
+    fn from(err: EnrollTotpError) -> Self {
+        match err {
+            EnrollTotpError::AlreadyEnabled => ApiError::new(
+                StatusCode::CONFLICT,
+                "Two-factor authentication is already enabled",
+            ),
+            EnrollTotpError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ConfirmTotpError> for ApiError {
+    fn from(err: ConfirmTotpError) -> Self {
+        match err {
+            ConfirmTotpError::NotEnrolled => {
+                ApiError::new(StatusCode::NOT_FOUND, "No pending two-factor enrollment")
+            }
+            ConfirmTotpError::AlreadyEnabled => ApiError::new(
+                StatusCode::CONFLICT,
+                "Two-factor authentication is already enabled",
+            ),
+            ConfirmTotpError::InvalidCode => ApiError::new(StatusCode::BAD_REQUEST, "Invalid code"),
+            ConfirmTotpError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ResetTotpError> for ApiError {
+    fn from(err: ResetTotpError) -> Self {
+        match err {
+            ResetTotpError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            ResetTotpError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to reset two-factor authentication",
+            ),
+            ResetTotpError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
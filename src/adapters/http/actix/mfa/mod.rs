@@ -0,0 +1,24 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::enroll_totp,
+        handler::confirm_totp,
+        handler::reset_totp,
+    ),
+    components(
+        schemas(
+            dto::TotpEnrollmentResponseDto,
+            dto::ConfirmTotpDto
+        )
+    ),
+    tags(
+        (name = "MFA", description = "Two-factor authentication endpoints")
+    )
+)]
+pub struct MfaApiDoc;
@@ -0,0 +1,27 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{confirm_totp, enroll_totp};
+use actix_web::web;
+
+/// The admin reset endpoint lives under `/users`, see the user routes.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/me/mfa/totp").wrap(AuthMiddleware).route(
+            web::post()
+                .to(enroll_totp)
+                .wrap(RequireScope::new([Scope::UsersWrite])),
+        ),
+    )
+    .service(
+        web::resource("/me/mfa/totp/confirm")
+            .wrap(AuthMiddleware)
+            .route(
+                web::post()
+                    .to(confirm_totp)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    );
+}
@@ -1,8 +1,24 @@
 mod api_error;
+pub mod audit;
 pub mod auth;
+pub mod avatar;
+pub mod client;
+pub mod email_verification;
+pub mod federation;
+pub mod group;
+pub mod mfa;
+pub mod oidc;
+pub mod organization;
+pub mod passkey;
+pub mod password;
+pub mod password_reset;
+pub mod registration;
+pub mod role;
 pub mod server;
+pub mod session;
 pub mod user;
 
+use crate::application::auth::scope::Scope;
 use utoipa::{
     Modify, OpenApi,
     openapi::{
@@ -21,7 +37,11 @@ impl Modify for JwtSecurityAddon {
             "oauth2_password",
             SecurityScheme::OAuth2(OAuth2::new([Flow::Password(Password::new(
                 "/oauth/token",
-                Scopes::new(),
+                Scopes::from_iter(
+                    Scope::ALL
+                        .iter()
+                        .map(|scope| (scope.as_str(), scope.description())),
+                ),
             ))])),
         );
 
@@ -33,7 +53,22 @@ impl Modify for JwtSecurityAddon {
 #[openapi(
     nest(
         (path = "/users", api = user::UserApiDoc),
-        (path = "/oauth", api = auth::AuthApiDoc)
+        (path = "/oauth", api = auth::AuthApiDoc),
+        (path = "/oauth/clients", api = client::ClientApiDoc),
+        (path = "/", api = oidc::OidcApiDoc),
+        (path = "/", api = mfa::MfaApiDoc),
+        (path = "/", api = avatar::AvatarApiDoc),
+        (path = "/", api = passkey::PasskeyApiDoc),
+        (path = "/", api = federation::FederationApiDoc),
+        (path = "/", api = password::PasswordApiDoc),
+        (path = "/", api = password_reset::PasswordResetApiDoc),
+        (path = "/", api = email_verification::EmailVerificationApiDoc),
+        (path = "/", api = registration::RegistrationApiDoc),
+        (path = "/", api = role::RoleApiDoc),
+        (path = "/", api = organization::OrganizationApiDoc),
+        (path = "/", api = group::GroupApiDoc),
+        (path = "/", api = session::SessionApiDoc),
+        (path = "/", api = audit::AuditApiDoc)
     ),
     modifiers(&JwtSecurityAddon),
     security(
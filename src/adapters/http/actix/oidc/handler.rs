@@ -0,0 +1,138 @@
+use super::dto::{DiscoveryResponseDto, UserInfoResponseDto};
+use crate::{
+    adapters::{
+        http::actix::{api_error::ApiError, auth::dto::GrantType},
+        persistence::postgres::user::repository::PostgresUserRepository,
+        token::jwt::JwtService,
+    },
+    application::{
+        auth::{authenticated_user::AuthenticatedUser, scope::Scope},
+        user::find_user::FindUserService,
+    },
+    domain::user::{entity::User, profile::Profile},
+};
+use actix_web::{HttpResponse, web};
+
+#[utoipa::path(
+    get,
+    path = "userinfo",
+    tag = "OpenID Connect",
+    security(("oauth2_password" = ["openid"])),
+    responses(
+        (status = 200, description = "Claims about the authenticated user", body = UserInfoResponseDto),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 403, description = "Insufficient scope")
+    )
+)]
+pub async fn userinfo(
+    service: web::Data<FindUserService<PostgresUserRepository>>,
+    token_service: web::Data<JwtService>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let user: User = service.find_by_id(&actor.id, &actor).await?;
+    let profile: bool = actor.has_scope(&Scope::Profile);
+    let claims: Option<&Profile> = Some(&user.profile).filter(|_| profile);
+    let email: bool = actor.has_scope(&Scope::Email) && user.email.is_some();
+
+    Ok(HttpResponse::Ok().json(UserInfoResponseDto {
+        sub: user.id.to_string(),
+        preferred_username: profile.then(|| user.username.as_str().into()),
+        name: profile.then(|| user.name.as_str().into()),
+        role: profile.then(|| user.role.as_str().into()),
+        status: profile.then(|| serialized(&user.status)),
+        // Versioned by the upload time, as in user responses.
+        picture: claims
+            .and_then(|claims| claims.avatar_updated_at)
+            .map(|updated_at| {
+                format!(
+                    "{}/users/{}/avatar?v={}",
+                    token_service.issuer(),
+                    user.id,
+                    updated_at
+                )
+            }),
+        zoneinfo: claims
+            .and_then(|claims| claims.timezone.as_ref())
+            .map(|timezone| timezone.as_str().into()),
+        locale: claims
+            .and_then(|claims| claims.locale.as_ref())
+            .map(|locale| locale.as_str().into()),
+        email: user
+            .email
+            .as_ref()
+            .filter(|_| email)
+            .map(|address| address.as_str().into()),
+        email_verified: email.then_some(user.email_verified_at.is_some()),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = ".well-known/openid-configuration",
+    tag = "OpenID Connect",
+    security(()),
+    responses(
+        (status = 200, description = "OpenID Provider metadata", body = DiscoveryResponseDto)
+    )
+)]
+pub async fn discovery(token_service: web::Data<JwtService>) -> HttpResponse {
+    let issuer: &str = token_service.issuer();
+
+    HttpResponse::Ok().json(DiscoveryResponseDto {
+        issuer: issuer.into(),
+        authorization_endpoint: format!("{}/oauth/authorize", issuer),
+        token_endpoint: format!("{}/oauth/token", issuer),
+        userinfo_endpoint: format!("{}/userinfo", issuer),
+        jwks_uri: format!("{}/.well-known/jwks.json", issuer),
+        introspection_endpoint: format!("{}/oauth/introspect", issuer),
+        revocation_endpoint: format!("{}/oauth/revoke", issuer),
+        scopes_supported: Scope::ALL.iter().map(|s| s.as_str().into()).collect(),
+        // Tokens are only issued by the token endpoint, the authorization endpoint refuses
+        // every response type.
+        response_types_supported: Vec::new(),
+        grant_types_supported: GrantType::SUPPORTED.iter().map(serialized).collect(),
+        subject_types_supported: vec!["public".into()],
+        id_token_signing_alg_values_supported: vec!["ES256".into()],
+        token_endpoint_auth_methods_supported: vec![
+            "client_secret_basic".into(),
+            "client_secret_post".into(),
+        ],
+        claims_supported: vec![
+            "iss".into(),
+            "sub".into(),
+            "aud".into(),
+            "exp".into(),
+            "iat".into(),
+            "auth_time".into(),
+            "preferred_username".into(),
+            "name".into(),
+            "role".into(),
+            "status".into(),
+            "picture".into(),
+            "zoneinfo".into(),
+            "locale".into(),
+            "email".into(),
+            "email_verified".into(),
+        ],
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = ".well-known/jwks.json",
+    tag = "OpenID Connect",
+    security(()),
+    responses(
+        (status = 200, description = "Public keys used to sign id_tokens")
+    )
+)]
+pub async fn jwks(token_service: web::Data<JwtService>) -> HttpResponse {
+    HttpResponse::Ok().json(token_service.jwks())
+}
+
+fn serialized<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
+}
@@ -0,0 +1,24 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::userinfo,
+        handler::discovery,
+        handler::jwks,
+    ),
+    components(
+        schemas(
+            dto::UserInfoResponseDto,
+            dto::DiscoveryResponseDto
+        )
+    ),
+    tags(
+        (name = "OpenID Connect", description = "OpenID Connect provider endpoints")
+    )
+)]
+pub struct OidcApiDoc;
@@ -0,0 +1,29 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{discovery, jwks, userinfo};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/userinfo")
+            .wrap(AuthMiddleware)
+            .route(
+                web::get()
+                    .to(userinfo)
+                    .wrap(RequireScope::new([Scope::OpenId])),
+            )
+            .route(
+                web::post()
+                    .to(userinfo)
+                    .wrap(RequireScope::new([Scope::OpenId])),
+            ),
+    )
+    .service(
+        web::scope("/.well-known")
+            .route("/openid-configuration", web::get().to(discovery))
+            .route("/jwks.json", web::get().to(jwks)),
+    );
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateOrganizationDto {
+    /// The unique short name of the organization, used to sign in to it.
+    #[schema(min_length = 2, max_length = 32, example = "acme")]
+    pub slug: String,
+    /// The display name of the organization.
+    #[schema(max_length = 100, example = "Acme Corporation")]
+    pub name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct OrganizationResponseDto {
+    /// The unique identifier of the organization.
+    pub id: String,
+    /// The unique short name of the organization.
+    pub slug: String,
+    /// The display name of the organization.
+    pub name: String,
+    /// When the organization was created, in seconds since the Unix epoch.
+    pub created_at: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct MyOrganizationResponseDto {
+    /// The unique identifier of the organization.
+    pub id: String,
+    /// The unique short name of the organization.
+    pub slug: String,
+    /// The display name of the organization.
+    pub name: String,
+    /// The role the caller holds in the organization.
+    pub role: String,
+    /// Whether tokens of the request act in this organization.
+    pub active: bool,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct SaveMemberDto {
+    /// The role the member holds in the organization.
+    #[schema(example = "user")]
+    pub role: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct MemberResponseDto {
+    /// The unique identifier of the user.
+    pub user_id: String,
+    /// The role the member holds in the organization.
+    pub role: String,
+    /// When the user joined the organization, in seconds since the Unix epoch.
+    pub joined_at: u64,
+}
@@ -0,0 +1,336 @@
+use super::dto::{
+    CreateOrganizationDto, MemberResponseDto, MyOrganizationResponseDto, OrganizationResponseDto,
+    SaveMemberDto,
+};
+use crate::{
+    adapters::{
+        http::actix::{
+            api_error::ApiError,
+            server::{AppRevocationStore, AppRoleRepository},
+        },
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog,
+            organization::repository::PostgresOrganizationRepository,
+            user::repository::PostgresUserRepository,
+        },
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        organization::{
+            create_organization::{
+                CreateOrganizationError, CreateOrganizationInput, CreateOrganizationService,
+            },
+            find_organization::FindOrganizationError,
+            list_members::{ListMembersError, ListMembersService},
+            list_organizations::{ListOrganizationsError, ListOrganizationsService},
+            remove_member::{RemoveMemberError, RemoveMemberService},
+            save_member::{SaveMemberError, SaveMemberInput, SaveMemberService},
+        },
+    },
+    domain::{
+        organization::{
+            entity::{Organization, OrganizationSlug},
+            error::OrganizationError,
+            membership::Membership,
+        },
+        role::entity::RoleName,
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "organizations",
+    request_body = CreateOrganizationDto,
+    tag = "Organizations",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Organization created, the caller is its first member", body = OrganizationResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the organization.manage permission"),
+        (status = 409, description = "Organization already exists")
+    )
+)]
+pub async fn create_organization(
+    service: web::Data<CreateOrganizationService<PostgresOrganizationRepository, PostgresAuditLog>>,
+    payload: web::Json<CreateOrganizationDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let payload: CreateOrganizationDto = payload.into_inner();
+
+    let input: CreateOrganizationInput = CreateOrganizationInput {
+        slug: OrganizationSlug::new(payload.slug)?,
+        name: payload.name,
+    };
+
+    let organization: Organization = service.execute(input, &actor).await?;
+
+    Ok(HttpResponse::Created().json(OrganizationResponseDto::from(organization)))
+}
+
+#[utoipa::path(
+    get,
+    path = "organizations",
+    tag = "Organizations",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The organizations the caller is a member of", body = Vec<MyOrganizationResponseDto>)
+    )
+)]
+pub async fn list_organizations(
+    service: web::Data<ListOrganizationsService<PostgresOrganizationRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let organizations: Vec<(Organization, Membership)> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        organizations
+            .into_iter()
+            .map(|(organization, membership)| MyOrganizationResponseDto {
+                id: organization.id.to_string(),
+                slug: organization.slug.as_str().into(),
+                name: organization.name,
+                role: membership.role.as_str().into(),
+                active: organization.id == actor.organization_id,
+            })
+            .collect::<Vec<MyOrganizationResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "organizations/{id}/members",
+    params(
+        ("id" = String, Path, description = "Organization UUID")
+    ),
+    tag = "Organizations",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "The members of the organization", body = Vec<MemberResponseDto>),
+        (status = 400, description = "Invalid UUID format"),
+        (status = 403, description = "The organization is not the one the token acts in")
+    )
+)]
+pub async fn list_members(
+    service: web::Data<ListMembersService<PostgresOrganizationRepository>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let organization_id: Uuid = parse_id(&params)?;
+
+    let members: Vec<Membership> = service.execute(&organization_id, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        members
+            .into_iter()
+            .map(MemberResponseDto::from)
+            .collect::<Vec<MemberResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "organizations/{id}/members/{user_id}",
+    params(
+        ("id" = String, Path, description = "Organization UUID"),
+        ("user_id" = String, Path, description = "User UUID")
+    ),
+    request_body = SaveMemberDto,
+    tag = "Organizations",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Member added, or their role changed and their sessions ended", body = MemberResponseDto),
+        (status = 400, description = "Invalid data provided or unknown role"),
+        (status = 403, description = "Without the member.manage permission, granting permissions the caller lacks, or the organization is not the one the token acts in"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn save_member(
+    service: web::Data<
+        SaveMemberService<
+            PostgresOrganizationRepository,
+            PostgresUserRepository,
+            AppRoleRepository,
+            AppRevocationStore,
+            PostgresAuditLog,
+        >,
+    >,
+    params: web::Path<(String, String)>,
+    payload: web::Json<SaveMemberDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let (organization_id, user_id) = params.into_inner();
+    let organization_id: Uuid = parse_id(&organization_id)?;
+    let user_id: Uuid = parse_id(&user_id)?;
+
+    let input: SaveMemberInput = SaveMemberInput {
+        role: RoleName::new(payload.into_inner().role)?,
+    };
+
+    let membership: Membership = service
+        .execute(&organization_id, &user_id, input, &actor)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(MemberResponseDto::from(membership)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "organizations/{id}/members/{user_id}",
+    params(
+        ("id" = String, Path, description = "Organization UUID"),
+        ("user_id" = String, Path, description = "User UUID")
+    ),
+    tag = "Organizations",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Member removed and their sessions ended"),
+        (status = 400, description = "Invalid UUID format"),
+        (status = 403, description = "Without the member.manage permission, unless leaving, or the organization is not the one the token acts in"),
+        (status = 404, description = "Member not found"),
+        (status = 409, description = "Users cannot leave their home organization")
+    )
+)]
+pub async fn remove_member(
+    service: web::Data<
+        RemoveMemberService<
+            PostgresOrganizationRepository,
+            PostgresUserRepository,
+            AppRoleRepository,
+            AppRevocationStore,
+            PostgresAuditLog,
+        >,
+    >,
+    params: web::Path<(String, String)>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let (organization_id, user_id) = params.into_inner();
+    let organization_id: Uuid = parse_id(&organization_id)?;
+    let user_id: Uuid = parse_id(&user_id)?;
+
+    service.execute(&organization_id, &user_id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+fn parse_id(raw: &str) -> Result<Uuid, ApiError> {
+    Uuid::parse_str(raw).map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))
+}
+
+impl From<Organization> for OrganizationResponseDto {
+    fn from(organization: Organization) -> Self {
+        OrganizationResponseDto {
+            id: organization.id.to_string(),
+            slug: organization.slug.as_str().into(),
+            name: organization.name,
+            created_at: organization.created_at,
+        }
+    }
+}
+
+impl From<Membership> for MemberResponseDto {
+    fn from(membership: Membership) -> Self {
+        MemberResponseDto {
+            user_id: membership.user_id.to_string(),
+            role: membership.role.as_str().into(),
+            joined_at: membership.joined_at,
+        }
+    }
+}
+
+impl From<OrganizationError> for ApiError {
+    fn from(err: OrganizationError) -> Self {
+        match err {
+            OrganizationError::InvalidSlug(message) | OrganizationError::InvalidName(message) => {
+                ApiError::new(StatusCode::BAD_REQUEST, message)
+            }
+        }
+    }
+}
+
+impl From<FindOrganizationError> for ApiError {
+    fn from(err: FindOrganizationError) -> Self {
+        match err {
+            FindOrganizationError::NotFound => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Unknown organization")
+            }
+            FindOrganizationError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<CreateOrganizationError> for ApiError {
+    fn from(err: CreateOrganizationError) -> Self {
+        match err {
+            CreateOrganizationError::InvalidOrganization(error) => ApiError::from(error),
+            CreateOrganizationError::AlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Organization already exists")
+            }
+            CreateOrganizationError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to create organizations",
+            ),
+            CreateOrganizationError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ListOrganizationsError> for ApiError {
+    fn from(err: ListOrganizationsError) -> Self {
+        match err {
+            ListOrganizationsError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ListMembersError> for ApiError {
+    fn from(err: ListMembersError) -> Self {
+        match err {
+            ListMembersError::NotActive => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Sign in to the organization to see its members",
+            ),
+            ListMembersError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<SaveMemberError> for ApiError {
+    fn from(err: SaveMemberError) -> Self {
+        match err {
+            SaveMemberError::NotActive => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Sign in to the organization to manage its members",
+            ),
+            SaveMemberError::UserNotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            SaveMemberError::UnknownRole => ApiError::new(StatusCode::BAD_REQUEST, "Unknown role"),
+            SaveMemberError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to give this role",
+            ),
+            SaveMemberError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<RemoveMemberError> for ApiError {
+    fn from(err: RemoveMemberError) -> Self {
+        match err {
+            RemoveMemberError::NotActive => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Sign in to the organization to manage its members",
+            ),
+            RemoveMemberError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Member not found"),
+            RemoveMemberError::HomeOrganization => ApiError::new(
+                StatusCode::CONFLICT,
+                "Users cannot leave their home organization",
+            ),
+            RemoveMemberError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to remove this member",
+            ),
+            RemoveMemberError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
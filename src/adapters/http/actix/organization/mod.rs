@@ -0,0 +1,29 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create_organization,
+        handler::list_organizations,
+        handler::list_members,
+        handler::save_member,
+        handler::remove_member,
+    ),
+    components(
+        schemas(
+            dto::CreateOrganizationDto,
+            dto::OrganizationResponseDto,
+            dto::MyOrganizationResponseDto,
+            dto::SaveMemberDto,
+            dto::MemberResponseDto
+        )
+    ),
+    tags(
+        (name = "Organizations", description = "Organization and membership endpoints")
+    )
+)]
+pub struct OrganizationApiDoc;
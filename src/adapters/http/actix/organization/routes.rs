@@ -0,0 +1,46 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{
+    create_organization, list_members, list_organizations, remove_member, save_member,
+};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/organizations")
+            .wrap(AuthMiddleware)
+            .route(
+                "",
+                web::get()
+                    .to(list_organizations)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "",
+                web::post()
+                    .to(create_organization)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/members",
+                web::get()
+                    .to(list_members)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "/{id}/members/{user_id}",
+                web::put()
+                    .to(save_member)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/members/{user_id}",
+                web::delete()
+                    .to(remove_member)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    );
+}
@@ -0,0 +1,88 @@
+//! Options follow the WebAuthn JSON serialization (`PublicKeyCredentialCreationOptionsJSON`
+//! and `PublicKeyCredentialRequestOptionsJSON`), so browsers can parse them as they are.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationOptionsDto {
+    /// Base64url encoded challenge.
+    pub challenge: String,
+    pub rp: RelyingPartyDto,
+    pub user: PasskeyUserDto,
+    pub pub_key_cred_params: Vec<CredentialParameterDto>,
+    /// Time allowed to complete the ceremony, in milliseconds.
+    pub timeout: u64,
+    pub exclude_credentials: Vec<CredentialDescriptorDto>,
+    pub authenticator_selection: AuthenticatorSelectionDto,
+    pub attestation: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RelyingPartyDto {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserDto {
+    /// Base64url encoded user handle.
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CredentialParameterDto {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// COSE algorithm identifier.
+    pub alg: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CredentialDescriptorDto {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Base64url encoded credential id.
+    pub id: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelectionDto {
+    pub resident_key: String,
+    pub user_verification: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestOptionsDto {
+    /// Base64url encoded challenge.
+    pub challenge: String,
+    pub rp_id: String,
+    /// Time allowed to complete the ceremony, in milliseconds.
+    pub timeout: u64,
+    pub user_verification: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct RegisterPasskeyDto {
+    /// A name to tell the passkey apart from the user's other ones.
+    #[schema(max_length = 64)]
+    pub name: String,
+    /// Base64url encoded `response.clientDataJSON`.
+    pub client_data_json: String,
+    /// Base64url encoded `response.attestationObject`.
+    pub attestation_object: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PasskeyResponseDto {
+    /// The unique identifier of the passkey.
+    pub id: String,
+    /// The name given to the passkey.
+    pub name: String,
+}
@@ -0,0 +1,262 @@
+use super::dto::{
+    AuthenticatorSelectionDto, CreationOptionsDto, CredentialDescriptorDto, CredentialParameterDto,
+    PasskeyResponseDto, PasskeyUserDto, RegisterPasskeyDto, RelyingPartyDto, RequestOptionsDto,
+};
+use crate::{
+    adapters::{
+        http::actix::api_error::ApiError,
+        persistence::postgres::passkey::repository::PostgresPasskeyRepository,
+        webauthn::verifier::WebAuthnVerifier,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        passkey::{
+            delete_passkey::{DeletePasskeyError, DeletePasskeyService},
+            list_passkeys::{ListPasskeysError, ListPasskeysService},
+            register_passkey::{
+                RegisterPasskeyError, RegisterPasskeyInput, RegisterPasskeyService,
+            },
+            start_authentication::{
+                StartPasskeyAuthenticationError, StartPasskeyAuthenticationOutput,
+                StartPasskeyAuthenticationService,
+            },
+            start_registration::{
+                StartPasskeyRegistrationError, StartPasskeyRegistrationOutput,
+                StartPasskeyRegistrationService,
+            },
+        },
+    },
+    domain::passkey::entity::Passkey,
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use uuid::Uuid;
+
+const PUBLIC_KEY_TYPE: &str = "public-key";
+const ES256: i64 = -7;
+const CEREMONY_TIMEOUT_MS: u64 = 300_000;
+
+#[utoipa::path(
+    post,
+    path = "me/passkeys/options",
+    tag = "Passkeys",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Options for navigator.credentials.create()", body = CreationOptionsDto)
+    )
+)]
+pub async fn registration_options(
+    service: web::Data<
+        StartPasskeyRegistrationService<PostgresPasskeyRepository, WebAuthnVerifier>,
+    >,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let options: StartPasskeyRegistrationOutput = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(CreationOptionsDto {
+        challenge: options.challenge,
+        rp: RelyingPartyDto {
+            id: options.rp_id,
+            name: options.rp_name,
+        },
+        user: PasskeyUserDto {
+            id: URL_SAFE_NO_PAD.encode(options.user_id.as_bytes()),
+            name: options.username.clone(),
+            display_name: options.username,
+        },
+        pub_key_cred_params: vec![CredentialParameterDto {
+            kind: PUBLIC_KEY_TYPE.into(),
+            alg: ES256,
+        }],
+        timeout: CEREMONY_TIMEOUT_MS,
+        exclude_credentials: options
+            .exclude_credentials
+            .iter()
+            .map(|id| CredentialDescriptorDto {
+                kind: PUBLIC_KEY_TYPE.into(),
+                id: URL_SAFE_NO_PAD.encode(id),
+            })
+            .collect(),
+        authenticator_selection: AuthenticatorSelectionDto {
+            resident_key: "required".into(),
+            user_verification: "preferred".into(),
+        },
+        attestation: "none".into(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "me/passkeys",
+    request_body = RegisterPasskeyDto,
+    tag = "Passkeys",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Passkey registered", body = PasskeyResponseDto),
+        (status = 400, description = "Invalid name or attestation"),
+        (status = 409, description = "Passkey already registered")
+    )
+)]
+pub async fn register_passkey(
+    service: web::Data<RegisterPasskeyService<PostgresPasskeyRepository, WebAuthnVerifier>>,
+    payload: web::Json<RegisterPasskeyDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let input: RegisterPasskeyInput = RegisterPasskeyInput {
+        name: payload.name.clone(),
+        client_data_json: decode_base64url(&payload.client_data_json, "client_data_json")?,
+        attestation_object: decode_base64url(&payload.attestation_object, "attestation_object")?,
+    };
+
+    let passkey: Passkey = service.execute(input, &actor).await?;
+
+    Ok(HttpResponse::Created().json(PasskeyResponseDto::from(passkey)))
+}
+
+#[utoipa::path(
+    get,
+    path = "me/passkeys",
+    tag = "Passkeys",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "Passkeys of the authenticated user", body = Vec<PasskeyResponseDto>)
+    )
+)]
+pub async fn list_passkeys(
+    service: web::Data<ListPasskeysService<PostgresPasskeyRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let passkeys: Vec<Passkey> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        passkeys
+            .into_iter()
+            .map(PasskeyResponseDto::from)
+            .collect::<Vec<PasskeyResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "me/passkeys/{id}",
+    params(
+        ("id" = String, Path, description = "Passkey UUID")
+    ),
+    tag = "Passkeys",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Passkey deleted"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 404, description = "Passkey not found")
+    )
+)]
+pub async fn delete_passkey(
+    service: web::Data<DeletePasskeyService<PostgresPasskeyRepository>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    post,
+    path = "oauth/passkey/options",
+    tag = "Passkeys",
+    responses(
+        (status = 200, description = "Options for navigator.credentials.get(), the assertion is then exchanged with the passkey grant", body = RequestOptionsDto)
+    )
+)]
+pub async fn authentication_options(
+    service: web::Data<
+        StartPasskeyAuthenticationService<PostgresPasskeyRepository, WebAuthnVerifier>,
+    >,
+) -> Result<HttpResponse, ApiError> {
+    let options: StartPasskeyAuthenticationOutput = service.execute().await?;
+
+    Ok(HttpResponse::Ok().json(RequestOptionsDto {
+        challenge: options.challenge,
+        rp_id: options.rp_id,
+        timeout: CEREMONY_TIMEOUT_MS,
+        user_verification: "preferred".into(),
+    }))
+}
+
+pub fn decode_base64url(value: &str, field: &str) -> Result<Vec<u8>, ApiError> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|_| {
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("{} must be base64url encoded", field),
+            )
+        })
+}
+
+impl From<Passkey> for PasskeyResponseDto {
+    fn from(passkey: Passkey) -> Self {
+        PasskeyResponseDto {
+            id: passkey.id.to_string(),
+            name: passkey.name,
+        }
+    }
+}
+
+impl From<StartPasskeyRegistrationError> for ApiError {
+    fn from(err: StartPasskeyRegistrationError) -> Self {
+        match err {
+            StartPasskeyRegistrationError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<StartPasskeyAuthenticationError> for ApiError {
+    fn from(err: StartPasskeyAuthenticationError) -> Self {
+        match err {
+            StartPasskeyAuthenticationError::InfrastructureError => {
+                ApiError::internal_server_error()
+            }
+        }
+    }
+}
+
+impl From<RegisterPasskeyError> for ApiError {
+    fn from(err: RegisterPasskeyError) -> Self {
+        match err {
+            RegisterPasskeyError::InvalidName => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Name must be between 1 and 64 characters long",
+            ),
+            RegisterPasskeyError::InvalidAttestation => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Invalid passkey attestation")
+            }
+            RegisterPasskeyError::AlreadyRegistered => {
+                ApiError::new(StatusCode::CONFLICT, "Passkey already registered")
+            }
+            RegisterPasskeyError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ListPasskeysError> for ApiError {
+    fn from(err: ListPasskeysError) -> Self {
+        match err {
+            ListPasskeysError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeletePasskeyError> for ApiError {
+    fn from(err: DeletePasskeyError) -> Self {
+        match err {
+            DeletePasskeyError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Passkey not found")
+            }
+            DeletePasskeyError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
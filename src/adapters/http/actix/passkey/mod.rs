@@ -0,0 +1,33 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::registration_options,
+        handler::register_passkey,
+        handler::list_passkeys,
+        handler::delete_passkey,
+        handler::authentication_options,
+    ),
+    components(
+        schemas(
+            dto::CreationOptionsDto,
+            dto::RelyingPartyDto,
+            dto::PasskeyUserDto,
+            dto::CredentialParameterDto,
+            dto::CredentialDescriptorDto,
+            dto::AuthenticatorSelectionDto,
+            dto::RequestOptionsDto,
+            dto::RegisterPasskeyDto,
+            dto::PasskeyResponseDto
+        )
+    ),
+    tags(
+        (name = "Passkeys", description = "WebAuthn passkey endpoints")
+    )
+)]
+pub struct PasskeyApiDoc;
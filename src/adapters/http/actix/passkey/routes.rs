@@ -0,0 +1,43 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{delete_passkey, list_passkeys, register_passkey, registration_options};
+use actix_web::web;
+
+/// The unauthenticated `/oauth/passkey/options` endpoint lives with the auth routes.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/me/passkeys")
+            .wrap(AuthMiddleware)
+            .route(
+                web::get()
+                    .to(list_passkeys)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                web::post()
+                    .to(register_passkey)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    )
+    .service(
+        web::resource("/me/passkeys/options")
+            .wrap(AuthMiddleware)
+            .route(
+                web::post()
+                    .to(registration_options)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    )
+    .service(
+        web::resource("/me/passkeys/{id}")
+            .wrap(AuthMiddleware)
+            .route(
+                web::delete()
+                    .to(delete_passkey)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    );
+}
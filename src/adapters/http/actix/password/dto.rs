@@ -0,0 +1,21 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangePasswordDto {
+    pub current_password: String,
+    #[schema(min_length = 8, max_length = 64)]
+    pub new_password: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangeExpiredPasswordDto {
+    /// Slug of the organization the username belongs to, the default organization when left
+    /// out.
+    pub organization: Option<String>,
+    /// Username or email address.
+    pub username: String,
+    pub current_password: String,
+    #[schema(min_length = 8, max_length = 64)]
+    pub new_password: String,
+}
@@ -0,0 +1,142 @@
+use super::dto::{ChangeExpiredPasswordDto, ChangePasswordDto};
+use crate::{
+    adapters::{
+        http::actix::{
+            api_error::ApiError,
+            auth::{handler::token_response, login_context::login_context},
+            server::AppChangePassword,
+        },
+        persistence::postgres::organization::repository::PostgresOrganizationRepository,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        organization::find_organization::FindOrganizationService,
+        security::token::IssuedToken,
+        user::change_password::{
+            ChangeExpiredPasswordInput, ChangePasswordError, ChangePasswordInput,
+        },
+    },
+    domain::{
+        organization::entity::{Organization, OrganizationSlug},
+        user::value_objects::password_plain::PasswordPlain,
+    },
+};
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        StatusCode,
+        header::{HeaderValue, RETRY_AFTER},
+    },
+    web,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "me/password",
+    request_body = ChangePasswordDto,
+    tag = "Password",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Password changed, other sessions are ended and new tokens are issued"),
+        (status = 400, description = "Invalid new password, or one that was used recently"),
+        (status = 403, description = "Wrong current password"),
+        (status = 429, description = "Too many wrong current passwords")
+    )
+)]
+pub async fn change_password(
+    service: web::Data<AppChangePassword>,
+    payload: web::Json<ChangePasswordDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let ChangePasswordDto {
+        current_password,
+        new_password,
+    } = payload.into_inner();
+
+    let issued: IssuedToken = service
+        .execute(
+            ChangePasswordInput {
+                current_password,
+                new_password: PasswordPlain::new(new_password)?,
+            },
+            &actor,
+        )
+        .await?;
+
+    Ok(token_response(issued))
+}
+
+#[utoipa::path(
+    post,
+    path = "auth/password/expired",
+    request_body = ChangeExpiredPasswordDto,
+    tag = "Password",
+    responses(
+        (status = 204, description = "Password changed, every session is ended; sign in with the new password"),
+        (status = 400, description = "Invalid new password or one that was used recently, user inactive, or the password has not expired"),
+        (status = 403, description = "Wrong username or current password"),
+        (status = 404, description = "Organization not found"),
+        (status = 429, description = "Too many wrong current passwords")
+    )
+)]
+pub async fn change_expired_password(
+    req: HttpRequest,
+    service: web::Data<AppChangePassword>,
+    organization_service: web::Data<FindOrganizationService<PostgresOrganizationRepository>>,
+    payload: web::Json<ChangeExpiredPasswordDto>,
+) -> Result<HttpResponse, ApiError> {
+    let ChangeExpiredPasswordDto {
+        organization,
+        username,
+        current_password,
+        new_password,
+    } = payload.into_inner();
+
+    let organization_id: Uuid = match organization {
+        Some(slug) => {
+            organization_service
+                .find_by_slug(&OrganizationSlug::new(slug)?)
+                .await?
+                .id
+        }
+        None => Organization::DEFAULT_ID,
+    };
+
+    service
+        .change_expired(
+            ChangeExpiredPasswordInput {
+                organization_id,
+                username,
+                current_password,
+                new_password: PasswordPlain::new(new_password)?,
+            },
+            &login_context(&req),
+        )
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<ChangePasswordError> for ApiError {
+    fn from(value: ChangePasswordError) -> Self {
+        match value {
+            ChangePasswordError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            ChangePasswordError::InvalidCurrentPassword => {
+                ApiError::new(StatusCode::FORBIDDEN, "Invalid current password")
+            }
+            ChangePasswordError::UserInactive => {
+                ApiError::new(StatusCode::BAD_REQUEST, "User inactive")
+            }
+            ChangePasswordError::NotExpired => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Password has not expired")
+            }
+            ChangePasswordError::UserError(error) => ApiError::from(error),
+            ChangePasswordError::Throttled(retry_after) => {
+                ApiError::new(StatusCode::TOO_MANY_REQUESTS, "Too many failed attempts")
+                    .with_header(RETRY_AFTER, HeaderValue::from(retry_after))
+            }
+            ChangePasswordError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(handler::change_password, handler::change_expired_password),
+    components(schemas(dto::ChangePasswordDto, dto::ChangeExpiredPasswordDto)),
+    tags(
+        (name = "Password", description = "Password management of the authenticated user")
+    )
+)]
+pub struct PasswordApiDoc;
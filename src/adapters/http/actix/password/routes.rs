@@ -0,0 +1,21 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{change_expired_password, change_password};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/auth/password/expired",
+        web::post().to(change_expired_password),
+    )
+    .service(
+        web::resource("/me/password").wrap(AuthMiddleware).route(
+            web::post()
+                .to(change_password)
+                .wrap(RequireScope::new([Scope::UsersWrite])),
+        ),
+    );
+}
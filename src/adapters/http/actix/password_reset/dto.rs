@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ForgotPasswordDto {
+    pub username: String,
+    /// Slug of the organization the user belongs to, defaults to the default organization.
+    pub organization: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ResetPasswordDto {
+    /// The reset token the user received.
+    pub token: String,
+    #[schema(min_length = 8, max_length = 64)]
+    pub new_password: String,
+}
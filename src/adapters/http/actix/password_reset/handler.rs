@@ -0,0 +1,154 @@
+use super::dto::{ForgotPasswordDto, ResetPasswordDto};
+use crate::{
+    adapters::{
+        hash::argon2::Argon2Hasher,
+        http::actix::{
+            api_error::ApiError, auth::login_context::login_context, server::AppResetPassword,
+        },
+        notification::AppNotifier,
+        persistence::postgres::{
+            login_attempt::repository::PostgresLoginAttemptStore,
+            organization::repository::PostgresOrganizationRepository,
+            password_reset::repository::PostgresPasswordResetRepository,
+            user::repository::PostgresUserRepository,
+        },
+    },
+    application::{
+        organization::find_organization::{FindOrganizationError, FindOrganizationService},
+        password_reset::{
+            request_reset::{RequestPasswordResetError, RequestPasswordResetService},
+            reset_password::{ResetPasswordError, ResetPasswordInput},
+        },
+    },
+    domain::{
+        organization::entity::{Organization, OrganizationSlug},
+        user::value_objects::{password_plain::PasswordPlain, username::Username},
+    },
+};
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        StatusCode,
+        header::{HeaderValue, RETRY_AFTER},
+    },
+    rt, web,
+};
+use log::warn;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "auth/password/forgot",
+    tag = "Password reset",
+    request_body = ForgotPasswordDto,
+    security(()),
+    responses(
+        (status = 202, description = "A reset token is sent to the user, if they exist"),
+        (status = 400, description = "Invalid username"),
+        (status = 429, description = "Too many requests for the username or from the client, retry after the Retry-After delay")
+    )
+)]
+pub async fn forgot_password(
+    req: HttpRequest,
+    service: web::Data<
+        RequestPasswordResetService<
+            PostgresUserRepository,
+            PostgresPasswordResetRepository,
+            Argon2Hasher,
+            AppNotifier,
+            PostgresLoginAttemptStore,
+        >,
+    >,
+    organization_service: web::Data<FindOrganizationService<PostgresOrganizationRepository>>,
+    payload: web::Json<ForgotPasswordDto>,
+) -> Result<HttpResponse, ApiError> {
+    let ForgotPasswordDto {
+        username,
+        organization,
+    } = payload.into_inner();
+    let username: Username = Username::new(username)?;
+    let organization: Option<OrganizationSlug> =
+        organization.map(OrganizationSlug::new).transpose()?;
+
+    let organization_id: Uuid = match organization {
+        Some(slug) => match organization_service.find_by_slug(&slug).await {
+            Ok(organization) => organization.id,
+            Err(FindOrganizationError::NotFound) => return Ok(HttpResponse::Accepted().finish()),
+            Err(FindOrganizationError::InfrastructureError) => {
+                return Err(ApiError::internal_server_error());
+            }
+        },
+        None => Organization::DEFAULT_ID,
+    };
+
+    service
+        .admit(&organization_id, &username, &login_context(&req))
+        .await?;
+
+    // Answered before the work is done, so the response time does not tell whether the user
+    // exists.
+    rt::spawn(async move {
+        if let Err(error) = service.execute(&organization_id, &username).await {
+            warn!("Failed to send password reset token: {:?}", error);
+        }
+    });
+
+    Ok(HttpResponse::Accepted().finish())
+}
+
+#[utoipa::path(
+    post,
+    path = "auth/password/reset",
+    tag = "Password reset",
+    request_body = ResetPasswordDto,
+    security(()),
+    responses(
+        (status = 204, description = "Password changed, existing sessions are ended"),
+        (status = 400, description = "Invalid, expired or used token, or invalid password")
+    )
+)]
+pub async fn reset_password(
+    req: HttpRequest,
+    service: web::Data<AppResetPassword>,
+    payload: web::Json<ResetPasswordDto>,
+) -> Result<HttpResponse, ApiError> {
+    let ResetPasswordDto {
+        token,
+        new_password,
+    } = payload.into_inner();
+
+    service
+        .execute(ResetPasswordInput {
+            token,
+            new_password: PasswordPlain::new(new_password)?,
+            ip_address: req.peer_addr().map(|address| address.ip()),
+        })
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<RequestPasswordResetError> for ApiError {
+    fn from(value: RequestPasswordResetError) -> Self {
+        match value {
+            RequestPasswordResetError::Throttled(retry_after) => {
+                ApiError::new(StatusCode::TOO_MANY_REQUESTS, "Too many reset requests")
+                    .with_header(RETRY_AFTER, HeaderValue::from(retry_after))
+            }
+            RequestPasswordResetError::Unreachable
+            | RequestPasswordResetError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ResetPasswordError> for ApiError {
+    fn from(value: ResetPasswordError) -> Self {
+        match value {
+            ResetPasswordError::InvalidToken => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Invalid or expired reset token")
+            }
+            ResetPasswordError::UserError(error) => ApiError::from(error),
+            ResetPasswordError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(handler::forgot_password, handler::reset_password),
+    components(schemas(dto::ForgotPasswordDto, dto::ResetPasswordDto)),
+    tags(
+        (name = "Password reset", description = "Self-service password reset")
+    )
+)]
+pub struct PasswordResetApiDoc;
@@ -0,0 +1,7 @@
+use super::handler::{forgot_password, reset_password};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/auth/password/forgot", web::post().to(forgot_password))
+        .route("/auth/password/reset", web::post().to(reset_password));
+}
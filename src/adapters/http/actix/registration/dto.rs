@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct RegisterDto {
+    /// The username of the user.
+    #[schema(min_length = 3, max_length = 32)]
+    pub username: String,
+    /// The name of the user.
+    #[schema(max_length = 255)]
+    pub name: String,
+    /// The password of the user.
+    #[schema(min_length = 8, max_length = 64)]
+    pub password: String,
+    /// The email address of the user, to be verified.
+    #[schema(max_length = 254)]
+    pub email: Option<String>,
+    /// Invite code, required while registration is invite-only.
+    pub invite_code: Option<String>,
+    /// Response of the solved CAPTCHA, required on open registration when a CAPTCHA is
+    /// configured.
+    pub captcha_response: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateInviteDto {
+    /// How many users can sign up with the code. Defaults to 1.
+    #[schema(minimum = 1, maximum = 10000)]
+    pub max_uses: Option<u32>,
+    /// Seconds until the code expires. Defaults to 7 days, at most 365 days.
+    #[schema(minimum = 1)]
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct InviteResponseDto {
+    /// The unique identifier of the invite.
+    pub id: String,
+    /// The invite code, only returned when the invite is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The administrator who created the invite.
+    pub created_by: String,
+    /// How many users can sign up with the code.
+    pub max_uses: u32,
+    /// How many users signed up with the code.
+    pub uses: u32,
+    /// When the code expires, in seconds since the Unix epoch.
+    pub expires_at: u64,
+    /// When the invite was created, in seconds since the Unix epoch.
+    pub created_at: u64,
+}
@@ -0,0 +1,256 @@
+use super::dto::{CreateInviteDto, InviteResponseDto, RegisterDto};
+use crate::{
+    adapters::{
+        hash::argon2::Argon2Hasher,
+        http::actix::{
+            api_error::ApiError,
+            server::AppRegister,
+            user::dto::{ProfileDto, UserResponseDto},
+        },
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog, invite::repository::PostgresInviteRepository,
+        },
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        registration::{
+            create_invite::{
+                CreateInviteError, CreateInviteInput, CreateInviteOutput, CreateInviteService,
+                DEFAULT_INVITE_TTL_SECONDS,
+            },
+            delete_invite::{DeleteInviteError, DeleteInviteService},
+            list_invites::{ListInvitesError, ListInvitesService},
+            register::{RegisterError, RegisterInput},
+        },
+        user::create_user::CreateUserOutput,
+    },
+    domain::invite::entity::Invite,
+};
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        StatusCode,
+        header::{HeaderValue, RETRY_AFTER},
+    },
+    web,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "auth/register",
+    tag = "Registration",
+    request_body = RegisterDto,
+    security(()),
+    responses(
+        (status = 201, description = "User registered, they can sign in now", body = UserResponseDto),
+        (status = 400, description = "Invalid data, invite code or CAPTCHA response"),
+        (status = 403, description = "Registration is closed"),
+        (status = 409, description = "Username already exists"),
+        (status = 429, description = "Too many wrong invite codes, retry after the Retry-After delay")
+    )
+)]
+pub async fn register(
+    service: web::Data<AppRegister>,
+    payload: web::Json<RegisterDto>,
+    req: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    let RegisterDto {
+        username,
+        name,
+        password,
+        email,
+        invite_code,
+        captcha_response,
+    } = payload.into_inner();
+
+    let user: CreateUserOutput = service
+        .execute(RegisterInput {
+            username,
+            name,
+            password,
+            email,
+            invite_code,
+            captcha_response,
+            ip_address: req.peer_addr().map(|address| address.ip()),
+        })
+        .await?;
+
+    Ok(HttpResponse::Created().json(UserResponseDto {
+        id: user.id.to_string(),
+        username: user.username,
+        name: user.name,
+        email_verified: user.email.as_ref().map(|_| false),
+        email: user.email,
+        profile: ProfileDto::default(),
+        deleted_at: None,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "invites",
+    tag = "Registration",
+    request_body = CreateInviteDto,
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Invite created, the code is only shown now", body = InviteResponseDto),
+        (status = 400, description = "Invalid usage limit or expiry"),
+        (status = 403, description = "Without administrator access")
+    )
+)]
+pub async fn create_invite(
+    service: web::Data<
+        CreateInviteService<PostgresInviteRepository, Argon2Hasher, PostgresAuditLog>,
+    >,
+    payload: web::Json<CreateInviteDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let input: CreateInviteInput = CreateInviteInput {
+        max_uses: payload.max_uses.unwrap_or(1),
+        expires_in: payload.expires_in.unwrap_or(DEFAULT_INVITE_TTL_SECONDS),
+    };
+
+    let CreateInviteOutput { invite, code } = service.execute(input, &actor).await?;
+
+    Ok(HttpResponse::Created().json(InviteResponseDto {
+        code: Some(code),
+        ..InviteResponseDto::from(invite)
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "invites",
+    tag = "Registration",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "All invites, newest first", body = Vec<InviteResponseDto>),
+        (status = 403, description = "Without administrator access")
+    )
+)]
+pub async fn list_invites(
+    service: web::Data<ListInvitesService<PostgresInviteRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let invites: Vec<Invite> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        invites
+            .into_iter()
+            .map(InviteResponseDto::from)
+            .collect::<Vec<InviteResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "invites/{id}",
+    params(
+        ("id" = String, Path, description = "Invite UUID")
+    ),
+    tag = "Registration",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Invite withdrawn"),
+        (status = 400, description = "Invalid UUID"),
+        (status = 403, description = "Without administrator access"),
+        (status = 404, description = "Invite not found")
+    )
+)]
+pub async fn delete_invite(
+    service: web::Data<DeleteInviteService<PostgresInviteRepository, PostgresAuditLog>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<Invite> for InviteResponseDto {
+    fn from(invite: Invite) -> Self {
+        InviteResponseDto {
+            id: invite.id.to_string(),
+            code: None,
+            created_by: invite.created_by.to_string(),
+            max_uses: invite.max_uses,
+            uses: invite.uses,
+            expires_at: invite.expires_at,
+            created_at: invite.created_at,
+        }
+    }
+}
+
+impl From<RegisterError> for ApiError {
+    fn from(err: RegisterError) -> Self {
+        match err {
+            RegisterError::Closed => ApiError::new(StatusCode::FORBIDDEN, "Registration is closed"),
+            RegisterError::InviteRequired => {
+                ApiError::new(StatusCode::BAD_REQUEST, "An invite code is required")
+            }
+            RegisterError::InvalidInvite => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Invalid or expired invite code")
+            }
+            RegisterError::CaptchaFailed => {
+                ApiError::new(StatusCode::BAD_REQUEST, "CAPTCHA verification failed")
+            }
+            RegisterError::Throttled(retry_after) => {
+                ApiError::new(StatusCode::TOO_MANY_REQUESTS, "Too many failed attempts")
+                    .with_header(RETRY_AFTER, HeaderValue::from(retry_after))
+            }
+            RegisterError::UserError(error) => ApiError::from(error),
+            RegisterError::AlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Username already exists")
+            }
+            RegisterError::EmailAlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Email address already in use")
+            }
+            RegisterError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<CreateInviteError> for ApiError {
+    fn from(err: CreateInviteError) -> Self {
+        match err {
+            CreateInviteError::InvalidLimits => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Invites allow 1 to 10000 uses and expire within 365 days",
+            ),
+            CreateInviteError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to create invites",
+            ),
+            CreateInviteError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ListInvitesError> for ApiError {
+    fn from(err: ListInvitesError) -> Self {
+        match err {
+            ListInvitesError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to list invites",
+            ),
+            ListInvitesError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeleteInviteError> for ApiError {
+    fn from(err: DeleteInviteError) -> Self {
+        match err {
+            DeleteInviteError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Invite not found"),
+            DeleteInviteError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to delete invites",
+            ),
+            DeleteInviteError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::register,
+        handler::create_invite,
+        handler::list_invites,
+        handler::delete_invite,
+    ),
+    components(
+        schemas(
+            dto::RegisterDto,
+            dto::CreateInviteDto,
+            dto::InviteResponseDto
+        )
+    ),
+    tags(
+        (name = "Registration", description = "Self-registration and invite codes")
+    )
+)]
+pub struct RegistrationApiDoc;
@@ -0,0 +1,33 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{create_invite, delete_invite, list_invites, register};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/auth/register", web::post().to(register))
+        .service(
+            web::scope("/invites")
+                .wrap(AuthMiddleware)
+                .route(
+                    "",
+                    web::post()
+                        .to(create_invite)
+                        .wrap(RequireScope::new([Scope::UsersWrite])),
+                )
+                .route(
+                    "",
+                    web::get()
+                        .to(list_invites)
+                        .wrap(RequireScope::new([Scope::UsersRead])),
+                )
+                .route(
+                    "/{id}",
+                    web::delete()
+                        .to(delete_invite)
+                        .wrap(RequireScope::new([Scope::UsersWrite])),
+                ),
+        );
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateRoleDto {
+    /// The unique name of the role, which cannot be changed.
+    #[schema(min_length = 2, max_length = 32, example = "moderator")]
+    pub name: String,
+    /// What the role is meant for.
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// The permissions the role grants, such as `room.moderate`.
+    pub permissions: Vec<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateRoleDto {
+    /// What the role is meant for.
+    #[schema(max_length = 255)]
+    pub description: Option<String>,
+    /// The permissions the role grants, replacing the current ones.
+    pub permissions: Option<Vec<String>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RoleResponseDto {
+    /// The unique name of the role.
+    pub name: String,
+    /// What the role is meant for.
+    pub description: String,
+    /// The permissions the role grants.
+    pub permissions: Vec<String>,
+    /// Whether the role comes with the server and cannot be deleted.
+    pub builtin: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PermissionResponseDto {
+    /// The name of the permission.
+    pub name: String,
+    /// What the permission allows.
+    pub description: String,
+}
@@ -0,0 +1,262 @@
+use super::dto::{CreateRoleDto, PermissionResponseDto, RoleResponseDto, UpdateRoleDto};
+use crate::{
+    adapters::{
+        http::actix::{
+            api_error::ApiError,
+            server::{AppDeleteRole, AppRoleRepository},
+        },
+        persistence::postgres::audit::repository::PostgresAuditLog,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        role::{
+            create_role::{CreateRoleError, CreateRoleInput, CreateRoleService},
+            delete_role::DeleteRoleError,
+            list_roles::{ListRolesError, ListRolesService},
+            update_role::{UpdateRoleError, UpdateRoleInput, UpdateRoleService},
+        },
+    },
+    domain::role::{
+        entity::{Role, RoleName},
+        error::RoleError,
+        permission::Permission,
+    },
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+
+#[utoipa::path(
+    get,
+    path = "roles",
+    tag = "Roles",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "All roles, built-in ones first", body = Vec<RoleResponseDto>),
+        (status = 403, description = "Without the role.assign or role.manage permission")
+    )
+)]
+pub async fn list_roles(
+    service: web::Data<ListRolesService<AppRoleRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let roles: Vec<Role> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        roles
+            .into_iter()
+            .map(RoleResponseDto::from)
+            .collect::<Vec<RoleResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "roles",
+    request_body = CreateRoleDto,
+    tag = "Roles",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 201, description = "Role created", body = RoleResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the role.manage permission, or granting permissions the caller lacks"),
+        (status = 409, description = "Role already exists")
+    )
+)]
+pub async fn create_role(
+    service: web::Data<CreateRoleService<AppRoleRepository, PostgresAuditLog>>,
+    payload: web::Json<CreateRoleDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let payload: CreateRoleDto = payload.into_inner();
+
+    let input: CreateRoleInput = CreateRoleInput {
+        name: RoleName::new(payload.name)?,
+        description: payload.description.unwrap_or_default(),
+        permissions: permissions(payload.permissions)?,
+    };
+
+    let role: Role = service.execute(input, &actor).await?;
+
+    Ok(HttpResponse::Created().json(RoleResponseDto::from(role)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "roles/{name}",
+    params(
+        ("name" = String, Path, description = "Role name")
+    ),
+    request_body = UpdateRoleDto,
+    tag = "Roles",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 200, description = "Role updated, holders get the new permissions at once", body = RoleResponseDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the role.manage permission, or changing permissions the caller lacks"),
+        (status = 404, description = "Role not found"),
+        (status = 409, description = "The administrator role cannot be changed")
+    )
+)]
+pub async fn update_role(
+    service: web::Data<UpdateRoleService<AppRoleRepository, PostgresAuditLog>>,
+    params: web::Path<String>,
+    payload: web::Json<UpdateRoleDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let name: RoleName = RoleName::new(params.into_inner())?;
+    let payload: UpdateRoleDto = payload.into_inner();
+
+    let input: UpdateRoleInput = UpdateRoleInput {
+        description: payload.description,
+        permissions: payload.permissions.map(permissions).transpose()?,
+    };
+
+    let role: Role = service.execute(&name, input, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(RoleResponseDto::from(role)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "roles/{name}",
+    params(
+        ("name" = String, Path, description = "Role name")
+    ),
+    tag = "Roles",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Role deleted"),
+        (status = 400, description = "Invalid role name"),
+        (status = 403, description = "Without the role.manage permission"),
+        (status = 404, description = "Role not found"),
+        (status = 409, description = "Built-in roles and roles users or memberships still hold cannot be deleted")
+    )
+)]
+pub async fn delete_role(
+    service: web::Data<AppDeleteRole>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let name: RoleName = RoleName::new(params.into_inner())?;
+
+    service.execute(&name, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "permissions",
+    tag = "Roles",
+    responses(
+        (status = 200, description = "Every permission roles can grant", body = Vec<PermissionResponseDto>)
+    )
+)]
+pub async fn list_permissions() -> HttpResponse {
+    HttpResponse::Ok().json(
+        Permission::ALL
+            .into_iter()
+            .map(|permission| PermissionResponseDto {
+                name: permission.as_str().into(),
+                description: permission.description().into(),
+            })
+            .collect::<Vec<PermissionResponseDto>>(),
+    )
+}
+
+pub(crate) fn permissions(names: Vec<String>) -> Result<Vec<Permission>, RoleError> {
+    names.iter().map(|name| name.parse()).collect()
+}
+
+impl From<Role> for RoleResponseDto {
+    fn from(role: Role) -> Self {
+        RoleResponseDto {
+            name: role.name.as_str().into(),
+            description: role.description.clone(),
+            permissions: role
+                .grants()
+                .into_iter()
+                .map(|permission| permission.as_str().into())
+                .collect(),
+            builtin: role.builtin,
+        }
+    }
+}
+
+impl From<RoleError> for ApiError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::InvalidName(message) | RoleError::InvalidDescription(message) => {
+                ApiError::new(StatusCode::BAD_REQUEST, message)
+            }
+            RoleError::UnknownPermission(permission) => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("Unknown permission: {}", permission),
+            ),
+        }
+    }
+}
+
+impl From<ListRolesError> for ApiError {
+    fn from(err: ListRolesError) -> Self {
+        match err {
+            ListRolesError::Forbidden => {
+                ApiError::new(StatusCode::FORBIDDEN, "You don't have access to list roles")
+            }
+            ListRolesError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<CreateRoleError> for ApiError {
+    fn from(err: CreateRoleError) -> Self {
+        match err {
+            CreateRoleError::InvalidRole(error) => ApiError::from(error),
+            CreateRoleError::AlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Role already exists")
+            }
+            CreateRoleError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to create this role",
+            ),
+            CreateRoleError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<UpdateRoleError> for ApiError {
+    fn from(err: UpdateRoleError) -> Self {
+        match err {
+            UpdateRoleError::InvalidRole(error) => ApiError::from(error),
+            UpdateRoleError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Role not found"),
+            UpdateRoleError::Immutable => ApiError::new(
+                StatusCode::CONFLICT,
+                "The administrator role cannot be changed",
+            ),
+            UpdateRoleError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to change this role",
+            ),
+            UpdateRoleError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeleteRoleError> for ApiError {
+    fn from(err: DeleteRoleError) -> Self {
+        match err {
+            DeleteRoleError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "Role not found"),
+            DeleteRoleError::Builtin => {
+                ApiError::new(StatusCode::CONFLICT, "Built-in roles cannot be deleted")
+            }
+            DeleteRoleError::InUse => ApiError::new(
+                StatusCode::CONFLICT,
+                "The role is still held by users, move them to another role first",
+            ),
+            DeleteRoleError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to delete roles",
+            ),
+            DeleteRoleError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
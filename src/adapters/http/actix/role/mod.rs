@@ -0,0 +1,28 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::list_roles,
+        handler::create_role,
+        handler::update_role,
+        handler::delete_role,
+        handler::list_permissions,
+    ),
+    components(
+        schemas(
+            dto::CreateRoleDto,
+            dto::UpdateRoleDto,
+            dto::RoleResponseDto,
+            dto::PermissionResponseDto
+        )
+    ),
+    tags(
+        (name = "Roles", description = "Role and permission management endpoints")
+    )
+)]
+pub struct RoleApiDoc;
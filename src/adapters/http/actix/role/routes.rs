@@ -0,0 +1,43 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{create_role, delete_role, list_permissions, list_roles, update_role};
+use actix_web::web;
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/roles")
+            .wrap(AuthMiddleware)
+            .route(
+                "",
+                web::get()
+                    .to(list_roles)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "",
+                web::post()
+                    .to(create_role)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}",
+                web::patch()
+                    .to(update_role)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{name}",
+                web::delete()
+                    .to(delete_role)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    )
+    .service(
+        web::scope("/permissions")
+            .wrap(AuthMiddleware)
+            .route("", web::get().to(list_permissions)),
+    );
+}
@@ -1,73 +1,940 @@
 use crate::{
     adapters::{
-        auth::local::LocalAuthenticator,
+        auth::{
+            chain::ChainAuthenticator, federated::FederatedAuthenticator, ldap::LdapAuthenticator,
+            local::LocalAuthenticator, passkey::PasskeyAuthenticator,
+            second_factor::SecondFactorAuthenticator,
+        },
+        avatar::resize::ResizingAvatarProcessor,
+        cache::{
+            group_repository::CachedGroupRepository, revocation_store::CachedRevocationStore,
+            role_repository::CachedRoleRepository,
+        },
+        captcha::{AppCaptcha, site_verify::SiteVerifyCaptcha},
+        event::{AppEventPublisher, log::LogEventPublisher, webhook::WebhookEventPublisher},
+        federation::oidc::OidcFederation,
         hash::argon2::Argon2Hasher,
         http::actix::{
-            ApiDoc, auth::routes::routes as auth_routes, user::routes::routes as user_routes,
+            ApiDoc, audit::routes::routes as audit_routes, auth::routes::routes as auth_routes,
+            client::routes::routes as client_routes,
+            email_verification::routes::routes as email_verification_routes,
+            federation::routes::routes as federation_routes, group::routes::routes as group_routes,
+            mfa::routes::routes as mfa_routes, oidc::routes::routes as oidc_routes,
+            organization::routes::routes as organization_routes,
+            passkey::routes::routes as passkey_routes, password::routes::routes as password_routes,
+            password_reset::routes::routes as password_reset_routes,
+            registration::routes::routes as registration_routes,
+            role::routes::routes as role_routes, session::routes::routes as session_routes,
+            user::routes::routes as user_routes,
         },
-        persistence::postgres::user::repository::PostgresUserRepository,
-        token::jwt::JwtService,
+        mfa::totp::HmacTotp,
+        notification::{AppNotifier, log::LogNotifier, smtp::SmtpNotifier},
+        password::breach_list::FileBreachList,
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog, avatar::repository::PostgresAvatarRepository,
+            client::repository::PostgresClientRepository,
+            email_verification::repository::PostgresEmailVerificationRepository,
+            external_identity::repository::PostgresExternalIdentityRepository,
+            group::repository::PostgresGroupRepository,
+            invite::repository::PostgresInviteRepository,
+            login_attempt::repository::PostgresLoginAttemptStore,
+            organization::repository::PostgresOrganizationRepository,
+            passkey::repository::PostgresPasskeyRepository,
+            password_history::repository::PostgresPasswordHistoryRepository,
+            password_reset::repository::PostgresPasswordResetRepository,
+            revoked_token::repository::PostgresRevocationStore,
+            role::repository::PostgresRoleRepository,
+            session::repository::PostgresSessionRepository,
+            totp::repository::PostgresTotpRepository, user::repository::PostgresUserRepository,
+        },
+        token::{id_token_key::IdTokenKey, jwt::JwtService},
+        webauthn::verifier::WebAuthnVerifier,
     },
     application::{
-        auth::login::Login,
+        audit::list_entries::ListAuditEntriesService,
+        auth::{impersonate::ImpersonateService, login::Login},
+        avatar::{
+            delete_avatar::DeleteAvatarService, get_avatar::GetAvatarService,
+            set_avatar::SetAvatarService,
+        },
+        client::{
+            authenticate_client::AuthenticateClientService, register_client::RegisterClientService,
+        },
+        email_verification::{
+            request_verification::RequestEmailVerificationService, verify_email::VerifyEmailService,
+        },
+        federation::start_login::StartFederatedLoginService,
+        group::{
+            add_member::AddGroupMemberService, create_group::CreateGroupService,
+            delete_group::DeleteGroupService, list_groups::ListGroupsService,
+            list_members::ListGroupMembersService, remove_member::RemoveGroupMemberService,
+            update_group::UpdateGroupService,
+        },
+        mfa::{
+            confirm_totp::ConfirmTotpService, enroll_totp::EnrollTotpService,
+            reset_totp::ResetTotpService,
+        },
+        organization::{
+            create_organization::CreateOrganizationService,
+            find_organization::FindOrganizationService, list_members::ListMembersService,
+            list_organizations::ListOrganizationsService, remove_member::RemoveMemberService,
+            save_member::SaveMemberService,
+        },
+        passkey::{
+            delete_passkey::DeletePasskeyService, list_passkeys::ListPasskeysService,
+            register_passkey::RegisterPasskeyService,
+            start_authentication::StartPasskeyAuthenticationService,
+            start_registration::StartPasskeyRegistrationService,
+        },
+        password_reset::{
+            request_reset::RequestPasswordResetService, reset_password::ResetPasswordService,
+        },
+        registration::{
+            create_invite::CreateInviteService,
+            delete_invite::DeleteInviteService,
+            list_invites::ListInvitesService,
+            register::{RegisterService, RegistrationMode},
+        },
+        role::{
+            create_role::CreateRoleService, delete_role::DeleteRoleService,
+            list_roles::ListRolesService, update_role::UpdateRoleService,
+        },
+        security::{
+            introspect_token::IntrospectTokenService,
+            login_throttle::{LoginThrottle, ThrottlePolicy},
+            revoke_token::RevokeTokenService,
+            verify_access::VerifyAccessService,
+        },
+        session::{
+            delete_session::DeleteSessionService, end_sessions::EndSessionsService,
+            list_sessions::ListSessionsService,
+        },
         user::{
-            create_user::CreateUserService, delete_user::DeleteUserService,
-            find_user::FindUserService, update_user::UpdateUserService,
+            change_password::ChangePasswordService, change_role::ChangeUserRoleService,
+            change_status::ChangeUserStatusService, create_user::CreateUserService,
+            delete_user::DeleteUserService, find_user::FindUserService,
+            list_users::ListUsersService, purge_deleted_users::PurgeDeletedUsersService,
+            restore_user::RestoreUserService, update_user::UpdateUserService,
         },
     },
-    config::http::ports::HttpConfig,
+    config::{Config, registration::ports::RegistrationMode as ConfigRegistrationMode},
+    domain::user::password_policy::{PasswordPolicy, PasswordRules},
 };
-use actix_web::{App, HttpResponse, HttpServer, Responder, get, web};
+use actix_web::{App, HttpResponse, HttpServer, Responder, get, rt, web};
+use log::{info, warn};
 use sea_orm::DatabaseConnection;
-use std::io::Error;
+use std::{io::Error, path::Path, time::Duration};
+use url::Url;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+/// Directory users first, then local accounts, then passkeys, then upstream providers.
+pub type AppAuthenticator = ChainAuthenticator<
+    ChainAuthenticator<
+        SecondFactorAuthenticator<
+            ChainAuthenticator<
+                Option<
+                    LdapAuthenticator<
+                        PostgresUserRepository,
+                        PostgresExternalIdentityRepository,
+                        Argon2Hasher,
+                        AppRevocationStore,
+                    >,
+                >,
+                LocalAuthenticator<
+                    PostgresUserRepository,
+                    Argon2Hasher,
+                    JwtService,
+                    PostgresTotpRepository,
+                    HmacTotp,
+                    AppRevocationStore,
+                >,
+            >,
+            PostgresTotpRepository,
+            JwtService,
+        >,
+        PasskeyAuthenticator<PostgresUserRepository, PostgresPasskeyRepository, WebAuthnVerifier>,
+    >,
+    SecondFactorAuthenticator<
+        FederatedAuthenticator<
+            PostgresUserRepository,
+            PostgresExternalIdentityRepository,
+            OidcFederation,
+            Argon2Hasher,
+        >,
+        PostgresTotpRepository,
+        JwtService,
+    >,
+>;
+pub type AppRevocationStore = CachedRevocationStore<PostgresRevocationStore>;
+pub type AppRoleRepository = CachedRoleRepository<PostgresRoleRepository>;
+pub type AppGroupRepository = CachedGroupRepository<PostgresGroupRepository>;
+pub type AppDeleteRole = DeleteRoleService<
+    AppRoleRepository,
+    PostgresUserRepository,
+    PostgresOrganizationRepository,
+    PostgresAuditLog,
+>;
+pub type AppVerifyAccess =
+    VerifyAccessService<JwtService, AppRevocationStore, AppRoleRepository, AppGroupRepository>;
+pub type AppImpersonate = ImpersonateService<
+    JwtService,
+    AppRevocationStore,
+    PostgresUserRepository,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+pub type AppLogin = Login<
+    AppAuthenticator,
+    JwtService,
+    PostgresLoginAttemptStore,
+    PostgresSessionRepository,
+    PostgresOrganizationRepository,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+pub type AppRegister = RegisterService<
+    PostgresUserRepository,
+    Argon2Hasher,
+    FileBreachList,
+    PostgresInviteRepository,
+    AppCaptcha,
+    PostgresAuditLog,
+    PostgresLoginAttemptStore,
+>;
+pub type AppChangePassword = ChangePasswordService<
+    PostgresUserRepository,
+    PostgresPasswordHistoryRepository,
+    Argon2Hasher,
+    AppRevocationStore,
+    JwtService,
+    PostgresLoginAttemptStore,
+    FileBreachList,
+    PostgresSessionRepository,
+    PostgresAuditLog,
+>;
+pub type AppResetPassword = ResetPasswordService<
+    PostgresUserRepository,
+    PostgresPasswordResetRepository,
+    PostgresPasswordHistoryRepository,
+    Argon2Hasher,
+    AppRevocationStore,
+    FileBreachList,
+    PostgresSessionRepository,
+    PostgresAuditLog,
+>;
+
+pub type AppUpdateUser = UpdateUserService<
+    PostgresUserRepository,
+    PostgresPasswordHistoryRepository,
+    Argon2Hasher,
+    FileBreachList,
+    AppRevocationStore,
+    PostgresSessionRepository,
+    AppEventPublisher,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+pub type AppDeleteUser = DeleteUserService<
+    PostgresUserRepository,
+    PostgresSessionRepository,
+    AppRevocationStore,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+pub type AppSetAvatar = SetAvatarService<
+    PostgresUserRepository,
+    PostgresAvatarRepository,
+    ResizingAvatarProcessor,
+    AppEventPublisher,
+    AppRoleRepository,
+    AppGroupRepository,
+>;
+pub type AppDeleteAvatar = DeleteAvatarService<
+    PostgresUserRepository,
+    PostgresAvatarRepository,
+    AppEventPublisher,
+    AppRoleRepository,
+    AppGroupRepository,
+>;
+pub type AppResetTotp = ResetTotpService<
+    PostgresUserRepository,
+    PostgresTotpRepository,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+pub type AppEndSessions = EndSessionsService<
+    PostgresUserRepository,
+    PostgresSessionRepository,
+    AppRevocationStore,
+    AppRoleRepository,
+    AppGroupRepository,
+    PostgresAuditLog,
+>;
+
+const TOTP_ISSUER: &str = "Windwatcher";
+const WEBAUTHN_RP_NAME: &str = "Windwatcher";
+
 #[get("/")]
 async fn hello() -> impl Responder {
     HttpResponse::Ok().body("Hello, WindWatcher!")
 }
 
-pub async fn build_app(http_config: HttpConfig, db: DatabaseConnection) -> Result<(), Error> {
-    let user_repository: PostgresUserRepository = PostgresUserRepository::new(db);
-    let hasher: Argon2Hasher = Argon2Hasher;
+pub async fn build_app(config: Config, db: DatabaseConnection) -> Result<(), Error> {
+    let Config {
+        deletion: deletion_config,
+        http: http_config,
+        hashing: hashing_config,
+        ldap: ldap_config,
+        lockout: lockout_config,
+        oidc_providers,
+        password: password_config,
+        registration: registration_config,
+        smtp: smtp_config,
+        webhook: webhook_config,
+        ..
+    } = config;
+    let user_repository: PostgresUserRepository = PostgresUserRepository::new(db.clone());
+    let client_repository: PostgresClientRepository = PostgresClientRepository::new(db.clone());
+    let revocation_store: AppRevocationStore = CachedRevocationStore::new(
+        PostgresRevocationStore::new(db.clone()),
+        Duration::from_secs(http_config.revocation_cache_ttl),
+    );
+    let role_repository: AppRoleRepository = CachedRoleRepository::new(
+        PostgresRoleRepository::new(db.clone()),
+        Duration::from_secs(http_config.revocation_cache_ttl),
+    );
+    let group_repository: AppGroupRepository = CachedGroupRepository::new(
+        PostgresGroupRepository::new(db.clone()),
+        Duration::from_secs(http_config.revocation_cache_ttl),
+    );
+    let organization_repository: PostgresOrganizationRepository =
+        PostgresOrganizationRepository::new(db.clone());
+    let totp_repository: PostgresTotpRepository = PostgresTotpRepository::new(db.clone());
+    let passkey_repository: PostgresPasskeyRepository = PostgresPasskeyRepository::new(db.clone());
+    let external_identity_repository: PostgresExternalIdentityRepository =
+        PostgresExternalIdentityRepository::new(db.clone());
+    let login_attempt_store: PostgresLoginAttemptStore = PostgresLoginAttemptStore::new(db.clone());
+    let password_history_repository: PostgresPasswordHistoryRepository =
+        PostgresPasswordHistoryRepository::new(db.clone());
+    let password_reset_repository: PostgresPasswordResetRepository =
+        PostgresPasswordResetRepository::new(db.clone());
+    let email_verification_repository: PostgresEmailVerificationRepository =
+        PostgresEmailVerificationRepository::new(db.clone());
+    let avatar_repository: PostgresAvatarRepository = PostgresAvatarRepository::new(db.clone());
+    let invite_repository: PostgresInviteRepository = PostgresInviteRepository::new(db.clone());
+    let session_repository: PostgresSessionRepository = PostgresSessionRepository::new(db.clone());
+    let audit_log: PostgresAuditLog = PostgresAuditLog::new(db);
+    let notifier: AppNotifier = match smtp_config {
+        Some(config) => AppNotifier::Smtp(SmtpNotifier::new(config)),
+        None => {
+            warn!("No SMTP server configured, notifications are only logged");
+            AppNotifier::Log(LogNotifier)
+        }
+    };
+    let event_publisher: AppEventPublisher = match webhook_config {
+        Some(config) => AppEventPublisher::Webhook(WebhookEventPublisher::new(
+            Url::parse(&config.url).expect("Invalid webhook URL"),
+            config.secret,
+            Duration::from_secs(config.timeout),
+        )),
+        None => {
+            info!("No webhook configured, events are only logged");
+            AppEventPublisher::Log(LogEventPublisher)
+        }
+    };
+    let breach_list: FileBreachList = match &password_config.breach_list {
+        Some(path) => {
+            let list: FileBreachList = FileBreachList::load(Path::new(path))?;
+
+            match list.loaded() {
+                Some(0) => warn!("The breached password list {} is empty", path),
+                Some(count) => info!("Loaded {} breached passwords", count),
+                None => info!(
+                    "Looking breached passwords up in the range files of {}",
+                    path
+                ),
+            }
+
+            list
+        }
+        None => FileBreachList::empty(),
+    };
+    let password_policy: PasswordPolicy<FileBreachList> = PasswordPolicy::new(
+        PasswordRules {
+            min_length: password_config.min_length,
+            require_lowercase: password_config.require_lowercase,
+            require_uppercase: password_config.require_uppercase,
+            require_digit: password_config.require_digit,
+            require_symbol: password_config.require_symbol,
+            max_age: password_config.max_age.map(|days| days * 24 * 60 * 60),
+            history: password_config.history,
+        },
+        breach_list,
+    );
+    let totp_provider: HmacTotp = HmacTotp::new(TOTP_ISSUER);
+    let hasher: Argon2Hasher = Argon2Hasher::new(
+        hashing_config.memory,
+        hashing_config.iterations,
+        hashing_config.parallelism,
+        hashing_config.pepper,
+    )
+    .expect("Invalid Argon2 parameters");
+    let issuer: String = http_config
+        .public_url
+        .clone()
+        .unwrap_or_else(|| format!("http://{}:{}", http_config.host, http_config.port))
+        .trim_end_matches('/')
+        .to_owned();
+    let public_url: Url = Url::parse(&issuer).expect("Invalid public URL");
+    let email_verify_url: String = format!("{}/auth/email/verify", issuer);
+    let passkey_verifier: WebAuthnVerifier = WebAuthnVerifier::new(
+        &http_config.token_secret,
+        public_url.origin().ascii_serialization(),
+        public_url.host_str().unwrap_or(&http_config.host),
+        WEBAUTHN_RP_NAME,
+    );
+    let id_token_key: IdTokenKey = match &http_config.id_token_key {
+        Some(path) => IdTokenKey::from_pem(&std::fs::read(path)?)
+            .expect("Failed to load id_token signing key"),
+        None => {
+            warn!("No id_token signing key configured, generating an ephemeral one");
+            IdTokenKey::generate().expect("Failed to generate id_token signing key")
+        }
+    };
+    let federation: OidcFederation =
+        OidcFederation::new(oidc_providers, &http_config.token_secret, issuer.clone());
     let token_service: JwtService = JwtService::new(
         http_config.token_secret.clone(),
-        http_config.token_ttl.clone() * 60,
-        http_config.refresh_token_ttl.clone() * 60,
+        http_config.token_ttl * 60,
+        http_config.refresh_token_ttl * 60,
+        issuer,
+        id_token_key,
     );
-    let authenticator: LocalAuthenticator<PostgresUserRepository, Argon2Hasher, JwtService> =
-        LocalAuthenticator::new(
+    let captcha: AppCaptcha = match registration_config.captcha {
+        Some(config) => AppCaptcha::SiteVerify(SiteVerifyCaptcha::new(
+            Url::parse(&config.verify_url).expect("Invalid CAPTCHA verification URL"),
+            config.secret,
+        )),
+        None => AppCaptcha::Disabled,
+    };
+    let registration_mode: RegistrationMode = match registration_config.mode {
+        ConfigRegistrationMode::Closed => RegistrationMode::Closed,
+        ConfigRegistrationMode::InviteOnly => RegistrationMode::InviteOnly,
+        ConfigRegistrationMode::Open => {
+            if matches!(captcha, AppCaptcha::Disabled) {
+                warn!("Registration is open without a CAPTCHA");
+            }
+
+            RegistrationMode::Open
+        }
+    };
+    let ldap_authenticator: Option<
+        LdapAuthenticator<
+            PostgresUserRepository,
+            PostgresExternalIdentityRepository,
+            Argon2Hasher,
+            AppRevocationStore,
+        >,
+    > = ldap_config.map(|config| {
+        LdapAuthenticator::new(
             user_repository.clone(),
+            external_identity_repository.clone(),
             hasher.clone(),
+            revocation_store.clone(),
+            config,
+        )
+    });
+    // The second factor is asked for whichever authenticator checked the password, and for
+    // logins through an upstream provider.
+    let authenticator: AppAuthenticator = ChainAuthenticator::new(
+        ChainAuthenticator::new(
+            SecondFactorAuthenticator::new(
+                ChainAuthenticator::new(
+                    ldap_authenticator,
+                    LocalAuthenticator::new(
+                        user_repository.clone(),
+                        hasher.clone(),
+                        token_service.clone(),
+                        totp_repository.clone(),
+                        totp_provider.clone(),
+                        revocation_store.clone(),
+                        password_policy.max_age(),
+                    ),
+                ),
+                totp_repository.clone(),
+                token_service.clone(),
+            ),
+            PasskeyAuthenticator::new(
+                user_repository.clone(),
+                passkey_repository.clone(),
+                passkey_verifier.clone(),
+            ),
+        ),
+        SecondFactorAuthenticator::new(
+            FederatedAuthenticator::new(
+                user_repository.clone(),
+                external_identity_repository,
+                federation.clone(),
+                hasher.clone(),
+                registration_mode,
+            ),
+            totp_repository.clone(),
             token_service.clone(),
-        );
+        ),
+    );
 
     let find_user_service: FindUserService<PostgresUserRepository> =
         FindUserService::new(user_repository.clone());
-    let create_user_service: CreateUserService<PostgresUserRepository, Argon2Hasher> =
-        CreateUserService::new(user_repository.clone(), hasher.clone());
-    let update_user_service: UpdateUserService<PostgresUserRepository, Argon2Hasher> =
-        UpdateUserService::new(user_repository.clone(), hasher.clone());
-    let delete_user_service: DeleteUserService<PostgresUserRepository> =
-        DeleteUserService::new(user_repository.clone());
-    let login: Login<
-        LocalAuthenticator<PostgresUserRepository, Argon2Hasher, JwtService>,
+    let list_users_service: ListUsersService<PostgresUserRepository> =
+        ListUsersService::new(user_repository.clone());
+    let change_user_status_service: ChangeUserStatusService<
+        PostgresUserRepository,
+        PostgresSessionRepository,
+        AppRevocationStore,
+        AppRoleRepository,
+        AppGroupRepository,
+        PostgresAuditLog,
+    > = ChangeUserStatusService::new(
+        user_repository.clone(),
+        session_repository.clone(),
+        revocation_store.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let change_user_role_service: ChangeUserRoleService<
+        PostgresUserRepository,
+        PostgresSessionRepository,
+        AppRevocationStore,
+        AppRoleRepository,
+        AppGroupRepository,
+        PostgresAuditLog,
+    > = ChangeUserRoleService::new(
+        user_repository.clone(),
+        session_repository.clone(),
+        revocation_store.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let list_roles_service: ListRolesService<AppRoleRepository> =
+        ListRolesService::new(role_repository.clone());
+    let create_role_service: CreateRoleService<AppRoleRepository, PostgresAuditLog> =
+        CreateRoleService::new(role_repository.clone(), audit_log.clone());
+    let update_role_service: UpdateRoleService<AppRoleRepository, PostgresAuditLog> =
+        UpdateRoleService::new(role_repository.clone(), audit_log.clone());
+    let delete_role_service: AppDeleteRole = DeleteRoleService::new(
+        role_repository.clone(),
+        user_repository.clone(),
+        organization_repository.clone(),
+        audit_log.clone(),
+    );
+    let list_groups_service: ListGroupsService<AppGroupRepository> =
+        ListGroupsService::new(group_repository.clone());
+    let create_group_service: CreateGroupService<AppGroupRepository, PostgresAuditLog> =
+        CreateGroupService::new(group_repository.clone(), audit_log.clone());
+    let update_group_service: UpdateGroupService<AppGroupRepository, PostgresAuditLog> =
+        UpdateGroupService::new(group_repository.clone(), audit_log.clone());
+    let delete_group_service: DeleteGroupService<AppGroupRepository, PostgresAuditLog> =
+        DeleteGroupService::new(group_repository.clone(), audit_log.clone());
+    let list_group_members_service: ListGroupMembersService<AppGroupRepository> =
+        ListGroupMembersService::new(group_repository.clone());
+    let add_group_member_service: AddGroupMemberService<
+        AppGroupRepository,
+        PostgresOrganizationRepository,
+        PostgresAuditLog,
+    > = AddGroupMemberService::new(
+        group_repository.clone(),
+        organization_repository.clone(),
+        audit_log.clone(),
+    );
+    let remove_group_member_service: RemoveGroupMemberService<
+        AppGroupRepository,
+        PostgresAuditLog,
+    > = RemoveGroupMemberService::new(group_repository.clone(), audit_log.clone());
+    let find_organization_service: FindOrganizationService<PostgresOrganizationRepository> =
+        FindOrganizationService::new(organization_repository.clone());
+    let list_organizations_service: ListOrganizationsService<PostgresOrganizationRepository> =
+        ListOrganizationsService::new(organization_repository.clone());
+    let create_organization_service: CreateOrganizationService<
+        PostgresOrganizationRepository,
+        PostgresAuditLog,
+    > = CreateOrganizationService::new(organization_repository.clone(), audit_log.clone());
+    let list_members_service: ListMembersService<PostgresOrganizationRepository> =
+        ListMembersService::new(organization_repository.clone());
+    let save_member_service: SaveMemberService<
+        PostgresOrganizationRepository,
+        PostgresUserRepository,
+        AppRoleRepository,
+        AppRevocationStore,
+        PostgresAuditLog,
+    > = SaveMemberService::new(
+        organization_repository.clone(),
+        user_repository.clone(),
+        role_repository.clone(),
+        revocation_store.clone(),
+        audit_log.clone(),
+    );
+    let remove_member_service: RemoveMemberService<
+        PostgresOrganizationRepository,
+        PostgresUserRepository,
+        AppRoleRepository,
+        AppRevocationStore,
+        PostgresAuditLog,
+    > = RemoveMemberService::new(
+        organization_repository.clone(),
+        user_repository.clone(),
+        role_repository.clone(),
+        revocation_store.clone(),
+        audit_log.clone(),
+    );
+    let create_user_service: CreateUserService<
+        PostgresUserRepository,
+        Argon2Hasher,
+        FileBreachList,
+        PostgresAuditLog,
+    > = CreateUserService::new(
+        user_repository.clone(),
+        hasher.clone(),
+        password_policy.clone(),
+        audit_log.clone(),
+    );
+    let login_throttle: LoginThrottle<PostgresLoginAttemptStore> = LoginThrottle::new(
+        login_attempt_store,
+        ThrottlePolicy {
+            max_failures: lockout_config.max_failures,
+            ip_max_failures: lockout_config.ip_max_failures,
+            base_delay: lockout_config.base_delay,
+            lockout: lockout_config.duration * 60,
+        },
+    );
+    let register_service: AppRegister = RegisterService::new(
+        create_user_service.clone(),
+        invite_repository.clone(),
+        hasher.clone(),
+        captcha,
+        registration_mode,
+        audit_log.clone(),
+        login_throttle.clone(),
+    );
+    let create_invite_service: CreateInviteService<
+        PostgresInviteRepository,
+        Argon2Hasher,
+        PostgresAuditLog,
+    > = CreateInviteService::new(invite_repository.clone(), hasher.clone(), audit_log.clone());
+    let list_invites_service: ListInvitesService<PostgresInviteRepository> =
+        ListInvitesService::new(invite_repository.clone());
+    let delete_invite_service: DeleteInviteService<PostgresInviteRepository, PostgresAuditLog> =
+        DeleteInviteService::new(invite_repository.clone(), audit_log.clone());
+    let update_user_service: AppUpdateUser = UpdateUserService::new(
+        user_repository.clone(),
+        password_history_repository.clone(),
+        hasher.clone(),
+        password_policy.clone(),
+        revocation_store.clone(),
+        session_repository.clone(),
+        event_publisher.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let delete_user_service: AppDeleteUser = DeleteUserService::new(
+        user_repository.clone(),
+        session_repository.clone(),
+        revocation_store.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let grace_period: u64 = deletion_config.grace_period * 24 * 60 * 60;
+    let restore_user_service: RestoreUserService<PostgresUserRepository, PostgresAuditLog> =
+        RestoreUserService::new(user_repository.clone(), audit_log.clone(), grace_period);
+    let purge_deleted_users_service: PurgeDeletedUsersService<
+        PostgresUserRepository,
+        PostgresAuditLog,
+    > = PurgeDeletedUsersService::new(user_repository.clone(), audit_log.clone(), grace_period);
+    let get_avatar_service: GetAvatarService<PostgresAvatarRepository> =
+        GetAvatarService::new(avatar_repository.clone());
+    let set_avatar_service: AppSetAvatar = SetAvatarService::new(
+        user_repository.clone(),
+        avatar_repository.clone(),
+        ResizingAvatarProcessor::default(),
+        event_publisher.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+    );
+    let delete_avatar_service: AppDeleteAvatar = DeleteAvatarService::new(
+        user_repository.clone(),
+        avatar_repository.clone(),
+        event_publisher.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+    );
+    let login: AppLogin = Login::new(
+        authenticator.clone(),
+        token_service.clone(),
+        login_throttle.clone(),
+        session_repository.clone(),
+        organization_repository.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let change_password_service: AppChangePassword = ChangePasswordService::new(
+        user_repository.clone(),
+        password_history_repository.clone(),
+        hasher.clone(),
+        revocation_store.clone(),
+        token_service.clone(),
+        login_throttle.clone(),
+        password_policy.clone(),
+        session_repository.clone(),
+        audit_log.clone(),
+    );
+    let authenticate_client_service: AuthenticateClientService<
+        PostgresClientRepository,
+        Argon2Hasher,
+    > = AuthenticateClientService::new(client_repository.clone(), hasher.clone());
+    let register_client_service: RegisterClientService<
+        PostgresClientRepository,
+        Argon2Hasher,
+        PostgresAuditLog,
+    > = RegisterClientService::new(client_repository.clone(), hasher.clone(), audit_log.clone());
+    let verify_access_service: AppVerifyAccess = VerifyAccessService::new(
+        token_service.clone(),
+        revocation_store.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+    );
+    let introspect_token_service: IntrospectTokenService<
+        JwtService,
+        AppRevocationStore,
+        PostgresUserRepository,
+        AppRoleRepository,
+        AppGroupRepository,
+        PostgresOrganizationRepository,
+    > = IntrospectTokenService::new(
+        token_service.clone(),
+        revocation_store.clone(),
+        user_repository.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        organization_repository.clone(),
+        verify_access_service.clone(),
+    );
+    let revoke_token_service: RevokeTokenService<
         JwtService,
-    > = Login::new(authenticator.clone(), token_service.clone());
+        AppRevocationStore,
+        PostgresSessionRepository,
+    > = RevokeTokenService::new(
+        token_service.clone(),
+        revocation_store.clone(),
+        session_repository.clone(),
+    );
+    let impersonate_service: AppImpersonate = ImpersonateService::new(
+        token_service.clone(),
+        revocation_store.clone(),
+        user_repository.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let enroll_totp_service: EnrollTotpService<PostgresTotpRepository, HmacTotp, Argon2Hasher> =
+        EnrollTotpService::new(
+            totp_repository.clone(),
+            totp_provider.clone(),
+            hasher.clone(),
+        );
+    let confirm_totp_service: ConfirmTotpService<PostgresTotpRepository, HmacTotp> =
+        ConfirmTotpService::new(totp_repository.clone(), totp_provider.clone());
+    let reset_totp_service: AppResetTotp = ResetTotpService::new(
+        user_repository.clone(),
+        totp_repository.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let start_passkey_registration_service: StartPasskeyRegistrationService<
+        PostgresPasskeyRepository,
+        WebAuthnVerifier,
+    > = StartPasskeyRegistrationService::new(passkey_repository.clone(), passkey_verifier.clone());
+    let register_passkey_service: RegisterPasskeyService<
+        PostgresPasskeyRepository,
+        WebAuthnVerifier,
+    > = RegisterPasskeyService::new(passkey_repository.clone(), passkey_verifier.clone());
+    let list_passkeys_service: ListPasskeysService<PostgresPasskeyRepository> =
+        ListPasskeysService::new(passkey_repository.clone());
+    let delete_passkey_service: DeletePasskeyService<PostgresPasskeyRepository> =
+        DeletePasskeyService::new(passkey_repository.clone());
+    let start_passkey_authentication_service: StartPasskeyAuthenticationService<
+        PostgresPasskeyRepository,
+        WebAuthnVerifier,
+    > = StartPasskeyAuthenticationService::new(
+        passkey_repository.clone(),
+        passkey_verifier.clone(),
+    );
+
+    let request_password_reset_service: RequestPasswordResetService<
+        PostgresUserRepository,
+        PostgresPasswordResetRepository,
+        Argon2Hasher,
+        AppNotifier,
+        PostgresLoginAttemptStore,
+    > = RequestPasswordResetService::new(
+        user_repository.clone(),
+        password_reset_repository.clone(),
+        hasher.clone(),
+        notifier.clone(),
+        login_throttle.clone(),
+    );
+    let reset_password_service: AppResetPassword = ResetPasswordService::new(
+        user_repository.clone(),
+        password_reset_repository,
+        password_history_repository,
+        hasher.clone(),
+        revocation_store.clone(),
+        password_policy,
+        session_repository.clone(),
+        audit_log.clone(),
+    );
+    let request_email_verification_service: RequestEmailVerificationService<
+        PostgresUserRepository,
+        PostgresEmailVerificationRepository,
+        Argon2Hasher,
+        AppNotifier,
+    > = RequestEmailVerificationService::new(
+        user_repository.clone(),
+        email_verification_repository.clone(),
+        hasher.clone(),
+        notifier.clone(),
+        email_verify_url,
+    );
+    let verify_email_service: VerifyEmailService<
+        PostgresUserRepository,
+        PostgresEmailVerificationRepository,
+        Argon2Hasher,
+    > = VerifyEmailService::new(
+        user_repository.clone(),
+        email_verification_repository.clone(),
+        hasher.clone(),
+    );
+    let list_sessions_service: ListSessionsService<PostgresSessionRepository> =
+        ListSessionsService::new(session_repository.clone());
+    let delete_session_service: DeleteSessionService<PostgresSessionRepository> =
+        DeleteSessionService::new(session_repository.clone());
+    let end_sessions_service: AppEndSessions = EndSessionsService::new(
+        user_repository.clone(),
+        session_repository,
+        revocation_store.clone(),
+        role_repository.clone(),
+        group_repository.clone(),
+        audit_log.clone(),
+    );
+    let start_federated_login_service: StartFederatedLoginService<OidcFederation> =
+        StartFederatedLoginService::new(federation);
+    let list_audit_entries_service: ListAuditEntriesService<PostgresAuditLog> =
+        ListAuditEntriesService::new(audit_log.clone());
+
+    let purge_interval: Duration = Duration::from_secs(deletion_config.purge_interval * 60);
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(purge_interval);
+
+        loop {
+            interval.tick().await;
+
+            match purge_deleted_users_service.execute().await {
+                Ok(0) => {}
+                Ok(purged) => info!("Anonymized {} deleted users", purged),
+                Err(_) => warn!("Failed to purge deleted users"),
+            }
+        }
+    });
 
     let addrs: (String, u16) = (http_config.host.clone(), http_config.port);
 
     HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(token_service.clone()))
+            .app_data(web::Data::new(verify_access_service.clone()))
+            .app_data(web::Data::new(audit_log.clone()))
             .app_data(web::Data::new(login.clone()))
+            .app_data(web::Data::new(impersonate_service.clone()))
             .app_data(web::Data::new(find_user_service.clone()))
+            .app_data(web::Data::new(list_users_service.clone()))
+            .app_data(web::Data::new(change_user_status_service.clone()))
+            .app_data(web::Data::new(change_user_role_service.clone()))
+            .app_data(web::Data::new(list_roles_service.clone()))
+            .app_data(web::Data::new(create_role_service.clone()))
+            .app_data(web::Data::new(update_role_service.clone()))
+            .app_data(web::Data::new(delete_role_service.clone()))
+            .app_data(web::Data::new(find_organization_service.clone()))
+            .app_data(web::Data::new(list_organizations_service.clone()))
+            .app_data(web::Data::new(create_organization_service.clone()))
+            .app_data(web::Data::new(list_members_service.clone()))
+            .app_data(web::Data::new(save_member_service.clone()))
+            .app_data(web::Data::new(remove_member_service.clone()))
+            .app_data(web::Data::new(list_groups_service.clone()))
+            .app_data(web::Data::new(create_group_service.clone()))
+            .app_data(web::Data::new(update_group_service.clone()))
+            .app_data(web::Data::new(delete_group_service.clone()))
+            .app_data(web::Data::new(list_group_members_service.clone()))
+            .app_data(web::Data::new(add_group_member_service.clone()))
+            .app_data(web::Data::new(remove_group_member_service.clone()))
             .app_data(web::Data::new(create_user_service.clone()))
+            .app_data(web::Data::new(register_service.clone()))
+            .app_data(web::Data::new(request_email_verification_service.clone()))
+            .app_data(web::Data::new(verify_email_service.clone()))
+            .app_data(web::Data::new(create_invite_service.clone()))
+            .app_data(web::Data::new(list_invites_service.clone()))
+            .app_data(web::Data::new(delete_invite_service.clone()))
             .app_data(web::Data::new(delete_user_service.clone()))
+            .app_data(web::Data::new(restore_user_service.clone()))
+            .app_data(web::Data::new(get_avatar_service.clone()))
+            .app_data(web::Data::new(set_avatar_service.clone()))
+            .app_data(web::Data::new(delete_avatar_service.clone()))
             .app_data(web::Data::new(update_user_service.clone()))
+            .app_data(web::Data::new(change_password_service.clone()))
+            .app_data(web::Data::new(authenticate_client_service.clone()))
+            .app_data(web::Data::new(register_client_service.clone()))
+            .app_data(web::Data::new(introspect_token_service.clone()))
+            .app_data(web::Data::new(revoke_token_service.clone()))
+            .app_data(web::Data::new(enroll_totp_service.clone()))
+            .app_data(web::Data::new(confirm_totp_service.clone()))
+            .app_data(web::Data::new(reset_totp_service.clone()))
+            .app_data(web::Data::new(start_passkey_registration_service.clone()))
+            .app_data(web::Data::new(register_passkey_service.clone()))
+            .app_data(web::Data::new(list_passkeys_service.clone()))
+            .app_data(web::Data::new(delete_passkey_service.clone()))
+            .app_data(web::Data::new(start_passkey_authentication_service.clone()))
+            .app_data(web::Data::new(start_federated_login_service.clone()))
+            .app_data(web::Data::new(request_password_reset_service.clone()))
+            .app_data(web::Data::new(reset_password_service.clone()))
+            .app_data(web::Data::new(list_sessions_service.clone()))
+            .app_data(web::Data::new(delete_session_service.clone()))
+            .app_data(web::Data::new(end_sessions_service.clone()))
+            .app_data(web::Data::new(list_audit_entries_service.clone()))
             .configure(user_routes)
+            .configure(client_routes)
             .configure(auth_routes)
+            .configure(oidc_routes)
+            .configure(mfa_routes)
+            .configure(passkey_routes)
+            .configure(federation_routes)
+            .configure(password_routes)
+            .configure(password_reset_routes)
+            .configure(email_verification_routes)
+            .configure(registration_routes)
+            .configure(role_routes)
+            .configure(organization_routes)
+            .configure(group_routes)
+            .configure(session_routes)
+            .configure(audit_routes)
             .service(SwaggerUi::new("/docs/{_:.*}").url("/api-doc/openapi.json", ApiDoc::openapi()))
             .service(hello)
     })
@@ -0,0 +1,22 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct SessionResponseDto {
+    /// The unique identifier of the session.
+    pub id: String,
+    /// The OAuth client the session was started with, if any.
+    pub client_id: Option<String>,
+    /// User agent of the device, as last seen.
+    pub user_agent: Option<String>,
+    /// Address of the device, as last seen.
+    pub ip_address: Option<String>,
+    /// When the session started, in seconds since the Unix epoch.
+    pub created_at: u64,
+    /// When tokens were last issued for the session, in seconds since the Unix epoch.
+    pub last_used_at: u64,
+    /// When the session ends unless it is used again, in seconds since the Unix epoch.
+    pub expires_at: u64,
+    /// Whether this is the session of the token used for the request.
+    pub current: bool,
+}
@@ -0,0 +1,141 @@
+use super::dto::SessionResponseDto;
+use crate::{
+    adapters::{
+        http::actix::{api_error::ApiError, server::AppEndSessions},
+        persistence::postgres::session::repository::PostgresSessionRepository,
+    },
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        session::{
+            delete_session::{DeleteSessionError, DeleteSessionService},
+            end_sessions::EndSessionsError,
+            list_sessions::{ListSessionsError, ListSessionsService},
+        },
+    },
+    domain::session::entity::Session,
+};
+use actix_web::{HttpResponse, http::StatusCode, web};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "me/sessions",
+    tag = "Sessions",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "Active sessions of the authenticated user, most recently used first", body = Vec<SessionResponseDto>)
+    )
+)]
+pub async fn list_sessions(
+    service: web::Data<ListSessionsService<PostgresSessionRepository>>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let sessions: Vec<Session> = service.execute(&actor).await?;
+
+    Ok(HttpResponse::Ok().json(
+        sessions
+            .into_iter()
+            .map(|session| {
+                let current: bool = actor.session_id == Some(session.id);
+
+                SessionResponseDto {
+                    id: session.id.to_string(),
+                    client_id: session.client_id.map(|id| id.to_string()),
+                    user_agent: session.user_agent,
+                    ip_address: session.ip_address.map(|ip| ip.to_string()),
+                    created_at: session.created_at,
+                    last_used_at: session.last_used_at,
+                    expires_at: session.expires_at,
+                    current,
+                }
+            })
+            .collect::<Vec<SessionResponseDto>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "me/sessions/{id}",
+    params(
+        ("id" = String, Path, description = "Session UUID")
+    ),
+    tag = "Sessions",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Session ended, its refresh tokens no longer work"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 404, description = "Session not found")
+    )
+)]
+pub async fn delete_session(
+    service: web::Data<DeleteSessionService<PostgresSessionRepository>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    delete,
+    path = "users/{id}/sessions",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    tag = "Sessions",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "User signed out everywhere"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Whitout permission, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn end_sessions(
+    service: web::Data<AppEndSessions>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl From<ListSessionsError> for ApiError {
+    fn from(err: ListSessionsError) -> Self {
+        match err {
+            ListSessionsError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<DeleteSessionError> for ApiError {
+    fn from(err: DeleteSessionError) -> Self {
+        match err {
+            DeleteSessionError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Session not found")
+            }
+            DeleteSessionError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<EndSessionsError> for ApiError {
+    fn from(err: EndSessionsError) -> Self {
+        match err {
+            EndSessionsError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            EndSessionsError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to end this user's sessions",
+            ),
+            EndSessionsError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
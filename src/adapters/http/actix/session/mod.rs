@@ -0,0 +1,23 @@
+pub mod dto;
+pub mod handler;
+pub mod routes;
+
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::list_sessions,
+        handler::delete_session,
+        handler::end_sessions,
+    ),
+    components(
+        schemas(
+            dto::SessionResponseDto
+        )
+    ),
+    tags(
+        (name = "Sessions", description = "Signed-in devices and sign-out")
+    )
+)]
+pub struct SessionApiDoc;
@@ -0,0 +1,27 @@
+use crate::{
+    adapters::http::actix::auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+    application::auth::scope::Scope,
+};
+
+use super::handler::{delete_session, list_sessions};
+use actix_web::web;
+
+/// The admin `/users/{id}/sessions` endpoint lives with the user routes.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/me/sessions").wrap(AuthMiddleware).route(
+            web::get()
+                .to(list_sessions)
+                .wrap(RequireScope::new([Scope::UsersRead])),
+        ),
+    )
+    .service(
+        web::resource("/me/sessions/{id}")
+            .wrap(AuthMiddleware)
+            .route(
+                web::delete()
+                    .to(delete_session)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
+    );
+}
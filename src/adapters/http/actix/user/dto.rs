@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Deserialize, ToSchema)]
 pub struct CreateUserDto {
@@ -12,6 +12,9 @@ pub struct CreateUserDto {
     /// The password of the user.
     #[schema(min_length = 8, max_length = 64)]
     pub password: String,
+    /// The email address of the user, to be verified.
+    #[schema(max_length = 254)]
+    pub email: Option<String>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -22,9 +25,24 @@ pub struct UpdateUserDto {
     /// The name of the user.
     #[schema(max_length = 255)]
     pub name: Option<String>,
-    /// The password of the user.
+    /// The password of the user, only administrators can set it.
     #[schema(min_length = 8, max_length = 64)]
     pub password: Option<String>,
+    /// The email address of the user, a new address has to be verified again.
+    #[schema(max_length = 254)]
+    pub email: Option<String>,
+    /// A short line telling others what the user is up to, empty to clear it.
+    #[schema(max_length = 100)]
+    pub status_text: Option<String>,
+    /// A single emoji or a `:shortcode:` shown next to the status, empty to clear it.
+    pub status_emoji: Option<String>,
+    /// A few paragraphs about the user, empty to clear it.
+    #[schema(max_length = 1000)]
+    pub bio: Option<String>,
+    /// An IANA time zone name such as `Europe/Amsterdam`, empty to clear it.
+    pub timezone: Option<String>,
+    /// A language tag such as `en` or `pt-BR`, empty to clear it.
+    pub locale: Option<String>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -35,4 +53,109 @@ pub struct UserResponseDto {
     pub username: String,
     /// The name of the user.
     pub name: String,
+    /// The email address of the user, only shown to themselves and administrators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Whether the email address is verified, shown along with it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    #[serde(flatten)]
+    pub profile: ProfileDto,
+    /// When the user was deleted, in seconds since the epoch, for users that can be restored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<u64>,
+}
+
+/// How the user presents themselves, each field left out while unset.
+#[derive(Serialize, ToSchema, Default)]
+pub struct ProfileDto {
+    /// Where to fetch the avatar, changing with every upload. Add `size` to pick a size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UserStatusDto {
+    Active,
+    Inactive,
+    Banned,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub enum UserSortDto {
+    #[serde(rename = "username")]
+    Username,
+    #[serde(rename = "-username")]
+    UsernameDescending,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDescending,
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[serde(rename = "-created_at")]
+    CreatedAtDescending,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListUsersQuery {
+    /// Only list users with this status.
+    pub status: Option<UserStatusDto>,
+    /// Only list users with this role.
+    pub role: Option<String>,
+    /// Case-insensitive prefix of the username or the name.
+    pub q: Option<String>,
+    /// List deleted users that can still be restored instead, for administrators.
+    pub deleted: Option<bool>,
+    /// Sort order, descending when prefixed with `-`. Defaults to `username`.
+    #[param(inline)]
+    pub sort: Option<UserSortDto>,
+    /// Page size, at most 100. Defaults to 20.
+    pub limit: Option<u64>,
+    /// Number of users to skip, for offset pagination.
+    pub offset: Option<u64>,
+    /// The `next_cursor` of the previous page, for cursor pagination.
+    pub cursor: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct UserPageDto {
+    /// The users of the page.
+    pub items: Vec<UserResponseDto>,
+    /// How many users match the filters, only given to administrators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// Cursor of the next page, absent on the last page or with offset pagination.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangeUserStatusDto {
+    /// The new status of the user.
+    pub status: UserStatusDto,
+    /// Why the status changes, kept in the audit log.
+    #[schema(min_length = 1, max_length = 500)]
+    pub reason: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangeUserRoleDto {
+    /// Name of the new role of the user.
+    #[schema(example = "moderator")]
+    pub role: String,
+    /// Why the role changes, kept in the audit log.
+    #[schema(min_length = 1, max_length = 500)]
+    pub reason: String,
 }
@@ -1,21 +1,55 @@
-use super::dto::{CreateUserDto, UpdateUserDto, UserResponseDto};
+use super::dto::{
+    ChangeUserRoleDto, ChangeUserStatusDto, CreateUserDto, ListUsersQuery, ProfileDto,
+    UpdateUserDto, UserPageDto, UserResponseDto, UserSortDto, UserStatusDto,
+};
 use crate::{
     adapters::{
-        hash::argon2::Argon2Hasher, http::actix::api_error::ApiError,
-        persistence::postgres::user::repository::PostgresUserRepository,
+        hash::argon2::Argon2Hasher,
+        http::actix::{
+            api_error::ApiError,
+            server::{
+                AppDeleteUser, AppGroupRepository, AppRevocationStore, AppRoleRepository,
+                AppUpdateUser,
+            },
+        },
+        password::breach_list::FileBreachList,
+        persistence::postgres::{
+            audit::repository::PostgresAuditLog, session::repository::PostgresSessionRepository,
+            user::repository::PostgresUserRepository,
+        },
     },
     application::{
         auth::authenticated_user::AuthenticatedUser,
         user::{
+            change_role::{ChangeUserRoleError, ChangeUserRoleInput, ChangeUserRoleService},
+            change_status::{
+                ChangeUserStatusError, ChangeUserStatusInput, ChangeUserStatusService,
+            },
             create_user::{CreateUserError, CreateUserInput, CreateUserOutput, CreateUserService},
-            delete_user::{DeleteUserError, DeleteUserService},
+            delete_user::DeleteUserError,
             find_user::{FindUserError, FindUserService},
-            update_user::{UpdateUserError, UpdateUserInput, UpdateUserOutput, UpdateUserService},
+            list_users::{DEFAULT_PAGE_SIZE, ListUsersError, ListUsersOutput, ListUsersService},
+            restore_user::{RestoreUserError, RestoreUserService},
+            update_user::{UpdateUserError, UpdateUserInput, UpdateUserOutput},
+        },
+    },
+    domain::{
+        role::{entity::RoleName, permission::Permission},
+        user::{
+            entity::{User, UserStatus},
+            error::UserError,
+            profile::Profile,
+            query::{
+                Pagination, SortDirection, UserCursor, UserFilter, UserQuery, UserSort,
+                UserSortField,
+            },
+            value_objects::email::Email,
         },
     },
-    domain::user::{entity::User, error::UserError},
 };
 use actix_web::{HttpResponse, http::StatusCode, web};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde_json::json;
 use uuid::Uuid;
 
 #[utoipa::path(
@@ -25,26 +59,157 @@ use uuid::Uuid;
         ("id" = String, Path, description = "User UUID")
     ),
     tag = "Users",
+    security(("oauth2_password" = ["users:read"])),
     responses(
         (status = 200, description = "User retrieved successfully"),
         (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Insufficient scope"),
         (status = 404, description = "User not found")
     )
 )]
 pub async fn find_by_id(
     service: web::Data<FindUserService<PostgresUserRepository>>,
     params: web::Path<String>,
+    actor: AuthenticatedUser,
 ) -> Result<HttpResponse, ApiError> {
     let id: Uuid = Uuid::parse_str(&params)
         .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
 
-    let user: User = service.find_by_id(&id).await?;
+    let user: User = service.find_by_id(&id, &actor).await?;
 
-    Ok(HttpResponse::Ok().json(UserResponseDto {
+    Ok(HttpResponse::Ok().json(user_response(&user, &actor)))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(ListUsersQuery),
+    tag = "Users",
+    security(("oauth2_password" = ["users:read"])),
+    responses(
+        (status = 200, description = "Users listed successfully", body = UserPageDto),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Insufficient scope, or listing users that are not active without being an administrator")
+    )
+)]
+pub async fn list_users(
+    service: web::Data<ListUsersService<PostgresUserRepository>>,
+    params: web::Query<ListUsersQuery>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let params: ListUsersQuery = params.into_inner();
+    let limit: u64 = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+
+    let pagination: Pagination = match (params.offset, params.cursor) {
+        (Some(_), Some(_)) => {
+            return Err(ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Use either offset or cursor, not both",
+            ));
+        }
+        (Some(offset), None) => Pagination::Offset { offset, limit },
+        (None, cursor) => Pagination::Cursor {
+            after: cursor
+                .map(|cursor| {
+                    decode_cursor(&cursor)
+                        .ok_or_else(|| ApiError::new(StatusCode::BAD_REQUEST, "Invalid cursor"))
+                })
+                .transpose()?,
+            limit,
+        },
+    };
+
+    let query: UserQuery = UserQuery {
+        filter: UserFilter {
+            status: params.status.map(user_status),
+            role: params.role.map(RoleName::new).transpose()?,
+            search: params.q.filter(|q| !q.trim().is_empty()),
+            organization_id: Some(actor.organization_id),
+            deleted: params.deleted.unwrap_or_default(),
+        },
+        sort: params.sort.map(user_sort).unwrap_or_default(),
+        pagination,
+    };
+
+    let page: ListUsersOutput = service.execute(query, &actor).await?;
+
+    Ok(HttpResponse::Ok().json(UserPageDto {
+        items: page
+            .users
+            .into_iter()
+            .map(|user| user_response(&user, &actor))
+            .collect(),
+        total: page.total,
+        next_cursor: page.next_cursor.as_ref().map(encode_cursor),
+    }))
+}
+
+/// Email addresses are only shown to their owner and to users allowed to read all users.
+pub(crate) fn user_response(user: &User, actor: &AuthenticatedUser) -> UserResponseDto {
+    let show_email: bool = actor
+        .require_unless_owner(Permission::UserRead, &user.id)
+        .is_ok();
+    let email: Option<&Email> = user.email.as_ref().filter(|_| show_email);
+
+    UserResponseDto {
         id: user.id.to_string(),
         username: user.username.as_str().into(),
         name: user.name.as_str().into(),
-    }))
+        email: email.map(|email| email.as_str().into()),
+        email_verified: email.map(|_| user.verified_email().is_some()),
+        profile: profile_response(&user.id, &user.profile),
+        deleted_at: user.deleted_at,
+    }
+}
+
+/// Avatar URLs carry the upload time, so clients and caches fetch a new avatar only once.
+fn profile_response(id: &Uuid, profile: &Profile) -> ProfileDto {
+    ProfileDto {
+        avatar_url: profile
+            .avatar_updated_at
+            .map(|updated_at| format!("/users/{}/avatar?v={}", id, updated_at)),
+        status_text: profile.status_text.as_ref().map(|v| v.as_str().into()),
+        status_emoji: profile.status_emoji.as_ref().map(|v| v.as_str().into()),
+        bio: profile.bio.as_ref().map(|v| v.as_str().into()),
+        timezone: profile.timezone.as_ref().map(|v| v.as_str().into()),
+        locale: profile.locale.as_ref().map(|v| v.as_str().into()),
+    }
+}
+
+fn user_status(status: UserStatusDto) -> UserStatus {
+    match status {
+        UserStatusDto::Active => UserStatus::Active,
+        UserStatusDto::Inactive => UserStatus::Inactive,
+        UserStatusDto::Banned => UserStatus::Banned,
+    }
+}
+
+fn user_sort(sort: UserSortDto) -> UserSort {
+    let (field, direction): (UserSortField, SortDirection) = match sort {
+        UserSortDto::Username => (UserSortField::Username, SortDirection::Ascending),
+        UserSortDto::UsernameDescending => (UserSortField::Username, SortDirection::Descending),
+        UserSortDto::Name => (UserSortField::Name, SortDirection::Ascending),
+        UserSortDto::NameDescending => (UserSortField::Name, SortDirection::Descending),
+        UserSortDto::CreatedAt => (UserSortField::CreatedAt, SortDirection::Ascending),
+        UserSortDto::CreatedAtDescending => (UserSortField::CreatedAt, SortDirection::Descending),
+    };
+
+    UserSort { field, direction }
+}
+
+/// Cursors are opaque to clients: the identifier and the sort value, base64url encoded.
+fn encode_cursor(cursor: &UserCursor) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}:{}", cursor.id, cursor.value))
+}
+
+fn decode_cursor(cursor: &str) -> Option<UserCursor> {
+    let decoded: String = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+    let (id, value): (&str, &str) = decoded.split_once(':')?;
+
+    Some(UserCursor {
+        value: value.to_owned(),
+        id: Uuid::parse_str(id).ok()?,
+    })
 }
 
 #[utoipa::path(
@@ -52,15 +217,18 @@ pub async fn find_by_id(
     path = "",
     request_body = CreateUserDto,
     tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
     responses(
         (status = 201, description = "User created successfully", body = UserResponseDto),
         (status = 400, description = "Invalid data provided"),
         (status = 403, description = "Without access"),
-        (status = 409, description = "Username already exists")
+        (status = 409, description = "Username or email address already in use")
     )
 )]
 pub async fn create_user(
-    service: web::Data<CreateUserService<PostgresUserRepository, Argon2Hasher>>,
+    service: web::Data<
+        CreateUserService<PostgresUserRepository, Argon2Hasher, FileBreachList, PostgresAuditLog>,
+    >,
     payload: web::Json<CreateUserDto>,
     actor: AuthenticatedUser,
 ) -> Result<HttpResponse, ApiError> {
@@ -68,6 +236,7 @@ pub async fn create_user(
         username: payload.username.clone(),
         name: payload.name.clone(),
         password: payload.password.clone(),
+        email: payload.email.clone(),
     };
 
     let user: CreateUserOutput = service.execute(cmd, &actor).await?;
@@ -76,6 +245,10 @@ pub async fn create_user(
         id: user.id.to_string(),
         username: user.username,
         name: user.name,
+        email_verified: user.email.as_ref().map(|_| false),
+        email: user.email,
+        profile: ProfileDto::default(),
+        deleted_at: None,
     }))
 }
 
@@ -87,14 +260,17 @@ pub async fn create_user(
     ),
     request_body = UpdateUserDto,
     tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
     responses(
         (status = 200, description = "User updated successfully"),
         (status = 400, description = "Invalid data provided"),
-        (status = 404, description = "User not found")
+        (status = 403, description = "Without access, a password set by a non-administrator, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "Username or email address already in use")
     )
 )]
 pub async fn update_user(
-    service: web::Data<UpdateUserService<PostgresUserRepository, Argon2Hasher>>,
+    service: web::Data<AppUpdateUser>,
     params: web::Path<String>,
     payload: web::Json<UpdateUserDto>,
     actor: AuthenticatedUser,
@@ -106,6 +282,12 @@ pub async fn update_user(
         username: payload.username.clone(),
         name: payload.name.clone(),
         password: payload.password.clone(),
+        email: payload.email.clone(),
+        status_text: payload.status_text.clone(),
+        status_emoji: payload.status_emoji.clone(),
+        bio: payload.bio.clone(),
+        timezone: payload.timezone.clone(),
+        locale: payload.locale.clone(),
     };
 
     let updated_user: UpdateUserOutput = service.execute(id, update_user, &actor).await?;
@@ -114,6 +296,13 @@ pub async fn update_user(
         id: updated_user.id.to_string(),
         username: updated_user.username,
         name: updated_user.name,
+        email_verified: updated_user
+            .email
+            .as_ref()
+            .map(|_| updated_user.email_verified),
+        email: updated_user.email,
+        profile: profile_response(&updated_user.id, &updated_user.profile),
+        deleted_at: None,
     }))
 }
 
@@ -124,15 +313,17 @@ pub async fn update_user(
         ("id" = String, Path, description = "User UUID")
     ),
     tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
     responses(
-        (status = 204, description = "User deleted successfully"),
+        (status = 204, description = "User deleted and signed out everywhere, restorable until the grace period ends"),
         (status = 400, description = "Invalid data provided"),
-        (status = 403, description = "Whitout permission"),
-        (status = 404, description = "User not found")
+        (status = 403, description = "Whitout permission, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "The last active administrator of an organization cannot be deleted")
     )
 )]
 pub async fn delete_user(
-    service: web::Data<DeleteUserService<PostgresUserRepository>>,
+    service: web::Data<AppDeleteUser>,
     params: web::Path<String>,
     actor: AuthenticatedUser,
 ) -> Result<HttpResponse, ApiError> {
@@ -144,12 +335,150 @@ pub async fn delete_user(
     Ok(HttpResponse::Ok().finish())
 }
 
+#[utoipa::path(
+    post,
+    path = "/{id}/restore",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "User restored, with their username and email address"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without the user.delete permission"),
+        (status = 404, description = "No deleted user with this id"),
+        (status = 410, description = "The grace period ended, the user is being anonymized")
+    )
+)]
+pub async fn restore_user(
+    service: web::Data<RestoreUserService<PostgresUserRepository, PostgresAuditLog>>,
+    params: web::Path<String>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+
+    service.execute(&id, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}/status",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    request_body = ChangeUserStatusDto,
+    tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Status changed, leaving the active status signs the user out everywhere"),
+        (status = 400, description = "Invalid data provided"),
+        (status = 403, description = "Without administrator access, or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "The last active administrator of an organization cannot be deactivated")
+    )
+)]
+pub async fn change_status(
+    service: web::Data<
+        ChangeUserStatusService<
+            PostgresUserRepository,
+            PostgresSessionRepository,
+            AppRevocationStore,
+            AppRoleRepository,
+            AppGroupRepository,
+            PostgresAuditLog,
+        >,
+    >,
+    params: web::Path<String>,
+    payload: web::Json<ChangeUserStatusDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+    let payload: ChangeUserStatusDto = payload.into_inner();
+
+    let input: ChangeUserStatusInput = ChangeUserStatusInput {
+        status: user_status(payload.status),
+        reason: payload.reason,
+    };
+
+    service.execute(&id, input, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}/role",
+    params(
+        ("id" = String, Path, description = "User UUID")
+    ),
+    request_body = ChangeUserRoleDto,
+    tag = "Users",
+    security(("oauth2_password" = ["users:write"])),
+    responses(
+        (status = 204, description = "Role changed, the user is signed out everywhere"),
+        (status = 400, description = "Invalid data provided or unknown role"),
+        (status = 403, description = "Without the role.assign permission, or the role or the user holds permissions the caller lacks"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "The last active administrator of an organization cannot be demoted")
+    )
+)]
+pub async fn change_role(
+    service: web::Data<
+        ChangeUserRoleService<
+            PostgresUserRepository,
+            PostgresSessionRepository,
+            AppRevocationStore,
+            AppRoleRepository,
+            AppGroupRepository,
+            PostgresAuditLog,
+        >,
+    >,
+    params: web::Path<String>,
+    payload: web::Json<ChangeUserRoleDto>,
+    actor: AuthenticatedUser,
+) -> Result<HttpResponse, ApiError> {
+    let id: Uuid = Uuid::parse_str(&params)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "Invalid UUID format"))?;
+    let payload: ChangeUserRoleDto = payload.into_inner();
+
+    let input: ChangeUserRoleInput = ChangeUserRoleInput {
+        role: RoleName::new(payload.role)?,
+        reason: payload.reason,
+    };
+
+    service.execute(&id, input, &actor).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 impl From<UserError> for ApiError {
     fn from(err: UserError) -> Self {
         match err {
-            UserError::InvalidPassword(msg) | UserError::InvalidUsername(msg) => {
-                ApiError::new(StatusCode::BAD_REQUEST, msg)
-            }
+            UserError::InvalidPassword(msg)
+            | UserError::InvalidUsername(msg)
+            | UserError::InvalidEmail(msg)
+            | UserError::InvalidProfile(msg) => ApiError::new(StatusCode::BAD_REQUEST, msg),
+            UserError::WeakPassword(violations) => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "Password does not meet the password policy",
+            )
+            .with_details(
+                "violations",
+                violations
+                    .iter()
+                    .map(|violation| {
+                        json!({
+                            "code": violation.code(),
+                            "message": violation.description(),
+                        })
+                    })
+                    .collect(),
+            ),
         }
     }
 }
@@ -174,6 +503,9 @@ impl From<CreateUserError> for ApiError {
             CreateUserError::AlreadyExists => {
                 ApiError::new(StatusCode::CONFLICT, "Username already exists")
             }
+            CreateUserError::EmailAlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Email address already in use")
+            }
             CreateUserError::InfrastructureError => ApiError::internal_server_error(),
         }
     }
@@ -188,14 +520,83 @@ impl From<UpdateUserError> for ApiError {
                 StatusCode::FORBIDDEN,
                 "You don't have access to edit this user",
             ),
+            UpdateUserError::PasswordChangeRequiresCurrent => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Change your password with POST /me/password",
+            ),
             UpdateUserError::AlreadyExists => {
                 ApiError::new(StatusCode::CONFLICT, "Username already exists")
             }
+            UpdateUserError::EmailAlreadyExists => {
+                ApiError::new(StatusCode::CONFLICT, "Email address already in use")
+            }
             UpdateUserError::InfrastructureError => ApiError::internal_server_error(),
         }
     }
 }
 
+impl From<ListUsersError> for ApiError {
+    fn from(err: ListUsersError) -> Self {
+        match err {
+            ListUsersError::InvalidLimit => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Limit must be between 1 and 100")
+            }
+            ListUsersError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to list users that are not active",
+            ),
+            ListUsersError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ChangeUserStatusError> for ApiError {
+    fn from(err: ChangeUserStatusError) -> Self {
+        match err {
+            ChangeUserStatusError::InvalidReason => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "A reason of at most 500 characters is required",
+            ),
+            ChangeUserStatusError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "User not found")
+            }
+            ChangeUserStatusError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to change the status of users",
+            ),
+            ChangeUserStatusError::LastAdministrator => ApiError::new(
+                StatusCode::CONFLICT,
+                "The last active administrator cannot be deactivated",
+            ),
+            ChangeUserStatusError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
+impl From<ChangeUserRoleError> for ApiError {
+    fn from(err: ChangeUserRoleError) -> Self {
+        match err {
+            ChangeUserRoleError::InvalidReason => ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "A reason of at most 500 characters is required",
+            ),
+            ChangeUserRoleError::NotFound => ApiError::new(StatusCode::NOT_FOUND, "User not found"),
+            ChangeUserRoleError::UnknownRole => {
+                ApiError::new(StatusCode::BAD_REQUEST, "Unknown role")
+            }
+            ChangeUserRoleError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to change the role of users",
+            ),
+            ChangeUserRoleError::LastAdministrator => ApiError::new(
+                StatusCode::CONFLICT,
+                "The last active administrator cannot be demoted",
+            ),
+            ChangeUserRoleError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
+
 impl From<DeleteUserError> for ApiError {
     fn from(err: DeleteUserError) -> Self {
         match err {
@@ -204,7 +605,30 @@ impl From<DeleteUserError> for ApiError {
                 StatusCode::FORBIDDEN,
                 "You don't have access to delete this user",
             ),
+            DeleteUserError::LastAdministrator => ApiError::new(
+                StatusCode::CONFLICT,
+                "The last active administrator cannot be deleted",
+            ),
             DeleteUserError::InfrastructureError => ApiError::internal_server_error(),
         }
     }
 }
+
+impl From<RestoreUserError> for ApiError {
+    fn from(err: RestoreUserError) -> Self {
+        match err {
+            RestoreUserError::NotFound => {
+                ApiError::new(StatusCode::NOT_FOUND, "Deleted user not found")
+            }
+            RestoreUserError::GracePeriodEnded => ApiError::new(
+                StatusCode::GONE,
+                "The user can no longer be restored, the grace period ended",
+            ),
+            RestoreUserError::Forbidden => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "You don't have access to restore this user",
+            ),
+            RestoreUserError::InfrastructureError => ApiError::internal_server_error(),
+        }
+    }
+}
@@ -7,15 +7,25 @@ use utoipa::OpenApi;
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        handler::list_users,
         handler::find_by_id,
         handler::create_user,
         handler::update_user,
-        handler::delete_user
+        handler::delete_user,
+        handler::restore_user,
+        handler::change_status,
+        handler::change_role
     ),
     components(
         schemas(
             dto::CreateUserDto,
-            dto::UserResponseDto
+            dto::UserResponseDto,
+            dto::ProfileDto,
+            dto::UserPageDto,
+            dto::UserStatusDto,
+            dto::UserSortDto,
+            dto::ChangeUserStatusDto,
+            dto::ChangeUserRoleDto
         )
     ),
     tags(
@@ -1,15 +1,102 @@
-use crate::adapters::http::actix::auth::middleware::AuthMiddleware;
+use crate::{
+    adapters::http::actix::{
+        auth::{middleware::AuthMiddleware, require_scope::RequireScope},
+        avatar::handler::{delete_avatar, get_avatar, set_avatar},
+        mfa::handler::reset_totp,
+        session::handler::end_sessions,
+    },
+    application::auth::scope::Scope,
+    domain::avatar::entity::AvatarUpload,
+};
 
-use super::handler::{create_user, delete_user, find_by_id, update_user};
+use super::handler::{
+    change_role, change_status, create_user, delete_user, find_by_id, list_users, restore_user,
+    update_user,
+};
 use actix_web::web;
 
 pub fn routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/users")
             .wrap(AuthMiddleware)
-            .route("", web::post().to(create_user))
-            .route("/{id}", web::get().to(find_by_id))
-            .route("/{id}", web::patch().to(update_user))
-            .route("/{id}", web::delete().to(delete_user)),
+            .route(
+                "",
+                web::get()
+                    .to(list_users)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "",
+                web::post()
+                    .to(create_user)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(find_by_id)
+                    .wrap(RequireScope::new([Scope::UsersRead])),
+            )
+            .route(
+                "/{id}",
+                web::patch()
+                    .to(update_user)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(delete_user)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/restore",
+                web::post()
+                    .to(restore_user)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/status",
+                web::put()
+                    .to(change_status)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/role",
+                web::put()
+                    .to(change_role)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .service(
+                web::resource("/{id}/avatar")
+                    .app_data(web::PayloadConfig::new(AvatarUpload::MAX_BYTES))
+                    .route(
+                        web::get()
+                            .to(get_avatar)
+                            .wrap(RequireScope::new([Scope::UsersRead])),
+                    )
+                    .route(
+                        web::put()
+                            .to(set_avatar)
+                            .wrap(RequireScope::new([Scope::UsersWrite])),
+                    )
+                    .route(
+                        web::delete()
+                            .to(delete_avatar)
+                            .wrap(RequireScope::new([Scope::UsersWrite])),
+                    ),
+            )
+            .route(
+                "/{id}/mfa",
+                web::delete()
+                    .to(reset_totp)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            )
+            .route(
+                "/{id}/sessions",
+                web::delete()
+                    .to(end_sessions)
+                    .wrap(RequireScope::new([Scope::UsersWrite])),
+            ),
     );
 }
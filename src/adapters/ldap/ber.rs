@@ -0,0 +1,261 @@
+//! The subset of BER (X.690) needed for LDAPv3 messages.
+
+pub const INTEGER: u8 = 0x02;
+pub const OCTET_STRING: u8 = 0x04;
+pub const BOOLEAN: u8 = 0x01;
+pub const ENUMERATED: u8 = 0x0a;
+pub const SEQUENCE: u8 = 0x30;
+
+/// A decoded element: its tag and raw contents.
+pub struct Element<'a> {
+    pub tag: u8,
+    pub contents: &'a [u8],
+}
+
+impl<'a> Element<'a> {
+    /// Iterates over the elements of a constructed value.
+    pub fn children(&self) -> Children<'a> {
+        Children {
+            input: self.contents,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        if self.contents.is_empty() || self.contents.len() > 8 {
+            return None;
+        }
+
+        let negative: bool = self.contents[0] & 0x80 != 0;
+        let initial: i64 = if negative { -1 } else { 0 };
+
+        Some(
+            self.contents
+                .iter()
+                .fold(initial, |value, byte| (value << 8) | *byte as i64),
+        )
+    }
+}
+
+pub struct Children<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for Children<'a> {
+    type Item = Element<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (element, rest) = decode(self.input)?;
+        self.input = rest;
+
+        Some(element)
+    }
+}
+
+/// Decodes the element at the front of `input`, returning it with the remaining bytes.
+pub fn decode(input: &[u8]) -> Option<(Element<'_>, &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (length, rest) = decode_length(rest)?;
+
+    if rest.len() < length {
+        return None;
+    }
+
+    let (contents, rest) = rest.split_at(length);
+
+    Some((Element { tag, contents }, rest))
+}
+
+/// Returns the total size of the element at the front of `input`, if its header is complete.
+pub fn element_size(input: &[u8]) -> Option<usize> {
+    let (_, rest) = input.split_first()?;
+    let (length, rest) = decode_length(rest)?;
+
+    Some(input.len() - rest.len() + length)
+}
+
+fn decode_length(input: &[u8]) -> Option<(usize, &[u8])> {
+    let (&first, rest) = input.split_first()?;
+
+    if first & 0x80 == 0 {
+        return Some((first as usize, rest));
+    }
+
+    let width: usize = (first & 0x7f) as usize;
+
+    if width == 0 || width > 4 || rest.len() < width {
+        return None;
+    }
+
+    let (bytes, rest) = rest.split_at(width);
+    let length: usize = bytes
+        .iter()
+        .fold(0usize, |value, byte| (value << 8) | *byte as usize);
+
+    Some((length, rest))
+}
+
+pub fn encode(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = vec![tag];
+    let length: usize = contents.len();
+
+    if length < 0x80 {
+        out.push(length as u8);
+    } else {
+        let bytes: Vec<u8> = length
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend(bytes);
+    }
+
+    out.extend_from_slice(contents);
+    out
+}
+
+pub fn integer(tag: u8, value: i64) -> Vec<u8> {
+    let bytes: [u8; 8] = value.to_be_bytes();
+    let mut start: usize = 0;
+
+    // Drop redundant leading bytes while keeping the sign bit intact.
+    while start < 7
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+
+    encode(tag, &bytes[start..])
+}
+
+pub fn constructed(tag: u8, elements: &[Vec<u8>]) -> Vec<u8> {
+    encode(tag, &elements.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_short_and_long_lengths() {
+        assert_eq!(encode(OCTET_STRING, b"ab"), vec![0x04, 0x02, b'a', b'b']);
+
+        let long: Vec<u8> = encode(OCTET_STRING, &[0x00; 200]);
+        assert_eq!(&long[..3], &[0x04, 0x81, 200]);
+        assert_eq!(long.len(), 203);
+
+        let longer: Vec<u8> = encode(OCTET_STRING, &[0x00; 300]);
+        assert_eq!(&longer[..4], &[0x04, 0x82, 0x01, 0x2c]);
+    }
+
+    #[test]
+    fn encodes_integers_minimally() {
+        let vectors: [(i64, &[u8]); 7] = [
+            (0, &[0x00]),
+            (127, &[0x7f]),
+            (128, &[0x00, 0x80]),
+            (256, &[0x01, 0x00]),
+            (-1, &[0xff]),
+            (-128, &[0x80]),
+            (-129, &[0xff, 0x7f]),
+        ];
+
+        for (value, contents) in vectors {
+            assert_eq!(integer(INTEGER, value)[2..], *contents, "{}", value);
+        }
+    }
+
+    #[test]
+    fn integers_round_trip() {
+        for value in [
+            0,
+            1,
+            49,
+            127,
+            128,
+            65535,
+            -1,
+            -128,
+            -129,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            let encoded: Vec<u8> = integer(ENUMERATED, value);
+            let (element, rest) = decode(&encoded).unwrap();
+
+            assert_eq!(element.tag, ENUMERATED);
+            assert_eq!(element.as_integer(), Some(value));
+            assert!(rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn iterates_over_constructed_values() {
+        let sequence: Vec<u8> = constructed(
+            SEQUENCE,
+            &[
+                integer(INTEGER, 7),
+                encode(OCTET_STRING, b"cn"),
+                encode(BOOLEAN, &[0xff]),
+            ],
+        );
+
+        let (element, _) = decode(&sequence).unwrap();
+        let children: Vec<Element> = element.children().collect();
+
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[0].as_integer(), Some(7));
+        assert_eq!(children[1].contents, b"cn");
+        assert_eq!(children[2].tag, BOOLEAN);
+    }
+
+    #[test]
+    fn returns_the_bytes_after_an_element() {
+        let mut input: Vec<u8> = encode(OCTET_STRING, b"first");
+        input.extend(encode(OCTET_STRING, b"second"));
+
+        let (first, rest) = decode(&input).unwrap();
+        let (second, rest) = decode(rest).unwrap();
+
+        assert_eq!(first.contents, b"first");
+        assert_eq!(second.contents, b"second");
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn element_size_needs_only_the_header() {
+        let element: Vec<u8> = encode(OCTET_STRING, &[0x00; 300]);
+
+        assert_eq!(element_size(&element[..4]), Some(304));
+        assert_eq!(element_size(&element[..2]), None);
+        assert_eq!(element_size(&[]), None);
+    }
+
+    #[test]
+    fn rejects_truncated_and_oversized_lengths() {
+        assert!(decode(&[0x04, 0x05, b'a']).is_none());
+        assert!(decode(&[0x04, 0x80]).is_none());
+        assert!(decode(&[0x04, 0x85, 0x01, 0x00, 0x00, 0x00, 0x00]).is_none());
+    }
+
+    #[test]
+    fn as_integer_rejects_empty_and_oversized_contents() {
+        assert!(
+            Element {
+                tag: INTEGER,
+                contents: &[]
+            }
+            .as_integer()
+            .is_none()
+        );
+        assert!(
+            Element {
+                tag: INTEGER,
+                contents: &[0x01; 9]
+            }
+            .as_integer()
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use super::ber::{
+    self, BOOLEAN, ENUMERATED, Element, INTEGER, OCTET_STRING, SEQUENCE, constructed, encode,
+    integer,
+};
+use crate::adapters::net::{self, Stream};
+use std::{fmt::Display, time::Duration};
+use url::Url;
+
+const BIND_REQUEST: u8 = 0x60;
+const BIND_RESPONSE: u8 = 0x61;
+const UNBIND_REQUEST: u8 = 0x42;
+const SEARCH_REQUEST: u8 = 0x63;
+const SEARCH_RESULT_ENTRY: u8 = 0x64;
+const SEARCH_RESULT_DONE: u8 = 0x65;
+const SIMPLE_AUTHENTICATION: u8 = 0x80;
+const EQUALITY_MATCH: u8 = 0xa3;
+
+const SCOPE_WHOLE_SUBTREE: i64 = 2;
+const NEVER_DEREF_ALIASES: i64 = 0;
+const PROTOCOL_VERSION: i64 = 3;
+
+pub const SUCCESS: i64 = 0;
+pub const INVALID_CREDENTIALS: i64 = 49;
+
+/// Refuse messages larger than this, a search for a single entry never comes close.
+const MAX_MESSAGE_SIZE: usize = 1 << 20;
+
+/// A blocking LDAPv3 client connection, supporting the simple bind and search operations.
+pub struct LdapConnection {
+    stream: Box<dyn Stream>,
+    buffer: Vec<u8>,
+    message_id: i64,
+}
+
+pub struct SearchEntry {
+    pub attributes: Vec<(String, Vec<String>)>,
+}
+
+impl SearchEntry {
+    /// Values of `name`; attribute descriptions are case insensitive.
+    pub fn values(&self, name: &str) -> &[String] {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, values)| values.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+pub enum LdapError {
+    Io(std::io::Error),
+    /// The server answered with something that is not a valid LDAP message.
+    Protocol,
+    /// The operation failed with the given LDAP result code.
+    Result(i64),
+}
+
+impl LdapConnection {
+    /// Connects to an `ldap://` or `ldaps://` URL.
+    pub fn connect(url: &Url, timeout: Duration) -> Result<Self, LdapError> {
+        let host: &str = url.host_str().ok_or(LdapError::Protocol)?;
+        let secure: bool = match url.scheme() {
+            "ldap" => false,
+            "ldaps" => true,
+            _ => return Err(LdapError::Protocol),
+        };
+        let port: u16 = url.port().unwrap_or(if secure { 636 } else { 389 });
+
+        let stream: Box<dyn Stream> = net::connect(host, port, secure, timeout)?;
+
+        Ok(Self {
+            stream,
+            buffer: Vec::new(),
+            message_id: 0,
+        })
+    }
+
+    pub fn simple_bind(&mut self, dn: &str, password: &str) -> Result<(), LdapError> {
+        let id: i64 = self.send(constructed(
+            BIND_REQUEST,
+            &[
+                integer(INTEGER, PROTOCOL_VERSION),
+                encode(OCTET_STRING, dn.as_bytes()),
+                encode(SIMPLE_AUTHENTICATION, password.as_bytes()),
+            ],
+        ))?;
+
+        let (tag, contents) = self.receive(id)?;
+
+        if tag != BIND_RESPONSE {
+            return Err(LdapError::Protocol);
+        }
+
+        check_result(&contents)
+    }
+
+    /// Searches the subtree under `base` for entries whose `attribute` equals `value`.
+    pub fn search(
+        &mut self,
+        base: &str,
+        attribute: &str,
+        value: &str,
+        attributes: &[&str],
+        time_limit: Duration,
+    ) -> Result<Vec<SearchEntry>, LdapError> {
+        let id: i64 = self.send(constructed(
+            SEARCH_REQUEST,
+            &[
+                encode(OCTET_STRING, base.as_bytes()),
+                integer(ENUMERATED, SCOPE_WHOLE_SUBTREE),
+                integer(ENUMERATED, NEVER_DEREF_ALIASES),
+                integer(INTEGER, 0),
+                integer(INTEGER, time_limit.as_secs() as i64),
+                encode(BOOLEAN, &[0x00]),
+                constructed(
+                    EQUALITY_MATCH,
+                    &[
+                        encode(OCTET_STRING, attribute.as_bytes()),
+                        encode(OCTET_STRING, value.as_bytes()),
+                    ],
+                ),
+                constructed(
+                    SEQUENCE,
+                    &attributes
+                        .iter()
+                        .map(|attribute| encode(OCTET_STRING, attribute.as_bytes()))
+                        .collect::<Vec<Vec<u8>>>(),
+                ),
+            ],
+        ))?;
+
+        let mut entries: Vec<SearchEntry> = Vec::new();
+
+        loop {
+            let (tag, contents) = self.receive(id)?;
+
+            match tag {
+                SEARCH_RESULT_ENTRY => entries.push(parse_entry(&contents)?),
+                SEARCH_RESULT_DONE => {
+                    check_result(&contents)?;
+
+                    return Ok(entries);
+                }
+                // Referrals are not followed.
+                _ => {}
+            }
+        }
+    }
+
+    pub fn unbind(mut self) {
+        let _ = self.send(encode(UNBIND_REQUEST, &[]));
+    }
+
+    fn send(&mut self, operation: Vec<u8>) -> Result<i64, LdapError> {
+        self.message_id += 1;
+
+        let message: Vec<u8> =
+            constructed(SEQUENCE, &[integer(INTEGER, self.message_id), operation]);
+
+        self.stream.write_all(&message)?;
+        self.stream.flush()?;
+
+        Ok(self.message_id)
+    }
+
+    /// Reads the next message answering request `id`, returning its operation tag and
+    /// contents.
+    fn receive(&mut self, id: i64) -> Result<(u8, Vec<u8>), LdapError> {
+        loop {
+            let message: Vec<u8> = self.read_message()?;
+            let (envelope, _) = ber::decode(&message).ok_or(LdapError::Protocol)?;
+
+            if envelope.tag != SEQUENCE {
+                return Err(LdapError::Protocol);
+            }
+
+            let mut children = envelope.children();
+            let message_id: Option<i64> = children.next().and_then(|e| e.as_integer());
+            let operation: Element = children.next().ok_or(LdapError::Protocol)?;
+
+            // Unsolicited notifications (message id 0) mean the server is closing the
+            // connection.
+            match message_id {
+                Some(message_id) if message_id == id => {
+                    return Ok((operation.tag, operation.contents.to_vec()));
+                }
+                Some(0) | None => return Err(LdapError::Protocol),
+                Some(_) => continue,
+            }
+        }
+    }
+
+    fn read_message(&mut self) -> Result<Vec<u8>, LdapError> {
+        let mut chunk: [u8; 4096] = [0; 4096];
+
+        loop {
+            if let Some(size) = ber::element_size(&self.buffer) {
+                if size > MAX_MESSAGE_SIZE {
+                    return Err(LdapError::Protocol);
+                }
+
+                if self.buffer.len() >= size {
+                    let rest: Vec<u8> = self.buffer.split_off(size);
+
+                    return Ok(std::mem::replace(&mut self.buffer, rest));
+                }
+            }
+
+            let read: usize = self.stream.read(&mut chunk)?;
+
+            if read == 0 {
+                return Err(LdapError::Io(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+fn check_result(contents: &[u8]) -> Result<(), LdapError> {
+    let (code, _) = ber::decode(contents).ok_or(LdapError::Protocol)?;
+
+    match code.as_integer() {
+        Some(SUCCESS) if code.tag == ENUMERATED => Ok(()),
+        Some(code) => Err(LdapError::Result(code)),
+        None => Err(LdapError::Protocol),
+    }
+}
+
+fn parse_entry(contents: &[u8]) -> Result<SearchEntry, LdapError> {
+    let text = |element: &Element| String::from_utf8_lossy(element.contents).into_owned();
+
+    let (_dn, rest) = ber::decode(contents).ok_or(LdapError::Protocol)?;
+    let (list, _) = ber::decode(rest).ok_or(LdapError::Protocol)?;
+
+    let attributes: Vec<(String, Vec<String>)> = list
+        .children()
+        .filter_map(|attribute| {
+            let mut parts = attribute.children();
+            let name: String = text(&parts.next()?);
+            let values: Vec<String> = parts.next()?.children().map(|v| text(&v)).collect();
+
+            Some((name, values))
+        })
+        .collect();
+
+    Ok(SearchEntry { attributes })
+}
+
+impl Display for LdapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LdapError::Io(error) => write!(f, "{}", error),
+            LdapError::Protocol => write!(f, "protocol error"),
+            LdapError::Result(code) => write!(f, "result code {}", code),
+        }
+    }
+}
+
+impl From<std::io::Error> for LdapError {
+    fn from(value: std::io::Error) -> Self {
+        LdapError::Io(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ldap::stand_in::{Entry, Request, StandInLdap};
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+    const ALICE_DN: &str = "uid=alice,ou=people,dc=example,dc=org";
+
+    fn directory() -> StandInLdap {
+        // A value longer than 127 bytes makes the server use long-form lengths.
+        let description: String = "x".repeat(300);
+
+        StandInLdap::start(vec![Entry::new(
+            ALICE_DN,
+            "secret",
+            &[
+                ("uid", &["alice"]),
+                ("cn", &["Alice Example"]),
+                (
+                    "memberOf",
+                    &["cn=admins,dc=example,dc=org", "cn=staff,dc=example,dc=org"],
+                ),
+                ("description", &[description.as_str()]),
+            ],
+        )])
+    }
+
+    fn connect(directory: &StandInLdap) -> LdapConnection {
+        LdapConnection::connect(&Url::parse(&directory.url()).unwrap(), TIMEOUT).unwrap()
+    }
+
+    #[test]
+    fn binds_with_valid_credentials() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+
+        connection.simple_bind(ALICE_DN, "secret").unwrap();
+
+        assert_eq!(
+            directory.requests(),
+            vec![Request::Bind {
+                dn: ALICE_DN.into(),
+                password: "secret".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_credentials_as_a_result_code() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+
+        let result: Result<(), LdapError> = connection.simple_bind(ALICE_DN, "wrong");
+
+        assert!(matches!(
+            result,
+            Err(LdapError::Result(INVALID_CREDENTIALS))
+        ));
+    }
+
+    #[test]
+    fn searches_for_entries_and_reads_their_attributes() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+        connection.simple_bind(ALICE_DN, "secret").unwrap();
+
+        let entries: Vec<SearchEntry> = connection
+            .search(
+                "dc=example,dc=org",
+                "uid",
+                "alice",
+                &["cn", "memberOf", "description"],
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].values("CN"), ["Alice Example"]);
+        assert_eq!(entries[0].values("memberof").len(), 2);
+        assert_eq!(entries[0].values("description")[0].len(), 300);
+        assert!(entries[0].values("mail").is_empty());
+    }
+
+    #[test]
+    fn returns_no_entries_when_nothing_matches() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+        connection.simple_bind(ALICE_DN, "secret").unwrap();
+
+        let entries: Vec<SearchEntry> = connection
+            .search("dc=example,dc=org", "uid", "bob", &["cn"], TIMEOUT)
+            .unwrap();
+
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn reports_failed_searches_as_a_result_code() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+
+        let result: Result<Vec<SearchEntry>, LdapError> =
+            connection.search("dc=example,dc=org", "uid", "alice", &["cn"], TIMEOUT);
+
+        assert!(matches!(result, Err(LdapError::Result(50))));
+    }
+
+    #[test]
+    fn numbers_requests_and_unbinds() {
+        let directory: StandInLdap = directory();
+        let mut connection: LdapConnection = connect(&directory);
+
+        connection.simple_bind(ALICE_DN, "wrong").ok();
+        connection.simple_bind(ALICE_DN, "secret").unwrap();
+        assert_eq!(connection.message_id, 2);
+
+        connection.unbind();
+        // The server records the unbind before closing the connection.
+        std::thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(directory.requests().last(), Some(&Request::Unbind));
+    }
+
+    #[test]
+    fn fails_when_the_server_closes_the_connection() {
+        let listener: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url: Url = Url::parse(&format!("ldap://{}", listener.local_addr().unwrap())).unwrap();
+        std::thread::spawn(move || drop(listener.accept()));
+
+        let mut connection: LdapConnection = LdapConnection::connect(&url, TIMEOUT).unwrap();
+
+        assert!(matches!(
+            connection.simple_bind(ALICE_DN, "secret"),
+            Err(LdapError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_other_url_schemes() {
+        let url: Url = Url::parse("http://127.0.0.1:389").unwrap();
+
+        assert!(matches!(
+            LdapConnection::connect(&url, TIMEOUT),
+            Err(LdapError::Protocol)
+        ));
+    }
+}
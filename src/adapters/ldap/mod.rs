@@ -0,0 +1,5 @@
+mod ber;
+pub mod connection;
+
+#[cfg(test)]
+pub mod stand_in;
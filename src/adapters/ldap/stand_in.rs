@@ -0,0 +1,245 @@
+//! A stand-in LDAP server for tests, answering simple binds and equality searches from a
+//! fixed set of entries.
+
+use super::ber::{
+    self, ENUMERATED, Element, INTEGER, OCTET_STRING, SEQUENCE, constructed, encode, integer,
+};
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+const BIND_REQUEST: u8 = 0x60;
+const BIND_RESPONSE: u8 = 0x61;
+const UNBIND_REQUEST: u8 = 0x42;
+const SEARCH_REQUEST: u8 = 0x63;
+const SEARCH_RESULT_ENTRY: u8 = 0x64;
+const SEARCH_RESULT_DONE: u8 = 0x65;
+const SET: u8 = 0x31;
+
+const SUCCESS: i64 = 0;
+const INVALID_CREDENTIALS: i64 = 49;
+const INSUFFICIENT_ACCESS_RIGHTS: i64 = 50;
+
+pub struct Entry {
+    pub dn: String,
+    pub password: String,
+    pub attributes: Vec<(String, Vec<String>)>,
+}
+
+impl Entry {
+    pub fn new(dn: &str, password: &str, attributes: &[(&str, &[&str])]) -> Self {
+        Self {
+            dn: dn.into(),
+            password: password.into(),
+            attributes: attributes
+                .iter()
+                .map(|(name, values)| {
+                    (
+                        (*name).into(),
+                        values.iter().map(|value| (*value).into()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn matches(&self, attribute: &str, value: &str) -> bool {
+        self.attributes.iter().any(|(name, values)| {
+            name.eq_ignore_ascii_case(attribute)
+                && values.iter().any(|v| v.eq_ignore_ascii_case(value))
+        })
+    }
+}
+
+/// Requests the server received, for assertions on what the client sent.
+#[derive(Debug, PartialEq)]
+pub enum Request {
+    Bind {
+        dn: String,
+        password: String,
+    },
+    Search {
+        base: String,
+        attribute: String,
+        value: String,
+    },
+    Unbind,
+}
+
+pub struct StandInLdap {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StandInLdap {
+    /// Starts serving `entries` on an ephemeral local port, until the test process exits.
+    pub fn start(entries: Vec<Entry>) -> Self {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address: SocketAddr = listener.local_addr().unwrap();
+        let entries: Arc<Vec<Entry>> = Arc::new(entries);
+        let requests: Arc<Mutex<Vec<Request>>> = Arc::default();
+
+        let (served, recorded) = (entries, requests.clone());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { return };
+                let (entries, requests) = (served.clone(), recorded.clone());
+
+                thread::spawn(move || serve(stream, &entries, &requests));
+            }
+        });
+
+        Self { address, requests }
+    }
+
+    pub fn url(&self) -> String {
+        format!("ldap://{}", self.address)
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        std::mem::take(&mut *self.requests.lock().unwrap())
+    }
+}
+
+fn serve(mut stream: TcpStream, entries: &[Entry], requests: &Mutex<Vec<Request>>) {
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk: [u8; 4096] = [0; 4096];
+    // The identity of the connection, searches are only answered once bound.
+    let mut bound: bool = false;
+
+    loop {
+        while let Some(size) = ber::element_size(&buffer).filter(|size| buffer.len() >= *size) {
+            let rest: Vec<u8> = buffer.split_off(size);
+            let message: Vec<u8> = std::mem::replace(&mut buffer, rest);
+
+            let Some(responses) = answer(&message, entries, requests, &mut bound) else {
+                return;
+            };
+
+            for response in responses {
+                if stream.write_all(&response).is_err() {
+                    return;
+                }
+            }
+        }
+
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => return,
+            Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+        }
+    }
+}
+
+/// Answers one request, or returns `None` when the connection should be closed.
+fn answer(
+    message: &[u8],
+    entries: &[Entry],
+    requests: &Mutex<Vec<Request>>,
+    bound: &mut bool,
+) -> Option<Vec<Vec<u8>>> {
+    let text = |element: &Element| String::from_utf8_lossy(element.contents).into_owned();
+
+    let (envelope, _) = ber::decode(message)?;
+    let mut children = envelope.children();
+    let id: i64 = children.next()?.as_integer()?;
+    let operation: Element = children.next()?;
+    let mut fields = operation.children();
+    let reply = |tag: u8, contents: Vec<Vec<u8>>| {
+        constructed(
+            SEQUENCE,
+            &[integer(INTEGER, id), constructed(tag, &contents)],
+        )
+    };
+
+    match operation.tag {
+        BIND_REQUEST => {
+            let _version: Element = fields.next()?;
+            let dn: String = text(&fields.next()?);
+            let password: String = text(&fields.next()?);
+
+            *bound = entries
+                .iter()
+                .any(|entry| entry.dn == dn && entry.password == password);
+            let code: i64 = if *bound { SUCCESS } else { INVALID_CREDENTIALS };
+            requests
+                .lock()
+                .unwrap()
+                .push(Request::Bind { dn, password });
+
+            Some(vec![reply(BIND_RESPONSE, result(code))])
+        }
+        SEARCH_REQUEST => {
+            let base: String = text(&fields.next()?);
+            let filter: Element = fields.nth(5)?;
+            let mut assertion = filter.children();
+            let attribute: String = text(&assertion.next()?);
+            let value: String = text(&assertion.next()?);
+
+            requests.lock().unwrap().push(Request::Search {
+                base: base.clone(),
+                attribute: attribute.clone(),
+                value: value.clone(),
+            });
+
+            if !*bound {
+                return Some(vec![reply(
+                    SEARCH_RESULT_DONE,
+                    result(INSUFFICIENT_ACCESS_RIGHTS),
+                )]);
+            }
+
+            let mut responses: Vec<Vec<u8>> = entries
+                .iter()
+                .filter(|entry| entry.dn.ends_with(&base) && entry.matches(&attribute, &value))
+                .map(|entry| reply(SEARCH_RESULT_ENTRY, search_entry(entry)))
+                .collect();
+            responses.push(reply(SEARCH_RESULT_DONE, result(SUCCESS)));
+
+            Some(responses)
+        }
+        UNBIND_REQUEST => {
+            requests.lock().unwrap().push(Request::Unbind);
+
+            None
+        }
+        _ => None,
+    }
+}
+
+fn result(code: i64) -> Vec<Vec<u8>> {
+    vec![
+        integer(ENUMERATED, code),
+        encode(OCTET_STRING, b""),
+        encode(OCTET_STRING, b""),
+    ]
+}
+
+fn search_entry(entry: &Entry) -> Vec<Vec<u8>> {
+    let attributes: Vec<Vec<u8>> = entry
+        .attributes
+        .iter()
+        .map(|(name, values)| {
+            constructed(
+                SEQUENCE,
+                &[
+                    encode(OCTET_STRING, name.as_bytes()),
+                    constructed(
+                        SET,
+                        &values
+                            .iter()
+                            .map(|value| encode(OCTET_STRING, value.as_bytes()))
+                            .collect::<Vec<Vec<u8>>>(),
+                    ),
+                ],
+            )
+        })
+        .collect();
+
+    vec![
+        encode(OCTET_STRING, entry.dn.as_bytes()),
+        constructed(SEQUENCE, &attributes),
+    ]
+}
@@ -0,0 +1,240 @@
+use crate::domain::mfa::totp::TotpProvider;
+use hmac::{Hmac, Mac};
+use rand::{RngCore, rngs::OsRng};
+use sha1::Sha1;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const SECRET_BYTES: usize = 20;
+const DIGITS: u32 = 6;
+const PERIOD_SECONDS: u64 = 30;
+/// Number of time steps accepted on either side of the current one, to absorb clock drift.
+const ALLOWED_SKEW: u64 = 1;
+
+/// HMAC-SHA1 TOTP with the parameters every mainstream authenticator app supports: 6 digits
+/// and a 30 second period.
+#[derive(Clone)]
+pub struct HmacTotp {
+    issuer: String,
+}
+
+impl HmacTotp {
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+        }
+    }
+
+    fn code_at(key: &[u8], step: u64) -> Option<u32> {
+        let mut mac: Hmac<Sha1> = Hmac::<Sha1>::new_from_slice(key).ok()?;
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        let offset: usize = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary: u32 = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+
+        Some(binary % 10u32.pow(DIGITS))
+    }
+}
+
+impl TotpProvider for HmacTotp {
+    fn generate_secret(&self) -> String {
+        let mut secret: [u8; SECRET_BYTES] = [0; SECRET_BYTES];
+        OsRng.fill_bytes(&mut secret);
+
+        base32_encode(&secret)
+    }
+
+    fn provisioning_uri(&self, secret: &str, account: &str) -> String {
+        let issuer: String = percent_encode(&self.issuer);
+
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            percent_encode(account),
+            secret,
+            issuer,
+            DIGITS,
+            PERIOD_SECONDS
+        )
+    }
+
+    fn verify(&self, secret: &str, code: &str, last_used_step: Option<u64>) -> Option<u64> {
+        let code: &str = code.trim();
+
+        if code.len() != DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let code: u32 = code.parse().ok()?;
+        let key: Vec<u8> = base32_decode(secret)?;
+        let now: u64 = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let current: u64 = now / PERIOD_SECONDS;
+
+        (current.saturating_sub(ALLOWED_SKEW)..=current + ALLOWED_SKEW)
+            .filter(|step| last_used_step.is_none_or(|last| *step > last))
+            .find(|step| Self::code_at(&key, *step) == Some(code))
+    }
+}
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut output: String = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for byte in data {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            output.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        output.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    output
+}
+
+fn base32_decode(value: &str) -> Option<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for c in value.trim_end_matches('=').chars() {
+        let index: u32 = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a as char == c.to_ascii_uppercase())? as u32;
+
+        buffer = (buffer << 5) | index;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push(((buffer >> bits) & 0xff) as u8);
+        }
+    }
+
+    Some(output)
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The RFC 6238 test secret for HMAC-SHA1.
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn base32_encode_matches_rfc_4648_vectors() {
+        let vectors: [(&str, &str); 7] = [
+            ("", ""),
+            ("f", "MY"),
+            ("fo", "MZXQ"),
+            ("foo", "MZXW6"),
+            ("foob", "MZXW6YQ"),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI"),
+        ];
+
+        for (input, expected) in vectors {
+            assert_eq!(base32_encode(input.as_bytes()), expected);
+        }
+    }
+
+    #[test]
+    fn base32_decode_accepts_padding_and_lowercase() {
+        assert_eq!(base32_decode("MZXW6YTBOI======").unwrap(), b"foobar");
+        assert_eq!(base32_decode("mzxw6ytboi").unwrap(), b"foobar");
+    }
+
+    #[test]
+    fn base32_decode_rejects_characters_outside_the_alphabet() {
+        assert!(base32_decode("MZXW1").is_none());
+        assert!(base32_decode("MZ XW").is_none());
+    }
+
+    #[test]
+    fn base32_round_trips_generated_secrets() {
+        let secret: String = HmacTotp::new("windwatcher").generate_secret();
+
+        assert_eq!(secret.len(), 32);
+        assert_eq!(base32_decode(&secret).unwrap().len(), SECRET_BYTES);
+        assert_eq!(base32_encode(&base32_decode(&secret).unwrap()), secret);
+    }
+
+    #[test]
+    fn code_at_matches_rfc_6238_vectors() {
+        // The RFC lists 8 digit codes, the last 6 digits are the 6 digit codes.
+        let vectors: [(u64, u32); 4] = [
+            (59, 287082),
+            (1111111109, 81804),
+            (1234567890, 5924),
+            (2000000000, 279037),
+        ];
+
+        for (time, expected) in vectors {
+            assert_eq!(
+                HmacTotp::code_at(RFC_SECRET, time / PERIOD_SECONDS),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn verify_accepts_the_current_code_once() {
+        let totp: HmacTotp = HmacTotp::new("windwatcher");
+        let secret: String = base32_encode(RFC_SECRET);
+        let step: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / PERIOD_SECONDS;
+        let code: String = format!("{:06}", HmacTotp::code_at(RFC_SECRET, step).unwrap());
+
+        let used: u64 = totp.verify(&secret, &code, None).unwrap();
+
+        assert!(used.abs_diff(step) <= ALLOWED_SKEW);
+        assert_eq!(totp.verify(&secret, &code, Some(used)), None);
+    }
+
+    #[test]
+    fn verify_rejects_malformed_codes() {
+        let totp: HmacTotp = HmacTotp::new("windwatcher");
+        let secret: String = base32_encode(RFC_SECRET);
+
+        assert_eq!(totp.verify(&secret, "12345", None), None);
+        assert_eq!(totp.verify(&secret, "12345a", None), None);
+        assert_eq!(totp.verify(&secret, "1234567", None), None);
+    }
+
+    #[test]
+    fn provisioning_uri_percent_encodes_the_account() {
+        let uri: String = HmacTotp::new("Wind Watcher").provisioning_uri("ABC", "a b@c");
+
+        assert_eq!(
+            uri,
+            "otpauth://totp/Wind%20Watcher:a%20b%40c?secret=ABC&issuer=Wind%20Watcher&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
@@ -1,5 +1,16 @@
 pub mod auth;
+pub mod avatar;
+pub mod cache;
+pub mod captcha;
+pub mod event;
+pub mod federation;
 pub mod hash;
 pub mod http;
+pub mod ldap;
+pub mod mfa;
+pub mod net;
+pub mod notification;
+pub mod password;
 pub mod persistence;
 pub mod token;
+pub mod webauthn;
@@ -0,0 +1,73 @@
+//! Blocking TCP connections, optionally wrapped in TLS, for the protocol clients that talk to
+//! external services (directories, identity providers, mail servers).
+
+use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned, pki_types::ServerName};
+use std::{
+    io::{Error, ErrorKind, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::Arc,
+    time::Duration,
+};
+
+pub trait Stream: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Stream for T {}
+
+/// Connects to `host:port`. With `secure`, the server certificate is checked against the
+/// Mozilla root store.
+pub fn connect(
+    host: &str,
+    port: u16,
+    secure: bool,
+    timeout: Duration,
+) -> Result<Box<dyn Stream>, Error> {
+    let tcp: TcpStream = connect_tcp(host, port, timeout)?;
+
+    if !secure {
+        return Ok(Box::new(tcp));
+    }
+
+    tls(tcp, host)
+}
+
+/// Connects to `host:port` without TLS, for protocols that upgrade the connection later.
+pub fn connect_tcp(host: &str, port: u16, timeout: Duration) -> Result<TcpStream, Error> {
+    let mut last_error: Error = ErrorKind::NotFound.into();
+    let mut tcp: Option<TcpStream> = None;
+
+    for address in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => {
+                tcp = Some(stream);
+                break;
+            }
+            Err(error) => last_error = error,
+        }
+    }
+
+    let tcp: TcpStream = tcp.ok_or(last_error)?;
+    tcp.set_read_timeout(Some(timeout))?;
+    tcp.set_write_timeout(Some(timeout))?;
+
+    Ok(tcp)
+}
+
+/// Starts a TLS session for `host` over an established connection.
+pub fn tls(tcp: TcpStream, host: &str) -> Result<Box<dyn Stream>, Error> {
+    let mut roots: RootCertStore = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+    let config: ClientConfig =
+        ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(Error::other)?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+    let server_name: ServerName<'static> =
+        ServerName::try_from(host.to_owned()).map_err(Error::other)?;
+    let connection: ClientConnection =
+        ClientConnection::new(Arc::new(config), server_name).map_err(Error::other)?;
+
+    Ok(Box::new(StreamOwned::new(connection, tcp)))
+}
@@ -0,0 +1,28 @@
+use super::message;
+use crate::{
+    application::notification::notifier::{Notification, Notifier, NotifierError},
+    domain::user::entity::User,
+};
+use log::info;
+
+/// Writes notifications to the log instead of sending them, for local development. The log
+/// then holds secrets such as reset tokens, so it must not be used in production.
+#[derive(Clone)]
+pub struct LogNotifier;
+
+#[async_trait::async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, user: &User, notification: Notification) -> Result<(), NotifierError> {
+        let (subject, body) = message::render(user, &notification);
+
+        info!(
+            "Notification for {} ({}): {}\n{}",
+            user.username.as_str(),
+            user.id,
+            subject,
+            body
+        );
+
+        Ok(())
+    }
+}
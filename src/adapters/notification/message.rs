@@ -0,0 +1,27 @@
+use crate::{application::notification::notifier::Notification, domain::user::entity::User};
+
+/// Renders a notification as a plain text subject and body.
+pub fn render(user: &User, notification: &Notification) -> (String, String) {
+    match notification {
+        Notification::PasswordReset { token, expires_in } => (
+            "Reset your Windwatcher password".into(),
+            format!(
+                "Hello {name},\n\nSomeone asked to reset the password of your account {username}.\nTo choose a new password, use this reset token:\n\n{token}\n\nIt can be used once, within {minutes} minutes.\nIf you did not ask for it, you can ignore this message.\n",
+                name = user.name.as_str(),
+                username = user.username.as_str(),
+                token = token,
+                minutes = expires_in / 60,
+            ),
+        ),
+        Notification::EmailVerification { link, expires_in } => (
+            "Verify your Windwatcher email address".into(),
+            format!(
+                "Hello {name},\n\nThis address was given for the account {username}.\nTo confirm that it is yours, open this link:\n\n{link}\n\nIt is valid for {hours} hours.\nIf you did not ask for it, you can ignore this message.\n",
+                name = user.name.as_str(),
+                username = user.username.as_str(),
+                link = link,
+                hours = expires_in / 3600,
+            ),
+        ),
+    }
+}
@@ -0,0 +1,26 @@
+pub mod log;
+mod message;
+pub mod smtp;
+
+use self::{log::LogNotifier, smtp::SmtpNotifier};
+use crate::{
+    application::notification::notifier::{Notification, Notifier, NotifierError},
+    domain::user::entity::User,
+};
+
+/// Sends email when SMTP is configured, and logs notifications otherwise.
+#[derive(Clone)]
+pub enum AppNotifier {
+    Smtp(SmtpNotifier),
+    Log(LogNotifier),
+}
+
+#[async_trait::async_trait]
+impl Notifier for AppNotifier {
+    async fn notify(&self, user: &User, notification: Notification) -> Result<(), NotifierError> {
+        match self {
+            AppNotifier::Smtp(notifier) => notifier.notify(user, notification).await,
+            AppNotifier::Log(notifier) => notifier.notify(user, notification).await,
+        }
+    }
+}
@@ -0,0 +1,568 @@
+//! Sends notifications by email, with a minimal blocking SMTP client (RFC 5321).
+
+use super::message;
+use crate::{
+    adapters::net::{self, Stream},
+    application::notification::notifier::{Notification, Notifier, NotifierError},
+    config::smtp::ports::{RECIPIENT_PLACEHOLDER, SmtpConfig, SmtpTls},
+    domain::user::entity::User,
+};
+use actix_web::rt::task::spawn_blocking;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::Utc;
+use log::warn;
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, Read, Write},
+    net::TcpStream,
+    sync::Arc,
+    time::Duration,
+};
+use url::Url;
+use uuid::Uuid;
+
+const EHLO_DOMAIN: &str = "localhost";
+const MAX_REPLY_SIZE: usize = 64 * 1024;
+
+#[derive(Clone)]
+pub struct SmtpNotifier {
+    config: Arc<SmtpConfig>,
+}
+
+impl SmtpNotifier {
+    pub fn new(config: SmtpConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for SmtpNotifier {
+    async fn notify(&self, user: &User, notification: Notification) -> Result<(), NotifierError> {
+        let recipient: String = recipient(&self.config, user, &notification)
+            .filter(|recipient| is_valid_address(recipient))
+            .ok_or(NotifierError::Unreachable)?;
+
+        let (subject, body) = message::render(user, &notification);
+        let config: Arc<SmtpConfig> = self.config.clone();
+
+        spawn_blocking(move || send(&config, &recipient, &subject, &body))
+            .await
+            .map_err(|_| NotifierError::Unavailable)?
+            .map_err(|error| {
+                warn!("Failed to send email: {}", error);
+
+                NotifierError::Unavailable
+            })
+    }
+}
+
+enum SmtpError {
+    Io(io::Error),
+    /// The server answered a command with an unexpected reply.
+    Reply(u16, String),
+    /// The server does not offer TLS, and credentials would be sent in clear text.
+    TlsRequired,
+}
+
+impl Display for SmtpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmtpError::Io(error) => write!(f, "{}", error),
+            SmtpError::Reply(code, text) => write!(f, "server replied {} {}", code, text),
+            SmtpError::TlsRequired => write!(f, "server does not support STARTTLS"),
+        }
+    }
+}
+
+impl From<io::Error> for SmtpError {
+    fn from(error: io::Error) -> Self {
+        SmtpError::Io(error)
+    }
+}
+
+struct Reply {
+    code: u16,
+    lines: Vec<String>,
+}
+
+struct SmtpConnection {
+    stream: Box<dyn Stream>,
+    buffer: Vec<u8>,
+}
+
+impl SmtpConnection {
+    fn new(stream: Box<dyn Stream>) -> Self {
+        Self {
+            stream,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn read_line(&mut self) -> Result<String, SmtpError> {
+        loop {
+            if let Some(end) = self.buffer.windows(2).position(|w| w == b"\r\n") {
+                let line: Vec<u8> = self.buffer.drain(..end + 2).take(end).collect();
+
+                return Ok(String::from_utf8_lossy(&line).into_owned());
+            }
+
+            if self.buffer.len() > MAX_REPLY_SIZE {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "reply too long").into());
+            }
+
+            let mut chunk: [u8; 1024] = [0; 1024];
+            let read: usize = self.stream.read(&mut chunk)?;
+
+            if read == 0 {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+
+            self.buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+
+    /// Reads a possibly multiline reply: every line but the last has a `-` after the code.
+    fn read_reply(&mut self) -> Result<Reply, SmtpError> {
+        let mut lines: Vec<String> = Vec::new();
+
+        loop {
+            let line: String = self.read_line()?;
+            let code: u16 = line
+                .get(..3)
+                .and_then(|code| code.parse().ok())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed reply"))?;
+            let last: bool = line.as_bytes().get(3) != Some(&b'-');
+            lines.push(line.get(4..).unwrap_or_default().to_owned());
+
+            if last {
+                return Ok(Reply { code, lines });
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: u16) -> Result<Reply, SmtpError> {
+        let reply: Reply = self.read_reply()?;
+
+        if reply.code != expected {
+            return Err(SmtpError::Reply(reply.code, reply.lines.join(" ")));
+        }
+
+        Ok(reply)
+    }
+
+    fn command(&mut self, command: &str, expected: u16) -> Result<Reply, SmtpError> {
+        self.stream.write_all(command.as_bytes())?;
+        self.stream.write_all(b"\r\n")?;
+        self.stream.flush()?;
+
+        self.expect(expected)
+    }
+
+    /// Returns the extensions the server supports.
+    fn ehlo(&mut self) -> Result<Vec<String>, SmtpError> {
+        let reply: Reply = self.command(&format!("EHLO {}", EHLO_DOMAIN), 250)?;
+
+        Ok(reply
+            .lines
+            .iter()
+            .skip(1)
+            .map(|line| line.to_ascii_uppercase())
+            .collect())
+    }
+}
+
+fn send(config: &SmtpConfig, recipient: &str, subject: &str, body: &str) -> Result<(), SmtpError> {
+    let url: Url = Url::parse(&config.url)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+    let host: &str = url
+        .host_str()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "URL without host"))?;
+    let implicit_tls: bool = url.scheme() == "smtps";
+    let port: u16 = url.port().unwrap_or(if implicit_tls { 465 } else { 587 });
+    let timeout: Duration = Duration::from_secs(config.timeout);
+
+    let mut connection: SmtpConnection = if implicit_tls {
+        let mut connection: SmtpConnection =
+            SmtpConnection::new(net::connect(host, port, true, timeout)?);
+        connection.expect(220)?;
+        connection.ehlo()?;
+
+        connection
+    } else {
+        let tcp: TcpStream = net::connect_tcp(host, port, timeout)?;
+        let mut connection: SmtpConnection = SmtpConnection::new(Box::new(tcp.try_clone()?));
+        connection.expect(220)?;
+
+        if connection
+            .ehlo()?
+            .iter()
+            .any(|extension| extension == "STARTTLS")
+        {
+            connection.command("STARTTLS", 220)?;
+
+            // The session starts over on the secured connection, without a greeting.
+            let mut connection: SmtpConnection = SmtpConnection::new(net::tls(tcp, host)?);
+            connection.ehlo()?;
+
+            connection
+        } else if config.tls == SmtpTls::Required || config.username.is_some() {
+            // Credentials never go in clear text, even with TLS turned off.
+            return Err(SmtpError::TlsRequired);
+        } else {
+            connection
+        }
+    };
+
+    if let (Some(username), Some(password)) = (&config.username, &config.password) {
+        let credentials: String = STANDARD.encode(format!("\0{}\0{}", username, password));
+        connection.command(&format!("AUTH PLAIN {}", credentials), 235)?;
+    }
+
+    connection.command(&format!("MAIL FROM:<{}>", config.from), 250)?;
+    connection.command(&format!("RCPT TO:<{}>", recipient), 250)?;
+    connection.command("DATA", 354)?;
+
+    let message: String = format!(
+        "From: {from}\r\nTo: {to}\r\nSubject: {subject}\r\nDate: {date}\r\nMessage-ID: <{id}@{domain}>\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\n{body}",
+        from = config.from,
+        to = recipient,
+        subject = subject,
+        date = Utc::now().to_rfc2822(),
+        id = Uuid::now_v7(),
+        domain = config.from.rsplit('@').next().unwrap_or(EHLO_DOMAIN),
+        body = dot_stuff(body),
+    );
+
+    connection.stream.write_all(message.as_bytes())?;
+    connection.command("\r\n.", 250)?;
+    connection.command("QUIT", 221)?;
+
+    Ok(())
+}
+
+/// Normalizes line endings and escapes lines starting with a dot (RFC 5321, section 4.5.2).
+fn dot_stuff(body: &str) -> String {
+    body.lines()
+        .map(|line| match line.starts_with('.') {
+            true => format!(".{}", line),
+            false => line.to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join("\r\n")
+}
+
+/// Users are reached at their verified address, or else at the configured recipient. Email
+/// verifications go to the address to verify.
+fn recipient(config: &SmtpConfig, user: &User, notification: &Notification) -> Option<String> {
+    if let Notification::EmailVerification { .. } = notification {
+        return user.email.as_ref().map(|email| email.as_str().to_owned());
+    }
+
+    match user.verified_email() {
+        Some(email) => Some(email.as_str().to_owned()),
+        None => config
+            .recipient
+            .as_ref()
+            .map(|recipient| recipient.replace(RECIPIENT_PLACEHOLDER, user.username.as_str())),
+    }
+}
+
+/// Rejects addresses that would break out of an SMTP command or a header.
+fn is_valid_address(address: &str) -> bool {
+    address.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+    }) && !address
+        .chars()
+        .any(|c| c.is_control() || c.is_whitespace() || c == '<' || c == '>')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::user::value_objects::{
+        email::Email, name::Name, password_hash::PasswordHash, username::Username,
+    };
+    use std::{
+        io::{BufRead, BufReader},
+        net::{SocketAddr, TcpListener},
+        sync::Mutex,
+        thread,
+    };
+
+    /// A stand-in SMTP server for one session, recording the commands and the message data.
+    struct StandInSmtp {
+        address: SocketAddr,
+        transcript: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StandInSmtp {
+        /// Starts a server advertising `extensions` and answering `RCPT TO` with `rcpt_reply`.
+        fn start(extensions: &'static [&'static str], rcpt_reply: &'static str) -> Self {
+            let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let address: SocketAddr = listener.local_addr().unwrap();
+            let transcript: Arc<Mutex<Vec<String>>> = Arc::default();
+            let recorded: Arc<Mutex<Vec<String>>> = transcript.clone();
+
+            thread::spawn(move || {
+                let (stream, _) = listener.accept().unwrap();
+                let mut writer: TcpStream = stream.try_clone().unwrap();
+                let mut reader: BufReader<TcpStream> = BufReader::new(stream);
+                let mut reply = |text: &str| writer.write_all(text.as_bytes()).is_ok();
+
+                reply("220 stand-in ESMTP\r\n");
+
+                loop {
+                    let mut line: String = String::new();
+
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                        return;
+                    }
+
+                    let command: String = line.trim_end().to_owned();
+                    recorded.lock().unwrap().push(command.clone());
+
+                    let answer: String = match command.split(' ').next().unwrap_or_default() {
+                        "EHLO" => {
+                            let mut lines: Vec<String> = vec!["stand-in".into()];
+                            lines.extend(extensions.iter().map(|e| e.to_string()));
+                            let last: usize = lines.len() - 1;
+
+                            lines
+                                .iter()
+                                .enumerate()
+                                .map(|(i, l)| {
+                                    format!("250{}{}\r\n", if i == last { ' ' } else { '-' }, l)
+                                })
+                                .collect()
+                        }
+                        "STARTTLS" => {
+                            reply("220 go ahead\r\n");
+                            // No TLS here, the client's handshake fails.
+                            return;
+                        }
+                        "AUTH" => "235 authenticated\r\n".into(),
+                        "MAIL" => "250 ok\r\n".into(),
+                        "RCPT" => format!("{}\r\n", rcpt_reply),
+                        "DATA" => {
+                            reply("354 go ahead\r\n");
+
+                            let mut data: String = String::new();
+
+                            while !data.ends_with("\r\n.\r\n") {
+                                if reader.read_line(&mut data).unwrap_or(0) == 0 {
+                                    return;
+                                }
+                            }
+
+                            recorded.lock().unwrap().push(data);
+                            "250 queued\r\n".into()
+                        }
+                        "QUIT" => {
+                            reply("221 bye\r\n");
+                            return;
+                        }
+                        _ => "502 not implemented\r\n".into(),
+                    };
+
+                    if !reply(&answer) {
+                        return;
+                    }
+                }
+            });
+
+            Self {
+                address,
+                transcript,
+            }
+        }
+
+        fn config(&self) -> SmtpConfig {
+            SmtpConfig {
+                url: format!("smtp://{}", self.address),
+                from: "windwatcher@example.org".into(),
+                recipient: Some("{username}@users.example.org".into()),
+                username: None,
+                password: None,
+                timeout: 5,
+                tls: SmtpTls::Disabled,
+            }
+        }
+
+        fn transcript(&self) -> Vec<String> {
+            self.transcript.lock().unwrap().clone()
+        }
+    }
+
+    fn user(email: Option<&str>, verified: bool) -> User {
+        let mut user: User = User::new(
+            Uuid::now_v7(),
+            Name::new("Alice".into()).unwrap(),
+            Username::new("alice".into()).unwrap(),
+            PasswordHash::new("hash".into()).unwrap(),
+            None,
+            None,
+        );
+        user.email = email.map(|email| Email::new(email.into()).unwrap());
+        user.email_verified_at = verified.then_some(1);
+
+        user
+    }
+
+    fn reset() -> Notification {
+        Notification::PasswordReset {
+            token: "token".into(),
+            expires_in: 900,
+        }
+    }
+
+    #[actix_web::test]
+    async fn delivers_a_message_to_the_verified_address() {
+        let server: StandInSmtp = StandInSmtp::start(&["8BITMIME", "SIZE 1000"], "250 ok");
+
+        SmtpNotifier::new(server.config())
+            .notify(&user(Some("alice@example.org"), true), reset())
+            .await
+            .unwrap();
+
+        let transcript: Vec<String> = server.transcript();
+        assert_eq!(
+            transcript[..4],
+            [
+                "EHLO localhost",
+                "MAIL FROM:<windwatcher@example.org>",
+                "RCPT TO:<alice@example.org>",
+                "DATA",
+            ]
+        );
+        assert!(transcript[4].contains("To: alice@example.org\r\n"));
+        assert!(transcript[4].contains("Subject: Reset your Windwatcher password\r\n"));
+        assert!(transcript[4].contains("\r\ntoken\r\n"));
+        assert_eq!(transcript[5], "QUIT");
+    }
+
+    #[actix_web::test]
+    async fn falls_back_to_the_configured_recipient() {
+        let server: StandInSmtp = StandInSmtp::start(&[], "250 ok");
+
+        SmtpNotifier::new(server.config())
+            .notify(&user(Some("alice@example.org"), false), reset())
+            .await
+            .unwrap();
+
+        assert_eq!(server.transcript()[2], "RCPT TO:<alice@users.example.org>");
+    }
+
+    #[actix_web::test]
+    async fn sends_verifications_to_the_address_to_verify() {
+        let server: StandInSmtp = StandInSmtp::start(&[], "250 ok");
+
+        SmtpNotifier::new(server.config())
+            .notify(
+                &user(Some("new@example.org"), false),
+                Notification::EmailVerification {
+                    link: "https://login.example.org/verify".into(),
+                    expires_in: 86400,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(server.transcript()[2], "RCPT TO:<new@example.org>");
+    }
+
+    #[actix_web::test]
+    async fn reports_users_without_an_address_as_unreachable() {
+        let server: StandInSmtp = StandInSmtp::start(&[], "250 ok");
+        let config: SmtpConfig = SmtpConfig {
+            recipient: None,
+            ..server.config()
+        };
+
+        let result: Result<(), NotifierError> = SmtpNotifier::new(config)
+            .notify(&user(None, false), reset())
+            .await;
+
+        assert!(matches!(result, Err(NotifierError::Unreachable)));
+    }
+
+    #[actix_web::test]
+    async fn reports_rejected_recipients_as_unavailable() {
+        let server: StandInSmtp = StandInSmtp::start(&[], "550 no such user");
+
+        let result: Result<(), NotifierError> = SmtpNotifier::new(server.config())
+            .notify(&user(Some("alice@example.org"), true), reset())
+            .await;
+
+        assert!(matches!(result, Err(NotifierError::Unavailable)));
+        assert!(!server.transcript().iter().any(|line| line == "DATA"));
+    }
+
+    #[test]
+    fn requires_tls_by_default() {
+        let server: StandInSmtp = StandInSmtp::start(&[], "250 ok");
+        let config: SmtpConfig = SmtpConfig {
+            tls: SmtpTls::default(),
+            ..server.config()
+        };
+
+        let result: Result<(), SmtpError> = send(&config, "alice@example.org", "Hi", "Hello");
+
+        assert!(matches!(result, Err(SmtpError::TlsRequired)));
+        assert_eq!(server.transcript(), ["EHLO localhost"]);
+    }
+
+    #[test]
+    fn refuses_to_send_credentials_without_tls() {
+        let server: StandInSmtp = StandInSmtp::start(&["AUTH PLAIN"], "250 ok");
+        let config: SmtpConfig = SmtpConfig {
+            username: Some("mailer".into()),
+            password: Some("secret".into()),
+            ..server.config()
+        };
+
+        let result: Result<(), SmtpError> = send(&config, "alice@example.org", "Hi", "Hello");
+
+        assert!(matches!(result, Err(SmtpError::TlsRequired)));
+        assert_eq!(server.transcript(), ["EHLO localhost"]);
+    }
+
+    #[test]
+    fn upgrades_to_tls_before_authenticating() {
+        let server: StandInSmtp = StandInSmtp::start(&["STARTTLS", "AUTH PLAIN"], "250 ok");
+        let config: SmtpConfig = SmtpConfig {
+            username: Some("mailer".into()),
+            password: Some("secret".into()),
+            ..server.config()
+        };
+
+        // The stand-in cannot complete the handshake, so the session ends there.
+        let result: Result<(), SmtpError> = send(&config, "alice@example.org", "Hi", "Hello");
+
+        assert!(matches!(result, Err(SmtpError::Io(_))));
+        assert_eq!(server.transcript(), ["EHLO localhost", "STARTTLS"]);
+    }
+
+    #[test]
+    fn dot_stuffs_lines_and_normalizes_line_endings() {
+        assert_eq!(dot_stuff("a\n.b\r\n..c\nd"), "a\r\n..b\r\n...c\r\nd");
+    }
+
+    #[test]
+    fn rejects_addresses_that_could_inject_commands() {
+        assert!(is_valid_address("alice@example.org"));
+
+        for address in [
+            "alice",
+            "@example.org",
+            "alice@",
+            "a@b@c",
+            "alice@example.org>\r\nRCPT TO:<eve@example.org",
+            "alice @example.org",
+            "<alice@example.org>",
+        ] {
+            assert!(!is_valid_address(address), "{}", address);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::domain::user::password_policy::BreachedPasswords;
+use log::warn;
+use sha1::{Digest, Sha1};
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+const DIGEST_LEN: usize = 20;
+/// Length in hex of the hash prefix naming a range file.
+const PREFIX_LEN: usize = 5;
+
+/// Breached passwords read from local files, so nothing is sent anywhere.
+///
+/// A file holds one entry per line, either a SHA-1 hash in hex, optionally followed by
+/// `:count` as in the Pwned Passwords dumps, or a password in plain text. Its SHA-1 digests
+/// are kept in memory, 20 bytes each: meant for curated lists rather than complete dumps.
+///
+/// A directory holds k-anonymity range files instead: each is named after the first five hex
+/// digits of the hashes, with an optional `.txt` extension, and lists the remaining digits.
+/// Only the range file matching a password is read, when that password is looked up, so a
+/// complete dump can be used.
+#[derive(Clone, Default)]
+pub struct FileBreachList {
+    source: Source,
+}
+
+#[derive(Clone)]
+enum Source {
+    Digests(Arc<Vec<[u8; DIGEST_LEN]>>),
+    Ranges(Arc<PathBuf>),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Digests(Arc::default())
+    }
+}
+
+impl FileBreachList {
+    /// A list nothing is breached in.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if path.is_dir() {
+            // Fails early on a directory that cannot be listed.
+            fs::read_dir(path)?;
+
+            return Ok(Self {
+                source: Source::Ranges(Arc::new(path.to_path_buf())),
+            });
+        }
+
+        let mut digests: Vec<[u8; DIGEST_LEN]> = Vec::new();
+
+        for line in fs::read_to_string(path)?.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            digests.push(parse_digest(hash_part(line)).unwrap_or_else(|| digest(line)));
+        }
+
+        digests.sort_unstable();
+        digests.dedup();
+
+        Ok(Self {
+            source: Source::Digests(Arc::new(digests)),
+        })
+    }
+
+    /// How many breached passwords were loaded, unknown for range files as they are read on
+    /// lookup.
+    pub fn loaded(&self) -> Option<usize> {
+        match &self.source {
+            Source::Digests(digests) => Some(digests.len()),
+            Source::Ranges(_) => None,
+        }
+    }
+}
+
+impl BreachedPasswords for FileBreachList {
+    fn contains(&self, password: &str) -> bool {
+        match &self.source {
+            Source::Digests(digests) => digests.binary_search(&digest(password)).is_ok(),
+            Source::Ranges(directory) => in_range(directory, &hex::encode_upper(digest(password))),
+        }
+    }
+}
+
+/// Looks `hash` up in the range file named after its prefix. A missing range file holds
+/// nothing, one that cannot be read is logged and treated the same, rather than refusing
+/// every password.
+fn in_range(directory: &Path, hash: &str) -> bool {
+    let (prefix, suffix) = hash.split_at(PREFIX_LEN);
+    let candidates = [prefix.to_owned(), prefix.to_ascii_lowercase()]
+        .into_iter()
+        .flat_map(|name| [format!("{}.txt", name), name]);
+
+    for name in candidates {
+        match fs::read_to_string(directory.join(name)) {
+            Ok(range) => {
+                return range
+                    .lines()
+                    .any(|line| hash_part(line).eq_ignore_ascii_case(suffix));
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => continue,
+            Err(error) => {
+                warn!(
+                    "Cannot read the breached password range {}: {}",
+                    prefix, error
+                );
+                return false;
+            }
+        }
+    }
+
+    false
+}
+
+/// The line without its `:count` suffix.
+fn hash_part(line: &str) -> &str {
+    line.split(':').next().unwrap_or_default().trim()
+}
+
+fn parse_digest(value: &str) -> Option<[u8; DIGEST_LEN]> {
+    let mut digest: [u8; DIGEST_LEN] = [0; DIGEST_LEN];
+
+    hex::decode_to_slice(value, &mut digest).ok()?;
+
+    Some(digest)
+}
+
+fn digest(password: &str) -> [u8; DIGEST_LEN] {
+    Sha1::digest(password.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn looks_passwords_up_in_their_range_file() {
+        let directory: PathBuf = std::env::temp_dir().join(Uuid::now_v7().to_string());
+        fs::create_dir(&directory).unwrap();
+        // SHA-1 of "password" is 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8.
+        fs::write(
+            directory.join("5BAA6.txt"),
+            "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:52256179\r\n",
+        )
+        .unwrap();
+
+        let list: FileBreachList = FileBreachList::load(&directory).unwrap();
+        // Written after loading, so it can only be seen if ranges are read on lookup.
+        fs::write(
+            directory.join("7c4a8"),
+            "d09ca3762af61e59520943dc26494f8941b\n",
+        )
+        .unwrap();
+
+        assert!(list.contains("password"));
+        assert!(list.contains("123456"));
+        assert!(!list.contains("correct horse battery staple"));
+        assert_eq!(list.loaded(), None);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
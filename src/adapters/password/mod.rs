@@ -0,0 +1 @@
+pub mod breach_list;
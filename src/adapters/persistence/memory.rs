@@ -0,0 +1,1043 @@
+//! In-memory repositories for tests that exercise adapters without a database.
+
+use crate::{
+    application::security::{
+        login_attempt_store::{LoginAttemptStore, LoginAttempts},
+        revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditEntry, log::AuditLog, query::AuditQuery},
+        errors::repository::RepositoryError,
+        federation::{entity::ExternalIdentity, repository::ExternalIdentityRepository},
+        group::{
+            entity::{Group, GroupName},
+            member::GroupMember,
+            repository::GroupRepository,
+        },
+        organization::{
+            entity::{Organization, OrganizationSlug},
+            membership::Membership,
+            repository::OrganizationRepository,
+        },
+        passkey::{entity::Passkey, repository::PasskeyRepository, verifier::PasskeyChallenge},
+        role::{
+            entity::{Role, RoleName},
+            permission::Permission,
+            repository::RoleRepository,
+        },
+        session::{entity::Session, repository::SessionRepository},
+        user::{
+            entity::{User, UserStatus},
+            password_hasher::{HashError, PasswordHasher, PasswordMatch},
+            patch::UserPatch,
+            query::{Pagination, SortDirection, UserFilter, UserPage, UserQuery, UserSortField},
+            repository::UserRepository,
+            value_objects::{
+                email::Email, name::Name, password_hash::PasswordHash, username::Username,
+            },
+        },
+    },
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+use uuid::Uuid;
+
+#[derive(Clone, Default)]
+pub struct InMemoryUserRepository {
+    users: Arc<Mutex<Vec<User>>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn with(users: Vec<User>) -> Self {
+        Self {
+            users: Arc::new(Mutex::new(users)),
+        }
+    }
+
+    /// Every stored user, deleted ones included.
+    pub fn all(&self) -> Vec<User> {
+        self.users.lock().unwrap().clone()
+    }
+
+    fn find(&self, predicate: impl Fn(&User) -> bool) -> Option<User> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|user| predicate(user))
+            .cloned()
+    }
+
+    /// Whether the user is the only active administrator of their home organization.
+    /// Memberships are not kept here, so they administer no other organization.
+    fn is_last_administrator(&self, user: &User) -> bool {
+        let administers = |other: &User| {
+            other.organization_id == user.organization_id
+                && other.is_active()
+                && other.role.is_administrator()
+                && other.deleted_at.is_none()
+        };
+
+        administers(user)
+            && !self
+                .users
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|other| other.id != user.id && administers(other))
+    }
+
+    fn modify<T>(&self, id: &Uuid, change: impl FnOnce(&mut User) -> T) -> Option<T> {
+        self.users
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|user| &user.id == id)
+            .map(change)
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<User>, RepositoryError> {
+        Ok(self.find(|user| &user.id == id && user.deleted_at.is_none()))
+    }
+
+    async fn find_in_organization(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError> {
+        Ok(self.find(|user| {
+            &user.id == id && &user.organization_id == organization_id && user.deleted_at.is_none()
+        }))
+    }
+
+    async fn find_by_username(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<Option<User>, RepositoryError> {
+        Ok(self.find(|user| {
+            &user.organization_id == organization_id
+                && &user.username == username
+                && user.deleted_at.is_none()
+        }))
+    }
+
+    async fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError> {
+        Ok(self.find(|user| user.email.as_ref() == Some(email) && user.deleted_at.is_none()))
+    }
+
+    async fn is_username_taken(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self
+            .find(|user| &user.organization_id == organization_id && &user.username == username)
+            .is_some())
+    }
+
+    async fn is_email_taken(&self, email: &Email) -> Result<bool, RepositoryError> {
+        Ok(self
+            .find(|user| user.email.as_ref() == Some(email))
+            .is_some())
+    }
+
+    async fn has_users_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError> {
+        Ok(self.find(|user| &user.role == role).is_some())
+    }
+
+    async fn find_deleted(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError> {
+        Ok(self.find(|user| {
+            &user.id == id && &user.organization_id == organization_id && user.deleted_at.is_some()
+        }))
+    }
+
+    async fn find_purgeable(
+        &self,
+        deleted_before: u64,
+        limit: u64,
+    ) -> Result<Vec<User>, RepositoryError> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| user.deleted_at.is_some_and(|at| at < deleted_before))
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn list(&self, query: &UserQuery) -> Result<UserPage, RepositoryError> {
+        let filter: &UserFilter = &query.filter;
+        let search: Option<String> = filter.search.as_ref().map(|search| search.to_lowercase());
+        let sort_value = |user: &User| match query.sort.field {
+            UserSortField::Username => user.username.as_str().to_owned(),
+            UserSortField::Name => user.name.as_str().to_owned(),
+            UserSortField::CreatedAt => String::new(),
+        };
+
+        let mut users: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| user.deleted_at.is_some() == filter.deleted)
+            .filter(|user| {
+                filter
+                    .organization_id
+                    .is_none_or(|organization_id| user.organization_id == organization_id)
+            })
+            .filter(|user| {
+                filter
+                    .status
+                    .as_ref()
+                    .is_none_or(|status| &user.status == status)
+            })
+            .filter(|user| filter.role.as_ref().is_none_or(|role| &user.role == role))
+            .filter(|user| {
+                search.as_ref().is_none_or(|search| {
+                    user.username.as_str().to_lowercase().starts_with(search)
+                        || user.name.as_str().to_lowercase().starts_with(search)
+                })
+            })
+            .cloned()
+            .collect();
+        users.sort_by(|a, b| {
+            let order = sort_value(a).cmp(&sort_value(b)).then(a.id.cmp(&b.id));
+
+            match query.sort.direction {
+                SortDirection::Ascending => order,
+                SortDirection::Descending => order.reverse(),
+            }
+        });
+
+        let total: u64 = users.len() as u64;
+        let users: Vec<User> = match &query.pagination {
+            Pagination::Offset { offset, limit } => users
+                .into_iter()
+                .skip(*offset as usize)
+                .take(*limit as usize)
+                .collect(),
+            Pagination::Cursor { after, limit } => users
+                .into_iter()
+                .filter(|user| {
+                    after.as_ref().is_none_or(|cursor| {
+                        let order = sort_value(user)
+                            .cmp(&cursor.value)
+                            .then(user.id.cmp(&cursor.id));
+
+                        match query.sort.direction {
+                            SortDirection::Ascending => order.is_gt(),
+                            SortDirection::Descending => order.is_lt(),
+                        }
+                    })
+                })
+                .take(*limit as usize)
+                .collect(),
+        };
+
+        Ok(UserPage { users, total })
+    }
+
+    async fn create(&self, user: User) -> Result<User, RepositoryError> {
+        self.users.lock().unwrap().push(user.clone());
+
+        Ok(user)
+    }
+
+    async fn update(&self, id: &Uuid, patch: UserPatch) -> Result<User, RepositoryError> {
+        if self.find_by_id(id).await?.is_none() {
+            return Err(RepositoryError::InvariantViolation);
+        }
+
+        self.modify(id, |user| {
+            if let Some(name) = patch.name {
+                user.name = Name::new(name).map_err(|_| RepositoryError::InvariantViolation)?;
+            }
+            if let Some(username) = patch.username {
+                user.username = username;
+            }
+            if let Some(password_hash) = patch.password_hash {
+                user.password_hash = password_hash;
+            }
+            if let Some(role) = patch.role {
+                user.role = role;
+            }
+            if let Some(status) = patch.status {
+                user.status = status;
+            }
+            if let Some(email) = patch.email {
+                user.email = Some(email);
+                user.email_verified_at = None;
+            }
+
+            Ok(user.clone())
+        })
+        .ok_or(RepositoryError::InvariantViolation)?
+    }
+
+    async fn update_keeping_administrators(
+        &self,
+        id: &Uuid,
+        patch: UserPatch,
+    ) -> Result<Option<User>, RepositoryError> {
+        let user: User = self
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::InvariantViolation)?;
+        let demotes: bool = patch
+            .role
+            .as_ref()
+            .is_some_and(|role| !role.is_administrator())
+            || patch
+                .status
+                .as_ref()
+                .is_some_and(|status| status != &UserStatus::Active);
+
+        if demotes && self.is_last_administrator(&user) {
+            return Ok(None);
+        }
+
+        self.update(id, patch).await.map(Some)
+    }
+
+    async fn rehash_password(
+        &self,
+        id: &Uuid,
+        password_hash: &PasswordHash,
+    ) -> Result<(), RepositoryError> {
+        self.modify(id, |user| user.password_hash = password_hash.clone());
+
+        Ok(())
+    }
+
+    async fn mark_email_verified(
+        &self,
+        id: &Uuid,
+        email: &Email,
+        verified_at: u64,
+    ) -> Result<bool, RepositoryError> {
+        Ok(self
+            .modify(id, |user| {
+                let matches: bool = user.email.as_ref() == Some(email);
+
+                if matches {
+                    user.email_verified_at = Some(verified_at);
+                }
+
+                matches
+            })
+            .unwrap_or(false))
+    }
+
+    async fn soft_delete(&self, id: &Uuid, deleted_at: u64) -> Result<bool, RepositoryError> {
+        let Some(user) = self.find_by_id(id).await? else {
+            return Ok(true);
+        };
+
+        if self.is_last_administrator(&user) {
+            return Ok(false);
+        }
+
+        self.modify(id, |user| user.deleted_at = Some(deleted_at));
+
+        Ok(true)
+    }
+
+    async fn restore(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        Ok(self
+            .modify(id, |user| user.deleted_at.take().is_some())
+            .unwrap_or(false))
+    }
+
+    async fn anonymize(&self, id: &Uuid, _purged_at: u64) -> Result<(), RepositoryError> {
+        self.modify(id, |user| {
+            user.email = None;
+            user.email_verified_at = None;
+        });
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError> {
+        self.users.lock().unwrap().retain(|user| &user.id != id);
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryExternalIdentityRepository {
+    identities: Arc<Mutex<Vec<ExternalIdentity>>>,
+}
+
+impl InMemoryExternalIdentityRepository {
+    /// The user ids linked to `provider`, by subject.
+    pub fn links(&self, provider: &str) -> HashMap<String, Uuid> {
+        self.identities
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|identity| identity.provider == provider)
+            .map(|identity| (identity.subject.clone(), identity.user_id))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl ExternalIdentityRepository for InMemoryExternalIdentityRepository {
+    async fn find(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<ExternalIdentity>, RepositoryError> {
+        Ok(self
+            .identities
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|identity| identity.provider == provider && identity.subject == subject)
+            .map(|identity| {
+                ExternalIdentity::new(
+                    identity.id,
+                    identity.user_id,
+                    identity.provider.clone(),
+                    identity.subject.clone(),
+                )
+            }))
+    }
+
+    async fn create(
+        &self,
+        identity: ExternalIdentity,
+    ) -> Result<ExternalIdentity, RepositoryError> {
+        let mut identities = self.identities.lock().unwrap();
+
+        if identities
+            .iter()
+            .any(|i| i.provider == identity.provider && i.subject == identity.subject)
+        {
+            return Err(RepositoryError::InvariantViolation);
+        }
+
+        identities.push(ExternalIdentity::new(
+            identity.id,
+            identity.user_id,
+            identity.provider.clone(),
+            identity.subject.clone(),
+        ));
+
+        Ok(identity)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryPasskeyRepository {
+    passkeys: Arc<Mutex<Vec<Passkey>>>,
+    challenges: Arc<Mutex<HashSet<String>>>,
+}
+
+impl InMemoryPasskeyRepository {
+    pub fn all(&self) -> Vec<Passkey> {
+        self.passkeys.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl PasskeyRepository for InMemoryPasskeyRepository {
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, RepositoryError> {
+        Ok(self
+            .passkeys
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|passkey| passkey.credential_id == credential_id)
+            .cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Passkey>, RepositoryError> {
+        Ok(self
+            .passkeys
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|passkey| &passkey.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, passkey: Passkey) -> Result<Passkey, RepositoryError> {
+        self.passkeys.lock().unwrap().push(passkey.clone());
+
+        Ok(passkey)
+    }
+
+    async fn update_sign_count(&self, id: &Uuid, sign_count: u32) -> Result<(), RepositoryError> {
+        if let Some(passkey) = self
+            .passkeys
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|passkey| &passkey.id == id)
+        {
+            passkey.sign_count = sign_count;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError> {
+        self.passkeys
+            .lock()
+            .unwrap()
+            .retain(|passkey| &passkey.id != id);
+
+        Ok(())
+    }
+
+    async fn create_challenge(&self, challenge: &PasskeyChallenge) -> Result<(), RepositoryError> {
+        self.challenges
+            .lock()
+            .unwrap()
+            .insert(challenge.value.clone());
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, challenge: &str) -> Result<bool, RepositoryError> {
+        Ok(self.challenges.lock().unwrap().remove(challenge))
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryRoleRepository {
+    roles: Arc<Mutex<Vec<Role>>>,
+}
+
+impl InMemoryRoleRepository {
+    pub fn with(roles: Vec<Role>) -> Self {
+        Self {
+            roles: Arc::new(Mutex::new(roles)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleRepository for InMemoryRoleRepository {
+    async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError> {
+        Ok(self
+            .roles
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|role| &role.name == name)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Role>, RepositoryError> {
+        Ok(self.roles.lock().unwrap().clone())
+    }
+
+    async fn create(&self, role: Role) -> Result<Role, RepositoryError> {
+        self.roles.lock().unwrap().push(role.clone());
+
+        Ok(role)
+    }
+
+    async fn update(
+        &self,
+        name: &RoleName,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Role, RepositoryError> {
+        let mut roles = self.roles.lock().unwrap();
+        let role: &mut Role = roles
+            .iter_mut()
+            .find(|role| &role.name == name)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        role.description = description;
+        role.permissions = permissions;
+
+        Ok(role.clone())
+    }
+
+    async fn delete(&self, name: &RoleName) -> Result<bool, RepositoryError> {
+        let mut roles = self.roles.lock().unwrap();
+        let count: usize = roles.len();
+        roles.retain(|role| &role.name != name);
+
+        Ok(roles.len() < count)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryGroupRepository {
+    groups: Arc<Mutex<Vec<Group>>>,
+    members: Arc<Mutex<Vec<GroupMember>>>,
+}
+
+#[async_trait::async_trait]
+impl GroupRepository for InMemoryGroupRepository {
+    async fn find_by_name(
+        &self,
+        organization_id: &Uuid,
+        name: &GroupName,
+    ) -> Result<Option<Group>, RepositoryError> {
+        Ok(self
+            .groups
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|group| &group.organization_id == organization_id && &group.name == name)
+            .cloned())
+    }
+
+    async fn find_all(&self, organization_id: &Uuid) -> Result<Vec<Group>, RepositoryError> {
+        Ok(self
+            .groups
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|group| &group.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_member(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Vec<Group>, RepositoryError> {
+        let group_ids: Vec<Uuid> = self
+            .members
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|member| {
+                &member.organization_id == organization_id && &member.user_id == user_id
+            })
+            .map(|member| member.group_id)
+            .collect();
+
+        Ok(self
+            .groups
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|group| group_ids.contains(&group.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, group: Group) -> Result<Group, RepositoryError> {
+        self.groups.lock().unwrap().push(group.clone());
+
+        Ok(group)
+    }
+
+    async fn update(
+        &self,
+        id: &Uuid,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Group, RepositoryError> {
+        let mut groups = self.groups.lock().unwrap();
+        let group: &mut Group = groups
+            .iter_mut()
+            .find(|group| &group.id == id)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        group.description = description;
+        group.permissions = permissions;
+
+        Ok(group.clone())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        self.members
+            .lock()
+            .unwrap()
+            .retain(|member| &member.group_id != id);
+
+        let mut groups = self.groups.lock().unwrap();
+        let count: usize = groups.len();
+        groups.retain(|group| &group.id != id);
+
+        Ok(groups.len() < count)
+    }
+
+    async fn find_members(&self, group_id: &Uuid) -> Result<Vec<GroupMember>, RepositoryError> {
+        Ok(self
+            .members
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|member| &member.group_id == group_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn add_member(&self, member: GroupMember) -> Result<GroupMember, RepositoryError> {
+        let mut members = self.members.lock().unwrap();
+
+        if let Some(existing) = members
+            .iter()
+            .find(|m| m.group_id == member.group_id && m.user_id == member.user_id)
+        {
+            return Ok(existing.clone());
+        }
+
+        members.push(member.clone());
+
+        Ok(member)
+    }
+
+    async fn remove_member(
+        &self,
+        group_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let mut members = self.members.lock().unwrap();
+        let count: usize = members.len();
+        members.retain(|member| &member.group_id != group_id || &member.user_id != user_id);
+
+        Ok(members.len() < count)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemorySessionRepository {
+    sessions: Arc<Mutex<Vec<Session>>>,
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for InMemorySessionRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Session>, RepositoryError> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|session| &session.id == id)
+            .cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Session>, RepositoryError> {
+        let mut sessions: Vec<Session> = self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|session| &session.user_id == user_id)
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.last_used_at));
+
+        Ok(sessions)
+    }
+
+    async fn save(&self, session: Session) -> Result<Session, RepositoryError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|existing| existing.id != session.id);
+        sessions.push(session.clone());
+
+        Ok(session)
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let count: usize = sessions.len();
+        sessions.retain(|session| &session.id != id);
+
+        Ok(sessions.len() < count)
+    }
+
+    async fn delete_by_user_id(
+        &self,
+        user_id: &Uuid,
+        except: Option<&Uuid>,
+    ) -> Result<(), RepositoryError> {
+        self.sessions.lock().unwrap().retain(|session| {
+            &session.user_id != user_id || except.is_some_and(|id| &session.id == id)
+        });
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, user_id: &Uuid, now: u64) -> Result<(), RepositoryError> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .retain(|session| &session.user_id != user_id || !session.is_expired(now));
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryOrganizationRepository {
+    organizations: Arc<Mutex<Vec<Organization>>>,
+    memberships: Arc<Mutex<Vec<Membership>>>,
+}
+
+#[async_trait::async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> Result<Option<Organization>, RepositoryError> {
+        Ok(self
+            .organizations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|organization| &organization.slug == slug)
+            .cloned())
+    }
+
+    async fn create(
+        &self,
+        organization: Organization,
+        founder: Membership,
+    ) -> Result<Organization, RepositoryError> {
+        self.organizations
+            .lock()
+            .unwrap()
+            .push(organization.clone());
+        self.memberships.lock().unwrap().push(founder);
+
+        Ok(organization)
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<Membership>, RepositoryError> {
+        Ok(self
+            .memberships
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|membership| {
+                &membership.organization_id == organization_id && &membership.user_id == user_id
+            })
+            .cloned())
+    }
+
+    async fn find_by_member(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<(Organization, Membership)>, RepositoryError> {
+        let organizations = self.organizations.lock().unwrap();
+        let mut found: Vec<(Organization, Membership)> = self
+            .memberships
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|membership| &membership.user_id == user_id)
+            .filter_map(|membership| {
+                organizations
+                    .iter()
+                    .find(|organization| organization.id == membership.organization_id)
+                    .map(|organization| (organization.clone(), membership.clone()))
+            })
+            .collect();
+        found.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+
+        Ok(found)
+    }
+
+    async fn find_members(
+        &self,
+        organization_id: &Uuid,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let mut members: Vec<Membership> = self
+            .memberships
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|membership| &membership.organization_id == organization_id)
+            .cloned()
+            .collect();
+        members.sort_by_key(|membership| membership.joined_at);
+
+        Ok(members)
+    }
+
+    async fn has_members_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError> {
+        Ok(self
+            .memberships
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|membership| &membership.role == role))
+    }
+
+    async fn save_membership(&self, membership: Membership) -> Result<Membership, RepositoryError> {
+        let mut memberships = self.memberships.lock().unwrap();
+        memberships.retain(|existing| {
+            existing.organization_id != membership.organization_id
+                || existing.user_id != membership.user_id
+        });
+        memberships.push(membership.clone());
+
+        Ok(membership)
+    }
+
+    async fn delete_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let mut memberships = self.memberships.lock().unwrap();
+        let count: usize = memberships.len();
+        memberships.retain(|membership| {
+            &membership.organization_id != organization_id || &membership.user_id != user_id
+        });
+
+        Ok(memberships.len() < count)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryRevocationStore {
+    revoked: Arc<Mutex<HashSet<Uuid>>>,
+    cutoffs: Arc<Mutex<HashMap<Uuid, u64>>>,
+}
+
+#[async_trait::async_trait]
+impl RevocationStore for InMemoryRevocationStore {
+    async fn revoke(&self, token_id: &Uuid, _expires_at: u64) -> Result<(), RepositoryError> {
+        self.revoked.lock().unwrap().insert(*token_id);
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, token_id: &Uuid) -> Result<bool, RepositoryError> {
+        Ok(self.revoked.lock().unwrap().contains(token_id))
+    }
+
+    async fn revoke_all(&self, user_id: &Uuid, not_before: u64) -> Result<(), RepositoryError> {
+        self.cutoffs.lock().unwrap().insert(*user_id, not_before);
+
+        Ok(())
+    }
+
+    async fn not_before(&self, user_id: &Uuid) -> Result<Option<u64>, RepositoryError> {
+        Ok(self.cutoffs.lock().unwrap().get(user_id).copied())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryAuditLog {
+    entries: Arc<Mutex<Vec<AuditEntry>>>,
+}
+
+#[async_trait::async_trait]
+impl AuditLog for InMemoryAuditLog {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryError> {
+        self.entries.lock().unwrap().push(entry);
+
+        Ok(())
+    }
+
+    async fn list(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, RepositoryError> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|entry| entry.organization_id == Some(query.organization_id))
+            .filter(|entry| query.actor_id.is_none() || entry.actor_id == query.actor_id)
+            .filter(|entry| query.action.is_none_or(|action| entry.action == action))
+            .filter(|entry| query.target.is_none() || entry.target == query.target)
+            .filter(|entry| query.since.is_none_or(|since| entry.occurred_at >= since))
+            .filter(|entry| query.until.is_none_or(|until| entry.occurred_at < until))
+            .filter(|entry| query.before.is_none_or(|before| entry.id < before))
+            .take(query.limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryLoginAttemptStore {
+    attempts: Arc<Mutex<HashMap<String, (u32, u64)>>>,
+}
+
+#[async_trait::async_trait]
+impl LoginAttemptStore for InMemoryLoginAttemptStore {
+    async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, RepositoryError> {
+        Ok(self
+            .attempts
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|&(failures, last_failure_at)| LoginAttempts {
+                failures,
+                last_failure_at,
+            }))
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: u64,
+        window: u64,
+    ) -> Result<LoginAttempts, RepositoryError> {
+        let mut attempts = self.attempts.lock().unwrap();
+        let (failures, last_failure_at) = attempts.entry(key.to_owned()).or_insert((0, now));
+
+        if last_failure_at.saturating_add(window) <= now {
+            *failures = 0;
+        }
+
+        *failures += 1;
+        *last_failure_at = now;
+
+        Ok(LoginAttempts {
+            failures: *failures,
+            last_failure_at: now,
+        })
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), RepositoryError> {
+        self.attempts.lock().unwrap().remove(key);
+
+        Ok(())
+    }
+}
+
+/// Stores passwords as they are, hashing is not what these tests are about.
+#[derive(Clone, Default)]
+pub struct PlainPasswordHasher;
+
+impl PasswordHasher for PlainPasswordHasher {
+    fn hash(&self, plain: &str) -> String {
+        format!("plain:{}", plain)
+    }
+
+    fn verify(&self, plain: &str, hash: &str) -> Result<PasswordMatch, HashError> {
+        match hash.strip_prefix("plain:") {
+            Some(stored) if stored == plain => Ok(PasswordMatch::Match),
+            Some(_) => Ok(PasswordMatch::Mismatch),
+            None => Err(HashError::Malformed),
+        }
+    }
+}
@@ -1 +1,4 @@
 pub mod postgres;
+
+#[cfg(test)]
+pub mod memory;
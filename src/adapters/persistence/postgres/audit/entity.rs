@@ -0,0 +1,21 @@
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub organization_id: Option<Uuid>,
+    pub actor_id: Option<Uuid>,
+    pub actor_username: Option<String>,
+    pub action: String,
+    pub target: Option<String>,
+    pub ip_address: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub changes: Json,
+    pub reason: Option<String>,
+    pub occurred_at: DateTimeWithTimeZone,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
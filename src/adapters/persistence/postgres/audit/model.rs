@@ -0,0 +1,91 @@
+use super::entity;
+use crate::domain::{
+    audit::entry::{AuditChange, AuditEntry},
+    errors::repository::RepositoryError,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, prelude::DateTimeWithTimeZone};
+use serde_json::{Value, json};
+
+fn to_timestamp(value: DateTimeWithTimeZone) -> Result<u64, RepositoryError> {
+    u64::try_from(value.timestamp()).map_err(|_| RepositoryError::InvariantViolation)
+}
+
+fn from_timestamp(value: u64) -> Result<DateTimeWithTimeZone, RepositoryError> {
+    let value: DateTime<Utc> =
+        DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+    Ok(value.into())
+}
+
+/// Changes are stored as `[{"field": ..., "before": ..., "after": ...}]`.
+fn to_changes(value: &Value) -> Result<Vec<AuditChange>, RepositoryError> {
+    let text = |change: &Value, key: &str| change.get(key).and_then(Value::as_str).map(Into::into);
+
+    value
+        .as_array()
+        .ok_or(RepositoryError::InvariantViolation)?
+        .iter()
+        .map(|change| {
+            Ok(AuditChange {
+                field: text(change, "field").ok_or(RepositoryError::InvariantViolation)?,
+                before: text(change, "before"),
+                after: text(change, "after"),
+            })
+        })
+        .collect()
+}
+
+fn from_changes(changes: &[AuditChange]) -> Value {
+    changes
+        .iter()
+        .map(|change| {
+            json!({
+                "field": change.field,
+                "before": change.before,
+                "after": change.after,
+            })
+        })
+        .collect()
+}
+
+impl TryFrom<entity::Model> for AuditEntry {
+    type Error = RepositoryError;
+
+    fn try_from(model: entity::Model) -> Result<Self, RepositoryError> {
+        Ok(AuditEntry {
+            id: model.id,
+            organization_id: model.organization_id,
+            actor_id: model.actor_id,
+            actor_username: model.actor_username,
+            action: model
+                .action
+                .parse()
+                .map_err(|_| RepositoryError::InvariantViolation)?,
+            target: model.target,
+            ip_address: model.ip_address.and_then(|ip| ip.parse().ok()),
+            changes: to_changes(&model.changes)?,
+            reason: model.reason,
+            occurred_at: to_timestamp(model.occurred_at)?,
+        })
+    }
+}
+
+impl TryFrom<AuditEntry> for entity::ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(entry: AuditEntry) -> Result<Self, RepositoryError> {
+        Ok(entity::ActiveModel {
+            id: Set(entry.id),
+            organization_id: Set(entry.organization_id),
+            actor_id: Set(entry.actor_id),
+            actor_username: Set(entry.actor_username),
+            action: Set(entry.action.as_str().to_owned()),
+            target: Set(entry.target),
+            ip_address: Set(entry.ip_address.map(|ip| ip.to_string())),
+            changes: Set(from_changes(&entry.changes)),
+            reason: Set(entry.reason),
+            occurred_at: Set(from_timestamp(entry.occurred_at)?),
+        })
+    }
+}
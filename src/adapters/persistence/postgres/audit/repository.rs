@@ -0,0 +1,74 @@
+use super::entity::{self, Column, Entity as AuditLogEntity};
+use crate::domain::{
+    audit::{entry::AuditEntry, log::AuditLog, query::AuditQuery},
+    errors::repository::RepositoryError,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select,
+};
+
+#[derive(Clone)]
+pub struct PostgresAuditLog {
+    db: DatabaseConnection,
+}
+
+impl PostgresAuditLog {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn at(value: u64) -> Result<DateTime<Utc>, RepositoryError> {
+    DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)
+}
+
+#[async_trait::async_trait]
+impl AuditLog for PostgresAuditLog {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryError> {
+        entity::ActiveModel::try_from(entry)?
+            .insert(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Ids are UUIDv7, so ordering by id lists the newest entries first.
+    async fn list(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, RepositoryError> {
+        let mut select: Select<AuditLogEntity> =
+            AuditLogEntity::find().filter(Column::OrganizationId.eq(query.organization_id));
+
+        if let Some(actor_id) = query.actor_id {
+            select = select.filter(Column::ActorId.eq(actor_id));
+        }
+
+        if let Some(action) = query.action {
+            select = select.filter(Column::Action.eq(action.as_str()));
+        }
+
+        if let Some(target) = &query.target {
+            select = select.filter(Column::Target.eq(target.as_str()));
+        }
+
+        if let Some(since) = query.since {
+            select = select.filter(Column::OccurredAt.gte(at(since)?));
+        }
+
+        if let Some(until) = query.until {
+            select = select.filter(Column::OccurredAt.lt(at(until)?));
+        }
+
+        if let Some(before) = query.before {
+            select = select.filter(Column::Id.lt(before));
+        }
+
+        let models: Vec<entity::Model> = select
+            .order_by_desc(Column::Id)
+            .limit(query.limit)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(AuditEntry::try_from).collect()
+    }
+}
@@ -0,0 +1,16 @@
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_avatars")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    /// The side of the square image in pixels.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub size: i16,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,37 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{
+    avatar::entity::{AvatarImage, AvatarSize},
+    errors::repository::RepositoryError,
+};
+use sea_orm::ActiveValue::Set;
+use uuid::Uuid;
+
+pub(super) fn size_to_column(size: AvatarSize) -> i16 {
+    size.pixels() as i16
+}
+
+impl TryFrom<Model> for AvatarImage {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        let size: AvatarSize = AvatarSize::ALL
+            .into_iter()
+            .find(|size| size_to_column(*size) == model.size)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        Ok(AvatarImage {
+            size,
+            content_type: model.content_type,
+            data: model.data,
+        })
+    }
+}
+
+pub(super) fn to_active_model(user_id: &Uuid, image: AvatarImage) -> ActiveModel {
+    ActiveModel {
+        user_id: Set(user_id.to_owned()),
+        size: Set(size_to_column(image.size)),
+        content_type: Set(image.content_type),
+        data: Set(image.data),
+    }
+}
@@ -0,0 +1,79 @@
+use super::{
+    entity::{ActiveModel, Column, Entity as AvatarEntity, Model},
+    model::{size_to_column, to_active_model},
+};
+use crate::domain::{
+    avatar::{
+        entity::{AvatarImage, AvatarSize},
+        repository::AvatarRepository,
+    },
+    errors::repository::RepositoryError,
+};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresAvatarRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresAvatarRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl AvatarRepository for PostgresAvatarRepository {
+    async fn find(
+        &self,
+        user_id: &Uuid,
+        size: AvatarSize,
+    ) -> Result<Option<AvatarImage>, RepositoryError> {
+        let model: Option<Model> =
+            AvatarEntity::find_by_id((user_id.to_owned(), size_to_column(size)))
+                .one(&self.db)
+                .await?;
+
+        model.map(AvatarImage::try_from).transpose()
+    }
+
+    async fn replace(
+        &self,
+        user_id: &Uuid,
+        images: Vec<AvatarImage>,
+    ) -> Result<(), RepositoryError> {
+        let models: Vec<ActiveModel> = images
+            .into_iter()
+            .map(|image| to_active_model(user_id, image))
+            .collect();
+
+        // Readers never see a mix of the old and the new avatar.
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        AvatarEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .exec(&transaction)
+            .await?;
+
+        if !models.is_empty() {
+            AvatarEntity::insert_many(models).exec(&transaction).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError> {
+        AvatarEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
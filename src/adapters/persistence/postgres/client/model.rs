@@ -0,0 +1,28 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{
+    client::entity::Client, errors::repository::RepositoryError,
+    user::value_objects::password_hash::PasswordHash,
+};
+use sea_orm::ActiveValue::Set;
+
+impl TryFrom<Model> for Client {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        let secret_hash: PasswordHash = PasswordHash::new(model.secret_hash)?;
+        let scopes: Vec<String> = model.scope.split_whitespace().map(String::from).collect();
+
+        Ok(Client::new(model.id, model.name, secret_hash, scopes))
+    }
+}
+
+impl From<Client> for ActiveModel {
+    fn from(client: Client) -> Self {
+        ActiveModel {
+            id: Set(client.id),
+            name: Set(client.name),
+            secret_hash: Set(client.secret_hash.as_str().into()),
+            scope: Set(client.scopes.join(" ")),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::entity::{ActiveModel, Column, Entity as ClientEntity, Model};
+use crate::domain::{
+    client::{entity::Client, repository::ClientRepository},
+    errors::repository::RepositoryError,
+};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresClientRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresClientRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl ClientRepository for PostgresClientRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Client>, RepositoryError> {
+        let model: Option<Model> = ClientEntity::find()
+            .filter(Column::Id.eq(id.to_owned()))
+            .one(&self.db)
+            .await?;
+
+        match model {
+            Some(m) => Ok(Some(Client::try_from(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, client: Client) -> Result<Client, RepositoryError> {
+        let active: ActiveModel = client.into();
+
+        let model: Model = active.insert(&self.db).await?;
+
+        Client::try_from(model)
+    }
+}
@@ -0,0 +1,45 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{
+    email_verification::entity::EmailVerificationToken,
+    errors::repository::RepositoryError,
+    user::value_objects::{email::Email, password_hash::PasswordHash},
+};
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+
+impl TryFrom<Model> for EmailVerificationToken {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        let email: Email = Email::new(model.email)?;
+        let secret_hash: PasswordHash = PasswordHash::new(model.secret_hash)
+            .map_err(|_| RepositoryError::InvariantViolation)?;
+        let expires_at: u64 = u64::try_from(model.expires_at.timestamp())
+            .map_err(|_| RepositoryError::InvariantViolation)?;
+
+        Ok(EmailVerificationToken::new(
+            model.id,
+            model.user_id,
+            email,
+            secret_hash,
+            expires_at,
+        ))
+    }
+}
+
+impl TryFrom<EmailVerificationToken> for ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(token: EmailVerificationToken) -> Result<Self, RepositoryError> {
+        let expires_at: DateTime<Utc> = DateTime::from_timestamp(token.expires_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        Ok(ActiveModel {
+            id: Set(token.id),
+            user_id: Set(token.user_id),
+            email: Set(token.email.as_str().to_owned()),
+            secret_hash: Set(token.secret_hash.as_str().to_owned()),
+            expires_at: Set(expires_at.into()),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use super::entity::{ActiveModel, Column, Entity as EmailVerificationEntity, Model};
+use crate::domain::{
+    email_verification::{entity::EmailVerificationToken, repository::EmailVerificationRepository},
+    errors::repository::RepositoryError,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait, QueryFilter,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresEmailVerificationRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresEmailVerificationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailVerificationRepository for PostgresEmailVerificationRepository {
+    async fn find_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<EmailVerificationToken>, RepositoryError> {
+        let model: Option<Model> = EmailVerificationEntity::find_by_id(id.to_owned())
+            .one(&self.db)
+            .await?;
+
+        model.map(EmailVerificationToken::try_from).transpose()
+    }
+
+    async fn create(
+        &self,
+        token: EmailVerificationToken,
+    ) -> Result<EmailVerificationToken, RepositoryError> {
+        let active: ActiveModel = token.try_into()?;
+
+        let model: Model = active.insert(&self.db).await?;
+
+        EmailVerificationToken::try_from(model)
+    }
+
+    async fn consume(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = EmailVerificationEntity::delete_by_id(id.to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError> {
+        EmailVerificationEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::federation::entity::ExternalIdentity;
+use sea_orm::ActiveValue::Set;
+
+impl From<Model> for ExternalIdentity {
+    fn from(model: Model) -> Self {
+        ExternalIdentity::new(model.id, model.user_id, model.provider, model.subject)
+    }
+}
+
+impl From<ExternalIdentity> for ActiveModel {
+    fn from(identity: ExternalIdentity) -> Self {
+        ActiveModel {
+            id: Set(identity.id),
+            user_id: Set(identity.user_id),
+            provider: Set(identity.provider),
+            subject: Set(identity.subject),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::entity::{ActiveModel, Column, Entity as ExternalIdentityEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    federation::{entity::ExternalIdentity, repository::ExternalIdentityRepository},
+};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+#[derive(Clone)]
+pub struct PostgresExternalIdentityRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresExternalIdentityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExternalIdentityRepository for PostgresExternalIdentityRepository {
+    async fn find(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<ExternalIdentity>, RepositoryError> {
+        let model: Option<Model> = ExternalIdentityEntity::find()
+            .filter(Column::Provider.eq(provider))
+            .filter(Column::Subject.eq(subject))
+            .one(&self.db)
+            .await?;
+
+        Ok(model.map(ExternalIdentity::from))
+    }
+
+    async fn create(
+        &self,
+        identity: ExternalIdentity,
+    ) -> Result<ExternalIdentity, RepositoryError> {
+        let active: ActiveModel = identity.into();
+
+        let model: Model = active.insert(&self.db).await?;
+
+        Ok(ExternalIdentity::from(model))
+    }
+}
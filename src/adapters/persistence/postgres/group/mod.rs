@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod member_entity;
+pub mod model;
+pub mod permission_entity;
+pub mod repository;
@@ -0,0 +1,98 @@
+use super::{entity, member_entity, permission_entity};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    group::{
+        entity::{Group, GroupName},
+        member::GroupMember,
+    },
+    role::{entity::Role, permission::Permission},
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, prelude::DateTimeWithTimeZone};
+use uuid::Uuid;
+
+fn to_timestamp(value: DateTimeWithTimeZone) -> Result<u64, RepositoryError> {
+    u64::try_from(value.timestamp()).map_err(|_| RepositoryError::InvariantViolation)
+}
+
+fn from_timestamp(value: u64) -> Result<DateTimeWithTimeZone, RepositoryError> {
+    let value: DateTime<Utc> =
+        DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+    Ok(value.into())
+}
+
+/// Builds a group from its row and permission rows, which may include ones of other groups.
+/// Permissions this version no longer knows are ignored.
+pub(super) fn to_group(
+    model: entity::Model,
+    permissions: &[permission_entity::Model],
+) -> Result<Group, RepositoryError> {
+    let permissions: Vec<Permission> = permissions
+        .iter()
+        .filter(|permission| permission.group_id == model.id)
+        .filter_map(|permission| permission.permission.parse().ok())
+        .collect();
+
+    Ok(Group {
+        id: model.id,
+        organization_id: model.organization_id,
+        name: GroupName::new(model.name).map_err(|_| RepositoryError::InvariantViolation)?,
+        description: model.description,
+        permissions: Role::dedup(permissions),
+        created_at: to_timestamp(model.created_at)?,
+    })
+}
+
+pub(super) fn to_permission_models(
+    group_id: &Uuid,
+    permissions: &[Permission],
+) -> Vec<permission_entity::ActiveModel> {
+    permissions
+        .iter()
+        .map(|permission| permission_entity::ActiveModel {
+            group_id: Set(group_id.to_owned()),
+            permission: Set(permission.as_str().to_owned()),
+        })
+        .collect()
+}
+
+impl TryFrom<&Group> for entity::ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(group: &Group) -> Result<Self, RepositoryError> {
+        Ok(entity::ActiveModel {
+            id: Set(group.id),
+            organization_id: Set(group.organization_id),
+            name: Set(group.name.as_str().to_owned()),
+            description: Set(group.description.clone()),
+            created_at: Set(from_timestamp(group.created_at)?),
+        })
+    }
+}
+
+impl TryFrom<member_entity::Model> for GroupMember {
+    type Error = RepositoryError;
+
+    fn try_from(model: member_entity::Model) -> Result<Self, RepositoryError> {
+        Ok(GroupMember {
+            group_id: model.group_id,
+            organization_id: model.organization_id,
+            user_id: model.user_id,
+            added_at: to_timestamp(model.added_at)?,
+        })
+    }
+}
+
+impl TryFrom<GroupMember> for member_entity::ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(member: GroupMember) -> Result<Self, RepositoryError> {
+        Ok(member_entity::ActiveModel {
+            group_id: Set(member.group_id),
+            organization_id: Set(member.organization_id),
+            user_id: Set(member.user_id),
+            added_at: Set(from_timestamp(member.added_at)?),
+        })
+    }
+}
@@ -0,0 +1,216 @@
+use super::{
+    entity::{self, Column, Entity as GroupEntity},
+    member_entity::{self, Column as MemberColumn, Entity as GroupMemberEntity},
+    model::{to_group, to_permission_models},
+    permission_entity::{self, Column as PermissionColumn, Entity as GroupPermissionEntity},
+};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    group::{
+        entity::{Group, GroupName},
+        member::GroupMember,
+        repository::GroupRepository,
+    },
+    role::permission::Permission,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DeleteResult, EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresGroupRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresGroupRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Builds the groups of the rows with their permissions, in the order of the rows.
+    async fn to_groups<C>(db: &C, models: Vec<entity::Model>) -> Result<Vec<Group>, RepositoryError>
+    where
+        C: ConnectionTrait,
+    {
+        if models.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let permissions: Vec<permission_entity::Model> = GroupPermissionEntity::find()
+            .filter(PermissionColumn::GroupId.is_in(models.iter().map(|model| model.id)))
+            .all(db)
+            .await?;
+
+        models
+            .into_iter()
+            .map(|model| to_group(model, &permissions))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl GroupRepository for PostgresGroupRepository {
+    async fn find_by_name(
+        &self,
+        organization_id: &Uuid,
+        name: &GroupName,
+    ) -> Result<Option<Group>, RepositoryError> {
+        let Some(model) = GroupEntity::find()
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .filter(Column::Name.eq(name.as_str()))
+            .one(&self.db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Self::to_groups(&self.db, vec![model]).await?.pop())
+    }
+
+    async fn find_all(&self, organization_id: &Uuid) -> Result<Vec<Group>, RepositoryError> {
+        let models: Vec<entity::Model> = GroupEntity::find()
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Self::to_groups(&self.db, models).await
+    }
+
+    async fn find_by_member(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Vec<Group>, RepositoryError> {
+        let members: Vec<member_entity::Model> = GroupMemberEntity::find()
+            .filter(MemberColumn::OrganizationId.eq(organization_id.to_owned()))
+            .filter(MemberColumn::UserId.eq(user_id.to_owned()))
+            .all(&self.db)
+            .await?;
+
+        if members.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let models: Vec<entity::Model> = GroupEntity::find()
+            .filter(Column::Id.is_in(members.iter().map(|member| member.group_id)))
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await?;
+
+        Self::to_groups(&self.db, models).await
+    }
+
+    async fn create(&self, group: Group) -> Result<Group, RepositoryError> {
+        let active: entity::ActiveModel = (&group).try_into()?;
+
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+        active.insert(&transaction).await?;
+
+        let permissions: Vec<permission_entity::ActiveModel> =
+            to_permission_models(&group.id, &group.permissions);
+
+        if !permissions.is_empty() {
+            GroupPermissionEntity::insert_many(permissions)
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(group)
+    }
+
+    async fn update(
+        &self,
+        id: &Uuid,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Group, RepositoryError> {
+        // Members of the group never see a mix of the old and the new permissions.
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        let model: entity::Model = GroupEntity::find_by_id(id.to_owned())
+            .one(&transaction)
+            .await?
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let mut active_model: entity::ActiveModel = model.into();
+        active_model.description = Set(description);
+        let model: entity::Model = active_model.update(&transaction).await?;
+
+        GroupPermissionEntity::delete_many()
+            .filter(PermissionColumn::GroupId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+
+        let models: Vec<permission_entity::ActiveModel> = to_permission_models(id, &permissions);
+
+        if !models.is_empty() {
+            GroupPermissionEntity::insert_many(models)
+                .exec(&transaction)
+                .await?;
+        }
+
+        let mut groups: Vec<Group> = Self::to_groups(&transaction, vec![model]).await?;
+
+        transaction.commit().await?;
+
+        groups.pop().ok_or(RepositoryError::InvariantViolation)
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = GroupEntity::delete_by_id(id.to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn find_members(&self, group_id: &Uuid) -> Result<Vec<GroupMember>, RepositoryError> {
+        let models: Vec<member_entity::Model> = GroupMemberEntity::find()
+            .filter(MemberColumn::GroupId.eq(group_id.to_owned()))
+            .order_by_asc(MemberColumn::AddedAt)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(GroupMember::try_from).collect()
+    }
+
+    async fn add_member(&self, member: GroupMember) -> Result<GroupMember, RepositoryError> {
+        let key: (Uuid, Uuid) = (member.group_id, member.user_id);
+        let active: member_entity::ActiveModel = member.try_into()?;
+
+        GroupMemberEntity::insert(active)
+            .on_conflict(
+                OnConflict::columns([MemberColumn::GroupId, MemberColumn::UserId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await?;
+
+        let model: member_entity::Model = GroupMemberEntity::find_by_id(key)
+            .one(&self.db)
+            .await?
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        GroupMember::try_from(model)
+    }
+
+    async fn remove_member(
+        &self,
+        group_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let result: DeleteResult =
+            GroupMemberEntity::delete_by_id((group_id.to_owned(), user_id.to_owned()))
+                .exec(&self.db)
+                .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
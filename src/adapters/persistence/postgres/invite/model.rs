@@ -0,0 +1,56 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{
+    errors::repository::RepositoryError, invite::entity::Invite,
+    user::value_objects::password_hash::PasswordHash,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, prelude::DateTimeWithTimeZone};
+
+fn to_timestamp(value: DateTimeWithTimeZone) -> Result<u64, RepositoryError> {
+    u64::try_from(value.timestamp()).map_err(|_| RepositoryError::InvariantViolation)
+}
+
+fn from_timestamp(value: u64) -> Result<DateTimeWithTimeZone, RepositoryError> {
+    let value: DateTime<Utc> =
+        DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+    Ok(value.into())
+}
+
+impl TryFrom<Model> for Invite {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        Ok(Invite {
+            id: model.id,
+            organization_id: model.organization_id,
+            secret_hash: PasswordHash::new(model.secret_hash)
+                .map_err(|_| RepositoryError::InvariantViolation)?,
+            created_by: model.created_by,
+            max_uses: u32::try_from(model.max_uses)
+                .map_err(|_| RepositoryError::InvariantViolation)?,
+            uses: u32::try_from(model.uses).map_err(|_| RepositoryError::InvariantViolation)?,
+            expires_at: to_timestamp(model.expires_at)?,
+            created_at: to_timestamp(model.created_at)?,
+        })
+    }
+}
+
+impl TryFrom<Invite> for ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(invite: Invite) -> Result<Self, RepositoryError> {
+        Ok(ActiveModel {
+            id: Set(invite.id),
+            organization_id: Set(invite.organization_id),
+            secret_hash: Set(invite.secret_hash.as_str().to_owned()),
+            created_by: Set(invite.created_by),
+            max_uses: Set(
+                i32::try_from(invite.max_uses).map_err(|_| RepositoryError::InvariantViolation)?
+            ),
+            uses: Set(i32::try_from(invite.uses).map_err(|_| RepositoryError::InvariantViolation)?),
+            expires_at: Set(from_timestamp(invite.expires_at)?),
+            created_at: Set(from_timestamp(invite.created_at)?),
+        })
+    }
+}
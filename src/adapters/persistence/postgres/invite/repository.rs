@@ -0,0 +1,92 @@
+use super::entity::{ActiveModel, Column, Entity as InviteEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    invite::{entity::Invite, repository::InviteRepository},
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait, QueryFilter,
+    QueryOrder, UpdateResult,
+    sea_query::{Expr, ExprTrait},
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresInviteRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresInviteRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl InviteRepository for PostgresInviteRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Invite>, RepositoryError> {
+        let model: Option<Model> = InviteEntity::find_by_id(id.to_owned())
+            .one(&self.db)
+            .await?;
+
+        model.map(Invite::try_from).transpose()
+    }
+
+    async fn find_by_organization(
+        &self,
+        organization_id: &Uuid,
+    ) -> Result<Vec<Invite>, RepositoryError> {
+        let models: Vec<Model> = InviteEntity::find()
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .order_by_desc(Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Invite::try_from).collect()
+    }
+
+    async fn create(&self, invite: Invite) -> Result<Invite, RepositoryError> {
+        let active: ActiveModel = invite.try_into()?;
+
+        let model: Model = active.insert(&self.db).await?;
+
+        Invite::try_from(model)
+    }
+
+    async fn redeem(&self, id: &Uuid, now: u64) -> Result<bool, RepositoryError> {
+        let now: DateTime<Utc> =
+            DateTime::from_timestamp(now as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+        // A single conditional update, so the row lock settles concurrent redemptions.
+        let result: UpdateResult = InviteEntity::update_many()
+            .col_expr(Column::Uses, Expr::col(Column::Uses).add(1))
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Expr::col(Column::Uses).lt(Expr::col(Column::MaxUses)))
+            .filter(Column::ExpiresAt.gt(now))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn release(&self, id: &Uuid) -> Result<(), RepositoryError> {
+        InviteEntity::update_many()
+            .col_expr(Column::Uses, Expr::col(Column::Uses).sub(1))
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::Uses.gt(0))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, organization_id: &Uuid, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = InviteEntity::delete_many()
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -0,0 +1,92 @@
+use super::entity::{ActiveModel, Column, Entity as LoginAttemptEntity, Model};
+use crate::{
+    application::security::login_attempt_store::{LoginAttemptStore, LoginAttempts},
+    domain::errors::repository::RepositoryError,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::{Expr, ExprTrait, OnConflict},
+};
+
+#[derive(Clone)]
+pub struct PostgresLoginAttemptStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresLoginAttemptStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl LoginAttemptStore for PostgresLoginAttemptStore {
+    async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, RepositoryError> {
+        let model: Option<Model> = LoginAttemptEntity::find_by_id(key.to_owned())
+            .one(&self.db)
+            .await?;
+
+        Ok(model.map(LoginAttempts::from))
+    }
+
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: u64,
+        window: u64,
+    ) -> Result<LoginAttempts, RepositoryError> {
+        let now: DateTime<Utc> =
+            DateTime::from_timestamp(now as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+        let window_start: DateTime<Utc> = now - chrono::Duration::seconds(window as i64);
+
+        let active: ActiveModel = ActiveModel {
+            key: Set(key.to_owned()),
+            failures: Set(1),
+            last_failure_at: Set(now.into()),
+        };
+
+        // Incremented in the database, so concurrent attempts are all counted.
+        let model: Model = LoginAttemptEntity::insert(active)
+            .on_conflict(
+                OnConflict::column(Column::Key)
+                    .values([
+                        (
+                            Column::Failures,
+                            Expr::case(
+                                Expr::col((LoginAttemptEntity, Column::LastFailureAt))
+                                    .lt(window_start),
+                                1,
+                            )
+                            .finally(Expr::col((LoginAttemptEntity, Column::Failures)).add(1))
+                            .into(),
+                        ),
+                        (Column::LastFailureAt, Expr::val(now)),
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await?;
+
+        Ok(LoginAttempts::from(model))
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), RepositoryError> {
+        LoginAttemptEntity::delete_many()
+            .filter(Column::Key.eq(key))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl From<Model> for LoginAttempts {
+    fn from(model: Model) -> Self {
+        Self {
+            failures: u32::try_from(model.failures).unwrap_or_default(),
+            last_failure_at: u64::try_from(model.last_failure_at.timestamp()).unwrap_or_default(),
+        }
+    }
+}
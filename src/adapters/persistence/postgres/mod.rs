@@ -1,2 +1,18 @@
+pub mod audit;
+pub mod avatar;
+pub mod client;
 pub mod connection;
+pub mod email_verification;
+pub mod external_identity;
+pub mod group;
+pub mod invite;
+pub mod login_attempt;
+pub mod organization;
+pub mod passkey;
+pub mod password_history;
+pub mod password_reset;
+pub mod revoked_token;
+pub mod role;
+pub mod session;
+pub mod totp;
 pub mod user;
@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod membership_entity;
+pub mod model;
+pub mod repository;
@@ -0,0 +1,75 @@
+use super::{entity, membership_entity};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    organization::{
+        entity::{Organization, OrganizationSlug},
+        membership::Membership,
+    },
+    role::entity::RoleName,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, prelude::DateTimeWithTimeZone};
+
+fn to_timestamp(value: DateTimeWithTimeZone) -> Result<u64, RepositoryError> {
+    u64::try_from(value.timestamp()).map_err(|_| RepositoryError::InvariantViolation)
+}
+
+fn from_timestamp(value: u64) -> Result<DateTimeWithTimeZone, RepositoryError> {
+    let value: DateTime<Utc> =
+        DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+    Ok(value.into())
+}
+
+impl TryFrom<entity::Model> for Organization {
+    type Error = RepositoryError;
+
+    fn try_from(model: entity::Model) -> Result<Self, RepositoryError> {
+        Ok(Organization {
+            id: model.id,
+            slug: OrganizationSlug::new(model.slug)
+                .map_err(|_| RepositoryError::InvariantViolation)?,
+            name: model.name,
+            created_at: to_timestamp(model.created_at)?,
+        })
+    }
+}
+
+impl TryFrom<Organization> for entity::ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(organization: Organization) -> Result<Self, RepositoryError> {
+        Ok(entity::ActiveModel {
+            id: Set(organization.id),
+            slug: Set(organization.slug.as_str().to_owned()),
+            name: Set(organization.name),
+            created_at: Set(from_timestamp(organization.created_at)?),
+        })
+    }
+}
+
+impl TryFrom<membership_entity::Model> for Membership {
+    type Error = RepositoryError;
+
+    fn try_from(model: membership_entity::Model) -> Result<Self, RepositoryError> {
+        Ok(Membership {
+            organization_id: model.organization_id,
+            user_id: model.user_id,
+            role: RoleName::new(model.role).map_err(|_| RepositoryError::InvariantViolation)?,
+            joined_at: to_timestamp(model.joined_at)?,
+        })
+    }
+}
+
+impl TryFrom<Membership> for membership_entity::ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(membership: Membership) -> Result<Self, RepositoryError> {
+        Ok(membership_entity::ActiveModel {
+            organization_id: Set(membership.organization_id),
+            user_id: Set(membership.user_id),
+            role: Set(membership.role.as_str().to_owned()),
+            joined_at: Set(from_timestamp(membership.joined_at)?),
+        })
+    }
+}
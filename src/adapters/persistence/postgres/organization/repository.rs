@@ -0,0 +1,161 @@
+use super::{
+    entity::{self, Entity as OrganizationEntity},
+    membership_entity::{self, Column as MembershipColumn, Entity as MembershipEntity},
+};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    organization::{
+        entity::{Organization, OrganizationSlug},
+        membership::Membership,
+        repository::OrganizationRepository,
+    },
+    role::entity::RoleName,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, DeleteResult,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait, sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresOrganizationRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresOrganizationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> Result<Option<Organization>, RepositoryError> {
+        let model: Option<entity::Model> = OrganizationEntity::find()
+            .filter(entity::Column::Slug.eq(slug.as_str()))
+            .one(&self.db)
+            .await?;
+
+        model.map(Organization::try_from).transpose()
+    }
+
+    async fn create(
+        &self,
+        organization: Organization,
+        founder: Membership,
+    ) -> Result<Organization, RepositoryError> {
+        let active: entity::ActiveModel = organization.try_into()?;
+        let membership: membership_entity::ActiveModel = founder.try_into()?;
+
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+        let model: entity::Model = active.insert(&transaction).await?;
+        membership.insert(&transaction).await?;
+        transaction.commit().await?;
+
+        Organization::try_from(model)
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<Membership>, RepositoryError> {
+        let model: Option<membership_entity::Model> =
+            MembershipEntity::find_by_id((organization_id.to_owned(), user_id.to_owned()))
+                .one(&self.db)
+                .await?;
+
+        model.map(Membership::try_from).transpose()
+    }
+
+    async fn find_by_member(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<(Organization, Membership)>, RepositoryError> {
+        let memberships: Vec<membership_entity::Model> = MembershipEntity::find()
+            .filter(MembershipColumn::UserId.eq(user_id.to_owned()))
+            .all(&self.db)
+            .await?;
+        let organizations: Vec<entity::Model> = OrganizationEntity::find()
+            .filter(
+                entity::Column::Id.is_in(
+                    memberships
+                        .iter()
+                        .map(|membership| membership.organization_id),
+                ),
+            )
+            .order_by_asc(entity::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        organizations
+            .into_iter()
+            .map(|organization| {
+                let membership: membership_entity::Model = memberships
+                    .iter()
+                    .find(|membership| membership.organization_id == organization.id)
+                    .cloned()
+                    .ok_or(RepositoryError::InvariantViolation)?;
+
+                Ok((
+                    Organization::try_from(organization)?,
+                    Membership::try_from(membership)?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_members(
+        &self,
+        organization_id: &Uuid,
+    ) -> Result<Vec<Membership>, RepositoryError> {
+        let models: Vec<membership_entity::Model> = MembershipEntity::find()
+            .filter(MembershipColumn::OrganizationId.eq(organization_id.to_owned()))
+            .order_by_asc(MembershipColumn::JoinedAt)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Membership::try_from).collect()
+    }
+
+    async fn has_members_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError> {
+        let count: u64 = MembershipEntity::find()
+            .filter(MembershipColumn::Role.eq(role.as_str()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn save_membership(&self, membership: Membership) -> Result<Membership, RepositoryError> {
+        let active: membership_entity::ActiveModel = membership.try_into()?;
+
+        // Members who are already there keep the date they joined.
+        let model: membership_entity::Model = MembershipEntity::insert(active)
+            .on_conflict(
+                OnConflict::columns([MembershipColumn::OrganizationId, MembershipColumn::UserId])
+                    .update_column(MembershipColumn::Role)
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await?;
+
+        Membership::try_from(model)
+    }
+
+    async fn delete_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError> {
+        let result: DeleteResult =
+            MembershipEntity::delete_by_id((organization_id.to_owned(), user_id.to_owned()))
+                .exec(&self.db)
+                .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -0,0 +1,12 @@
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "passkey_challenges")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub challenge: String,
+    pub expires_at: DateTimeWithTimeZone,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
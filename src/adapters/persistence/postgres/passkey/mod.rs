@@ -0,0 +1,4 @@
+pub mod challenge;
+pub mod entity;
+pub mod model;
+pub mod repository;
@@ -0,0 +1,34 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{errors::repository::RepositoryError, passkey::entity::Passkey};
+use sea_orm::ActiveValue::Set;
+
+impl TryFrom<Model> for Passkey {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        let sign_count: u32 =
+            u32::try_from(model.sign_count).map_err(|_| RepositoryError::InvariantViolation)?;
+
+        Ok(Passkey::new(
+            model.id,
+            model.user_id,
+            model.name,
+            model.credential_id,
+            model.public_key,
+            sign_count,
+        ))
+    }
+}
+
+impl From<Passkey> for ActiveModel {
+    fn from(passkey: Passkey) -> Self {
+        ActiveModel {
+            id: Set(passkey.id),
+            user_id: Set(passkey.user_id),
+            name: Set(passkey.name),
+            credential_id: Set(passkey.credential_id),
+            public_key: Set(passkey.public_key),
+            sign_count: Set(passkey.sign_count as i64),
+        }
+    }
+}
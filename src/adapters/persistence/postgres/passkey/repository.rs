@@ -0,0 +1,116 @@
+use super::{
+    challenge::{
+        ActiveModel as ChallengeActiveModel, Column as ChallengeColumn,
+        Entity as PasskeyChallengeEntity,
+    },
+    entity::{ActiveModel, Column, Entity as PasskeyEntity, Model},
+};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    passkey::{entity::Passkey, repository::PasskeyRepository, verifier::PasskeyChallenge},
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresPasskeyRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPasskeyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasskeyRepository for PostgresPasskeyRepository {
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, RepositoryError> {
+        let model: Option<Model> = PasskeyEntity::find()
+            .filter(Column::CredentialId.eq(credential_id.to_vec()))
+            .one(&self.db)
+            .await?;
+
+        match model {
+            Some(m) => Ok(Some(Passkey::try_from(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Passkey>, RepositoryError> {
+        let models: Vec<Model> = PasskeyEntity::find()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Passkey::try_from).collect()
+    }
+
+    async fn create(&self, passkey: Passkey) -> Result<Passkey, RepositoryError> {
+        let active: ActiveModel = passkey.into();
+
+        let model: Model = active.insert(&self.db).await?;
+
+        Passkey::try_from(model)
+    }
+
+    async fn update_sign_count(&self, id: &Uuid, sign_count: u32) -> Result<(), RepositoryError> {
+        let active: ActiveModel = ActiveModel {
+            id: Set(id.to_owned()),
+            sign_count: Set(sign_count as i64),
+            ..Default::default()
+        };
+
+        active.update(&self.db).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError> {
+        PasskeyEntity::delete_by_id(id.to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_challenge(&self, challenge: &PasskeyChallenge) -> Result<(), RepositoryError> {
+        let expires_at: DateTime<Utc> = DateTime::from_timestamp(challenge.expires_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        // Challenges that were never answered are dropped here rather than by a separate job.
+        PasskeyChallengeEntity::delete_many()
+            .filter(ChallengeColumn::ExpiresAt.lt(Utc::now()))
+            .exec(&self.db)
+            .await?;
+
+        let active: ChallengeActiveModel = ChallengeActiveModel {
+            challenge: Set(challenge.value.clone()),
+            expires_at: Set(expires_at.into()),
+        };
+
+        PasskeyChallengeEntity::insert(active)
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn consume_challenge(&self, challenge: &str) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = PasskeyChallengeEntity::delete_many()
+            .filter(ChallengeColumn::Challenge.eq(challenge))
+            .filter(ChallengeColumn::ExpiresAt.gte(Utc::now()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
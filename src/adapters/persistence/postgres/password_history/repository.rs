@@ -0,0 +1,82 @@
+use super::entity::{ActiveModel, Column, Entity as PasswordHistoryEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    user::{
+        password_history::PasswordHistoryRepository, value_objects::password_hash::PasswordHash,
+    },
+};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresPasswordHistoryRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPasswordHistoryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordHistoryRepository for PostgresPasswordHistoryRepository {
+    async fn recent(
+        &self,
+        user_id: &Uuid,
+        limit: u64,
+    ) -> Result<Vec<PasswordHash>, RepositoryError> {
+        let models: Vec<Model> = PasswordHistoryEntity::find()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .order_by_desc(Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        models
+            .into_iter()
+            .map(|model| {
+                PasswordHash::new(model.password_hash)
+                    .map_err(|_| RepositoryError::InvariantViolation)
+            })
+            .collect()
+    }
+
+    async fn record(
+        &self,
+        user_id: &Uuid,
+        hash: &PasswordHash,
+        keep: u64,
+    ) -> Result<(), RepositoryError> {
+        ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user_id.to_owned()),
+            password_hash: Set(hash.as_str().to_owned()),
+            created_at: Set(Utc::now().into()),
+        }
+        .insert(&self.db)
+        .await?;
+
+        let kept: Vec<Uuid> = PasswordHistoryEntity::find()
+            .select_only()
+            .column(Column::Id)
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .order_by_desc(Column::CreatedAt)
+            .limit(keep)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        PasswordHistoryEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .filter(Column::Id.is_not_in(kept))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use super::entity::{ActiveModel, Column, Entity as PasswordResetEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    password_reset::{entity::PasswordResetToken, repository::PasswordResetRepository},
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait, QueryFilter,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresPasswordResetRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPasswordResetRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordResetRepository for PostgresPasswordResetRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<PasswordResetToken>, RepositoryError> {
+        let model: Option<Model> = PasswordResetEntity::find_by_id(id.to_owned())
+            .one(&self.db)
+            .await?;
+
+        model.map(PasswordResetToken::try_from).transpose()
+    }
+
+    async fn create(
+        &self,
+        token: PasswordResetToken,
+    ) -> Result<PasswordResetToken, RepositoryError> {
+        let active: ActiveModel = token.try_into()?;
+
+        let model: Model = active.insert(&self.db).await?;
+
+        PasswordResetToken::try_from(model)
+    }
+
+    async fn consume(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = PasswordResetEntity::delete_by_id(id.to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError> {
+        PasswordResetEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use super::{
+    entity::{ActiveModel, Column, Entity as RevokedTokenEntity},
+    user_cutoff::{
+        ActiveModel as UserCutoffActiveModel, Column as UserCutoffColumn,
+        Entity as UserCutoffEntity, Model as UserCutoffModel,
+    },
+};
+use crate::{
+    application::security::revocation_store::RevocationStore,
+    domain::errors::repository::RepositoryError,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresRevocationStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresRevocationStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl RevocationStore for PostgresRevocationStore {
+    async fn revoke(&self, token_id: &Uuid, expires_at: u64) -> Result<(), RepositoryError> {
+        let expires_at: DateTime<Utc> = DateTime::from_timestamp(expires_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let active: ActiveModel = ActiveModel {
+            token_id: Set(token_id.to_owned()),
+            expires_at: Set(expires_at.into()),
+        };
+
+        RevokedTokenEntity::insert(active)
+            .on_conflict(OnConflict::column(Column::TokenId).do_nothing().to_owned())
+            .do_nothing()
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, token_id: &Uuid) -> Result<bool, RepositoryError> {
+        let count: u64 = RevokedTokenEntity::find()
+            .filter(Column::TokenId.eq(token_id.to_owned()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn revoke_all(&self, user_id: &Uuid, not_before: u64) -> Result<(), RepositoryError> {
+        let not_before: DateTime<Utc> = DateTime::from_timestamp_millis(not_before as i64)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let active: UserCutoffActiveModel = UserCutoffActiveModel {
+            user_id: Set(user_id.to_owned()),
+            not_before: Set(not_before.into()),
+        };
+
+        UserCutoffEntity::insert(active)
+            .on_conflict(
+                OnConflict::column(UserCutoffColumn::UserId)
+                    .update_column(UserCutoffColumn::NotBefore)
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn not_before(&self, user_id: &Uuid) -> Result<Option<u64>, RepositoryError> {
+        let model: Option<UserCutoffModel> = UserCutoffEntity::find_by_id(user_id.to_owned())
+            .one(&self.db)
+            .await?;
+
+        Ok(model.and_then(|model| u64::try_from(model.not_before.timestamp_millis()).ok()))
+    }
+}
@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod model;
+pub mod permission_entity;
+pub mod repository;
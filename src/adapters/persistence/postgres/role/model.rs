@@ -0,0 +1,44 @@
+use super::{entity::Model, permission_entity};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    role::{
+        entity::{Role, RoleName},
+        permission::Permission,
+    },
+};
+use sea_orm::ActiveValue::Set;
+
+/// Builds a role from its row and its permission rows. Permissions this version no longer
+/// knows are ignored.
+pub(super) fn to_role(
+    model: Model,
+    permissions: &[permission_entity::Model],
+) -> Result<Role, RepositoryError> {
+    let name: RoleName =
+        RoleName::new(model.name).map_err(|_| RepositoryError::InvariantViolation)?;
+    let permissions: Vec<Permission> = permissions
+        .iter()
+        .filter(|permission| permission.role == name.as_str())
+        .filter_map(|permission| permission.permission.parse().ok())
+        .collect();
+
+    Ok(Role {
+        name,
+        description: model.description,
+        permissions: Role::dedup(permissions),
+        builtin: model.builtin,
+    })
+}
+
+pub(super) fn to_permission_models(
+    name: &RoleName,
+    permissions: &[Permission],
+) -> Vec<permission_entity::ActiveModel> {
+    permissions
+        .iter()
+        .map(|permission| permission_entity::ActiveModel {
+            role: Set(name.as_str().to_owned()),
+            permission: Set(permission.as_str().to_owned()),
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+use super::{
+    entity::{ActiveModel, Column, Entity as RoleEntity, Model},
+    model::{to_permission_models, to_role},
+    permission_entity::{self, Column as PermissionColumn, Entity as RolePermissionEntity},
+};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    role::{
+        entity::{Role, RoleName},
+        permission::Permission,
+        repository::RoleRepository,
+    },
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DatabaseTransaction,
+    DeleteResult, EntityTrait, NotSet, QueryFilter, QueryOrder, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct PostgresRoleRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresRoleRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleRepository for PostgresRoleRepository {
+    async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError> {
+        let Some(model) = RoleEntity::find_by_id(name.as_str().to_owned())
+            .one(&self.db)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let permissions: Vec<permission_entity::Model> = RolePermissionEntity::find()
+            .filter(PermissionColumn::Role.eq(name.as_str()))
+            .all(&self.db)
+            .await?;
+
+        to_role(model, &permissions).map(Some)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Role>, RepositoryError> {
+        let models: Vec<Model> = RoleEntity::find()
+            .order_by_desc(Column::Builtin)
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await?;
+        let permissions: Vec<permission_entity::Model> =
+            RolePermissionEntity::find().all(&self.db).await?;
+
+        models
+            .into_iter()
+            .map(|model| to_role(model, &permissions))
+            .collect()
+    }
+
+    async fn create(&self, role: Role) -> Result<Role, RepositoryError> {
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        ActiveModel {
+            name: Set(role.name.as_str().to_owned()),
+            description: Set(role.description.clone()),
+            builtin: Set(role.builtin),
+            created_at: NotSet,
+        }
+        .insert(&transaction)
+        .await?;
+
+        let permissions: Vec<permission_entity::ActiveModel> =
+            to_permission_models(&role.name, &role.permissions);
+
+        if !permissions.is_empty() {
+            RolePermissionEntity::insert_many(permissions)
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(role)
+    }
+
+    async fn update(
+        &self,
+        name: &RoleName,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Role, RepositoryError> {
+        // Holders of the role never see a mix of the old and the new permissions.
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        let model: Model = RoleEntity::find_by_id(name.as_str().to_owned())
+            .one(&transaction)
+            .await?
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let mut active_model: ActiveModel = model.into();
+        active_model.description = Set(description);
+        let model: Model = active_model.update(&transaction).await?;
+
+        RolePermissionEntity::delete_many()
+            .filter(PermissionColumn::Role.eq(name.as_str()))
+            .exec(&transaction)
+            .await?;
+
+        let models: Vec<permission_entity::ActiveModel> = to_permission_models(name, &permissions);
+
+        if !models.is_empty() {
+            RolePermissionEntity::insert_many(models)
+                .exec(&transaction)
+                .await?;
+        }
+
+        let permissions: Vec<permission_entity::Model> = RolePermissionEntity::find()
+            .filter(PermissionColumn::Role.eq(name.as_str()))
+            .all(&transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        to_role(model, &permissions)
+    }
+
+    async fn delete(&self, name: &RoleName) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = RoleEntity::delete_by_id(name.as_str().to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
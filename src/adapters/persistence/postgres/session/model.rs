@@ -0,0 +1,56 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{errors::repository::RepositoryError, session::entity::Session};
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveValue::Set, prelude::DateTimeWithTimeZone};
+
+const USER_AGENT_MAX_LENGTH: usize = 512;
+
+fn to_timestamp(value: DateTimeWithTimeZone) -> Result<u64, RepositoryError> {
+    u64::try_from(value.timestamp()).map_err(|_| RepositoryError::InvariantViolation)
+}
+
+fn from_timestamp(value: u64) -> Result<DateTimeWithTimeZone, RepositoryError> {
+    let value: DateTime<Utc> =
+        DateTime::from_timestamp(value as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+    Ok(value.into())
+}
+
+impl TryFrom<Model> for Session {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        Ok(Session {
+            id: model.id,
+            user_id: model.user_id,
+            client_id: model.client_id,
+            user_agent: model.user_agent,
+            // An address that no longer parses is dropped rather than failing the lookup.
+            ip_address: model.ip_address.and_then(|ip| ip.parse().ok()),
+            created_at: to_timestamp(model.created_at)?,
+            last_used_at: to_timestamp(model.last_used_at)?,
+            expires_at: to_timestamp(model.expires_at)?,
+        })
+    }
+}
+
+impl TryFrom<Session> for ActiveModel {
+    type Error = RepositoryError;
+
+    fn try_from(session: Session) -> Result<Self, RepositoryError> {
+        let user_agent: Option<String> = session
+            .user_agent
+            .map(|agent| agent.chars().take(USER_AGENT_MAX_LENGTH).collect());
+
+        Ok(ActiveModel {
+            id: Set(session.id),
+            user_id: Set(session.user_id),
+            client_id: Set(session.client_id),
+            user_agent: Set(user_agent),
+            ip_address: Set(session.ip_address.map(|ip| ip.to_string())),
+            created_at: Set(from_timestamp(session.created_at)?),
+            last_used_at: Set(from_timestamp(session.last_used_at)?),
+            expires_at: Set(from_timestamp(session.expires_at)?),
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use super::entity::{ActiveModel, Column, Entity as SessionEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    session::{entity::Session, repository::SessionRepository},
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait, QueryFilter, QueryOrder,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresSessionRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Session>, RepositoryError> {
+        let model: Option<Model> = SessionEntity::find_by_id(id.to_owned())
+            .one(&self.db)
+            .await?;
+
+        model.map(Session::try_from).transpose()
+    }
+
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Session>, RepositoryError> {
+        let models: Vec<Model> = SessionEntity::find()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .order_by_desc(Column::LastUsedAt)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(Session::try_from).collect()
+    }
+
+    async fn save(&self, session: Session) -> Result<Session, RepositoryError> {
+        let active: ActiveModel = session.clone().try_into()?;
+
+        SessionEntity::insert(active)
+            .on_conflict(
+                OnConflict::column(Column::Id)
+                    .update_columns([
+                        Column::UserAgent,
+                        Column::IpAddress,
+                        Column::LastUsedAt,
+                        Column::ExpiresAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+
+        Ok(session)
+    }
+
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: DeleteResult = SessionEntity::delete_by_id(id.to_owned())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn delete_by_user_id(
+        &self,
+        user_id: &Uuid,
+        except: Option<&Uuid>,
+    ) -> Result<(), RepositoryError> {
+        let mut query = SessionEntity::delete_many().filter(Column::UserId.eq(user_id.to_owned()));
+        if let Some(except) = except {
+            query = query.filter(Column::Id.ne(except.to_owned()));
+        }
+
+        query.exec(&self.db).await?;
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, user_id: &Uuid, now: u64) -> Result<(), RepositoryError> {
+        let now: DateTime<Utc> =
+            DateTime::from_timestamp(now as i64, 0).ok_or(RepositoryError::InvariantViolation)?;
+
+        SessionEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .filter(Column::ExpiresAt.lte(now))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_totp")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    pub secret: String,
+    pub confirmed: bool,
+    pub recovery_codes: String,
+    pub last_used_step: Option<i64>,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
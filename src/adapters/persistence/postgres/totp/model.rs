@@ -0,0 +1,43 @@
+use super::entity::{ActiveModel, Model};
+use crate::domain::{
+    errors::repository::RepositoryError, mfa::entity::TotpEnrollment,
+    user::value_objects::password_hash::PasswordHash,
+};
+use sea_orm::ActiveValue::Set;
+
+impl TryFrom<Model> for TotpEnrollment {
+    type Error = RepositoryError;
+
+    fn try_from(model: Model) -> Result<Self, RepositoryError> {
+        let recovery_codes: Vec<PasswordHash> = model
+            .recovery_codes
+            .split_whitespace()
+            .map(|hash| PasswordHash::new(hash.into()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(TotpEnrollment {
+            user_id: model.user_id,
+            secret: model.secret,
+            confirmed: model.confirmed,
+            recovery_codes,
+            last_used_step: model.last_used_step.map(|step| step as u64),
+        })
+    }
+}
+
+impl From<TotpEnrollment> for ActiveModel {
+    fn from(enrollment: TotpEnrollment) -> Self {
+        ActiveModel {
+            user_id: Set(enrollment.user_id),
+            secret: Set(enrollment.secret),
+            confirmed: Set(enrollment.confirmed),
+            recovery_codes: Set(enrollment
+                .recovery_codes
+                .iter()
+                .map(PasswordHash::as_str)
+                .collect::<Vec<&str>>()
+                .join(" ")),
+            last_used_step: Set(enrollment.last_used_step.map(|step| step as i64)),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use super::entity::{ActiveModel, Column, Entity as TotpEntity, Model};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    mfa::{entity::TotpEnrollment, repository::TotpRepository},
+};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, sea_query::OnConflict};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresTotpRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresTotpRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl TotpRepository for PostgresTotpRepository {
+    async fn find_by_user_id(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Option<TotpEnrollment>, RepositoryError> {
+        let model: Option<Model> = TotpEntity::find()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .one(&self.db)
+            .await?;
+
+        match model {
+            Some(m) => Ok(Some(TotpEnrollment::try_from(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, enrollment: TotpEnrollment) -> Result<TotpEnrollment, RepositoryError> {
+        let active: ActiveModel = enrollment.into();
+
+        let model: Model = TotpEntity::insert(active)
+            .on_conflict(
+                OnConflict::column(Column::UserId)
+                    .update_columns([
+                        Column::Secret,
+                        Column::Confirmed,
+                        Column::RecoveryCodes,
+                        Column::LastUsedStep,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await?;
+
+        TotpEnrollment::try_from(model)
+    }
+
+    async fn delete(&self, user_id: &Uuid) -> Result<(), RepositoryError> {
+        TotpEntity::delete_many()
+            .filter(Column::UserId.eq(user_id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use super::{user_role::UserRole, user_status::UserStatus};
+use super::user_status::UserStatus;
 use sea_orm::entity::prelude::*;
 
 #[sea_orm::model]
@@ -7,13 +7,27 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: Uuid,
+    pub organization_id: Uuid,
     pub username: String,
     pub name: String,
     pub password_hash: String,
     #[sea_orm(default_value = "user")]
-    pub role: UserRole,
+    pub role: String,
     #[sea_orm(default_value = "active")]
     pub status: UserStatus,
+    pub email: Option<String>,
+    pub email_verified_at: Option<DateTimeWithTimeZone>,
+    pub password_changed_at: DateTimeWithTimeZone,
+    pub avatar_updated_at: Option<DateTimeWithTimeZone>,
+    pub status_text: Option<String>,
+    pub status_emoji: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub bio: Option<String>,
+    pub timezone: Option<String>,
+    pub locale: Option<String>,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    /// When the user was anonymized, after their grace period.
+    pub purged_at: Option<DateTimeWithTimeZone>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
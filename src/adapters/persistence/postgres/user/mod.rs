@@ -1,5 +1,4 @@
 pub mod entity;
 pub mod model;
 pub mod repository;
-pub mod user_role;
 pub mod user_status;
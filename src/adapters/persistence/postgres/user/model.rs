@@ -1,13 +1,20 @@
 use super::entity::{ActiveModel, Model};
 use crate::domain::{
     errors::repository::RepositoryError,
+    role::entity::RoleName,
     user::{
-        entity::{User, UserRole, UserStatus},
+        entity::{User, UserStatus},
         error::UserError,
-        value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        profile::Profile,
+        value_objects::{
+            bio::Bio, email::Email, locale::Locale, name::Name, password_hash::PasswordHash,
+            status_emoji::StatusEmoji, status_text::StatusText, timezone::Timezone,
+            username::Username,
+        },
     },
 };
-use sea_orm::ActiveValue::Set;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::{NotSet, Set};
 
 impl TryFrom<Model> for User {
     type Error = RepositoryError;
@@ -16,17 +23,32 @@ impl TryFrom<Model> for User {
         let username: Username = Username::new(model.username)?;
         let name: Name = Name::new(model.name)?;
         let password_hash: PasswordHash = PasswordHash::new(model.password_hash)?;
-        let role: Option<UserRole> = Some(model.role.into());
+        let role: Option<RoleName> =
+            Some(RoleName::new(model.role).map_err(|_| RepositoryError::InvariantViolation)?);
         let status: Option<UserStatus> = Some(model.status.into());
 
-        Ok(User::new(
-            model.id,
-            name,
-            username,
-            password_hash,
-            role,
-            status,
-        ))
+        let mut user: User = User::new(model.id, name, username, password_hash, role, status);
+        user.organization_id = model.organization_id;
+        user.email = model.email.map(Email::new).transpose()?;
+        user.email_verified_at = model
+            .email_verified_at
+            .and_then(|verified_at| u64::try_from(verified_at.timestamp()).ok());
+        user.password_changed_at = u64::try_from(model.password_changed_at.timestamp()).ok();
+        user.profile = Profile {
+            avatar_updated_at: model
+                .avatar_updated_at
+                .and_then(|updated_at| u64::try_from(updated_at.timestamp()).ok()),
+            status_text: model.status_text.map(StatusText::new).transpose()?,
+            status_emoji: model.status_emoji.map(StatusEmoji::new).transpose()?,
+            bio: model.bio.map(Bio::new).transpose()?,
+            timezone: model.timezone.map(Timezone::new).transpose()?,
+            locale: model.locale.map(Locale::new).transpose()?,
+        };
+        user.deleted_at = model
+            .deleted_at
+            .and_then(|deleted_at| u64::try_from(deleted_at.timestamp()).ok());
+
+        Ok(user)
     }
 }
 
@@ -34,19 +56,43 @@ impl From<User> for ActiveModel {
     fn from(user: User) -> Self {
         ActiveModel {
             id: Set(user.id),
+            organization_id: Set(user.organization_id),
             username: Set(user.username.as_str().into()),
             name: Set(user.name.as_str().into()),
             password_hash: Set(user.password_hash.as_str().into()),
-            role: Set(user.role.into()),
+            role: Set(user.role.as_str().to_owned()),
             status: Set(user.status.into()),
+            email: Set(user.email.map(|email| email.as_str().to_owned())),
+            email_verified_at: Set(user
+                .email_verified_at
+                .and_then(|verified_at| DateTime::from_timestamp(verified_at as i64, 0))
+                .map(|verified_at: DateTime<Utc>| verified_at.into())),
+            password_changed_at: match user.password_changed_at {
+                Some(changed_at) => DateTime::from_timestamp(changed_at as i64, 0)
+                    .map_or(NotSet, |changed_at: DateTime<Utc>| Set(changed_at.into())),
+                None => NotSet,
+            },
+            avatar_updated_at: Set(user
+                .profile
+                .avatar_updated_at
+                .and_then(|updated_at| DateTime::from_timestamp(updated_at as i64, 0))
+                .map(|updated_at: DateTime<Utc>| updated_at.into())),
+            status_text: Set(user.profile.status_text.map(|v| v.as_str().to_owned())),
+            status_emoji: Set(user.profile.status_emoji.map(|v| v.as_str().to_owned())),
+            bio: Set(user.profile.bio.map(|v| v.as_str().to_owned())),
+            timezone: Set(user.profile.timezone.map(|v| v.as_str().to_owned())),
+            locale: Set(user.profile.locale.map(|v| v.as_str().to_owned())),
+            deleted_at: Set(user
+                .deleted_at
+                .and_then(|deleted_at| DateTime::from_timestamp(deleted_at as i64, 0))
+                .map(|deleted_at: DateTime<Utc>| deleted_at.into())),
+            purged_at: NotSet,
         }
     }
 }
 
 impl From<UserError> for RepositoryError {
-    fn from(error: UserError) -> Self {
-        match error {
-            _ => RepositoryError::InvariantViolation,
-        }
+    fn from(_: UserError) -> Self {
+        RepositoryError::InvariantViolation
     }
 }
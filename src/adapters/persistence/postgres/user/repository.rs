@@ -1,15 +1,62 @@
-use super::entity::{ActiveModel, Column, Entity as UserEntity, Model};
-use crate::domain::{
-    errors::repository::RepositoryError,
-    user::{
-        entity::User, patch::UserPatch, repository::UserRepository,
-        value_objects::username::Username,
+use super::{
+    entity::{ActiveModel, Column, Entity as UserEntity, Model},
+    user_status::UserStatus,
+};
+use crate::{
+    adapters::persistence::postgres::{
+        avatar::entity::{Column as AvatarColumn, Entity as AvatarEntity},
+        email_verification::entity::{
+            Column as EmailVerificationColumn, Entity as EmailVerificationEntity,
+        },
+        external_identity::entity::{
+            Column as ExternalIdentityColumn, Entity as ExternalIdentityEntity,
+        },
+        organization::{
+            entity::Entity as OrganizationEntity,
+            membership_entity::{
+                ActiveModel as MembershipActiveModel, Column as MembershipColumn,
+                Entity as MembershipEntity,
+            },
+        },
+        passkey::entity::{Column as PasskeyColumn, Entity as PasskeyEntity},
+        password_history::entity::{
+            Column as PasswordHistoryColumn, Entity as PasswordHistoryEntity,
+        },
+        password_reset::entity::{Column as PasswordResetColumn, Entity as PasswordResetEntity},
+        session::entity::{Column as SessionColumn, Entity as SessionEntity},
+        totp::entity::{Column as TotpColumn, Entity as TotpEntity},
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        role::entity::RoleName,
+        user::{
+            entity::User,
+            patch::UserPatch,
+            profile::ProfilePatch,
+            query::{Pagination, SortDirection, UserPage, UserQuery, UserSortField},
+            repository::UserRepository,
+            value_objects::{email::Email, password_hash::PasswordHash, username::Username},
+        },
     },
 };
+use chrono::{DateTime, Utc};
 use log::error;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr,
+    EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+    TransactionTrait, UpdateResult, Value,
+    sea_query::{Expr, ExprTrait, Func, Query, SelectStatement, SimpleExpr},
+};
+use std::collections::BTreeSet;
 use uuid::Uuid;
 
+/// What purged users are called, their username becomes `deleted-` and part of their id.
+const PURGED_NAME: &str = "Deleted user";
+/// Matches no password, like the hash of accounts that cannot sign in.
+const PURGED_PASSWORD_HASH: &str = "!";
+
 #[derive(Clone)]
 pub struct PostgresUserRepository {
     db: DatabaseConnection,
@@ -24,9 +71,18 @@ impl PostgresUserRepository {
 #[async_trait::async_trait]
 impl UserRepository for PostgresUserRepository {
     async fn create(&self, user: User) -> Result<User, RepositoryError> {
+        let membership: MembershipActiveModel = MembershipActiveModel {
+            organization_id: Set(user.organization_id),
+            user_id: Set(user.id),
+            role: Set(RoleName::USER.into()),
+            joined_at: NotSet,
+        };
         let active: ActiveModel = user.into();
 
-        let model: Model = active.insert(&self.db).await?;
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+        let model: Model = active.insert(&transaction).await?;
+        membership.insert(&transaction).await?;
+        transaction.commit().await?;
 
         User::try_from(model)
     }
@@ -34,6 +90,7 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<User>, RepositoryError> {
         let model = UserEntity::find()
             .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_null())
             .one(&self.db)
             .await?;
 
@@ -43,9 +100,30 @@ impl UserRepository for PostgresUserRepository {
         }
     }
 
-    async fn find_by_username(&self, username: &Username) -> Result<Option<User>, RepositoryError> {
+    async fn find_in_organization(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError> {
+        let model: Option<Model> = UserEntity::find()
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .filter(Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await?;
+
+        model.map(User::try_from).transpose()
+    }
+
+    async fn find_by_username(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<Option<User>, RepositoryError> {
         let model: Option<Model> = UserEntity::find()
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
             .filter(Column::Username.eq(username.as_str()))
+            .filter(Column::DeletedAt.is_null())
             .one(&self.db)
             .await?;
 
@@ -55,29 +133,177 @@ impl UserRepository for PostgresUserRepository {
         }
     }
 
-    async fn update(&self, id: &Uuid, user: UserPatch) -> Result<User, RepositoryError> {
+    async fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError> {
+        let model: Option<Model> = UserEntity::find()
+            .filter(Column::Email.eq(email.as_str()))
+            .filter(Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await?;
+
+        model.map(User::try_from).transpose()
+    }
+
+    async fn is_username_taken(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<bool, RepositoryError> {
+        let count: u64 = UserEntity::find()
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .filter(Column::Username.eq(username.as_str()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn is_email_taken(&self, email: &Email) -> Result<bool, RepositoryError> {
+        let count: u64 = UserEntity::find()
+            .filter(Column::Email.eq(email.as_str()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn has_users_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError> {
+        let count: u64 = UserEntity::find()
+            .filter(Column::Role.eq(role.as_str()))
+            .count(&self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn find_deleted(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError> {
         let model: Option<Model> = UserEntity::find()
             .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::OrganizationId.eq(organization_id.to_owned()))
+            .filter(Column::DeletedAt.is_not_null())
+            .filter(Column::PurgedAt.is_null())
             .one(&self.db)
             .await?;
 
-        if let Some(m) = model {
-            let mut active_model: ActiveModel = m.into();
+        model.map(User::try_from).transpose()
+    }
 
-            if let Some(name) = user.name {
-                active_model.name = sea_orm::ActiveValue::Set(name);
-            }
+    async fn find_purgeable(
+        &self,
+        deleted_before: u64,
+        limit: u64,
+    ) -> Result<Vec<User>, RepositoryError> {
+        let deleted_before: DateTime<Utc> = DateTime::from_timestamp(deleted_before as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let models: Vec<Model> = UserEntity::find()
+            .filter(Column::DeletedAt.lt(deleted_before))
+            .filter(Column::PurgedAt.is_null())
+            .order_by_asc(Column::DeletedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        models.into_iter().map(User::try_from).collect()
+    }
+
+    async fn list(&self, query: &UserQuery) -> Result<UserPage, RepositoryError> {
+        let mut select: Select<UserEntity> = match query.filter.deleted {
+            true => UserEntity::find()
+                .filter(Column::DeletedAt.is_not_null())
+                .filter(Column::PurgedAt.is_null()),
+            false => UserEntity::find().filter(Column::DeletedAt.is_null()),
+        };
+
+        if let Some(organization_id) = &query.filter.organization_id {
+            select = select.filter(Column::OrganizationId.eq(organization_id.to_owned()));
+        }
+
+        if let Some(status) = &query.filter.status {
+            select = select.filter(Column::Status.eq(UserStatus::from(status.clone())));
+        }
 
-            if let Some(username) = user.username {
-                active_model.username = sea_orm::ActiveValue::Set(username.as_str().to_owned());
+        if let Some(role) = &query.filter.role {
+            select = select.filter(Column::Role.eq(role.as_str()));
+        }
+
+        if let Some(search) = &query.filter.search {
+            let pattern: String = format!("{}%", escape_like(&search.to_lowercase()));
+
+            select = select.filter(
+                Condition::any()
+                    .add(lower(Column::Username).like(pattern.as_str()))
+                    .add(lower(Column::Name).like(pattern.as_str())),
+            );
+        }
+
+        let total: u64 = select.clone().count(&self.db).await?;
+
+        let sort_column: Option<Column> = match query.sort.field {
+            UserSortField::Username => Some(Column::Username),
+            UserSortField::Name => Some(Column::Name),
+            UserSortField::CreatedAt => None,
+        };
+        let (order, descending): (Order, bool) = match query.sort.direction {
+            SortDirection::Ascending => (Order::Asc, false),
+            SortDirection::Descending => (Order::Desc, true),
+        };
+
+        match &query.pagination {
+            Pagination::Offset { offset, limit } => {
+                select = select.offset(*offset).limit(*limit);
             }
+            Pagination::Cursor { after, limit } => {
+                if let Some(cursor) = after {
+                    let past = |column: Column, value: Value| match descending {
+                        true => column.lt(value),
+                        false => column.gt(value),
+                    };
+                    let past_id: SimpleExpr = past(Column::Id, cursor.id.into());
+
+                    let condition: Condition = match sort_column {
+                        Some(column) => Condition::any()
+                            .add(past(column, cursor.value.as_str().into()))
+                            .add(
+                                Condition::all()
+                                    .add(column.eq(cursor.value.as_str()))
+                                    .add(past_id),
+                            ),
+                        None => Condition::all().add(past_id),
+                    };
+
+                    select = select.filter(condition);
+                }
 
-            if let Some(password_hash) = user.password_hash {
-                active_model.password_hash =
-                    sea_orm::ActiveValue::Set(password_hash.as_str().to_owned());
+                select = select.limit(*limit);
             }
+        }
+
+        if let Some(column) = sort_column {
+            select = select.order_by(column, order.clone());
+        }
+
+        let models: Vec<Model> = select.order_by(Column::Id, order).all(&self.db).await?;
+        let users: Vec<User> = models
+            .into_iter()
+            .map(User::try_from)
+            .collect::<Result<Vec<User>, RepositoryError>>()?;
+
+        Ok(UserPage { users, total })
+    }
 
-            let updated_model: Model = active_model.update(&self.db).await?;
+    async fn update(&self, id: &Uuid, user: UserPatch) -> Result<User, RepositoryError> {
+        let model: Option<Model> = UserEntity::find()
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_null())
+            .one(&self.db)
+            .await?;
+
+        if let Some(m) = model {
+            let updated_model: Model = patched(m, user).update(&self.db).await?;
 
             return User::try_from(updated_model);
         }
@@ -85,6 +311,197 @@ impl UserRepository for PostgresUserRepository {
         Err(RepositoryError::Unavailable)
     }
 
+    async fn update_keeping_administrators(
+        &self,
+        id: &Uuid,
+        user: UserPatch,
+    ) -> Result<Option<User>, RepositoryError> {
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        let model: Model = UserEntity::find()
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(&transaction)
+            .await?
+            .ok_or(RepositoryError::Unavailable)?;
+        let administered_before: BTreeSet<Uuid> = administered(&transaction, &model).await?;
+
+        let updated_model: Model = patched(model, user).update(&transaction).await?;
+        let administered_after: BTreeSet<Uuid> = administered(&transaction, &updated_model).await?;
+
+        if !administered_by_others(
+            &transaction,
+            id,
+            administered_before.difference(&administered_after),
+        )
+        .await?
+        {
+            transaction.rollback().await?;
+
+            return Ok(None);
+        }
+
+        transaction.commit().await?;
+
+        User::try_from(updated_model).map(Some)
+    }
+
+    async fn rehash_password(
+        &self,
+        id: &Uuid,
+        password_hash: &PasswordHash,
+    ) -> Result<(), RepositoryError> {
+        UserEntity::update_many()
+            .col_expr(Column::PasswordHash, Expr::value(password_hash.as_str()))
+            .filter(Column::Id.eq(id.to_owned()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn mark_email_verified(
+        &self,
+        id: &Uuid,
+        email: &Email,
+        verified_at: u64,
+    ) -> Result<bool, RepositoryError> {
+        let verified_at: DateTime<Utc> = DateTime::from_timestamp(verified_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let result: UpdateResult = UserEntity::update_many()
+            .col_expr(Column::EmailVerifiedAt, Expr::value(verified_at))
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::Email.eq(email.as_str()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn soft_delete(&self, id: &Uuid, deleted_at: u64) -> Result<bool, RepositoryError> {
+        let deleted_at: DateTime<Utc> = DateTime::from_timestamp(deleted_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        let Some(model) = UserEntity::find()
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(&transaction)
+            .await?
+        else {
+            return Ok(true);
+        };
+
+        if !administered_by_others(
+            &transaction,
+            id,
+            administered(&transaction, &model).await?.iter(),
+        )
+        .await?
+        {
+            transaction.rollback().await?;
+
+            return Ok(false);
+        }
+
+        UserEntity::update_many()
+            .col_expr(Column::DeletedAt, Expr::value(deleted_at))
+            .filter(Column::Id.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        transaction.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn restore(&self, id: &Uuid) -> Result<bool, RepositoryError> {
+        let result: UpdateResult = UserEntity::update_many()
+            .col_expr(
+                Column::DeletedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_not_null())
+            .filter(Column::PurgedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// Credentials, sessions, tokens, the avatar and links to external accounts are removed,
+    /// memberships and everything else stay with the anonymous row.
+    async fn anonymize(&self, id: &Uuid, purged_at: u64) -> Result<(), RepositoryError> {
+        let purged_at: DateTime<Utc> = DateTime::from_timestamp(purged_at as i64, 0)
+            .ok_or(RepositoryError::InvariantViolation)?;
+        // As long as any username, and unique as identifiers are.
+        let username: String = format!("deleted-{}", &id.simple().to_string()[8..]);
+        let no_time: Option<DateTime<Utc>> = None;
+        let no_text: Option<String> = None;
+
+        let transaction: DatabaseTransaction = self.db.begin().await?;
+
+        UserEntity::update_many()
+            .col_expr(Column::Username, Expr::value(username))
+            .col_expr(Column::Name, Expr::value(PURGED_NAME))
+            .col_expr(Column::PasswordHash, Expr::value(PURGED_PASSWORD_HASH))
+            .col_expr(Column::Status, Expr::value(UserStatus::Inactive))
+            .col_expr(Column::Email, Expr::value(no_text.clone()))
+            .col_expr(Column::EmailVerifiedAt, Expr::value(no_time))
+            .col_expr(Column::AvatarUpdatedAt, Expr::value(no_time))
+            .col_expr(Column::StatusText, Expr::value(no_text.clone()))
+            .col_expr(Column::StatusEmoji, Expr::value(no_text.clone()))
+            .col_expr(Column::Bio, Expr::value(no_text.clone()))
+            .col_expr(Column::Timezone, Expr::value(no_text.clone()))
+            .col_expr(Column::Locale, Expr::value(no_text))
+            .col_expr(Column::PurgedAt, Expr::value(purged_at))
+            .filter(Column::Id.eq(id.to_owned()))
+            .filter(Column::DeletedAt.is_not_null())
+            .exec(&transaction)
+            .await?;
+
+        AvatarEntity::delete_many()
+            .filter(AvatarColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        EmailVerificationEntity::delete_many()
+            .filter(EmailVerificationColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        ExternalIdentityEntity::delete_many()
+            .filter(ExternalIdentityColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        PasskeyEntity::delete_many()
+            .filter(PasskeyColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        PasswordHistoryEntity::delete_many()
+            .filter(PasswordHistoryColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        PasswordResetEntity::delete_many()
+            .filter(PasswordResetColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        SessionEntity::delete_many()
+            .filter(SessionColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+        TotpEntity::delete_many()
+            .filter(TotpColumn::UserId.eq(id.to_owned()))
+            .exec(&transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
     async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError> {
         let model = UserEntity::find()
             .filter(Column::Id.eq(id.to_owned()))
@@ -100,6 +517,162 @@ impl UserRepository for PostgresUserRepository {
     }
 }
 
+fn lower(column: Column) -> SimpleExpr {
+    Func::lower(Expr::col(column)).into()
+}
+
+/// The user with the changes of the patch applied, ready to be saved.
+fn patched(model: Model, user: UserPatch) -> ActiveModel {
+    let mut active_model: ActiveModel = model.into();
+
+    if let Some(name) = user.name {
+        active_model.name = sea_orm::ActiveValue::Set(name);
+    }
+
+    if let Some(username) = user.username {
+        active_model.username = sea_orm::ActiveValue::Set(username.as_str().to_owned());
+    }
+
+    if let Some(password_hash) = user.password_hash {
+        active_model.password_hash = sea_orm::ActiveValue::Set(password_hash.as_str().to_owned());
+        active_model.password_changed_at = sea_orm::ActiveValue::Set(Utc::now().into());
+    }
+
+    if let Some(role) = user.role {
+        active_model.role = sea_orm::ActiveValue::Set(role.as_str().to_owned());
+    }
+
+    if let Some(status) = user.status {
+        active_model.status = sea_orm::ActiveValue::Set(status.into());
+    }
+
+    if let Some(email) = user.email
+        && active_model.email.as_ref().as_deref() != Some(email.as_str())
+    {
+        active_model.email = sea_orm::ActiveValue::Set(Some(email.as_str().to_owned()));
+        active_model.email_verified_at = sea_orm::ActiveValue::Set(None);
+    }
+
+    let profile: ProfilePatch = user.profile;
+
+    if let Some(updated_at) = profile.avatar_updated_at {
+        active_model.avatar_updated_at = sea_orm::ActiveValue::Set(
+            updated_at
+                .and_then(|updated_at| DateTime::from_timestamp(updated_at as i64, 0))
+                .map(|updated_at: DateTime<Utc>| updated_at.into()),
+        );
+    }
+
+    if let Some(status_text) = profile.status_text {
+        active_model.status_text =
+            sea_orm::ActiveValue::Set(status_text.map(|v| v.as_str().to_owned()));
+    }
+
+    if let Some(status_emoji) = profile.status_emoji {
+        active_model.status_emoji =
+            sea_orm::ActiveValue::Set(status_emoji.map(|v| v.as_str().to_owned()));
+    }
+
+    if let Some(bio) = profile.bio {
+        active_model.bio = sea_orm::ActiveValue::Set(bio.map(|v| v.as_str().to_owned()));
+    }
+
+    if let Some(timezone) = profile.timezone {
+        active_model.timezone = sea_orm::ActiveValue::Set(timezone.map(|v| v.as_str().to_owned()));
+    }
+
+    if let Some(locale) = profile.locale {
+        active_model.locale = sea_orm::ActiveValue::Set(locale.map(|v| v.as_str().to_owned()));
+    }
+
+    active_model
+}
+
+/// The organizations the user administers: their home organization through the administrator
+/// role, and any organization through an administrator membership. Inactive and deleted users
+/// administer none.
+async fn administered<C>(connection: &C, model: &Model) -> Result<BTreeSet<Uuid>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut organization_ids: BTreeSet<Uuid> = BTreeSet::new();
+
+    if model.status != UserStatus::Active || model.deleted_at.is_some() {
+        return Ok(organization_ids);
+    }
+
+    if model.role == RoleName::ADMINISTRATOR {
+        organization_ids.insert(model.organization_id);
+    }
+
+    let memberships: Vec<Uuid> = MembershipEntity::find()
+        .select_only()
+        .column(MembershipColumn::OrganizationId)
+        .filter(MembershipColumn::UserId.eq(model.id))
+        .filter(MembershipColumn::Role.eq(RoleName::ADMINISTRATOR))
+        .into_tuple()
+        .all(connection)
+        .await?;
+    organization_ids.extend(memberships);
+
+    Ok(organization_ids)
+}
+
+/// Whether each organization has an active administrator besides the user. Organizations are
+/// locked in order before counting, so changes to their administrators are counted one at a
+/// time and without deadlocks.
+async fn administered_by_others<'a, C>(
+    connection: &C,
+    user_id: &Uuid,
+    organization_ids: impl Iterator<Item = &'a Uuid>,
+) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+{
+    for organization_id in organization_ids {
+        OrganizationEntity::find_by_id(organization_id.to_owned())
+            .lock_exclusive()
+            .one(connection)
+            .await?;
+
+        let administrator_members: SelectStatement = Query::select()
+            .column(MembershipColumn::UserId)
+            .from(MembershipEntity)
+            .and_where(MembershipColumn::OrganizationId.eq(organization_id.to_owned()))
+            .and_where(MembershipColumn::Role.eq(RoleName::ADMINISTRATOR))
+            .to_owned();
+        let others: u64 = UserEntity::find()
+            .filter(Column::Id.ne(user_id.to_owned()))
+            .filter(Column::Status.eq(UserStatus::Active))
+            .filter(Column::DeletedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(Column::OrganizationId.eq(organization_id.to_owned()))
+                            .add(Column::Role.eq(RoleName::ADMINISTRATOR)),
+                    )
+                    .add(Column::Id.in_subquery(administrator_members)),
+            )
+            .count(connection)
+            .await?;
+
+        if others == 0 {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Escapes the wildcards of a `LIKE` pattern, so user input only matches literally.
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 impl From<DbErr> for RepositoryError {
     fn from(error: DbErr) -> Self {
         match error {
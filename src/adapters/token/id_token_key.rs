@@ -0,0 +1,57 @@
+use crate::application::security::error::TokenError;
+use jsonwebtoken::{
+    Algorithm, EncodingKey,
+    jwk::{Jwk, JwkSet, PublicKeyUse, ThumbprintHash},
+};
+use p256::{SecretKey, pkcs8::EncodePrivateKey};
+use rand::rngs::OsRng;
+
+/// ES256 key pair used to sign OpenID Connect id_tokens. Unlike access tokens, id_tokens are
+/// verified by relying parties, so they are signed with an asymmetric key published as a JWKS.
+#[derive(Clone)]
+pub struct IdTokenKey {
+    encoding_key: EncodingKey,
+    jwk: Jwk,
+}
+
+impl IdTokenKey {
+    /// Loads a PKCS#8 PEM encoded P-256 private key.
+    pub fn from_pem(pem: &[u8]) -> Result<Self, TokenError> {
+        let encoding_key: EncodingKey = EncodingKey::from_ec_pem(pem)?;
+
+        Self::from_encoding_key(encoding_key)
+    }
+
+    /// Generates a throwaway key. Tokens signed with it stop validating once the process
+    /// restarts.
+    pub fn generate() -> Result<Self, TokenError> {
+        let secret_key: SecretKey = SecretKey::random(&mut OsRng);
+        let der = secret_key
+            .to_pkcs8_der()
+            .map_err(|_| TokenError::Internal)?;
+
+        Self::from_encoding_key(EncodingKey::from_ec_der(der.as_bytes()))
+    }
+
+    fn from_encoding_key(encoding_key: EncodingKey) -> Result<Self, TokenError> {
+        let mut jwk: Jwk = Jwk::from_encoding_key(&encoding_key, Algorithm::ES256)?;
+        jwk.common.key_id = Some(jwk.thumbprint(ThumbprintHash::SHA256));
+        jwk.common.public_key_use = Some(PublicKeyUse::Signature);
+
+        Ok(Self { encoding_key, jwk })
+    }
+
+    pub fn key_id(&self) -> Option<&str> {
+        self.jwk.common.key_id.as_deref()
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: vec![self.jwk.clone()],
+        }
+    }
+}
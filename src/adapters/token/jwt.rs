@@ -1,33 +1,98 @@
+use super::id_token_key::IdTokenKey;
 use crate::{
     application::{
-        auth::authenticated_user::AuthenticatedUser,
+        auth::{
+            authenticated_user::{Actor, AuthenticatedUser},
+            scope::Scope,
+        },
         security::{
             error::TokenError,
-            token::{IssuedToken, RefreshToken, Token},
+            token::{AccessGrant, IssuedToken, MfaToken, RefreshGrant, RefreshToken, Token},
             token_service::TokenService,
         },
     },
-    domain::user::entity::UserRole,
+    domain::{organization::entity::Organization, role::entity::RoleName},
 };
 use jsonwebtoken::{
-    DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode, errors::ErrorKind,
+    Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode,
+    errors::ErrorKind, jwk::JwkSet,
 };
 use serde::{Deserialize, Serialize};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
+const REFRESH_TOKEN_TYPE: &str = "refresh";
+const MFA_TOKEN_TYPE: &str = "mfa";
+const MFA_TOKEN_TTL_SECONDS: u64 = 5 * 60;
+const IMPERSONATION_TOKEN_TTL_SECONDS: u64 = 15 * 60;
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Claims {
+    jti: String,
     sub: String,
     username: String,
-    roles: Vec<UserRole>,
+    /// The active organization. Missing from tokens issued before organizations existed,
+    /// which act in the default organization.
+    #[serde(default)]
+    org: Option<String>,
+    roles: Vec<String>,
+    scope: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    /// The administrator impersonating the user (RFC 8693 actor claim).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    act: Option<ActorClaims>,
+    /// Fractional, to the millisecond, for revocation cutoffs. Missing from tokens issued
+    /// before it was added, which count as issued at the epoch.
+    #[serde(default)]
+    iat: f64,
     exp: usize,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct ActorClaims {
+    sub: String,
+    username: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct RefreshClaims {
+    jti: String,
+    typ: String,
+    sub: String,
+    #[serde(default)]
+    org: Option<String>,
+    scope: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    /// Fractional, to the millisecond, for revocation cutoffs. Missing from tokens issued
+    /// before it was added, which count as issued at the epoch.
+    #[serde(default)]
+    iat: f64,
+    exp: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MfaClaims {
+    typ: String,
+    sub: String,
+    exp: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct IdTokenClaims {
+    iss: String,
     sub: String,
+    aud: String,
+    iat: usize,
     exp: usize,
+    auth_time: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_username: Option<String>,
 }
 
 #[derive(Clone)]
@@ -35,33 +100,111 @@ pub struct JwtService {
     secret: String,
     ttl_seconds: u64,
     refresh_ttl_seconds: u64,
+    issuer: String,
+    id_token_key: IdTokenKey,
 }
 
 impl JwtService {
-    pub fn new(jwt_secret: String, ttl_seconds: u64, refresh_ttl_seconds: u64) -> Self {
+    pub fn new(
+        jwt_secret: String,
+        ttl_seconds: u64,
+        refresh_ttl_seconds: u64,
+        issuer: String,
+        id_token_key: IdTokenKey,
+    ) -> Self {
         let secret: String = jwt_secret;
 
         Self {
             secret,
             ttl_seconds,
             refresh_ttl_seconds,
+            issuer,
+            id_token_key,
         }
     }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Public keys relying parties use to verify id_tokens.
+    pub fn jwks(&self) -> JwkSet {
+        self.id_token_key.jwks()
+    }
+
+    fn issue_id_token(
+        &self,
+        user: &AuthenticatedUser,
+        client_id: &Uuid,
+    ) -> Result<Token, TokenError> {
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TokenError::Internal)?
+            .as_secs();
+        let claims: IdTokenClaims = IdTokenClaims {
+            iss: self.issuer.clone(),
+            sub: user.id.to_string(),
+            aud: client_id.to_string(),
+            iat: now as usize,
+            exp: (now + self.ttl_seconds) as usize,
+            auth_time: user.auth_time.unwrap_or(now) as usize,
+            preferred_username: user
+                .has_scope(&Scope::Profile)
+                .then(|| user.username.clone()),
+        };
+
+        let mut header: Header = Header::new(Algorithm::ES256);
+        header.kid = self.id_token_key.key_id().map(String::from);
+
+        Ok(Token::new(encode(
+            &header,
+            &claims,
+            self.id_token_key.encoding_key(),
+        )?))
+    }
+}
+
+fn milliseconds(issued_at: f64) -> u64 {
+    (issued_at * 1000.0).round() as u64
+}
+
+fn organization_id(org: Option<String>) -> Result<Uuid, TokenError> {
+    org.map(|id| id.parse().map_err(|_| TokenError::Malformed))
+        .unwrap_or(Ok(Organization::DEFAULT_ID))
 }
 
 impl TokenService for JwtService {
+    /// Impersonation tokens are short-lived and come without a refresh token or id_token.
     fn issue(&self, user: &AuthenticatedUser) -> Result<IssuedToken, TokenError> {
-        let expires_in: u64 = self.ttl_seconds;
+        let expires_in: u64 = match user.actor {
+            Some(_) => self.ttl_seconds.min(IMPERSONATION_TOKEN_TTL_SECONDS),
+            None => self.ttl_seconds,
+        };
 
-        let exp: u64 = SystemTime::now()
+        let elapsed: Duration = SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .map_err(|_| TokenError::Internal)?
-            .as_secs()
-            + self.ttl_seconds;
+            .map_err(|_| TokenError::Internal)?;
+        let now: u64 = elapsed.as_secs();
+        let issued_at: f64 = elapsed.as_millis() as f64 / 1000.0;
+        let exp: u64 = now + expires_in;
         let claims: Claims = Claims {
+            jti: Uuid::now_v7().to_string(),
             sub: user.id.to_string(),
             username: user.username.clone(),
-            roles: user.roles.clone(),
+            org: Some(user.organization_id.to_string()),
+            roles: user
+                .roles
+                .iter()
+                .map(|role| role.as_str().to_owned())
+                .collect(),
+            scope: Scope::join(&user.scopes),
+            client_id: user.client_id.map(|id| id.to_string()),
+            sid: user.session_id.map(|id| id.to_string()),
+            act: user.actor.as_ref().map(|actor| ActorClaims {
+                sub: actor.id.to_string(),
+                username: actor.username.clone(),
+            }),
+            iat: issued_at,
             exp: exp as usize,
         };
         let token: Token = Token::new(encode(
@@ -70,13 +213,27 @@ impl TokenService for JwtService {
             &EncodingKey::from_secret(self.secret.as_bytes()),
         )?);
 
-        let exp: u64 = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| TokenError::Internal)?
-            .as_secs()
-            + self.refresh_ttl_seconds;
+        if user.is_impersonated() {
+            return Ok(IssuedToken::new(
+                token,
+                expires_in,
+                None,
+                0,
+                None,
+                user.scopes.clone(),
+            ));
+        }
+
+        let exp: u64 = now + self.refresh_ttl_seconds;
         let refresh_claims: RefreshClaims = RefreshClaims {
+            jti: Uuid::now_v7().to_string(),
+            typ: REFRESH_TOKEN_TYPE.into(),
             sub: user.id.to_string(),
+            org: Some(user.organization_id.to_string()),
+            scope: Scope::join(&user.scopes),
+            client_id: user.client_id.map(|id| id.to_string()),
+            sid: user.session_id.map(|id| id.to_string()),
+            iat: issued_at,
             exp: exp as usize,
         };
         let refresh_token: Option<RefreshToken> = Some(RefreshToken::new(encode(
@@ -85,35 +242,156 @@ impl TokenService for JwtService {
             &EncodingKey::from_secret(self.secret.as_bytes()),
         )?));
 
-        let issued_token: IssuedToken = IssuedToken::new(token, expires_in, refresh_token);
+        let id_token: Option<Token> = match user.client_id {
+            Some(client_id) if user.has_scope(&Scope::OpenId) => {
+                Some(self.issue_id_token(user, &client_id)?)
+            }
+            _ => None,
+        };
+
+        let issued_token: IssuedToken = IssuedToken::new(
+            token,
+            expires_in,
+            refresh_token,
+            self.refresh_ttl_seconds,
+            id_token,
+            user.scopes.clone(),
+        );
 
         Ok(issued_token)
     }
 
-    fn verify(&self, token: &Token) -> Result<AuthenticatedUser, TokenError> {
+    fn verify(&self, token: &Token) -> Result<AccessGrant, TokenError> {
         let data: TokenData<Claims> = decode::<Claims>(
             token.as_str(),
             &DecodingKey::from_secret(self.secret.as_bytes()),
             &Validation::default(),
         )?;
 
-        Ok(AuthenticatedUser::new(
-            data.claims.sub.parse().unwrap(),
+        let token_id: Uuid = data.claims.jti.parse().map_err(|_| TokenError::Malformed)?;
+        let id: Uuid = data.claims.sub.parse().map_err(|_| TokenError::Malformed)?;
+        let scopes: Vec<Scope> =
+            Scope::parse_list(&data.claims.scope).map_err(|_| TokenError::Invalid)?;
+        let client_id: Option<Uuid> = data
+            .claims
+            .client_id
+            .map(|id| id.parse())
+            .transpose()
+            .map_err(|_| TokenError::Malformed)?;
+        let session_id: Option<Uuid> = data
+            .claims
+            .sid
+            .map(|id| id.parse())
+            .transpose()
+            .map_err(|_| TokenError::Malformed)?;
+        let actor: Option<Actor> = data
+            .claims
+            .act
+            .map(|act| {
+                act.sub.parse().map(|id| Actor {
+                    id,
+                    username: act.username,
+                })
+            })
+            .transpose()
+            .map_err(|_| TokenError::Malformed)?;
+        let roles: Vec<RoleName> = data
+            .claims
+            .roles
+            .into_iter()
+            .map(RoleName::new)
+            .collect::<Result<_, _>>()
+            .map_err(|_| TokenError::Malformed)?;
+        let user: AuthenticatedUser = AuthenticatedUser::new(
+            id,
             data.claims.username,
-            data.claims.roles,
+            organization_id(data.claims.org)?,
+            roles,
+            scopes,
+            client_id,
+            session_id,
+            actor,
+        );
+
+        Ok(AccessGrant::new(
+            token_id,
+            user,
+            milliseconds(data.claims.iat),
+            data.claims.exp as u64,
         ))
     }
 
-    fn verify_refresh(&self, refresh_token: &RefreshToken) -> Result<Uuid, TokenError> {
+    fn verify_refresh(&self, refresh_token: &RefreshToken) -> Result<RefreshGrant, TokenError> {
         let data: TokenData<RefreshClaims> = decode::<RefreshClaims>(
             refresh_token.as_str(),
             &DecodingKey::from_secret(self.secret.as_bytes()),
             &Validation::default(),
         )?;
 
+        if data.claims.typ != REFRESH_TOKEN_TYPE {
+            return Err(TokenError::Invalid);
+        }
+
+        let token_id: Uuid = data.claims.jti.parse().map_err(|_| TokenError::Malformed)?;
         let id: Uuid = data.claims.sub.parse().map_err(|_| TokenError::Malformed)?;
+        let scopes: Vec<Scope> =
+            Scope::parse_list(&data.claims.scope).map_err(|_| TokenError::Invalid)?;
+        let client_id: Option<Uuid> = data
+            .claims
+            .client_id
+            .map(|id| id.parse())
+            .transpose()
+            .map_err(|_| TokenError::Malformed)?;
+        let session_id: Option<Uuid> = data
+            .claims
+            .sid
+            .map(|id| id.parse())
+            .transpose()
+            .map_err(|_| TokenError::Malformed)?;
+
+        Ok(RefreshGrant::new(
+            token_id,
+            id,
+            organization_id(data.claims.org)?,
+            scopes,
+            client_id,
+            session_id,
+            milliseconds(data.claims.iat),
+            data.claims.exp as u64,
+        ))
+    }
+
+    fn issue_mfa(&self, user_id: &Uuid) -> Result<MfaToken, TokenError> {
+        let exp: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TokenError::Internal)?
+            .as_secs()
+            + MFA_TOKEN_TTL_SECONDS;
+        let claims: MfaClaims = MfaClaims {
+            typ: MFA_TOKEN_TYPE.into(),
+            sub: user_id.to_string(),
+            exp: exp as usize,
+        };
+
+        Ok(MfaToken::new(encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_bytes()),
+        )?))
+    }
+
+    fn verify_mfa(&self, mfa_token: &MfaToken) -> Result<Uuid, TokenError> {
+        let data: TokenData<MfaClaims> = decode::<MfaClaims>(
+            mfa_token.as_str(),
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &Validation::default(),
+        )?;
+
+        if data.claims.typ != MFA_TOKEN_TYPE {
+            return Err(TokenError::Invalid);
+        }
 
-        Ok(id)
+        data.claims.sub.parse().map_err(|_| TokenError::Malformed)
     }
 }
 
@@ -143,3 +421,47 @@ impl From<jsonwebtoken::errors::Error> for TokenError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    #[test]
+    fn keeps_the_issue_time_to_the_millisecond() {
+        let service: JwtService = JwtService::new(
+            "secret".into(),
+            60,
+            3600,
+            "http://localhost".into(),
+            IdTokenKey::generate().unwrap(),
+        );
+        let user: AuthenticatedUser = AuthenticatedUser::new(
+            Uuid::now_v7(),
+            "alice".into(),
+            Organization::DEFAULT_ID,
+            vec![RoleName::user()],
+            vec![Scope::Profile],
+            None,
+            None,
+            None,
+        );
+
+        let before: u64 = millis();
+        let issued: IssuedToken = service.issue(&user).unwrap();
+        let after: u64 = millis();
+
+        let grant: AccessGrant = service.verify(&issued.token).unwrap();
+        assert!((before..=after).contains(&grant.issued_at));
+
+        let refresh_token: &RefreshToken = issued.refresh_token.as_ref().unwrap();
+        let grant: RefreshGrant = service.verify_refresh(refresh_token).unwrap();
+        assert!((before..=after).contains(&grant.issued_at));
+    }
+}
@@ -1 +1,2 @@
+pub mod id_token_key;
 pub mod jwt;
@@ -0,0 +1,247 @@
+//! Just enough of CBOR (RFC 8949) to read WebAuthn attestation objects and COSE keys.
+
+pub enum Value {
+    Unsigned(u64),
+    Negative(i64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Map(Vec<(Value, Value)>),
+    /// Arrays and simple values (booleans, null) are skipped over, WebAuthn never needs their
+    /// contents.
+    Other,
+}
+
+impl Value {
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Value::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Unsigned(value) => i64::try_from(*value).ok(),
+            Value::Negative(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Looks up a map entry by text key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries()?
+            .iter()
+            .find(|(k, _)| k.as_text() == Some(key))
+            .map(|(_, value)| value)
+    }
+
+    /// Looks up a map entry by integer key, as used by COSE.
+    pub fn get_label(&self, label: i64) -> Option<&Value> {
+        self.entries()?
+            .iter()
+            .find(|(k, _)| k.as_integer() == Some(label))
+            .map(|(_, value)| value)
+    }
+
+    fn entries(&self) -> Option<&[(Value, Value)]> {
+        match self {
+            Value::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+}
+
+/// Maximum nesting accepted, WebAuthn structures are only a few levels deep.
+const MAX_DEPTH: usize = 16;
+
+/// Decodes one data item from the front of `input`, returning it with the remaining bytes.
+pub fn decode(input: &[u8]) -> Option<(Value, &[u8])> {
+    decode_item(input, 0)
+}
+
+fn decode_item(input: &[u8], depth: usize) -> Option<(Value, &[u8])> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+
+    let (&initial, rest) = input.split_first()?;
+    let major: u8 = initial >> 5;
+    let info: u8 = initial & 0x1f;
+
+    if major == 7 {
+        return match info {
+            20..=22 => Some((Value::Other, rest)),
+            _ => None,
+        };
+    }
+
+    let (argument, mut rest) = read_argument(info, rest)?;
+
+    match major {
+        0 => Some((Value::Unsigned(argument), rest)),
+        1 => Some((Value::Negative(-1 - i64::try_from(argument).ok()?), rest)),
+        2 | 3 => {
+            let length: usize = usize::try_from(argument).ok()?;
+
+            if rest.len() < length {
+                return None;
+            }
+
+            let (bytes, rest) = rest.split_at(length);
+            let value: Value = if major == 2 {
+                Value::Bytes(bytes.to_vec())
+            } else {
+                Value::Text(String::from_utf8(bytes.to_vec()).ok()?)
+            };
+
+            Some((value, rest))
+        }
+        4 => {
+            for _ in 0..argument {
+                let (_, remaining) = decode_item(rest, depth + 1)?;
+                rest = remaining;
+            }
+
+            Some((Value::Other, rest))
+        }
+        5 => {
+            let mut entries: Vec<(Value, Value)> = Vec::new();
+
+            for _ in 0..argument {
+                let (key, remaining) = decode_item(rest, depth + 1)?;
+                let (value, remaining) = decode_item(remaining, depth + 1)?;
+                entries.push((key, value));
+                rest = remaining;
+            }
+
+            Some((Value::Map(entries), rest))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the argument of a data item head. Indefinite lengths are not supported, they are
+/// not allowed in the CTAP2 canonical encoding authenticators use.
+fn read_argument(info: u8, input: &[u8]) -> Option<(u64, &[u8])> {
+    let width: usize = match info {
+        0..=23 => return Some((info as u64, input)),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return None,
+    };
+
+    if input.len() < width {
+        return None;
+    }
+
+    let (bytes, rest) = input.split_at(width);
+    let argument: u64 = bytes
+        .iter()
+        .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+
+    Some((argument, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_integers_of_every_width() {
+        let vectors: [(&[u8], i64); 8] = [
+            (&[0x00], 0),
+            (&[0x17], 23),
+            (&[0x18, 0x18], 24),
+            (&[0x19, 0x03, 0xe8], 1000),
+            (&[0x1a, 0x00, 0x0f, 0x42, 0x40], 1_000_000),
+            (
+                &[0x1b, 0, 0, 0, 0xe8, 0xd4, 0xa5, 0x10, 0x00],
+                1_000_000_000_000,
+            ),
+            (&[0x20], -1),
+            (&[0x38, 0x63], -100),
+        ];
+
+        for (input, expected) in vectors {
+            let (value, rest) = decode(input).unwrap();
+
+            assert_eq!(value.as_integer(), Some(expected));
+            assert!(rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn decodes_byte_and_text_strings() {
+        let (bytes, _) = decode(&[0x43, 0x01, 0x02, 0x03]).unwrap();
+        let (text, _) = decode(&[0x64, b'I', b'E', b'T', b'F']).unwrap();
+
+        assert_eq!(bytes.as_bytes(), Some(&[0x01, 0x02, 0x03][..]));
+        assert_eq!(text.as_text(), Some("IETF"));
+    }
+
+    #[test]
+    fn looks_up_map_entries_by_text_and_integer_key() {
+        // {"fmt": "none", 3: -7}
+        let input: &[u8] = &[
+            0xa2, 0x63, b'f', b'm', b't', 0x64, b'n', b'o', b'n', b'e', 0x03, 0x26,
+        ];
+
+        let (map, rest) = decode(input).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(map.get("fmt").and_then(Value::as_text), Some("none"));
+        assert_eq!(map.get_label(3).and_then(Value::as_integer), Some(-7));
+        assert!(map.get("attStmt").is_none());
+    }
+
+    #[test]
+    fn skips_arrays_and_simple_values() {
+        // {"a": [1, true, null], "b": false} followed by one more item.
+        let input: &[u8] = &[
+            0xa2, 0x61, b'a', 0x83, 0x01, 0xf5, 0xf6, 0x61, b'b', 0xf4, 0x07,
+        ];
+
+        let (map, rest) = decode(input).unwrap();
+
+        assert!(matches!(map.get("a"), Some(Value::Other)));
+        assert!(matches!(map.get("b"), Some(Value::Other)));
+        assert_eq!(rest, &[0x07]);
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        assert!(decode(&[]).is_none());
+        assert!(decode(&[0x19, 0x03]).is_none());
+        assert!(decode(&[0x43, 0x01, 0x02]).is_none());
+        assert!(decode(&[0xa1, 0x01]).is_none());
+    }
+
+    #[test]
+    fn rejects_indefinite_lengths_and_floats() {
+        assert!(decode(&[0x5f, 0x41, 0x00, 0xff]).is_none());
+        assert!(decode(&[0xf9, 0x3c, 0x00]).is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_utf8_text() {
+        assert!(decode(&[0x62, 0xc3, 0x28]).is_none());
+    }
+
+    #[test]
+    fn rejects_nesting_deeper_than_the_limit() {
+        let mut input: Vec<u8> = vec![0x81; MAX_DEPTH + 2];
+        input.push(0x00);
+
+        assert!(decode(&input).is_none());
+        assert!(decode(&input[2..]).is_some());
+    }
+}
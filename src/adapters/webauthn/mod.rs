@@ -0,0 +1,5 @@
+mod cbor;
+pub mod verifier;
+
+#[cfg(test)]
+pub mod software;
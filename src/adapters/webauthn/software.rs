@@ -0,0 +1,180 @@
+//! A software authenticator for tests, performing the authenticator side of the WebAuthn
+//! ceremonies with an ES256 key held in memory.
+
+use p256::{
+    EncodedPoint,
+    ecdsa::{DerSignature, SigningKey, signature::Signer},
+};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+pub struct SoftwareAuthenticator {
+    key: SigningKey,
+    pub credential_id: Vec<u8>,
+    pub sign_count: u32,
+    /// Whether the signature counter is incremented, some authenticators always report zero.
+    counts: bool,
+    rp_id: String,
+    origin: String,
+}
+
+/// What `navigator.credentials.create()` hands back.
+pub struct Attestation {
+    pub client_data_json: Vec<u8>,
+    pub attestation_object: Vec<u8>,
+}
+
+/// What `navigator.credentials.get()` hands back.
+pub struct Assertion {
+    pub client_data_json: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl SoftwareAuthenticator {
+    pub fn new(rp_id: &str, origin: &str) -> Self {
+        let mut credential_id: Vec<u8> = vec![0; 16];
+        OsRng.fill_bytes(&mut credential_id);
+
+        Self {
+            key: SigningKey::random(&mut OsRng),
+            credential_id,
+            sign_count: 0,
+            counts: true,
+            rp_id: rp_id.into(),
+            origin: origin.into(),
+        }
+    }
+
+    /// An authenticator that does not implement a signature counter.
+    pub fn without_counter(rp_id: &str, origin: &str) -> Self {
+        Self {
+            counts: false,
+            ..Self::new(rp_id, origin)
+        }
+    }
+
+    /// Creates the credential, answering `challenge`.
+    pub fn register(&mut self, challenge: &str) -> Attestation {
+        let point: EncodedPoint = self.key.verifying_key().to_encoded_point(false);
+
+        let cose_key: Vec<u8> = [
+            map(5),
+            unsigned(1),
+            unsigned(2),
+            unsigned(3),
+            negative(-7),
+            negative(-1),
+            unsigned(1),
+            negative(-2),
+            bytes(point.x().unwrap()),
+            negative(-3),
+            bytes(point.y().unwrap()),
+        ]
+        .concat();
+
+        let mut auth_data: Vec<u8> = self.authenticator_data(
+            FLAG_USER_PRESENT | FLAG_USER_VERIFIED | FLAG_ATTESTED_CREDENTIAL_DATA,
+        );
+        auth_data.extend_from_slice(&[0; 16]);
+        auth_data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+        auth_data.extend_from_slice(&self.credential_id);
+        auth_data.extend_from_slice(&cose_key);
+
+        let attestation_object: Vec<u8> = [
+            map(3),
+            text("fmt"),
+            text("none"),
+            text("attStmt"),
+            map(0),
+            text("authData"),
+            bytes(&auth_data),
+        ]
+        .concat();
+
+        Attestation {
+            client_data_json: self.client_data("webauthn.create", challenge, &self.origin),
+            attestation_object,
+        }
+    }
+
+    /// Signs in with the credential, answering `challenge`.
+    pub fn assert(&mut self, challenge: &str) -> Assertion {
+        self.assert_for(challenge, &self.origin.clone())
+    }
+
+    /// Signs in as if the browser were on `origin`.
+    pub fn assert_for(&mut self, challenge: &str, origin: &str) -> Assertion {
+        if self.counts {
+            self.sign_count += 1;
+        }
+
+        let client_data_json: Vec<u8> = self.client_data("webauthn.get", challenge, origin);
+        let authenticator_data: Vec<u8> =
+            self.authenticator_data(FLAG_USER_PRESENT | FLAG_USER_VERIFIED);
+
+        let mut signed: Vec<u8> = authenticator_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data_json));
+        let signature: DerSignature = self.key.sign(&signed);
+
+        Assertion {
+            client_data_json,
+            authenticator_data,
+            signature: signature.as_bytes().to_vec(),
+        }
+    }
+
+    fn authenticator_data(&self, flags: u8) -> Vec<u8> {
+        let mut data: Vec<u8> = Sha256::digest(self.rp_id.as_bytes()).to_vec();
+        data.push(flags);
+        data.extend_from_slice(&self.sign_count.to_be_bytes());
+
+        data
+    }
+
+    fn client_data(&self, kind: &str, challenge: &str, origin: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "type": kind,
+            "challenge": challenge,
+            "origin": origin,
+            "crossOrigin": false,
+        }))
+        .unwrap()
+    }
+}
+
+fn head(major: u8, argument: u64) -> Vec<u8> {
+    match argument {
+        0..=23 => vec![(major << 5) | argument as u8],
+        24..=0xff => vec![(major << 5) | 24, argument as u8],
+        _ => {
+            let mut out: Vec<u8> = vec![(major << 5) | 25];
+            out.extend_from_slice(&(argument as u16).to_be_bytes());
+            out
+        }
+    }
+}
+
+fn unsigned(value: u64) -> Vec<u8> {
+    head(0, value)
+}
+
+fn negative(value: i64) -> Vec<u8> {
+    head(1, (-1 - value) as u64)
+}
+
+fn bytes(value: &[u8]) -> Vec<u8> {
+    [head(2, value.len() as u64), value.to_vec()].concat()
+}
+
+fn text(value: &str) -> Vec<u8> {
+    [head(3, value.len() as u64), value.as_bytes().to_vec()].concat()
+}
+
+fn map(entries: u64) -> Vec<u8> {
+    head(5, entries)
+}
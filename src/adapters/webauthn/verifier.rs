@@ -0,0 +1,562 @@
+use super::cbor::{self, Value};
+use crate::domain::passkey::{
+    entity::Passkey,
+    verifier::{
+        PasskeyAssertion, PasskeyChallenge, PasskeyError, PasskeyRegistration, PasskeyVerifier,
+    },
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use rand::{RngCore, rngs::OsRng};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+const CHALLENGE_TTL_SECONDS: u64 = 300;
+const NONCE_BYTES: usize = 16;
+const TAG_BYTES: usize = 16;
+const PAYLOAD_BYTES: usize = NONCE_BYTES + 8 + 16;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+/// rpIdHash (32) + flags (1) + signCount (4).
+const AUTHENTICATOR_DATA_HEADER: usize = 37;
+/// Length of the AAGUID at the start of the attested credential data.
+const AAGUID_BYTES: usize = 16;
+
+const COSE_KEY_TYPE: i64 = 1;
+const COSE_ALGORITHM: i64 = 3;
+const COSE_EC2_CURVE: i64 = -1;
+const COSE_EC2_X: i64 = -2;
+const COSE_EC2_Y: i64 = -3;
+const COSE_KEY_TYPE_EC2: i64 = 2;
+const COSE_ALGORITHM_ES256: i64 = -7;
+const COSE_CURVE_P256: i64 = 1;
+
+/// WebAuthn relying party supporting ES256 credentials, the algorithm every platform
+/// authenticator offers.
+///
+/// Challenges carry their expiry and the user they were issued for, and are authenticated with
+/// an HMAC. The verifier does not remember them, callers store issued challenges and consume
+/// the one a ceremony answered. Attestation statements are not verified, since credentials are
+/// requested with `attestation: "none"`.
+#[derive(Clone)]
+pub struct WebAuthnVerifier {
+    secret: Vec<u8>,
+    origin: String,
+    rp_id: String,
+    rp_name: String,
+}
+
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    kind: String,
+    challenge: String,
+    origin: String,
+}
+
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    flags: u8,
+    sign_count: u32,
+    attested_credential_data: &'a [u8],
+}
+
+impl WebAuthnVerifier {
+    pub fn new(
+        secret: impl AsRef<[u8]>,
+        origin: impl Into<String>,
+        rp_id: impl Into<String>,
+        rp_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            secret: secret.as_ref().to_vec(),
+            origin: origin.into(),
+            rp_id: rp_id.into(),
+            rp_name: rp_name.into(),
+        }
+    }
+
+    fn tag(&self, payload: &[u8]) -> Vec<u8> {
+        let mut mac: Hmac<Sha256> =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any size");
+        mac.update(b"webauthn-challenge");
+        mac.update(payload);
+
+        mac.finalize().into_bytes()[..TAG_BYTES].to_vec()
+    }
+
+    /// Parses and checks the client data, returning the challenge and the user it was issued
+    /// for.
+    fn verify_client_data(
+        &self,
+        client_data_json: &[u8],
+        expected_type: &str,
+    ) -> Result<(String, Option<Uuid>), PasskeyError> {
+        let client_data: ClientData =
+            serde_json::from_slice(client_data_json).map_err(|_| PasskeyError::Malformed)?;
+
+        if client_data.kind != expected_type {
+            return Err(PasskeyError::Malformed);
+        }
+
+        if client_data.origin != self.origin {
+            return Err(PasskeyError::InvalidOrigin);
+        }
+
+        let challenge: Vec<u8> = URL_SAFE_NO_PAD
+            .decode(&client_data.challenge)
+            .map_err(|_| PasskeyError::InvalidChallenge)?;
+
+        if challenge.len() != PAYLOAD_BYTES + TAG_BYTES {
+            return Err(PasskeyError::InvalidChallenge);
+        }
+
+        let (payload, tag) = challenge.split_at(PAYLOAD_BYTES);
+
+        if !constant_time_eq(&self.tag(payload), tag) {
+            return Err(PasskeyError::InvalidChallenge);
+        }
+
+        let expires_at: u64 = u64::from_be_bytes(
+            payload[NONCE_BYTES..NONCE_BYTES + 8]
+                .try_into()
+                .map_err(|_| PasskeyError::InvalidChallenge)?,
+        );
+
+        if expires_at < now() {
+            return Err(PasskeyError::InvalidChallenge);
+        }
+
+        let user_id: Uuid = Uuid::from_slice(&payload[NONCE_BYTES + 8..])
+            .map_err(|_| PasskeyError::InvalidChallenge)?;
+
+        Ok((
+            client_data.challenge,
+            (!user_id.is_nil()).then_some(user_id),
+        ))
+    }
+
+    fn parse_authenticator_data<'a>(
+        &self,
+        data: &'a [u8],
+    ) -> Result<AuthenticatorData<'a>, PasskeyError> {
+        if data.len() < AUTHENTICATOR_DATA_HEADER {
+            return Err(PasskeyError::Malformed);
+        }
+
+        let authenticator_data: AuthenticatorData = AuthenticatorData {
+            rp_id_hash: &data[..32],
+            flags: data[32],
+            sign_count: u32::from_be_bytes([data[33], data[34], data[35], data[36]]),
+            attested_credential_data: &data[AUTHENTICATOR_DATA_HEADER..],
+        };
+
+        if authenticator_data.rp_id_hash != Sha256::digest(self.rp_id.as_bytes()).as_slice() {
+            return Err(PasskeyError::InvalidOrigin);
+        }
+
+        if authenticator_data.flags & FLAG_USER_PRESENT == 0 {
+            return Err(PasskeyError::Unsupported);
+        }
+
+        Ok(authenticator_data)
+    }
+}
+
+impl PasskeyVerifier for WebAuthnVerifier {
+    fn rp_id(&self) -> &str {
+        &self.rp_id
+    }
+
+    fn rp_name(&self) -> &str {
+        &self.rp_name
+    }
+
+    fn challenge(&self, user_id: Option<&Uuid>) -> PasskeyChallenge {
+        let expires_at: u64 = now() + CHALLENGE_TTL_SECONDS;
+
+        let mut payload: Vec<u8> = vec![0; NONCE_BYTES];
+        OsRng.fill_bytes(&mut payload);
+        payload.extend_from_slice(&expires_at.to_be_bytes());
+        payload.extend_from_slice(user_id.unwrap_or(&Uuid::nil()).as_bytes());
+
+        let tag: Vec<u8> = self.tag(&payload);
+        payload.extend_from_slice(&tag);
+
+        PasskeyChallenge {
+            value: URL_SAFE_NO_PAD.encode(payload),
+            expires_at,
+        }
+    }
+
+    fn verify_registration(
+        &self,
+        user_id: &Uuid,
+        client_data_json: &[u8],
+        attestation_object: &[u8],
+    ) -> Result<PasskeyRegistration, PasskeyError> {
+        let (challenge, challenge_user_id) =
+            self.verify_client_data(client_data_json, "webauthn.create")?;
+
+        if challenge_user_id != Some(*user_id) {
+            return Err(PasskeyError::InvalidChallenge);
+        }
+
+        let (attestation, _) = cbor::decode(attestation_object).ok_or(PasskeyError::Malformed)?;
+        let auth_data: &[u8] = attestation
+            .get("authData")
+            .and_then(Value::as_bytes)
+            .ok_or(PasskeyError::Malformed)?;
+
+        let authenticator_data: AuthenticatorData = self.parse_authenticator_data(auth_data)?;
+
+        if authenticator_data.flags & FLAG_ATTESTED_CREDENTIAL_DATA == 0 {
+            return Err(PasskeyError::Malformed);
+        }
+
+        let data: &[u8] = authenticator_data.attested_credential_data;
+
+        if data.len() < AAGUID_BYTES + 2 {
+            return Err(PasskeyError::Malformed);
+        }
+
+        let length: usize =
+            u16::from_be_bytes([data[AAGUID_BYTES], data[AAGUID_BYTES + 1]]) as usize;
+        let data: &[u8] = &data[AAGUID_BYTES + 2..];
+
+        if data.len() < length {
+            return Err(PasskeyError::Malformed);
+        }
+
+        let (credential_id, data) = data.split_at(length);
+        let (cose_key, _) = cbor::decode(data).ok_or(PasskeyError::Malformed)?;
+
+        Ok(PasskeyRegistration {
+            challenge,
+            credential_id: credential_id.to_vec(),
+            public_key: es256_public_key(&cose_key)?,
+            sign_count: authenticator_data.sign_count,
+        })
+    }
+
+    fn verify_assertion(
+        &self,
+        passkey: &Passkey,
+        client_data_json: &[u8],
+        authenticator_data: &[u8],
+        signature: &[u8],
+    ) -> Result<PasskeyAssertion, PasskeyError> {
+        let (challenge, challenge_user_id) =
+            self.verify_client_data(client_data_json, "webauthn.get")?;
+
+        if challenge_user_id.is_some() {
+            return Err(PasskeyError::InvalidChallenge);
+        }
+
+        let sign_count: u32 = self
+            .parse_authenticator_data(authenticator_data)?
+            .sign_count;
+
+        let key: VerifyingKey = VerifyingKey::from_sec1_bytes(&passkey.public_key)
+            .map_err(|_| PasskeyError::Unsupported)?;
+        let signature: Signature =
+            Signature::from_der(signature).map_err(|_| PasskeyError::InvalidSignature)?;
+
+        let mut signed: Vec<u8> = authenticator_data.to_vec();
+        signed.extend_from_slice(&Sha256::digest(client_data_json));
+
+        key.verify(&signed, &signature)
+            .map_err(|_| PasskeyError::InvalidSignature)?;
+
+        // Authenticators that do not implement a counter always report zero. Replays of their
+        // assertions are caught by consuming the challenge instead.
+        if (sign_count != 0 || passkey.sign_count != 0) && sign_count <= passkey.sign_count {
+            return Err(PasskeyError::CounterRegression);
+        }
+
+        Ok(PasskeyAssertion {
+            challenge,
+            sign_count,
+        })
+    }
+}
+
+/// Converts an ES256 COSE key into a SEC1 uncompressed point.
+fn es256_public_key(key: &Value) -> Result<Vec<u8>, PasskeyError> {
+    let label = |label: i64| key.get_label(label).ok_or(PasskeyError::Malformed);
+
+    if label(COSE_KEY_TYPE)?.as_integer() != Some(COSE_KEY_TYPE_EC2)
+        || label(COSE_ALGORITHM)?.as_integer() != Some(COSE_ALGORITHM_ES256)
+        || label(COSE_EC2_CURVE)?.as_integer() != Some(COSE_CURVE_P256)
+    {
+        return Err(PasskeyError::Unsupported);
+    }
+
+    let x: &[u8] = label(COSE_EC2_X)?
+        .as_bytes()
+        .ok_or(PasskeyError::Malformed)?;
+    let y: &[u8] = label(COSE_EC2_Y)?
+        .as_bytes()
+        .ok_or(PasskeyError::Malformed)?;
+
+    let mut point: Vec<u8> = Vec::with_capacity(1 + x.len() + y.len());
+    point.push(0x04);
+    point.extend_from_slice(x);
+    point.extend_from_slice(y);
+
+    VerifyingKey::from_sec1_bytes(&point).map_err(|_| PasskeyError::Malformed)?;
+
+    Ok(point)
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::webauthn::software::{Assertion, Attestation, SoftwareAuthenticator};
+
+    const ORIGIN: &str = "https://login.example.org";
+    const RP_ID: &str = "example.org";
+
+    fn verifier() -> WebAuthnVerifier {
+        WebAuthnVerifier::new("test-secret", ORIGIN, RP_ID, "Example")
+    }
+
+    /// Registers a software authenticator, returning the passkey that would be stored.
+    fn registered(authenticator: &mut SoftwareAuthenticator) -> Passkey {
+        let verifier: WebAuthnVerifier = verifier();
+        let user_id: Uuid = Uuid::now_v7();
+        let attestation: Attestation =
+            authenticator.register(&verifier.challenge(Some(&user_id)).value);
+
+        let registration: PasskeyRegistration = verifier
+            .verify_registration(
+                &user_id,
+                &attestation.client_data_json,
+                &attestation.attestation_object,
+            )
+            .unwrap();
+
+        Passkey::new(
+            Uuid::now_v7(),
+            user_id,
+            "Test key".into(),
+            registration.credential_id,
+            registration.public_key,
+            registration.sign_count,
+        )
+    }
+
+    fn verify(passkey: &Passkey, assertion: &Assertion) -> Result<PasskeyAssertion, PasskeyError> {
+        verifier().verify_assertion(
+            passkey,
+            &assertion.client_data_json,
+            &assertion.authenticator_data,
+            &assertion.signature,
+        )
+    }
+
+    #[test]
+    fn registers_a_credential_and_reports_the_challenge() {
+        let verifier: WebAuthnVerifier = verifier();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let user_id: Uuid = Uuid::now_v7();
+        let challenge: PasskeyChallenge = verifier.challenge(Some(&user_id));
+        let attestation: Attestation = authenticator.register(&challenge.value);
+
+        let registration: PasskeyRegistration = verifier
+            .verify_registration(
+                &user_id,
+                &attestation.client_data_json,
+                &attestation.attestation_object,
+            )
+            .unwrap();
+
+        assert_eq!(registration.challenge, challenge.value);
+        assert_eq!(registration.credential_id, authenticator.credential_id);
+        assert_eq!(registration.public_key.len(), 65);
+        assert_eq!(registration.sign_count, 0);
+        assert!(challenge.expires_at > now());
+    }
+
+    #[test]
+    fn rejects_registration_challenges_issued_for_someone_else() {
+        let verifier: WebAuthnVerifier = verifier();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+
+        for challenge in [
+            verifier.challenge(Some(&Uuid::now_v7())),
+            verifier.challenge(None),
+        ] {
+            let attestation: Attestation = authenticator.register(&challenge.value);
+
+            let result: Result<PasskeyRegistration, PasskeyError> = verifier.verify_registration(
+                &Uuid::now_v7(),
+                &attestation.client_data_json,
+                &attestation.attestation_object,
+            );
+
+            assert!(matches!(result, Err(PasskeyError::InvalidChallenge)));
+        }
+    }
+
+    #[test]
+    fn rejects_registrations_for_another_relying_party() {
+        let verifier: WebAuthnVerifier = verifier();
+        let user_id: Uuid = Uuid::now_v7();
+        let mut authenticator: SoftwareAuthenticator =
+            SoftwareAuthenticator::new("attacker.example", ORIGIN);
+        let attestation: Attestation =
+            authenticator.register(&verifier.challenge(Some(&user_id)).value);
+
+        let result: Result<PasskeyRegistration, PasskeyError> = verifier.verify_registration(
+            &user_id,
+            &attestation.client_data_json,
+            &attestation.attestation_object,
+        );
+
+        assert!(matches!(result, Err(PasskeyError::InvalidOrigin)));
+    }
+
+    #[test]
+    fn verifies_assertions_and_reports_the_challenge() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+        let challenge: PasskeyChallenge = verifier().challenge(None);
+
+        let assertion: PasskeyAssertion =
+            verify(&passkey, &authenticator.assert(&challenge.value)).unwrap();
+
+        assert_eq!(assertion.challenge, challenge.value);
+        assert_eq!(assertion.sign_count, 1);
+    }
+
+    #[test]
+    fn rejects_assertions_with_a_registration_challenge() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+        let challenge: PasskeyChallenge = verifier().challenge(Some(&passkey.user_id));
+
+        let result: Result<PasskeyAssertion, PasskeyError> =
+            verify(&passkey, &authenticator.assert(&challenge.value));
+
+        assert!(matches!(result, Err(PasskeyError::InvalidChallenge)));
+    }
+
+    #[test]
+    fn rejects_assertions_from_another_origin() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+        let challenge: PasskeyChallenge = verifier().challenge(None);
+
+        let result: Result<PasskeyAssertion, PasskeyError> = verify(
+            &passkey,
+            &authenticator.assert_for(&challenge.value, "https://attacker.example"),
+        );
+
+        assert!(matches!(result, Err(PasskeyError::InvalidOrigin)));
+    }
+
+    #[test]
+    fn rejects_tampered_and_foreign_challenges() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+
+        let mut tampered: Vec<u8> = URL_SAFE_NO_PAD
+            .decode(verifier().challenge(None).value)
+            .unwrap();
+        tampered[0] ^= 0x01;
+        let foreign: PasskeyChallenge =
+            WebAuthnVerifier::new("other-secret", ORIGIN, RP_ID, "Example").challenge(None);
+
+        for challenge in [
+            URL_SAFE_NO_PAD.encode(tampered),
+            foreign.value,
+            "abc".into(),
+        ] {
+            let result: Result<PasskeyAssertion, PasskeyError> =
+                verify(&passkey, &authenticator.assert(&challenge));
+
+            assert!(matches!(result, Err(PasskeyError::InvalidChallenge)));
+        }
+    }
+
+    #[test]
+    fn rejects_expired_challenges() {
+        let verifier: WebAuthnVerifier = verifier();
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+
+        let mut payload: Vec<u8> = vec![0; NONCE_BYTES];
+        payload.extend_from_slice(&(now() - 1).to_be_bytes());
+        payload.extend_from_slice(Uuid::nil().as_bytes());
+        let tag: Vec<u8> = verifier.tag(&payload);
+        payload.extend_from_slice(&tag);
+
+        let result: Result<PasskeyAssertion, PasskeyError> = verify(
+            &passkey,
+            &authenticator.assert(&URL_SAFE_NO_PAD.encode(payload)),
+        );
+
+        assert!(matches!(result, Err(PasskeyError::InvalidChallenge)));
+    }
+
+    #[test]
+    fn rejects_signatures_by_another_key() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+        let mut impostor: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+
+        let result: Result<PasskeyAssertion, PasskeyError> = verify(
+            &passkey,
+            &impostor.assert(&verifier().challenge(None).value),
+        );
+
+        assert!(matches!(result, Err(PasskeyError::InvalidSignature)));
+    }
+
+    #[test]
+    fn rejects_a_signature_counter_that_went_backwards() {
+        let mut authenticator: SoftwareAuthenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        let mut passkey: Passkey = registered(&mut authenticator);
+        passkey.sign_count = 5;
+
+        let result: Result<PasskeyAssertion, PasskeyError> = verify(
+            &passkey,
+            &authenticator.assert(&verifier().challenge(None).value),
+        );
+
+        assert!(matches!(result, Err(PasskeyError::CounterRegression)));
+    }
+
+    #[test]
+    fn accepts_authenticators_without_a_counter() {
+        let mut authenticator: SoftwareAuthenticator =
+            SoftwareAuthenticator::without_counter(RP_ID, ORIGIN);
+        let passkey: Passkey = registered(&mut authenticator);
+
+        for _ in 0..2 {
+            let assertion: PasskeyAssertion = verify(
+                &passkey,
+                &authenticator.assert(&verifier().challenge(None).value),
+            )
+            .unwrap();
+
+            assert_eq!(assertion.sign_count, 0);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        audit::{entry::AuditEntry, log::AuditLog, query::AuditQuery},
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::permission::Permission,
+    },
+};
+use uuid::Uuid;
+
+pub const DEFAULT_PAGE_SIZE: u64 = 50;
+pub const MAX_PAGE_SIZE: u64 = 200;
+/// How many entries one export holds at most. Larger exports are split by time.
+pub const MAX_EXPORT_SIZE: u64 = 10_000;
+
+#[derive(Clone)]
+pub struct ListAuditEntriesService<A>
+where
+    A: AuditLog,
+{
+    audit_log: A,
+}
+
+impl<A> ListAuditEntriesService<A>
+where
+    A: AuditLog,
+{
+    pub fn new(audit_log: A) -> Self {
+        Self { audit_log }
+    }
+
+    /// Lists one page of the audit log of the active organization of the actor, newest first.
+    pub async fn execute(
+        &self,
+        query: AuditQuery,
+        actor: &AuthenticatedUser,
+    ) -> Result<ListAuditEntriesOutput, ListAuditEntriesError> {
+        let limit: u64 = query.limit;
+        let entries: Vec<AuditEntry> = self.list(query, actor, MAX_PAGE_SIZE).await?;
+
+        let next_cursor: Option<Uuid> = match entries.len() as u64 == limit {
+            true => entries.last().map(|entry| entry.id),
+            false => None,
+        };
+
+        Ok(ListAuditEntriesOutput {
+            entries,
+            next_cursor,
+        })
+    }
+
+    /// Lists the matching entries for an export, up to `MAX_EXPORT_SIZE` of them.
+    pub async fn export(
+        &self,
+        mut query: AuditQuery,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<AuditEntry>, ListAuditEntriesError> {
+        query.limit = MAX_EXPORT_SIZE;
+
+        self.list(query, actor, MAX_EXPORT_SIZE).await
+    }
+
+    async fn list(
+        &self,
+        mut query: AuditQuery,
+        actor: &AuthenticatedUser,
+        max_limit: u64,
+    ) -> Result<Vec<AuditEntry>, ListAuditEntriesError> {
+        actor.require(Permission::AuditRead)?;
+
+        if query.limit == 0 || query.limit > max_limit {
+            return Err(ListAuditEntriesError::InvalidLimit);
+        }
+
+        query.organization_id = actor.organization_id;
+
+        Ok(self.audit_log.list(&query).await?)
+    }
+}
+
+pub struct ListAuditEntriesOutput {
+    pub entries: Vec<AuditEntry>,
+    /// Where the next page starts, when more entries may follow.
+    pub next_cursor: Option<Uuid>,
+}
+
+pub enum ListAuditEntriesError {
+    InvalidLimit,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<DomainError> for ListAuditEntriesError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ListAuditEntriesError::Forbidden,
+        }
+    }
+}
+
+impl From<RepositoryError> for ListAuditEntriesError {
+    fn from(_: RepositoryError) -> Self {
+        ListAuditEntriesError::InfrastructureError
+    }
+}
@@ -0,0 +1,2 @@
+pub mod list_entries;
+pub mod record;
@@ -0,0 +1,66 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::audit::{
+        entry::{AuditAction, AuditEntry},
+        log::AuditLog,
+    },
+};
+use log::{error, info};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Starts an entry for an action taken at the current time, with no actor.
+pub fn anonymous(action: AuditAction) -> AuditEntry {
+    let now: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+
+    AuditEntry::new(Uuid::now_v7(), action, now)
+}
+
+/// Starts an entry for an action the actor takes in their active organization. Actions taken
+/// while impersonating are put down to the administrator.
+pub fn entry(actor: &AuthenticatedUser, action: AuditAction) -> AuditEntry {
+    let mut entry: AuditEntry = anonymous(action);
+    entry.organization_id = Some(actor.organization_id);
+    entry.ip_address = actor.ip_address;
+
+    match &actor.actor {
+        Some(administrator) => {
+            entry.actor_id = Some(administrator.id);
+            entry.actor_username = Some(administrator.username.clone());
+        }
+        None => {
+            entry.actor_id = Some(actor.id);
+            entry.actor_username = Some(actor.username.clone());
+        }
+    }
+
+    entry
+}
+
+/// Appends the entry to the audit log and to the `audit` log target. The action already
+/// happened, so a failure to append is logged instead of returned.
+pub async fn record<A>(audit_log: &A, entry: AuditEntry)
+where
+    A: AuditLog,
+{
+    info!(
+        target: "audit",
+        "{} ({}) {} {}",
+        entry.actor_username.as_deref().unwrap_or("-"),
+        entry
+            .actor_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "-".into()),
+        entry.action,
+        entry.target.as_deref().unwrap_or("-")
+    );
+
+    let id: Uuid = entry.id;
+
+    if let Err(err) = audit_log.append(entry).await {
+        error!(target: "audit", "Failed to append audit entry {}: {:?}", id, err);
+    }
+}
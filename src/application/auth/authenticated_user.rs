@@ -1,38 +1,115 @@
+use super::scope::Scope;
 use crate::domain::{
     errors::domain::DomainError,
-    user::entity::{User, UserRole},
+    role::{entity::RoleName, permission::Permission},
+    user::entity::User,
 };
+use std::net::IpAddr;
 use uuid::Uuid;
 
+/// The administrator acting as the user of an impersonation token.
+#[derive(Debug, Clone)]
+pub struct Actor {
+    pub id: Uuid,
+    pub username: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser {
     pub id: Uuid,
     pub username: String,
-    pub roles: Vec<UserRole>,
+    /// The organization the user acts in, whose users and members they manage.
+    pub organization_id: Uuid,
+    /// The role of the user and the role of their membership when the active organization is
+    /// their home organization, only the role of their membership otherwise.
+    pub roles: Vec<RoleName>,
+    /// What the roles allow, resolved when the access token is verified. Tokens only carry
+    /// the role names, so changes to a role apply to tokens issued before them.
+    pub permissions: Vec<Permission>,
+    pub scopes: Vec<Scope>,
+    pub client_id: Option<Uuid>,
+    /// Session the token was issued for, absent for tokens issued before sessions existed.
+    pub session_id: Option<Uuid>,
+    /// When the user signed in to the session, kept across refreshes. Not part of tokens.
+    pub auth_time: Option<u64>,
+    /// Set when an administrator impersonates the user.
+    pub actor: Option<Actor>,
+    /// Where the request the token came with was sent from. Not part of tokens.
+    pub ip_address: Option<IpAddr>,
 }
 
 impl AuthenticatedUser {
-    pub fn new(id: Uuid, username: String, roles: Vec<UserRole>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        username: String,
+        organization_id: Uuid,
+        roles: Vec<RoleName>,
+        scopes: Vec<Scope>,
+        client_id: Option<Uuid>,
+        session_id: Option<Uuid>,
+        actor: Option<Actor>,
+    ) -> Self {
         Self {
             id,
             username,
+            organization_id,
             roles,
+            permissions: Vec::new(),
+            scopes,
+            client_id,
+            session_id,
+            auth_time: None,
+            actor,
+            ip_address: None,
         }
     }
 
-    pub fn must_be_admin(&self) -> Result<(), DomainError> {
-        if self.roles.contains(&UserRole::Administrator) {
+    pub fn is_impersonated(&self) -> bool {
+        self.actor.is_some()
+    }
+
+    pub fn has_scope(&self, scope: &Scope) -> bool {
+        self.scopes.contains(scope)
+    }
+
+    /// Narrows the granted scopes down to the ones also present in `scopes`.
+    pub fn restrict_scopes(&mut self, scopes: &[Scope]) {
+        self.scopes = Scope::intersect(&self.scopes, scopes);
+    }
+
+    /// Hands the user the permissions they hold. Users who lost their permissions lose the
+    /// admin scope with them, and tokens without it act on the own account only.
+    pub fn grant_permissions(&mut self, permissions: Vec<Permission>) {
+        self.restrict_scopes(&Scope::allowed_for(&permissions));
+        self.permissions = match self.has_scope(&Scope::Admin) {
+            true => permissions,
+            false => Vec::new(),
+        };
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    pub fn require(&self, permission: Permission) -> Result<(), DomainError> {
+        if self.can(permission) {
             Ok(())
         } else {
             Err(DomainError::Forbidden)
         }
     }
 
-    pub fn must_be_admin_or_owner(&self, id: &Uuid) -> Result<(), DomainError> {
+    /// Requires `permission` unless the user acts on their own account.
+    pub fn require_unless_owner(
+        &self,
+        permission: Permission,
+        id: &Uuid,
+    ) -> Result<(), DomainError> {
         if id == &self.id {
             Ok(())
         } else {
-            self.must_be_admin()
+            self.require(permission)
         }
     }
 }
@@ -42,7 +119,15 @@ impl From<User> for AuthenticatedUser {
         AuthenticatedUser {
             id: value.id,
             username: value.username.as_str().into(),
+            organization_id: value.organization_id,
+            scopes: Scope::ALL.to_vec(),
             roles: vec![value.role],
+            permissions: Vec::new(),
+            client_id: None,
+            session_id: None,
+            auth_time: None,
+            actor: None,
+            ip_address: None,
         }
     }
 }
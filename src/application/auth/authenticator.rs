@@ -7,3 +7,19 @@ pub trait Authenticator {
         credentials: Credentials,
     ) -> Result<AuthenticatedUser, AuthenticationError>;
 }
+
+/// An authenticator that is not configured supports no credentials.
+impl<A> Authenticator for Option<A>
+where
+    A: Authenticator,
+{
+    async fn authenticate(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthenticatedUser, AuthenticationError> {
+        match self {
+            Some(authenticator) => authenticator.authenticate(credentials).await,
+            None => Err(AuthenticationError::UnsupportedCredentials),
+        }
+    }
+}
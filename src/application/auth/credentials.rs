@@ -1,12 +1,40 @@
 use crate::{
-    application::security::token::RefreshToken,
-    domain::user::value_objects::{password_plain::PasswordPlain, username::Username},
+    application::security::token::{MfaToken, RefreshToken},
+    domain::user::value_objects::{
+        email::Email, password_plain::PasswordPlain, username::Username,
+    },
 };
+use uuid::Uuid;
 
+#[derive(Clone)]
 pub enum Credentials {
+    /// Usernames are unique within an organization only.
     UsernamePassword {
+        organization_id: Uuid,
         username: Username,
         password: PasswordPlain,
     },
+    /// Local users may sign in with their verified email address instead of their username.
+    EmailPassword {
+        email: Email,
+        password: PasswordPlain,
+    },
     RefreshToken(RefreshToken),
+    MfaOtp {
+        mfa_token: MfaToken,
+        code: String,
+    },
+    /// A WebAuthn assertion, with every field decoded from base64url.
+    Passkey {
+        credential_id: Vec<u8>,
+        client_data_json: Vec<u8>,
+        authenticator_data: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    /// An authorization code an upstream identity provider redirected the user back with.
+    AuthorizationCode {
+        provider: String,
+        code: String,
+        state: String,
+    },
 }
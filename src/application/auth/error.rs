@@ -1,8 +1,13 @@
+use crate::application::security::token::MfaToken;
+
 #[derive(Debug)]
 pub enum AuthenticationError {
+    MfaRequired(MfaToken),
     InvalidCredentials,
     UserInactive,
+    /// The password is right but too old, it has to be reset before logging in.
+    PasswordExpired,
     UserNotFound,
     ProviderUnavailable,
-    _UnsupportedCredentials,
+    UnsupportedCredentials,
 }
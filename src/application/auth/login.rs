@@ -1,44 +1,308 @@
-use crate::application::{
-    auth::{
-        authenticated_user::AuthenticatedUser, authenticator::Authenticator,
-        credentials::Credentials, error::AuthenticationError,
+use crate::{
+    application::{
+        audit::record::{anonymous, entry, record},
+        auth::{
+            authenticated_user::AuthenticatedUser, authenticator::Authenticator,
+            credentials::Credentials, error::AuthenticationError, scope::Scope,
+        },
+        organization::tenant::activate,
+        role::permissions::held_permissions,
+        security::{
+            error::TokenError,
+            login_attempt_store::LoginAttemptStore,
+            login_throttle::{LoginThrottle, ThrottleError, ThrottleKey},
+            token::IssuedToken,
+            token_service::TokenService,
+        },
     },
-    security::{error::TokenError, token::IssuedToken, token_service::TokenService},
+    domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
+        client::entity::Client,
+        errors::repository::RepositoryError,
+        group::repository::GroupRepository,
+        organization::{entity::Organization, repository::OrganizationRepository},
+        role::{permission::Permission, repository::RoleRepository},
+        session::{entity::Session, repository::SessionRepository},
+    },
+};
+use std::{
+    net::IpAddr,
+    time::{SystemTime, UNIX_EPOCH},
 };
+use uuid::Uuid;
+
+/// Where a login attempt comes from.
+#[derive(Default)]
+pub struct LoginContext {
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
 
 #[derive(Clone)]
-pub struct Login<A, T>
+pub struct Login<A, T, S, R, O, P, G, L>
 where
     A: Authenticator,
     T: TokenService,
+    S: LoginAttemptStore,
+    R: SessionRepository,
+    O: OrganizationRepository,
+    P: RoleRepository,
+    G: GroupRepository,
+    L: AuditLog,
 {
     authenticator: A,
     token_service: T,
+    throttle: LoginThrottle<S>,
+    session_repository: R,
+    organization_repository: O,
+    role_repository: P,
+    group_repository: G,
+    audit_log: L,
 }
 
-impl<A, T> Login<A, T>
+impl<A, T, S, R, O, P, G, L> Login<A, T, S, R, O, P, G, L>
 where
     A: Authenticator,
     T: TokenService,
+    S: LoginAttemptStore,
+    R: SessionRepository,
+    O: OrganizationRepository,
+    P: RoleRepository,
+    G: GroupRepository,
+    L: AuditLog,
 {
-    pub fn new(authenticator: A, token_service: T) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        authenticator: A,
+        token_service: T,
+        throttle: LoginThrottle<S>,
+        session_repository: R,
+        organization_repository: O,
+        role_repository: P,
+        group_repository: G,
+        audit_log: L,
+    ) -> Self {
         Self {
             authenticator,
             token_service,
+            throttle,
+            session_repository,
+            organization_repository,
+            role_repository,
+            group_repository,
+            audit_log,
         }
     }
 
-    pub async fn execute(&self, credentials: Credentials) -> Result<IssuedToken, LoginError> {
-        let user: AuthenticatedUser = self.authenticator.authenticate(credentials).await?;
+    /// Authenticates `credentials` and issues tokens for the scopes that were requested and that
+    /// both the user and the calling `client` are allowed. Only users holding permissions in
+    /// the organization are allowed the admin scope. A refresh token can only be redeemed
+    /// by the client it was issued to.
+    ///
+    /// Guessable credentials are throttled: failed passwords per username and per client
+    /// address, failed second factors per user, per MFA token and per client address.
+    ///
+    /// Every login starts a new session, which a refresh continues. A refresh token whose
+    /// session has ended is rejected.
+    ///
+    /// Logins and failed passwords are audited, refreshes are not.
+    ///
+    /// Tokens act in `organization_id`, which the user must be a member of. Logins default to
+    /// the home organization of the user and refreshes to the organization of the refresh
+    /// token, so refreshing with another organization switches to it.
+    pub async fn execute(
+        &self,
+        credentials: Credentials,
+        organization_id: Option<Uuid>,
+        requested_scopes: Option<Vec<Scope>>,
+        client: Option<&Client>,
+        context: &LoginContext,
+    ) -> Result<IssuedToken, LoginError> {
+        let is_refresh: bool = matches!(credentials, Credentials::RefreshToken(_));
+        let mfa_user_id: Option<Uuid> = match &credentials {
+            Credentials::MfaOtp { mfa_token, .. } => self.token_service.verify_mfa(mfa_token).ok(),
+            _ => None,
+        };
+        let throttle_keys: Vec<ThrottleKey> = throttle_keys(&credentials, mfa_user_id, context);
+        let login_name: Option<String> = login_name(&credentials);
+        let refresh_organization_id: Option<Uuid> = match &credentials {
+            Credentials::RefreshToken(refresh_token) => self
+                .token_service
+                .verify_refresh(refresh_token)
+                .ok()
+                .map(|grant| grant.organization_id),
+            _ => None,
+        };
+
+        self.throttle.check(&throttle_keys).await?;
+
+        let result: Result<AuthenticatedUser, AuthenticationError> =
+            self.authenticator.authenticate(credentials).await;
+
+        match &result {
+            Ok(_)
+            | Err(AuthenticationError::MfaRequired(_) | AuthenticationError::PasswordExpired) => {
+                self.throttle.record_success(&throttle_keys).await?
+            }
+            Err(AuthenticationError::InvalidCredentials | AuthenticationError::UserNotFound) => {
+                self.throttle.record_failure(&throttle_keys).await?;
+
+                if let Some(login_name) = &login_name {
+                    let mut audit: AuditEntry =
+                        anonymous(AuditAction::LoginFailed).with_target(login_name);
+                    audit.organization_id =
+                        Some(organization_id.unwrap_or(Organization::DEFAULT_ID));
+                    audit.ip_address = context.ip_address;
+
+                    record(&self.audit_log, audit).await;
+                }
+            }
+            Err(_) => {}
+        }
+
+        let mut user: AuthenticatedUser = result?;
+        let client_id: Option<Uuid> = client.map(|client| client.id);
+
+        if is_refresh && user.client_id != client_id {
+            return Err(LoginError::InvalidClient);
+        }
+
+        user.client_id = client_id;
+
+        let organization_id: Uuid = organization_id
+            .or(refresh_organization_id)
+            .unwrap_or(user.organization_id);
+
+        if !activate(&self.organization_repository, &mut user, &organization_id).await? {
+            return Err(LoginError::NotMember);
+        }
+
+        let permissions: Vec<Permission> = held_permissions(
+            &self.role_repository,
+            &self.group_repository,
+            &user.roles,
+            &user.organization_id,
+            &user.id,
+        )
+        .await?;
+        user.restrict_scopes(&Scope::allowed_for(&permissions));
+
+        if let Some(client) = client {
+            let client_scopes: Vec<Scope> = client
+                .scopes
+                .iter()
+                .filter_map(|scope| scope.parse().ok())
+                .collect();
+
+            user.restrict_scopes(&client_scopes);
+        }
+
+        match requested_scopes {
+            Some(requested_scopes) => {
+                user.scopes = Scope::intersect(&requested_scopes, &user.scopes);
+
+                if user.scopes.is_empty() {
+                    return Err(LoginError::InvalidScope);
+                }
+            }
+            None if !is_refresh => user.scopes.retain(Scope::is_default),
+            None => {}
+        }
+
+        if user.has_scope(&Scope::OpenId) && user.client_id.is_none() {
+            return Err(LoginError::InvalidScope);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TokenError::Internal)?
+            .as_secs();
+        let mut session: Session = match user.session_id {
+            Some(session_id) if is_refresh => self
+                .session_repository
+                .find_by_id(&session_id)
+                .await?
+                .filter(|session| session.user_id == user.id && !session.is_expired(now))
+                .ok_or(AuthenticationError::InvalidCredentials)?,
+            _ => Session::new(Uuid::now_v7(), user.id, user.client_id, now),
+        };
+
+        session.touch(context.user_agent.clone(), context.ip_address, now);
+        user.session_id = Some(session.id);
+        user.auth_time = Some(session.created_at);
+
         let token: IssuedToken = self.token_service.issue(&user)?;
 
+        session.expires_at = now + token.refresh_expires_in;
+        self.session_repository.save(session).await?;
+
+        if !is_refresh {
+            let mut audit: AuditEntry =
+                entry(&user, AuditAction::LoginSucceeded).with_target(user.id);
+            audit.ip_address = context.ip_address;
+
+            record(&self.audit_log, audit).await;
+        }
+
         Ok(token)
     }
 }
 
+/// What a login attempt is throttled by. Second factors count against the user their MFA
+/// token was issued to, when the token is valid, and against the token itself.
+fn throttle_keys(
+    credentials: &Credentials,
+    mfa_user_id: Option<Uuid>,
+    context: &LoginContext,
+) -> Vec<ThrottleKey> {
+    let mut keys: Vec<ThrottleKey> = match credentials {
+        Credentials::UsernamePassword {
+            organization_id,
+            username,
+            ..
+        } => vec![ThrottleKey::Username(
+            *organization_id,
+            username.as_str().to_owned(),
+        )],
+        Credentials::EmailPassword { email, .. } => {
+            vec![ThrottleKey::Email(email.as_str().to_owned())]
+        }
+        Credentials::MfaOtp { mfa_token, .. } => mfa_user_id
+            .map(ThrottleKey::User)
+            .into_iter()
+            .chain([ThrottleKey::MfaToken(mfa_token.as_str().to_owned())])
+            .collect(),
+        _ => return Vec::new(),
+    };
+
+    if let Some(ip_address) = context.ip_address {
+        keys.push(ThrottleKey::Ip(ip_address));
+    }
+
+    keys
+}
+
+/// The username or email address a password login was attempted with.
+fn login_name(credentials: &Credentials) -> Option<String> {
+    match credentials {
+        Credentials::UsernamePassword { username, .. } => Some(username.as_str().to_owned()),
+        Credentials::EmailPassword { email, .. } => Some(email.as_str().to_owned()),
+        _ => None,
+    }
+}
+
 pub enum LoginError {
     Authentication(AuthenticationError),
     Token(TokenError),
+    InvalidScope,
+    InvalidClient,
+    /// The user is not a member of the organization to act in.
+    NotMember,
+    /// Too many failed attempts, retry after this many seconds.
+    Throttled(u64),
 }
 
 impl From<AuthenticationError> for LoginError {
@@ -52,3 +316,20 @@ impl From<TokenError> for LoginError {
         LoginError::Token(value)
     }
 }
+
+impl From<RepositoryError> for LoginError {
+    fn from(_: RepositoryError) -> Self {
+        LoginError::Authentication(AuthenticationError::ProviderUnavailable)
+    }
+}
+
+impl From<ThrottleError> for LoginError {
+    fn from(value: ThrottleError) -> Self {
+        match value {
+            ThrottleError::Locked { retry_after } => LoginError::Throttled(retry_after),
+            ThrottleError::InfrastructureError => {
+                LoginError::Authentication(AuthenticationError::ProviderUnavailable)
+            }
+        }
+    }
+}
@@ -2,4 +2,6 @@ pub mod authenticated_user;
 pub mod authenticator;
 pub mod credentials;
 pub mod error;
+pub mod impersonate;
 pub mod login;
+pub mod scope;
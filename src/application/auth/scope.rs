@@ -0,0 +1,140 @@
+use crate::domain::role::permission::Permission;
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    UsersRead,
+    UsersWrite,
+    MessagesRead,
+    MessagesWrite,
+    OpenId,
+    Profile,
+    Email,
+    /// Act with the permissions of one's roles and groups. Tokens without it act on the own
+    /// account only.
+    Admin,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 8] = [
+        Scope::UsersRead,
+        Scope::UsersWrite,
+        Scope::MessagesRead,
+        Scope::MessagesWrite,
+        Scope::OpenId,
+        Scope::Profile,
+        Scope::Email,
+        Scope::Admin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::UsersRead => "users:read",
+            Scope::UsersWrite => "users:write",
+            Scope::MessagesRead => "messages:read",
+            Scope::MessagesWrite => "messages:write",
+            Scope::OpenId => "openid",
+            Scope::Profile => "profile",
+            Scope::Email => "email",
+            Scope::Admin => "admin",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Scope::UsersRead => "Read user profiles",
+            Scope::UsersWrite => "Create, update and delete users",
+            Scope::MessagesRead => "Read messages",
+            Scope::MessagesWrite => "Send messages",
+            Scope::OpenId => "Issue an OpenID Connect id_token",
+            Scope::Profile => "Include profile claims in the id_token and userinfo",
+            Scope::Email => "Include the email address in userinfo",
+            Scope::Admin => "Act with the permissions of one's roles and groups on other accounts",
+        }
+    }
+
+    /// The scopes a user holding `permissions` may be granted. Only users with permissions
+    /// beyond their own account get the admin scope.
+    pub fn allowed_for(permissions: &[Permission]) -> Vec<Scope> {
+        Scope::ALL
+            .into_iter()
+            .filter(|scope| scope != &Scope::Admin || !permissions.is_empty())
+            .collect()
+    }
+
+    /// Whether the scope is granted when the client does not request any scope. OpenID
+    /// Connect scopes must be asked for explicitly.
+    pub fn is_default(&self) -> bool {
+        !matches!(self, Scope::OpenId | Scope::Profile | Scope::Email)
+    }
+
+    /// Parses a space-delimited `scope` parameter (RFC 6749, section 3.3).
+    pub fn parse_list(value: &str) -> Result<Vec<Scope>, ScopeError> {
+        let mut scopes: Vec<Scope> = Vec::new();
+
+        for raw in value.split_whitespace() {
+            let scope: Scope = raw.parse()?;
+
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+
+        Ok(scopes)
+    }
+
+    pub fn join(scopes: &[Scope]) -> String {
+        scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Keeps the scopes of `requested` that are also present in `allowed`.
+    pub fn intersect(requested: &[Scope], allowed: &[Scope]) -> Vec<Scope> {
+        requested
+            .iter()
+            .filter(|scope| allowed.contains(scope))
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ScopeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Scope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == value)
+            .ok_or_else(|| ScopeError::Unknown(value.into()))
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub enum ScopeError {
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_the_admin_scope_only_to_users_holding_permissions() {
+        let user: Vec<Scope> = Scope::allowed_for(&[]);
+        let moderator: Vec<Scope> = Scope::allowed_for(&[Permission::RoomModerate]);
+
+        assert!(!user.contains(&Scope::Admin));
+        assert!(user.contains(&Scope::UsersWrite));
+        assert!(moderator.contains(&Scope::Admin));
+        assert_eq!(moderator.len(), user.len() + 1);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        event::publisher::{Event, EventPublisher, ProfileUpdated},
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+    },
+    domain::{
+        avatar::repository::AvatarRepository,
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        user::{entity::User, patch::UserPatch, profile::ProfilePatch, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DeleteAvatarService<U, A, E, L, G>
+where
+    U: UserRepository,
+    A: AvatarRepository,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    user_repository: U,
+    avatar_repository: A,
+    publisher: E,
+    role_repository: L,
+    group_repository: G,
+}
+
+impl<U, A, E, L, G> DeleteAvatarService<U, A, E, L, G>
+where
+    U: UserRepository,
+    A: AvatarRepository,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    pub fn new(
+        user_repository: U,
+        avatar_repository: A,
+        publisher: E,
+        role_repository: L,
+        group_repository: G,
+    ) -> Self {
+        Self {
+            user_repository,
+            avatar_repository,
+            publisher,
+            role_repository,
+            group_repository,
+        }
+    }
+
+    /// Removes the avatar of the user, unless they hold permissions the actor lacks.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeleteAvatarError> {
+        actor.require_unless_owner(Permission::UserUpdate, id)?;
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(DeleteAvatarError::NotFound)?;
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(DeleteAvatarError::Forbidden);
+        }
+
+        if user.profile.avatar_updated_at.is_none() {
+            return Ok(());
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| DeleteAvatarError::InfrastructureError)?
+            .as_secs();
+
+        let patch = UserPatch::new(None, None, None, None, None, None).with_profile(ProfilePatch {
+            avatar_updated_at: Some(None),
+            ..ProfilePatch::default()
+        });
+        let user: User = self.user_repository.update(id, patch).await?;
+
+        self.avatar_repository.delete_by_user_id(id).await?;
+
+        self.publisher
+            .publish(Event::ProfileUpdated(ProfileUpdated::new(&user, now)))
+            .await;
+
+        Ok(())
+    }
+}
+
+pub enum DeleteAvatarError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeleteAvatarError {
+    fn from(_: RepositoryError) -> Self {
+        Self::InfrastructureError
+    }
+}
+
+impl From<DomainError> for DeleteAvatarError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => Self::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::domain::{
+    avatar::{
+        entity::{AvatarImage, AvatarSize},
+        repository::AvatarRepository,
+    },
+    errors::repository::RepositoryError,
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct GetAvatarService<A>
+where
+    A: AvatarRepository,
+{
+    avatar_repository: A,
+}
+
+impl<A> GetAvatarService<A>
+where
+    A: AvatarRepository,
+{
+    pub fn new(avatar_repository: A) -> Self {
+        Self { avatar_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        size: AvatarSize,
+    ) -> Result<AvatarImage, GetAvatarError> {
+        self.avatar_repository
+            .find(id, size)
+            .await?
+            .ok_or(GetAvatarError::NotFound)
+    }
+}
+
+pub enum GetAvatarError {
+    NotFound,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for GetAvatarError {
+    fn from(_: RepositoryError) -> Self {
+        Self::InfrastructureError
+    }
+}
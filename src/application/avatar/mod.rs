@@ -0,0 +1,4 @@
+pub mod delete_avatar;
+pub mod get_avatar;
+pub mod processor;
+pub mod set_avatar;
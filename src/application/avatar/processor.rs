@@ -0,0 +1,17 @@
+use crate::domain::avatar::entity::{AvatarImage, AvatarUpload};
+
+/// Turns an uploaded image into one image per avatar size.
+#[async_trait::async_trait]
+pub trait AvatarProcessor {
+    async fn process(&self, upload: AvatarUpload)
+    -> Result<Vec<AvatarImage>, AvatarProcessorError>;
+}
+
+#[derive(Debug)]
+pub enum AvatarProcessorError {
+    /// Not an image, or in a format that is not supported.
+    Unsupported,
+    /// The image is too large to decode, in pixels rather than bytes.
+    TooLarge,
+    Unavailable,
+}
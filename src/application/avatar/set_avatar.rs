@@ -0,0 +1,152 @@
+use super::processor::{AvatarProcessor, AvatarProcessorError};
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        event::publisher::{Event, EventPublisher, ProfileUpdated},
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+    },
+    domain::{
+        avatar::{
+            entity::{AvatarImage, AvatarUpload},
+            repository::AvatarRepository,
+        },
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        user::{
+            entity::User, error::UserError, patch::UserPatch, profile::ProfilePatch,
+            repository::UserRepository,
+        },
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct SetAvatarService<U, A, P, E, L, G>
+where
+    U: UserRepository,
+    A: AvatarRepository,
+    P: AvatarProcessor,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    user_repository: U,
+    avatar_repository: A,
+    processor: P,
+    publisher: E,
+    role_repository: L,
+    group_repository: G,
+}
+
+impl<U, A, P, E, L, G> SetAvatarService<U, A, P, E, L, G>
+where
+    U: UserRepository,
+    A: AvatarRepository,
+    P: AvatarProcessor,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    pub fn new(
+        user_repository: U,
+        avatar_repository: A,
+        processor: P,
+        publisher: E,
+        role_repository: L,
+        group_repository: G,
+    ) -> Self {
+        Self {
+            user_repository,
+            avatar_repository,
+            processor,
+            publisher,
+            role_repository,
+            group_repository,
+        }
+    }
+
+    /// Resizes the image to every avatar size and replaces the previous avatar with it. Users
+    /// holding permissions the actor lacks keep theirs.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        data: Vec<u8>,
+        actor: &AuthenticatedUser,
+    ) -> Result<User, SetAvatarError> {
+        actor.require_unless_owner(Permission::UserUpdate, id)?;
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(SetAvatarError::NotFound)?;
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(SetAvatarError::Forbidden);
+        }
+
+        let upload: AvatarUpload = AvatarUpload::new(data)?;
+        let images: Vec<AvatarImage> = self.processor.process(upload).await?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SetAvatarError::InfrastructureError)?
+            .as_secs();
+
+        self.avatar_repository.replace(id, images).await?;
+
+        let patch = UserPatch::new(None, None, None, None, None, None).with_profile(ProfilePatch {
+            avatar_updated_at: Some(Some(now)),
+            ..ProfilePatch::default()
+        });
+        let user: User = self.user_repository.update(id, patch).await?;
+
+        self.publisher
+            .publish(Event::ProfileUpdated(ProfileUpdated::new(&user, now)))
+            .await;
+
+        Ok(user)
+    }
+}
+
+pub enum SetAvatarError {
+    UserError(UserError),
+    UnsupportedImage,
+    ImageTooLarge,
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<UserError> for SetAvatarError {
+    fn from(e: UserError) -> Self {
+        Self::UserError(e)
+    }
+}
+
+impl From<AvatarProcessorError> for SetAvatarError {
+    fn from(value: AvatarProcessorError) -> Self {
+        match value {
+            AvatarProcessorError::Unsupported => Self::UnsupportedImage,
+            AvatarProcessorError::TooLarge => Self::ImageTooLarge,
+            AvatarProcessorError::Unavailable => Self::InfrastructureError,
+        }
+    }
+}
+
+impl From<RepositoryError> for SetAvatarError {
+    fn from(_: RepositoryError) -> Self {
+        Self::InfrastructureError
+    }
+}
+
+impl From<DomainError> for SetAvatarError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => Self::Forbidden,
+        }
+    }
+}
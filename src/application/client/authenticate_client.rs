@@ -0,0 +1,71 @@
+use crate::domain::{
+    client::{entity::Client, repository::ClientRepository},
+    errors::repository::RepositoryError,
+    user::password_hasher::{HashError, PasswordHasher},
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct AuthenticateClientService<C, H>
+where
+    C: ClientRepository,
+    H: PasswordHasher,
+{
+    client_repository: C,
+    hasher: H,
+}
+
+impl<C, H> AuthenticateClientService<C, H>
+where
+    C: ClientRepository,
+    H: PasswordHasher,
+{
+    pub fn new(client_repository: C, hasher: H) -> Self {
+        Self {
+            client_repository,
+            hasher,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<Client, AuthenticateClientError> {
+        let id: Uuid =
+            Uuid::parse_str(client_id).map_err(|_| AuthenticateClientError::InvalidClient)?;
+
+        let client: Client = self
+            .client_repository
+            .find_by_id(&id)
+            .await?
+            .ok_or(AuthenticateClientError::InvalidClient)?;
+
+        if !self
+            .hasher
+            .verify(client_secret, client.secret_hash.as_str())?
+            .is_match()
+        {
+            return Err(AuthenticateClientError::InvalidClient);
+        }
+
+        Ok(client)
+    }
+}
+
+pub enum AuthenticateClientError {
+    InvalidClient,
+    InfrastructureError,
+}
+
+impl From<HashError> for AuthenticateClientError {
+    fn from(_: HashError) -> Self {
+        AuthenticateClientError::InfrastructureError
+    }
+}
+
+impl From<RepositoryError> for AuthenticateClientError {
+    fn from(_: RepositoryError) -> Self {
+        AuthenticateClientError::InfrastructureError
+    }
+}
@@ -0,0 +1,2 @@
+pub mod authenticate_client;
+pub mod register_client;
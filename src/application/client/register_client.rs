@@ -0,0 +1,132 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::{authenticated_user::AuthenticatedUser, scope::Scope},
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        client::{entity::Client, repository::ClientRepository},
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::permission::Permission,
+        user::{
+            error::UserError, password_hasher::PasswordHasher,
+            value_objects::password_hash::PasswordHash,
+        },
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+use uuid::Uuid;
+
+const CLIENT_SECRET_BYTES: usize = 32;
+
+#[derive(Clone)]
+pub struct RegisterClientService<C, H, A>
+where
+    C: ClientRepository,
+    H: PasswordHasher,
+    A: AuditLog,
+{
+    client_repository: C,
+    hasher: H,
+    audit_log: A,
+}
+
+impl<C, H, A> RegisterClientService<C, H, A>
+where
+    C: ClientRepository,
+    H: PasswordHasher,
+    A: AuditLog,
+{
+    pub fn new(client_repository: C, hasher: H, audit_log: A) -> Self {
+        Self {
+            client_repository,
+            hasher,
+            audit_log,
+        }
+    }
+
+    /// Registers a new client. The generated secret is only ever returned here; just its hash
+    /// is stored.
+    pub async fn execute(
+        &self,
+        input: RegisterClientInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<RegisterClientOutput, RegisterClientError> {
+        actor.require(Permission::ClientManage)?;
+
+        let name: String = input.name.trim().to_owned();
+
+        if name.is_empty() || name.chars().count() > 255 {
+            return Err(RegisterClientError::InvalidName);
+        }
+
+        let mut secret: [u8; CLIENT_SECRET_BYTES] = [0; CLIENT_SECRET_BYTES];
+        OsRng.fill_bytes(&mut secret);
+        let secret: String = hex::encode(secret);
+
+        let secret_hash: PasswordHash = PasswordHash::new(self.hasher.hash(&secret))?;
+        let scopes: Vec<String> = input
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str().to_owned())
+            .collect();
+
+        let client: Client = self
+            .client_repository
+            .create(Client::new(Uuid::now_v7(), name, secret_hash, scopes))
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::ClientRegistered)
+                .with_target(client.id)
+                .with_change("name", None::<&str>, Some(&client.name)),
+        )
+        .await;
+
+        Ok(RegisterClientOutput {
+            id: client.id,
+            name: client.name,
+            secret,
+            scopes: input.scopes,
+        })
+    }
+}
+
+pub struct RegisterClientInput {
+    pub name: String,
+    pub scopes: Vec<Scope>,
+}
+
+pub struct RegisterClientOutput {
+    pub id: Uuid,
+    pub name: String,
+    pub secret: String,
+    pub scopes: Vec<Scope>,
+}
+
+pub enum RegisterClientError {
+    InvalidName,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RegisterClientError {
+    fn from(_: RepositoryError) -> Self {
+        RegisterClientError::InfrastructureError
+    }
+}
+
+impl From<UserError> for RegisterClientError {
+    fn from(_: UserError) -> Self {
+        RegisterClientError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for RegisterClientError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => RegisterClientError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod request_verification;
+pub mod verify_email;
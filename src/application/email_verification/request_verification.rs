@@ -0,0 +1,146 @@
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        notification::notifier::{Notification, Notifier, NotifierError},
+    },
+    domain::{
+        email_verification::{
+            entity::EmailVerificationToken, repository::EmailVerificationRepository,
+        },
+        errors::repository::RepositoryError,
+        user::{
+            entity::User,
+            password_hasher::PasswordHasher,
+            repository::UserRepository,
+            value_objects::{email::Email, password_hash::PasswordHash},
+        },
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+pub const VERIFICATION_TOKEN_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(Clone)]
+pub struct RequestEmailVerificationService<U, R, H, N>
+where
+    U: UserRepository,
+    R: EmailVerificationRepository,
+    H: PasswordHasher,
+    N: Notifier,
+{
+    user_repository: U,
+    verification_repository: R,
+    hasher: H,
+    notifier: N,
+    /// Where verification links point to, the token is added as a query parameter.
+    verify_url: String,
+}
+
+impl<U, R, H, N> RequestEmailVerificationService<U, R, H, N>
+where
+    U: UserRepository,
+    R: EmailVerificationRepository,
+    H: PasswordHasher,
+    N: Notifier,
+{
+    pub fn new(
+        user_repository: U,
+        verification_repository: R,
+        hasher: H,
+        notifier: N,
+        verify_url: String,
+    ) -> Self {
+        Self {
+            user_repository,
+            verification_repository,
+            hasher,
+            notifier,
+            verify_url,
+        }
+    }
+
+    /// Sends a verification link to the email address of the user. Asking again replaces the
+    /// previous link.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), RequestEmailVerificationError> {
+        let user: User = self
+            .user_repository
+            .find_by_id(&actor.id)
+            .await?
+            .ok_or(RequestEmailVerificationError::NotFound)?;
+
+        let email: Email = user
+            .email
+            .clone()
+            .ok_or(RequestEmailVerificationError::NoEmail)?;
+
+        if user.email_verified_at.is_some() {
+            return Err(RequestEmailVerificationError::AlreadyVerified);
+        }
+
+        self.verification_repository
+            .delete_by_user_id(&user.id)
+            .await?;
+
+        let mut secret: [u8; 32] = [0; 32];
+        OsRng.fill_bytes(&mut secret);
+        let secret: String = hex::encode(secret);
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RequestEmailVerificationError::InfrastructureError)?
+            .as_secs();
+        let token: EmailVerificationToken = self
+            .verification_repository
+            .create(EmailVerificationToken::new(
+                Uuid::now_v7(),
+                user.id,
+                email,
+                PasswordHash::new(self.hasher.hash(&secret))
+                    .map_err(|_| RequestEmailVerificationError::InfrastructureError)?,
+                now + VERIFICATION_TOKEN_TTL_SECONDS,
+            ))
+            .await?;
+
+        self.notifier
+            .notify(
+                &user,
+                Notification::EmailVerification {
+                    link: format!("{}?token={}.{}", self.verify_url, token.id.simple(), secret),
+                    expires_in: VERIFICATION_TOKEN_TTL_SECONDS,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RequestEmailVerificationError {
+    NotFound,
+    NoEmail,
+    AlreadyVerified,
+    /// The address cannot be reached, e.g. no mail server is configured.
+    Unreachable,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RequestEmailVerificationError {
+    fn from(_: RepositoryError) -> Self {
+        RequestEmailVerificationError::InfrastructureError
+    }
+}
+
+impl From<NotifierError> for RequestEmailVerificationError {
+    fn from(value: NotifierError) -> Self {
+        match value {
+            NotifierError::Unreachable => RequestEmailVerificationError::Unreachable,
+            NotifierError::Unavailable => RequestEmailVerificationError::InfrastructureError,
+        }
+    }
+}
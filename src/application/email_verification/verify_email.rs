@@ -0,0 +1,105 @@
+use crate::domain::{
+    email_verification::{entity::EmailVerificationToken, repository::EmailVerificationRepository},
+    errors::repository::RepositoryError,
+    user::{
+        password_hasher::{HashError, PasswordHasher},
+        repository::UserRepository,
+    },
+};
+use log::info;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct VerifyEmailService<U, R, H>
+where
+    U: UserRepository,
+    R: EmailVerificationRepository,
+    H: PasswordHasher,
+{
+    user_repository: U,
+    verification_repository: R,
+    hasher: H,
+}
+
+impl<U, R, H> VerifyEmailService<U, R, H>
+where
+    U: UserRepository,
+    R: EmailVerificationRepository,
+    H: PasswordHasher,
+{
+    pub fn new(user_repository: U, verification_repository: R, hasher: H) -> Self {
+        Self {
+            user_repository,
+            verification_repository,
+            hasher,
+        }
+    }
+
+    /// Marks an email address as verified with the token sent to it, which is used up. The
+    /// token is refused when the user changed their address since.
+    pub async fn execute(&self, token: &str) -> Result<(), VerifyEmailError> {
+        let (id, secret) = token
+            .split_once('.')
+            .and_then(|(id, secret)| Some((Uuid::try_parse(id).ok()?, secret)))
+            .ok_or(VerifyEmailError::InvalidToken)?;
+
+        let token: EmailVerificationToken = self
+            .verification_repository
+            .find_by_id(&id)
+            .await?
+            .ok_or(VerifyEmailError::InvalidToken)?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| VerifyEmailError::InfrastructureError)?
+            .as_secs();
+
+        if token.is_expired(now) {
+            self.verification_repository.consume(&token.id).await?;
+
+            return Err(VerifyEmailError::InvalidToken);
+        }
+
+        if !self
+            .hasher
+            .verify(secret, token.secret_hash.as_str())?
+            .is_match()
+        {
+            return Err(VerifyEmailError::InvalidToken);
+        }
+
+        if !self.verification_repository.consume(&token.id).await?
+            || !self
+                .user_repository
+                .mark_email_verified(&token.user_id, &token.email, now)
+                .await?
+        {
+            return Err(VerifyEmailError::InvalidToken);
+        }
+
+        info!("Verified the email address of user {}", token.user_id);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum VerifyEmailError {
+    /// The token is malformed, unknown, expired, used, or for an address the user no longer
+    /// has.
+    InvalidToken,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for VerifyEmailError {
+    fn from(_: RepositoryError) -> Self {
+        VerifyEmailError::InfrastructureError
+    }
+}
+
+impl From<HashError> for VerifyEmailError {
+    fn from(_: HashError) -> Self {
+        VerifyEmailError::InfrastructureError
+    }
+}
@@ -0,0 +1,35 @@
+use crate::domain::user::{entity::User, profile::Profile};
+use uuid::Uuid;
+
+/// Changes other services react to, such as chat servers showing profiles.
+pub enum Event {
+    /// The name, username, avatar or another part of the profile of a user changed.
+    ProfileUpdated(ProfileUpdated),
+}
+
+pub struct ProfileUpdated {
+    pub user_id: Uuid,
+    pub username: String,
+    pub name: String,
+    pub profile: Profile,
+    pub occurred_at: u64,
+}
+
+impl ProfileUpdated {
+    pub fn new(user: &User, occurred_at: u64) -> Self {
+        Self {
+            user_id: user.id,
+            username: user.username.as_str().into(),
+            name: user.name.as_str().into(),
+            profile: user.profile.clone(),
+            occurred_at,
+        }
+    }
+}
+
+/// Broadcasts events. Delivery is best effort: the change already happened, so publishers
+/// log failures instead of returning them.
+#[async_trait::async_trait]
+pub trait EventPublisher {
+    async fn publish(&self, event: Event);
+}
@@ -0,0 +1,42 @@
+/// Upstream OpenID Connect providers users can sign in through.
+#[async_trait::async_trait]
+pub trait IdentityProviders {
+    /// Starts an authorization-code flow with `provider`.
+    async fn authorization_request(
+        &self,
+        provider: &str,
+    ) -> Result<AuthorizationRequest, FederationError>;
+    /// Redeems the `code` the provider redirected back with, after checking `state` matches
+    /// the request.
+    async fn complete(
+        &self,
+        provider: &str,
+        code: &str,
+        state: &str,
+    ) -> Result<ExternalClaims, FederationError>;
+}
+
+pub struct AuthorizationRequest {
+    /// Where to send the user's browser.
+    pub url: String,
+    /// Opaque value to keep in the user agent and hand back to `complete`.
+    pub state: String,
+}
+
+/// What the provider asserted about the user.
+pub struct ExternalClaims {
+    pub subject: String,
+    pub preferred_username: Option<String>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum FederationError {
+    UnknownProvider,
+    /// The state is forged, expired, or was issued for another provider.
+    InvalidState,
+    /// The provider rejected the code, or answered with an invalid id_token.
+    InvalidResponse,
+    Unavailable,
+}
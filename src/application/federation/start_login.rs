@@ -0,0 +1,22 @@
+use super::identity_provider::{AuthorizationRequest, FederationError, IdentityProviders};
+
+#[derive(Clone)]
+pub struct StartFederatedLoginService<P>
+where
+    P: IdentityProviders,
+{
+    providers: P,
+}
+
+impl<P> StartFederatedLoginService<P>
+where
+    P: IdentityProviders,
+{
+    pub fn new(providers: P) -> Self {
+        Self { providers }
+    }
+
+    pub async fn execute(&self, provider: &str) -> Result<AuthorizationRequest, FederationError> {
+        self.providers.authorization_request(provider).await
+    }
+}
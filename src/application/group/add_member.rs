@@ -0,0 +1,124 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::{
+            entity::{Group, GroupName},
+            member::GroupMember,
+            repository::GroupRepository,
+        },
+        organization::repository::OrganizationRepository,
+        role::permission::Permission,
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct AddGroupMemberService<G, O, A>
+where
+    G: GroupRepository,
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    group_repository: G,
+    organization_repository: O,
+    audit_log: A,
+}
+
+impl<G, O, A> AddGroupMemberService<G, O, A>
+where
+    G: GroupRepository,
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    pub fn new(group_repository: G, organization_repository: O, audit_log: A) -> Self {
+        Self {
+            group_repository,
+            organization_repository,
+            audit_log,
+        }
+    }
+
+    /// Adds a member of the active organization of the actor to one of its groups. They get
+    /// what the group grants on their next request. Nobody hands out permissions they do not
+    /// hold themselves.
+    pub async fn execute(
+        &self,
+        name: &GroupName,
+        user_id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<GroupMember, AddGroupMemberError> {
+        actor.require(Permission::GroupManage)?;
+
+        let group: Group = self
+            .group_repository
+            .find_by_name(&actor.organization_id, name)
+            .await?
+            .ok_or(AddGroupMemberError::NotFound)?;
+
+        if !Permission::all_granted(&group.permissions, &actor.permissions) {
+            return Err(AddGroupMemberError::Forbidden);
+        }
+
+        if self
+            .organization_repository
+            .find_membership(&group.organization_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AddGroupMemberError::NotMember);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| AddGroupMemberError::InfrastructureError)?
+            .as_secs();
+
+        let member: GroupMember = self
+            .group_repository
+            .add_member(GroupMember::new(
+                group.id,
+                group.organization_id,
+                *user_id,
+                now,
+            ))
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::GroupMemberAdded)
+                .with_target(user_id)
+                .with_change("group", None::<String>, Some(group.name.mention())),
+        )
+        .await;
+
+        Ok(member)
+    }
+}
+
+pub enum AddGroupMemberError {
+    NotFound,
+    /// The user is not a member of the organization of the group.
+    NotMember,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for AddGroupMemberError {
+    fn from(_: RepositoryError) -> Self {
+        AddGroupMemberError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for AddGroupMemberError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => AddGroupMemberError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        role::create_role::permission_list,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::{
+            entity::{Group, GroupName},
+            error::GroupError,
+            repository::GroupRepository,
+        },
+        role::permission::Permission,
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct CreateGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<G, A> CreateGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(group_repository: G, audit_log: A) -> Self {
+        Self {
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Creates a group in the active organization of the actor. Nobody grants a group
+    /// permissions they do not hold themselves.
+    pub async fn execute(
+        &self,
+        input: CreateGroupInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Group, CreateGroupError> {
+        actor.require(Permission::GroupManage)?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| CreateGroupError::InfrastructureError)?
+            .as_secs();
+        let group: Group = Group::new(
+            Uuid::now_v7(),
+            actor.organization_id,
+            input.name,
+            input.description,
+            input.permissions,
+            now,
+        )?;
+
+        if !Permission::all_granted(&group.permissions, &actor.permissions) {
+            return Err(CreateGroupError::Forbidden);
+        }
+
+        if self
+            .group_repository
+            .find_by_name(&group.organization_id, &group.name)
+            .await?
+            .is_some()
+        {
+            return Err(CreateGroupError::AlreadyExists);
+        }
+
+        let group: Group = self.group_repository.create(group).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::GroupCreated)
+                .with_target(group.name.mention())
+                .with_change(
+                    "permissions",
+                    None::<String>,
+                    Some(permission_list(&group.permissions)),
+                ),
+        )
+        .await;
+
+        Ok(group)
+    }
+}
+
+pub struct CreateGroupInput {
+    pub name: GroupName,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+pub enum CreateGroupError {
+    InvalidGroup(GroupError),
+    AlreadyExists,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<GroupError> for CreateGroupError {
+    fn from(value: GroupError) -> Self {
+        CreateGroupError::InvalidGroup(value)
+    }
+}
+
+impl From<RepositoryError> for CreateGroupError {
+    fn from(_: RepositoryError) -> Self {
+        CreateGroupError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for CreateGroupError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => CreateGroupError::Forbidden,
+        }
+    }
+}
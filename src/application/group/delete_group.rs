@@ -0,0 +1,86 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::{
+            entity::{Group, GroupName},
+            repository::GroupRepository,
+        },
+        role::permission::Permission,
+    },
+};
+
+#[derive(Clone)]
+pub struct DeleteGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<G, A> DeleteGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(group_repository: G, audit_log: A) -> Self {
+        Self {
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Deletes a group of the active organization of the actor. Its members lose what it
+    /// granted on their next request.
+    pub async fn execute(
+        &self,
+        name: &GroupName,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeleteGroupError> {
+        actor.require(Permission::GroupManage)?;
+
+        let group: Group = self
+            .group_repository
+            .find_by_name(&actor.organization_id, name)
+            .await?
+            .ok_or(DeleteGroupError::NotFound)?;
+
+        if !self.group_repository.delete(&group.id).await? {
+            return Err(DeleteGroupError::NotFound);
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::GroupDeleted).with_target(group.name.mention()),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum DeleteGroupError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeleteGroupError {
+    fn from(_: RepositoryError) -> Self {
+        DeleteGroupError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for DeleteGroupError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => DeleteGroupError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        group::{entity::Group, repository::GroupRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct ListGroupsService<G>
+where
+    G: GroupRepository,
+{
+    group_repository: G,
+}
+
+impl<G> ListGroupsService<G>
+where
+    G: GroupRepository,
+{
+    pub fn new(group_repository: G) -> Self {
+        Self { group_repository }
+    }
+
+    /// Lists the groups of the active organization of the actor, by name.
+    pub async fn execute(&self, actor: &AuthenticatedUser) -> Result<Vec<Group>, ListGroupsError> {
+        Ok(self
+            .group_repository
+            .find_all(&actor.organization_id)
+            .await?)
+    }
+}
+
+pub enum ListGroupsError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListGroupsError {
+    fn from(_: RepositoryError) -> Self {
+        ListGroupsError::InfrastructureError
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        group::{
+            entity::{Group, GroupName},
+            member::GroupMember,
+            repository::GroupRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct ListGroupMembersService<G>
+where
+    G: GroupRepository,
+{
+    group_repository: G,
+}
+
+impl<G> ListGroupMembersService<G>
+where
+    G: GroupRepository,
+{
+    pub fn new(group_repository: G) -> Self {
+        Self { group_repository }
+    }
+
+    /// Lists the members of a group of the active organization of the actor, which is who a
+    /// mention of the group reaches.
+    pub async fn execute(
+        &self,
+        name: &GroupName,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<GroupMember>, ListGroupMembersError> {
+        let group: Group = self
+            .group_repository
+            .find_by_name(&actor.organization_id, name)
+            .await?
+            .ok_or(ListGroupMembersError::NotFound)?;
+
+        Ok(self.group_repository.find_members(&group.id).await?)
+    }
+}
+
+pub enum ListGroupMembersError {
+    NotFound,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListGroupMembersError {
+    fn from(_: RepositoryError) -> Self {
+        ListGroupMembersError::InfrastructureError
+    }
+}
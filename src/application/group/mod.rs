@@ -0,0 +1,8 @@
+pub mod add_member;
+pub mod create_group;
+pub mod delete_group;
+pub mod list_groups;
+pub mod list_members;
+pub mod permissions;
+pub mod remove_member;
+pub mod update_group;
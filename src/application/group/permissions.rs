@@ -0,0 +1,25 @@
+use crate::domain::{
+    errors::repository::RepositoryError,
+    group::repository::GroupRepository,
+    role::{entity::Role, permission::Permission},
+};
+use uuid::Uuid;
+
+/// Everything the groups of the user in the organization grant together.
+pub async fn group_permissions<G>(
+    group_repository: &G,
+    organization_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<Permission>, RepositoryError>
+where
+    G: GroupRepository,
+{
+    let permissions: Vec<Permission> = group_repository
+        .find_by_member(organization_id, user_id)
+        .await?
+        .into_iter()
+        .flat_map(|group| group.permissions)
+        .collect();
+
+    Ok(Role::dedup(permissions))
+}
@@ -0,0 +1,100 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::{
+            entity::{Group, GroupName},
+            repository::GroupRepository,
+        },
+        role::permission::Permission,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct RemoveGroupMemberService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<G, A> RemoveGroupMemberService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(group_repository: G, audit_log: A) -> Self {
+        Self {
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Removes a member from a group of the active organization of the actor. They lose what
+    /// the group granted on their next request. Members may leave on their own, but nobody
+    /// takes away permissions they do not hold themselves.
+    pub async fn execute(
+        &self,
+        name: &GroupName,
+        user_id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), RemoveGroupMemberError> {
+        actor.require_unless_owner(Permission::GroupManage, user_id)?;
+
+        let group: Group = self
+            .group_repository
+            .find_by_name(&actor.organization_id, name)
+            .await?
+            .ok_or(RemoveGroupMemberError::NotFound)?;
+
+        if user_id != &actor.id && !Permission::all_granted(&group.permissions, &actor.permissions)
+        {
+            return Err(RemoveGroupMemberError::Forbidden);
+        }
+
+        if !self
+            .group_repository
+            .remove_member(&group.id, user_id)
+            .await?
+        {
+            return Err(RemoveGroupMemberError::NotFound);
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::GroupMemberRemoved)
+                .with_target(user_id)
+                .with_change("group", Some(group.name.mention()), None::<String>),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum RemoveGroupMemberError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RemoveGroupMemberError {
+    fn from(_: RepositoryError) -> Self {
+        RemoveGroupMemberError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for RemoveGroupMemberError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => RemoveGroupMemberError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        role::create_role::permission_list,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::{
+            entity::{Group, GroupName},
+            error::GroupError,
+            repository::GroupRepository,
+        },
+        role::{entity::Role, permission::Permission},
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdateGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<G, A> UpdateGroupService<G, A>
+where
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(group_repository: G, audit_log: A) -> Self {
+        Self {
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Changes the description and the permissions of a group of the active organization of
+    /// the actor. Members get the new permissions on their next request. Nobody adds or
+    /// removes permissions they do not hold themselves.
+    pub async fn execute(
+        &self,
+        name: &GroupName,
+        input: UpdateGroupInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Group, UpdateGroupError> {
+        actor.require(Permission::GroupManage)?;
+
+        let group: Group = self
+            .group_repository
+            .find_by_name(&actor.organization_id, name)
+            .await?
+            .ok_or(UpdateGroupError::NotFound)?;
+
+        let description: String = match input.description {
+            Some(description) => Group::valid_description(description)?,
+            None => group.description.clone(),
+        };
+        let permissions: Vec<Permission> = match input.permissions {
+            Some(permissions) => Role::dedup(permissions),
+            None => group.permissions.clone(),
+        };
+
+        let changed: Vec<Permission> = Permission::ALL
+            .into_iter()
+            .filter(|permission| {
+                group.permissions.contains(permission) != permissions.contains(permission)
+            })
+            .collect();
+
+        if !Permission::all_granted(&changed, &actor.permissions) {
+            return Err(UpdateGroupError::Forbidden);
+        }
+
+        let updated: Group = self
+            .group_repository
+            .update(&group.id, description, permissions)
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::GroupUpdated)
+                .with_target(group.name.mention())
+                .with_change(
+                    "description",
+                    Some(&group.description),
+                    Some(&updated.description),
+                )
+                .with_change(
+                    "permissions",
+                    Some(permission_list(&group.permissions)),
+                    Some(permission_list(&updated.permissions)),
+                ),
+        )
+        .await;
+
+        Ok(updated)
+    }
+}
+
+/// Fields left out stay unchanged.
+pub struct UpdateGroupInput {
+    pub description: Option<String>,
+    pub permissions: Option<Vec<Permission>>,
+}
+
+pub enum UpdateGroupError {
+    InvalidGroup(GroupError),
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<GroupError> for UpdateGroupError {
+    fn from(value: GroupError) -> Self {
+        UpdateGroupError::InvalidGroup(value)
+    }
+}
+
+impl From<RepositoryError> for UpdateGroupError {
+    fn from(_: RepositoryError) -> Self {
+        UpdateGroupError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for UpdateGroupError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => UpdateGroupError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        mfa::{entity::TotpEnrollment, repository::TotpRepository, totp::TotpProvider},
+    },
+};
+
+#[derive(Clone)]
+pub struct ConfirmTotpService<M, P>
+where
+    M: TotpRepository,
+    P: TotpProvider,
+{
+    totp_repository: M,
+    totp_provider: P,
+}
+
+impl<M, P> ConfirmTotpService<M, P>
+where
+    M: TotpRepository,
+    P: TotpProvider,
+{
+    pub fn new(totp_repository: M, totp_provider: P) -> Self {
+        Self {
+            totp_repository,
+            totp_provider,
+        }
+    }
+
+    /// Activates a pending enrollment once the actor proves their authenticator app produces
+    /// valid codes.
+    pub async fn execute(
+        &self,
+        code: &str,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), ConfirmTotpError> {
+        let mut enrollment: TotpEnrollment = self
+            .totp_repository
+            .find_by_user_id(&actor.id)
+            .await?
+            .ok_or(ConfirmTotpError::NotEnrolled)?;
+
+        if enrollment.is_active() {
+            return Err(ConfirmTotpError::AlreadyEnabled);
+        }
+
+        let step: u64 = self
+            .totp_provider
+            .verify(&enrollment.secret, code, enrollment.last_used_step)
+            .ok_or(ConfirmTotpError::InvalidCode)?;
+
+        enrollment.confirmed = true;
+        enrollment.last_used_step = Some(step);
+
+        self.totp_repository.save(enrollment).await?;
+
+        Ok(())
+    }
+}
+
+pub enum ConfirmTotpError {
+    NotEnrolled,
+    AlreadyEnabled,
+    InvalidCode,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ConfirmTotpError {
+    fn from(_: RepositoryError) -> Self {
+        ConfirmTotpError::InfrastructureError
+    }
+}
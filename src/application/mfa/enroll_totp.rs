@@ -0,0 +1,111 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        mfa::{entity::TotpEnrollment, repository::TotpRepository, totp::TotpProvider},
+        user::{
+            error::UserError, password_hasher::PasswordHasher,
+            value_objects::password_hash::PasswordHash,
+        },
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+#[derive(Clone)]
+pub struct EnrollTotpService<M, P, H>
+where
+    M: TotpRepository,
+    P: TotpProvider,
+    H: PasswordHasher,
+{
+    totp_repository: M,
+    totp_provider: P,
+    hasher: H,
+}
+
+impl<M, P, H> EnrollTotpService<M, P, H>
+where
+    M: TotpRepository,
+    P: TotpProvider,
+    H: PasswordHasher,
+{
+    pub fn new(totp_repository: M, totp_provider: P, hasher: H) -> Self {
+        Self {
+            totp_repository,
+            totp_provider,
+            hasher,
+        }
+    }
+
+    /// Starts (or restarts) TOTP enrollment for the actor. The enrollment stays inactive until
+    /// confirmed, and the recovery codes are only ever returned here.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<EnrollTotpOutput, EnrollTotpError> {
+        if self
+            .totp_repository
+            .find_by_user_id(&actor.id)
+            .await?
+            .is_some_and(|enrollment| enrollment.is_active())
+        {
+            return Err(EnrollTotpError::AlreadyEnabled);
+        }
+
+        let secret: String = self.totp_provider.generate_secret();
+        let provisioning_uri: String = self
+            .totp_provider
+            .provisioning_uri(&secret, &actor.username);
+
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+        let hashes: Vec<PasswordHash> = recovery_codes
+            .iter()
+            .map(|code| PasswordHash::new(self.hasher.hash(code)))
+            .collect::<Result<_, _>>()?;
+
+        self.totp_repository
+            .save(TotpEnrollment::new(actor.id, secret.clone(), hashes))
+            .await?;
+
+        Ok(EnrollTotpOutput {
+            secret,
+            provisioning_uri,
+            recovery_codes,
+        })
+    }
+}
+
+fn generate_recovery_code() -> String {
+    let mut bytes: [u8; 4] = [0; 4];
+    OsRng.fill_bytes(&mut bytes);
+    let code: String = hex::encode(bytes);
+
+    format!("{}-{}", &code[..4], &code[4..])
+}
+
+pub struct EnrollTotpOutput {
+    pub secret: String,
+    pub provisioning_uri: String,
+    pub recovery_codes: Vec<String>,
+}
+
+pub enum EnrollTotpError {
+    AlreadyEnabled,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for EnrollTotpError {
+    fn from(_: RepositoryError) -> Self {
+        EnrollTotpError::InfrastructureError
+    }
+}
+
+impl From<UserError> for EnrollTotpError {
+    fn from(_: UserError) -> Self {
+        EnrollTotpError::InfrastructureError
+    }
+}
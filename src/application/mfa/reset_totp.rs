@@ -0,0 +1,109 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        mfa::repository::TotpRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ResetTotpService<R, M, L, G, A>
+where
+    R: UserRepository,
+    M: TotpRepository,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    user_repository: R,
+    totp_repository: M,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<R, M, L, G, A> ResetTotpService<R, M, L, G, A>
+where
+    R: UserRepository,
+    M: TotpRepository,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(
+        user_repository: R,
+        totp_repository: M,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            totp_repository,
+            role_repository,
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Removes a user's second factor so they can sign in with their password alone, e.g.
+    /// after losing both their device and recovery codes. Users holding permissions the actor
+    /// lacks keep theirs.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), ResetTotpError> {
+        actor.require(Permission::MfaReset)?;
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(ResetTotpError::NotFound)?;
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(ResetTotpError::Forbidden);
+        }
+
+        self.totp_repository.delete(&user.id).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::MfaReset).with_target(user.id),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum ResetTotpError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ResetTotpError {
+    fn from(_: RepositoryError) -> Self {
+        ResetTotpError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for ResetTotpError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ResetTotpError::Forbidden,
+        }
+    }
+}
@@ -1,3 +1,18 @@
+pub mod audit;
 pub mod auth;
+pub mod avatar;
+pub mod client;
+pub mod email_verification;
+pub mod event;
+pub mod federation;
+pub mod group;
+pub mod mfa;
+pub mod notification;
+pub mod organization;
+pub mod passkey;
+pub mod password_reset;
+pub mod registration;
+pub mod role;
 pub mod security;
+pub mod session;
 pub mod user;
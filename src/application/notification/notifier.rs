@@ -0,0 +1,23 @@
+use crate::domain::user::entity::User;
+
+/// Messages sent to users outside of the API.
+pub enum Notification {
+    /// A single-use token to set a new password with, valid for `expires_in` seconds.
+    PasswordReset { token: String, expires_in: u64 },
+    /// A link to verify the email address of the user with, valid for `expires_in` seconds.
+    /// Unlike other notifications, it goes to the address even though it is not verified yet.
+    EmailVerification { link: String, expires_in: u64 },
+}
+
+/// Delivers notifications to users, e.g. by email.
+#[async_trait::async_trait]
+pub trait Notifier {
+    async fn notify(&self, user: &User, notification: Notification) -> Result<(), NotifierError>;
+}
+
+#[derive(Debug)]
+pub enum NotifierError {
+    /// The user cannot be reached, e.g. no address is known for them.
+    Unreachable,
+    Unavailable,
+}
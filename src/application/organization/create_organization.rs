@@ -0,0 +1,116 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        organization::{
+            entity::{Organization, OrganizationSlug},
+            error::OrganizationError,
+            membership::Membership,
+            repository::OrganizationRepository,
+        },
+        role::{entity::RoleName, permission::Permission},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct CreateOrganizationService<O, A>
+where
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    organization_repository: O,
+    audit_log: A,
+}
+
+impl<O, A> CreateOrganizationService<O, A>
+where
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    pub fn new(organization_repository: O, audit_log: A) -> Self {
+        Self {
+            organization_repository,
+            audit_log,
+        }
+    }
+
+    /// Creates an organization, which the actor joins with the user role. Their own role
+    /// applies in every organization they are a member of.
+    pub async fn execute(
+        &self,
+        input: CreateOrganizationInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Organization, CreateOrganizationError> {
+        actor.require(Permission::OrganizationManage)?;
+
+        if self
+            .organization_repository
+            .find_by_slug(&input.slug)
+            .await?
+            .is_some()
+        {
+            return Err(CreateOrganizationError::AlreadyExists);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| CreateOrganizationError::InfrastructureError)?
+            .as_secs();
+        let organization: Organization =
+            Organization::new(Uuid::now_v7(), input.slug, input.name, now)?;
+        let founder: Membership = Membership::new(organization.id, actor.id, RoleName::user(), now);
+
+        let organization: Organization = self
+            .organization_repository
+            .create(organization, founder)
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::OrganizationCreated)
+                .with_target(organization.slug.as_str())
+                .with_change("id", None::<String>, Some(organization.id)),
+        )
+        .await;
+
+        Ok(organization)
+    }
+}
+
+pub struct CreateOrganizationInput {
+    pub slug: OrganizationSlug,
+    pub name: String,
+}
+
+pub enum CreateOrganizationError {
+    InvalidOrganization(OrganizationError),
+    AlreadyExists,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<OrganizationError> for CreateOrganizationError {
+    fn from(value: OrganizationError) -> Self {
+        CreateOrganizationError::InvalidOrganization(value)
+    }
+}
+
+impl From<RepositoryError> for CreateOrganizationError {
+    fn from(_: RepositoryError) -> Self {
+        CreateOrganizationError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for CreateOrganizationError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => CreateOrganizationError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::domain::{
+    errors::repository::RepositoryError,
+    organization::{
+        entity::{Organization, OrganizationSlug},
+        repository::OrganizationRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct FindOrganizationService<O>
+where
+    O: OrganizationRepository,
+{
+    organization_repository: O,
+}
+
+impl<O> FindOrganizationService<O>
+where
+    O: OrganizationRepository,
+{
+    pub fn new(organization_repository: O) -> Self {
+        Self {
+            organization_repository,
+        }
+    }
+
+    pub async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> Result<Organization, FindOrganizationError> {
+        self.organization_repository
+            .find_by_slug(slug)
+            .await?
+            .ok_or(FindOrganizationError::NotFound)
+    }
+}
+
+pub enum FindOrganizationError {
+    NotFound,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for FindOrganizationError {
+    fn from(_: RepositoryError) -> Self {
+        FindOrganizationError::InfrastructureError
+    }
+}
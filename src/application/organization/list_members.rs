@@ -0,0 +1,55 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        organization::{membership::Membership, repository::OrganizationRepository},
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ListMembersService<O>
+where
+    O: OrganizationRepository,
+{
+    organization_repository: O,
+}
+
+impl<O> ListMembersService<O>
+where
+    O: OrganizationRepository,
+{
+    pub fn new(organization_repository: O) -> Self {
+        Self {
+            organization_repository,
+        }
+    }
+
+    /// Lists the members of the active organization of the actor. Other organizations cannot
+    /// be looked into.
+    pub async fn execute(
+        &self,
+        organization_id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<Membership>, ListMembersError> {
+        if organization_id != &actor.organization_id {
+            return Err(ListMembersError::NotActive);
+        }
+
+        Ok(self
+            .organization_repository
+            .find_members(organization_id)
+            .await?)
+    }
+}
+
+pub enum ListMembersError {
+    NotActive,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListMembersError {
+    fn from(_: RepositoryError) -> Self {
+        ListMembersError::InfrastructureError
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        organization::{
+            entity::Organization, membership::Membership, repository::OrganizationRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct ListOrganizationsService<O>
+where
+    O: OrganizationRepository,
+{
+    organization_repository: O,
+}
+
+impl<O> ListOrganizationsService<O>
+where
+    O: OrganizationRepository,
+{
+    pub fn new(organization_repository: O) -> Self {
+        Self {
+            organization_repository,
+        }
+    }
+
+    /// Lists the organizations the actor is a member of, with their membership.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<(Organization, Membership)>, ListOrganizationsError> {
+        Ok(self
+            .organization_repository
+            .find_by_member(&actor.id)
+            .await?)
+    }
+}
+
+pub enum ListOrganizationsError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListOrganizationsError {
+    fn from(_: RepositoryError) -> Self {
+        ListOrganizationsError::InfrastructureError
+    }
+}
@@ -0,0 +1,7 @@
+pub mod create_organization;
+pub mod find_organization;
+pub mod list_members;
+pub mod list_organizations;
+pub mod remove_member;
+pub mod save_member;
+pub mod tenant;
@@ -0,0 +1,142 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        security::revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        organization::{membership::Membership, repository::OrganizationRepository},
+        role::{permission::Permission, repository::RoleRepository},
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct RemoveMemberService<O, U, L, S, A>
+where
+    O: OrganizationRepository,
+    U: UserRepository,
+    L: RoleRepository,
+    S: RevocationStore,
+    A: AuditLog,
+{
+    organization_repository: O,
+    user_repository: U,
+    role_repository: L,
+    revocation_store: S,
+    audit_log: A,
+}
+
+impl<O, U, L, S, A> RemoveMemberService<O, U, L, S, A>
+where
+    O: OrganizationRepository,
+    U: UserRepository,
+    L: RoleRepository,
+    S: RevocationStore,
+    A: AuditLog,
+{
+    pub fn new(
+        organization_repository: O,
+        user_repository: U,
+        role_repository: L,
+        revocation_store: S,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            organization_repository,
+            user_repository,
+            role_repository,
+            revocation_store,
+            audit_log,
+        }
+    }
+
+    /// Removes a member from the active organization of the actor, signing them out
+    /// everywhere. Members may leave on their own, but nobody leaves their home organization.
+    pub async fn execute(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), RemoveMemberError> {
+        actor.require_unless_owner(Permission::MemberManage, user_id)?;
+
+        if organization_id != &actor.organization_id {
+            return Err(RemoveMemberError::NotActive);
+        }
+
+        let membership: Membership = self
+            .organization_repository
+            .find_membership(organization_id, user_id)
+            .await?
+            .ok_or(RemoveMemberError::NotFound)?;
+
+        let user: User = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(RemoveMemberError::NotFound)?;
+
+        if &user.organization_id == organization_id {
+            return Err(RemoveMemberError::HomeOrganization);
+        }
+
+        if user_id != &actor.id
+            && self
+                .role_repository
+                .find_by_name(&membership.role)
+                .await?
+                .is_some_and(|role| !Permission::all_granted(&role.grants(), &actor.permissions))
+        {
+            return Err(RemoveMemberError::Forbidden);
+        }
+
+        if !self
+            .organization_repository
+            .delete_membership(organization_id, user_id)
+            .await?
+        {
+            return Err(RemoveMemberError::NotFound);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RemoveMemberError::InfrastructureError)?
+            .as_millis() as u64;
+        self.revocation_store.revoke_all(user_id, now).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::MemberRemoved).with_target(user_id),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum RemoveMemberError {
+    NotActive,
+    NotFound,
+    HomeOrganization,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RemoveMemberError {
+    fn from(_: RepositoryError) -> Self {
+        RemoveMemberError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for RemoveMemberError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => RemoveMemberError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        security::revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        organization::{membership::Membership, repository::OrganizationRepository},
+        role::{
+            entity::{Role, RoleName},
+            permission::Permission,
+            repository::RoleRepository,
+        },
+        user::repository::UserRepository,
+    },
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct SaveMemberService<O, U, L, S, A>
+where
+    O: OrganizationRepository,
+    U: UserRepository,
+    L: RoleRepository,
+    S: RevocationStore,
+    A: AuditLog,
+{
+    organization_repository: O,
+    user_repository: U,
+    role_repository: L,
+    revocation_store: S,
+    audit_log: A,
+}
+
+impl<O, U, L, S, A> SaveMemberService<O, U, L, S, A>
+where
+    O: OrganizationRepository,
+    U: UserRepository,
+    L: RoleRepository,
+    S: RevocationStore,
+    A: AuditLog,
+{
+    pub fn new(
+        organization_repository: O,
+        user_repository: U,
+        role_repository: L,
+        revocation_store: S,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            organization_repository,
+            user_repository,
+            role_repository,
+            revocation_store,
+            audit_log,
+        }
+    }
+
+    /// Adds a user of any organization to the active organization of the actor, or changes
+    /// the role of a member. Nobody hands out or takes away permissions they do not hold
+    /// themselves. Members whose role changes are signed out everywhere, and pick the new
+    /// role up on the next sign in.
+    pub async fn execute(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+        input: SaveMemberInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Membership, SaveMemberError> {
+        actor.require(Permission::MemberManage)?;
+
+        if organization_id != &actor.organization_id {
+            return Err(SaveMemberError::NotActive);
+        }
+
+        let role: Role = self
+            .role_repository
+            .find_by_name(&input.role)
+            .await?
+            .ok_or(SaveMemberError::UnknownRole)?;
+
+        if !Permission::all_granted(&role.grants(), &actor.permissions) {
+            return Err(SaveMemberError::Forbidden);
+        }
+
+        if self.user_repository.find_by_id(user_id).await?.is_none() {
+            return Err(SaveMemberError::UserNotFound);
+        }
+
+        let current: Option<Membership> = self
+            .organization_repository
+            .find_membership(organization_id, user_id)
+            .await?;
+
+        if let Some(current) = &current {
+            if current.role == input.role {
+                return Ok(current.clone());
+            }
+
+            let current_role: Option<Role> =
+                self.role_repository.find_by_name(&current.role).await?;
+
+            if current_role.is_some_and(|current_role| {
+                !Permission::all_granted(&current_role.grants(), &actor.permissions)
+            }) {
+                return Err(SaveMemberError::Forbidden);
+            }
+        }
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| SaveMemberError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        let membership: Membership = self
+            .organization_repository
+            .save_membership(Membership::new(
+                *organization_id,
+                *user_id,
+                input.role.clone(),
+                now,
+            ))
+            .await?;
+
+        let action: AuditAction = match &current {
+            Some(_) => {
+                self.revocation_store
+                    .revoke_all(user_id, elapsed.as_millis() as u64)
+                    .await?;
+
+                AuditAction::MemberRoleChanged
+            }
+            None => AuditAction::MemberAdded,
+        };
+
+        record(
+            &self.audit_log,
+            entry(actor, action).with_target(user_id).with_change(
+                "role",
+                current.as_ref().map(|current| current.role.as_str()),
+                Some(input.role.as_str()),
+            ),
+        )
+        .await;
+
+        Ok(membership)
+    }
+}
+
+pub struct SaveMemberInput {
+    pub role: RoleName,
+}
+
+pub enum SaveMemberError {
+    NotActive,
+    UserNotFound,
+    UnknownRole,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for SaveMemberError {
+    fn from(_: RepositoryError) -> Self {
+        SaveMemberError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for SaveMemberError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => SaveMemberError::Forbidden,
+        }
+    }
+}
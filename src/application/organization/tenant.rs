@@ -0,0 +1,65 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        organization::{membership::Membership, repository::OrganizationRepository},
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use uuid::Uuid;
+
+/// Finds a user the way the actor may see them: the actor themselves, or a user whose home
+/// organization is the active organization of the actor. Users of other organizations are not
+/// found, so nothing leaks across organizations.
+pub async fn find_managed<U>(
+    user_repository: &U,
+    id: &Uuid,
+    actor: &AuthenticatedUser,
+) -> Result<Option<User>, RepositoryError>
+where
+    U: UserRepository,
+{
+    match id == &actor.id {
+        true => user_repository.find_by_id(id).await,
+        false => {
+            user_repository
+                .find_in_organization(&actor.organization_id, id)
+                .await
+        }
+    }
+}
+
+/// Makes the organization the active one of the user, who must still be in their home
+/// organization. The deployment-wide role of the user only applies in their home organization,
+/// elsewhere the role of their membership replaces it. Returns whether the user is a member.
+pub async fn activate<O>(
+    organization_repository: &O,
+    user: &mut AuthenticatedUser,
+    organization_id: &Uuid,
+) -> Result<bool, RepositoryError>
+where
+    O: OrganizationRepository,
+{
+    let Some(membership) = organization_repository
+        .find_membership(organization_id, &user.id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let Membership {
+        organization_id,
+        role,
+        ..
+    } = membership;
+
+    if organization_id != user.organization_id {
+        user.roles = vec![role];
+    } else if !user.roles.contains(&role) {
+        user.roles.push(role);
+    }
+
+    user.organization_id = organization_id;
+
+    Ok(true)
+}
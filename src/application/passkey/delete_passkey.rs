@@ -0,0 +1,55 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        passkey::{entity::Passkey, repository::PasskeyRepository},
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DeletePasskeyService<K>
+where
+    K: PasskeyRepository,
+{
+    passkey_repository: K,
+}
+
+impl<K> DeletePasskeyService<K>
+where
+    K: PasskeyRepository,
+{
+    pub fn new(passkey_repository: K) -> Self {
+        Self { passkey_repository }
+    }
+
+    /// Removes one of the actor's passkeys. Passkeys of other users are reported as missing.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeletePasskeyError> {
+        let passkey: Passkey = self
+            .passkey_repository
+            .find_by_user_id(&actor.id)
+            .await?
+            .into_iter()
+            .find(|passkey| passkey.id == *id)
+            .ok_or(DeletePasskeyError::NotFound)?;
+
+        self.passkey_repository.delete(&passkey.id).await?;
+
+        Ok(())
+    }
+}
+
+pub enum DeletePasskeyError {
+    NotFound,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeletePasskeyError {
+    fn from(_: RepositoryError) -> Self {
+        DeletePasskeyError::InfrastructureError
+    }
+}
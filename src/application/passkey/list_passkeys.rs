@@ -0,0 +1,41 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        passkey::{entity::Passkey, repository::PasskeyRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct ListPasskeysService<K>
+where
+    K: PasskeyRepository,
+{
+    passkey_repository: K,
+}
+
+impl<K> ListPasskeysService<K>
+where
+    K: PasskeyRepository,
+{
+    pub fn new(passkey_repository: K) -> Self {
+        Self { passkey_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<Passkey>, ListPasskeysError> {
+        Ok(self.passkey_repository.find_by_user_id(&actor.id).await?)
+    }
+}
+
+pub enum ListPasskeysError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListPasskeysError {
+    fn from(_: RepositoryError) -> Self {
+        ListPasskeysError::InfrastructureError
+    }
+}
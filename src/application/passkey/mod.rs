@@ -0,0 +1,5 @@
+pub mod delete_passkey;
+pub mod list_passkeys;
+pub mod register_passkey;
+pub mod start_authentication;
+pub mod start_registration;
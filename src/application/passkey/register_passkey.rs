@@ -0,0 +1,111 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        passkey::{
+            entity::Passkey,
+            repository::PasskeyRepository,
+            verifier::{PasskeyError, PasskeyRegistration, PasskeyVerifier},
+        },
+    },
+};
+use uuid::Uuid;
+
+const MAX_NAME_LENGTH: usize = 64;
+
+#[derive(Clone)]
+pub struct RegisterPasskeyService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    passkey_repository: K,
+    verifier: V,
+}
+
+impl<K, V> RegisterPasskeyService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    pub fn new(passkey_repository: K, verifier: V) -> Self {
+        Self {
+            passkey_repository,
+            verifier,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: RegisterPasskeyInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Passkey, RegisterPasskeyError> {
+        let name: String = input.name.trim().to_owned();
+
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(RegisterPasskeyError::InvalidName);
+        }
+
+        let registration: PasskeyRegistration = self.verifier.verify_registration(
+            &actor.id,
+            &input.client_data_json,
+            &input.attestation_object,
+        )?;
+
+        if !self
+            .passkey_repository
+            .consume_challenge(&registration.challenge)
+            .await?
+        {
+            return Err(RegisterPasskeyError::InvalidAttestation);
+        }
+
+        if self
+            .passkey_repository
+            .find_by_credential_id(&registration.credential_id)
+            .await?
+            .is_some()
+        {
+            return Err(RegisterPasskeyError::AlreadyRegistered);
+        }
+
+        let passkey: Passkey = self
+            .passkey_repository
+            .create(Passkey::new(
+                Uuid::now_v7(),
+                actor.id,
+                name,
+                registration.credential_id,
+                registration.public_key,
+                registration.sign_count,
+            ))
+            .await?;
+
+        Ok(passkey)
+    }
+}
+
+pub struct RegisterPasskeyInput {
+    pub name: String,
+    pub client_data_json: Vec<u8>,
+    pub attestation_object: Vec<u8>,
+}
+
+pub enum RegisterPasskeyError {
+    InvalidName,
+    InvalidAttestation,
+    AlreadyRegistered,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RegisterPasskeyError {
+    fn from(_: RepositoryError) -> Self {
+        RegisterPasskeyError::InfrastructureError
+    }
+}
+
+impl From<PasskeyError> for RegisterPasskeyError {
+    fn from(_: PasskeyError) -> Self {
+        RegisterPasskeyError::InvalidAttestation
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::{
+    errors::repository::RepositoryError,
+    passkey::{
+        repository::PasskeyRepository,
+        verifier::{PasskeyChallenge, PasskeyVerifier},
+    },
+};
+
+#[derive(Clone)]
+pub struct StartPasskeyAuthenticationService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    passkey_repository: K,
+    verifier: V,
+}
+
+impl<K, V> StartPasskeyAuthenticationService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    pub fn new(passkey_repository: K, verifier: V) -> Self {
+        Self {
+            passkey_repository,
+            verifier,
+        }
+    }
+
+    /// Builds the options passed to `navigator.credentials.get()`. No credentials are listed,
+    /// the user picks one of the discoverable passkeys stored for this relying party. The
+    /// challenge is stored so it can only be answered once.
+    pub async fn execute(
+        &self,
+    ) -> Result<StartPasskeyAuthenticationOutput, StartPasskeyAuthenticationError> {
+        let challenge: PasskeyChallenge = self.verifier.challenge(None);
+        self.passkey_repository.create_challenge(&challenge).await?;
+
+        Ok(StartPasskeyAuthenticationOutput {
+            challenge: challenge.value,
+            rp_id: self.verifier.rp_id().to_owned(),
+        })
+    }
+}
+
+pub struct StartPasskeyAuthenticationOutput {
+    pub challenge: String,
+    pub rp_id: String,
+}
+
+pub enum StartPasskeyAuthenticationError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for StartPasskeyAuthenticationError {
+    fn from(_: RepositoryError) -> Self {
+        StartPasskeyAuthenticationError::InfrastructureError
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        passkey::{
+            entity::Passkey,
+            repository::PasskeyRepository,
+            verifier::{PasskeyChallenge, PasskeyVerifier},
+        },
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct StartPasskeyRegistrationService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    passkey_repository: K,
+    verifier: V,
+}
+
+impl<K, V> StartPasskeyRegistrationService<K, V>
+where
+    K: PasskeyRepository,
+    V: PasskeyVerifier,
+{
+    pub fn new(passkey_repository: K, verifier: V) -> Self {
+        Self {
+            passkey_repository,
+            verifier,
+        }
+    }
+
+    /// Builds the options passed to `navigator.credentials.create()`. Passkeys the actor
+    /// already registered are excluded so an authenticator is not enrolled twice. The challenge
+    /// is stored so it can only be answered once.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<StartPasskeyRegistrationOutput, StartPasskeyRegistrationError> {
+        let passkeys: Vec<Passkey> = self.passkey_repository.find_by_user_id(&actor.id).await?;
+
+        let challenge: PasskeyChallenge = self.verifier.challenge(Some(&actor.id));
+        self.passkey_repository.create_challenge(&challenge).await?;
+
+        Ok(StartPasskeyRegistrationOutput {
+            challenge: challenge.value,
+            rp_id: self.verifier.rp_id().to_owned(),
+            rp_name: self.verifier.rp_name().to_owned(),
+            user_id: actor.id,
+            username: actor.username.clone(),
+            exclude_credentials: passkeys
+                .into_iter()
+                .map(|passkey| passkey.credential_id)
+                .collect(),
+        })
+    }
+}
+
+pub struct StartPasskeyRegistrationOutput {
+    pub challenge: String,
+    pub rp_id: String,
+    pub rp_name: String,
+    pub user_id: Uuid,
+    pub username: String,
+    pub exclude_credentials: Vec<Vec<u8>>,
+}
+
+pub enum StartPasskeyRegistrationError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for StartPasskeyRegistrationError {
+    fn from(_: RepositoryError) -> Self {
+        StartPasskeyRegistrationError::InfrastructureError
+    }
+}
@@ -0,0 +1,2 @@
+pub mod request_reset;
+pub mod reset_password;
@@ -0,0 +1,177 @@
+use crate::{
+    application::{
+        auth::login::LoginContext,
+        notification::notifier::{Notification, Notifier, NotifierError},
+        security::{
+            login_attempt_store::LoginAttemptStore,
+            login_throttle::{LoginThrottle, ThrottleError, ThrottleKey},
+        },
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        password_reset::{entity::PasswordResetToken, repository::PasswordResetRepository},
+        user::{
+            entity::User,
+            password_hasher::PasswordHasher,
+            repository::UserRepository,
+            value_objects::{password_hash::PasswordHash, username::Username},
+        },
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+pub const RESET_TOKEN_TTL_SECONDS: u64 = 30 * 60;
+
+#[derive(Clone)]
+pub struct RequestPasswordResetService<U, R, H, N, A>
+where
+    U: UserRepository,
+    R: PasswordResetRepository,
+    H: PasswordHasher,
+    N: Notifier,
+    A: LoginAttemptStore,
+{
+    user_repository: U,
+    reset_repository: R,
+    hasher: H,
+    notifier: N,
+    throttle: LoginThrottle<A>,
+}
+
+impl<U, R, H, N, A> RequestPasswordResetService<U, R, H, N, A>
+where
+    U: UserRepository,
+    R: PasswordResetRepository,
+    H: PasswordHasher,
+    N: Notifier,
+    A: LoginAttemptStore,
+{
+    pub fn new(
+        user_repository: U,
+        reset_repository: R,
+        hasher: H,
+        notifier: N,
+        throttle: LoginThrottle<A>,
+    ) -> Self {
+        Self {
+            user_repository,
+            reset_repository,
+            hasher,
+            notifier,
+            throttle,
+        }
+    }
+
+    /// Counts a reset request for the username and the client address, and fails with the
+    /// seconds to wait when either asked too often. Callers admit a request before running
+    /// it, which they may do in the background.
+    pub async fn admit(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+        context: &LoginContext,
+    ) -> Result<(), RequestPasswordResetError> {
+        let mut throttle_keys: Vec<ThrottleKey> = vec![ThrottleKey::PasswordReset(
+            *organization_id,
+            username.as_str().to_owned(),
+        )];
+
+        if let Some(ip_address) = context.ip_address {
+            throttle_keys.push(ThrottleKey::PasswordResetIp(ip_address));
+        }
+
+        self.throttle.check(&throttle_keys).await?;
+        self.throttle.record_failure(&throttle_keys).await?;
+
+        Ok(())
+    }
+
+    /// Sends a reset token to the user of an organization. Asking again replaces the previous
+    /// token. Nothing happens for unknown or inactive users, and callers must not tell the
+    /// difference.
+    pub async fn execute(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<(), RequestPasswordResetError> {
+        let user: User = match self
+            .user_repository
+            .find_by_username(organization_id, username)
+            .await?
+        {
+            Some(user) if user.is_active() => user,
+            _ => return Ok(()),
+        };
+
+        self.reset_repository.delete_by_user_id(&user.id).await?;
+
+        let mut secret: [u8; 32] = [0; 32];
+        OsRng.fill_bytes(&mut secret);
+        let secret: String = hex::encode(secret);
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RequestPasswordResetError::InfrastructureError)?
+            .as_secs();
+        let token: PasswordResetToken = self
+            .reset_repository
+            .create(PasswordResetToken::new(
+                Uuid::now_v7(),
+                user.id,
+                PasswordHash::new(self.hasher.hash(&secret))
+                    .map_err(|_| RequestPasswordResetError::InfrastructureError)?,
+                now + RESET_TOKEN_TTL_SECONDS,
+            ))
+            .await?;
+
+        self.notifier
+            .notify(
+                &user,
+                Notification::PasswordReset {
+                    token: format!("{}.{}", token.id.simple(), secret),
+                    expires_in: RESET_TOKEN_TTL_SECONDS,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RequestPasswordResetError {
+    /// No address is known to send the token to.
+    Unreachable,
+    /// Too many requests for the username or from the client address, retry after the given
+    /// number of seconds.
+    Throttled(u64),
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RequestPasswordResetError {
+    fn from(_: RepositoryError) -> Self {
+        RequestPasswordResetError::InfrastructureError
+    }
+}
+
+impl From<ThrottleError> for RequestPasswordResetError {
+    fn from(value: ThrottleError) -> Self {
+        match value {
+            ThrottleError::Locked { retry_after } => {
+                RequestPasswordResetError::Throttled(retry_after)
+            }
+            ThrottleError::InfrastructureError => RequestPasswordResetError::InfrastructureError,
+        }
+    }
+}
+
+impl From<NotifierError> for RequestPasswordResetError {
+    fn from(value: NotifierError) -> Self {
+        match value {
+            NotifierError::Unreachable => RequestPasswordResetError::Unreachable,
+            NotifierError::Unavailable => RequestPasswordResetError::InfrastructureError,
+        }
+    }
+}
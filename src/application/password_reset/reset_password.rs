@@ -0,0 +1,207 @@
+use crate::{
+    application::{
+        audit::record::{anonymous, record},
+        security::revocation_store::RevocationStore,
+        user::password::{check_new_password, end_sessions, set_password},
+    },
+    domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
+        errors::repository::RepositoryError,
+        password_reset::{entity::PasswordResetToken, repository::PasswordResetRepository},
+        session::repository::SessionRepository,
+        user::{
+            entity::User,
+            error::UserError,
+            password_hasher::{HashError, PasswordHasher},
+            password_history::PasswordHistoryRepository,
+            password_policy::{BreachedPasswords, PasswordPolicy},
+            repository::UserRepository,
+            value_objects::password_plain::PasswordPlain,
+        },
+    },
+};
+use std::{
+    net::IpAddr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ResetPasswordService<U, R, P, H, S, B, E, A>
+where
+    U: UserRepository,
+    R: PasswordResetRepository,
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+    B: BreachedPasswords,
+    E: SessionRepository,
+    A: AuditLog,
+{
+    user_repository: U,
+    reset_repository: R,
+    history_repository: P,
+    hasher: H,
+    revocation_store: S,
+    policy: PasswordPolicy<B>,
+    session_repository: E,
+    audit_log: A,
+}
+
+impl<U, R, P, H, S, B, E, A> ResetPasswordService<U, R, P, H, S, B, E, A>
+where
+    U: UserRepository,
+    R: PasswordResetRepository,
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+    B: BreachedPasswords,
+    E: SessionRepository,
+    A: AuditLog,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: U,
+        reset_repository: R,
+        history_repository: P,
+        hasher: H,
+        revocation_store: S,
+        policy: PasswordPolicy<B>,
+        session_repository: E,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            reset_repository,
+            history_repository,
+            hasher,
+            revocation_store,
+            policy,
+            session_repository,
+            audit_log,
+        }
+    }
+
+    /// Sets a new password with a reset token, which is used up. Every session of the user
+    /// ends: their refresh tokens are revoked.
+    pub async fn execute(&self, input: ResetPasswordInput) -> Result<(), ResetPasswordError> {
+        let (id, secret) = input
+            .token
+            .split_once('.')
+            .and_then(|(id, secret)| Some((Uuid::try_parse(id).ok()?, secret)))
+            .ok_or(ResetPasswordError::InvalidToken)?;
+
+        let token: PasswordResetToken = self
+            .reset_repository
+            .find_by_id(&id)
+            .await?
+            .ok_or(ResetPasswordError::InvalidToken)?;
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ResetPasswordError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        if token.is_expired(now) {
+            self.reset_repository.consume(&token.id).await?;
+
+            return Err(ResetPasswordError::InvalidToken);
+        }
+
+        if !self
+            .hasher
+            .verify(secret, token.secret_hash.as_str())?
+            .is_match()
+        {
+            return Err(ResetPasswordError::InvalidToken);
+        }
+
+        let user: User = self
+            .user_repository
+            .find_by_id(&token.user_id)
+            .await?
+            .ok_or(ResetPasswordError::InvalidToken)?;
+
+        // Checked before the token is used up, so that another password can be tried with it.
+        check_new_password::<_, _, _, ResetPasswordError>(
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+
+        if !self.reset_repository.consume(&token.id).await? {
+            return Err(ResetPasswordError::InvalidToken);
+        }
+
+        set_password(
+            &self.user_repository,
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+
+        self.reset_repository
+            .delete_by_user_id(&token.user_id)
+            .await?;
+        end_sessions(
+            &self.revocation_store,
+            &self.session_repository,
+            &token.user_id,
+            None,
+            elapsed.as_millis() as u64,
+        )
+        .await?;
+
+        let mut audit: AuditEntry = anonymous(AuditAction::PasswordReset).with_target(user.id);
+        audit.organization_id = Some(user.organization_id);
+        audit.actor_id = Some(user.id);
+        audit.actor_username = Some(user.username.as_str().to_owned());
+        audit.ip_address = input.ip_address;
+
+        record(&self.audit_log, audit).await;
+
+        Ok(())
+    }
+}
+
+pub struct ResetPasswordInput {
+    pub token: String,
+    pub new_password: PasswordPlain,
+    /// Where the request comes from, for the audit log.
+    pub ip_address: Option<IpAddr>,
+}
+
+pub enum ResetPasswordError {
+    /// The token is malformed, unknown, expired or already used.
+    InvalidToken,
+    /// The new password breaks the password policy, or was used recently.
+    UserError(UserError),
+    InfrastructureError,
+}
+
+impl From<UserError> for ResetPasswordError {
+    fn from(value: UserError) -> Self {
+        ResetPasswordError::UserError(value)
+    }
+}
+
+impl From<HashError> for ResetPasswordError {
+    fn from(_: HashError) -> Self {
+        ResetPasswordError::InfrastructureError
+    }
+}
+
+impl From<RepositoryError> for ResetPasswordError {
+    fn from(_: RepositoryError) -> Self {
+        ResetPasswordError::InfrastructureError
+    }
+}
@@ -0,0 +1,17 @@
+use std::net::IpAddr;
+
+/// Tells humans from bots on open registration, e.g. through a CAPTCHA service.
+#[async_trait::async_trait]
+pub trait CaptchaVerifier {
+    /// Whether `response`, which the client got by solving a challenge, is valid.
+    async fn verify(
+        &self,
+        response: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<bool, CaptchaError>;
+}
+
+#[derive(Debug)]
+pub enum CaptchaError {
+    Unavailable,
+}
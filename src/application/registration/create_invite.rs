@@ -0,0 +1,130 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        invite::{entity::Invite, repository::InviteRepository},
+        role::permission::Permission,
+        user::{password_hasher::PasswordHasher, value_objects::password_hash::PasswordHash},
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+pub const DEFAULT_INVITE_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+pub const MAX_INVITE_TTL_SECONDS: u64 = 365 * 24 * 60 * 60;
+pub const MAX_INVITE_USES: u32 = 10_000;
+
+#[derive(Clone)]
+pub struct CreateInviteService<I, H, A>
+where
+    I: InviteRepository,
+    H: PasswordHasher,
+    A: AuditLog,
+{
+    invite_repository: I,
+    hasher: H,
+    audit_log: A,
+}
+
+impl<I, H, A> CreateInviteService<I, H, A>
+where
+    I: InviteRepository,
+    H: PasswordHasher,
+    A: AuditLog,
+{
+    pub fn new(invite_repository: I, hasher: H, audit_log: A) -> Self {
+        Self {
+            invite_repository,
+            hasher,
+            audit_log,
+        }
+    }
+
+    /// Creates an invite code to the active organization of the actor. The code is only known
+    /// at this point, it cannot be shown again.
+    pub async fn execute(
+        &self,
+        input: CreateInviteInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<CreateInviteOutput, CreateInviteError> {
+        actor.require(Permission::InviteManage)?;
+
+        if !(1..=MAX_INVITE_USES).contains(&input.max_uses)
+            || !(1..=MAX_INVITE_TTL_SECONDS).contains(&input.expires_in)
+        {
+            return Err(CreateInviteError::InvalidLimits);
+        }
+
+        let mut secret: [u8; 32] = [0; 32];
+        OsRng.fill_bytes(&mut secret);
+        let secret: String = hex::encode(secret);
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| CreateInviteError::InfrastructureError)?
+            .as_secs();
+        let invite: Invite = self
+            .invite_repository
+            .create(Invite::new(
+                Uuid::now_v7(),
+                actor.organization_id,
+                PasswordHash::new(self.hasher.hash(&secret))
+                    .map_err(|_| CreateInviteError::InfrastructureError)?,
+                actor.id,
+                input.max_uses,
+                now + input.expires_in,
+                now,
+            ))
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::InviteCreated)
+                .with_target(invite.id)
+                .with_change("max_uses", None::<u32>, Some(invite.max_uses))
+                .with_change("expires_at", None::<u64>, Some(invite.expires_at)),
+        )
+        .await;
+
+        Ok(CreateInviteOutput {
+            code: format!("{}.{}", invite.id.simple(), secret),
+            invite,
+        })
+    }
+}
+
+pub struct CreateInviteInput {
+    pub max_uses: u32,
+    /// Seconds until the invite expires.
+    pub expires_in: u64,
+}
+
+pub struct CreateInviteOutput {
+    pub invite: Invite,
+    pub code: String,
+}
+
+pub enum CreateInviteError {
+    InvalidLimits,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for CreateInviteError {
+    fn from(_: RepositoryError) -> Self {
+        CreateInviteError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for CreateInviteError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => CreateInviteError::Forbidden,
+        }
+    }
+}
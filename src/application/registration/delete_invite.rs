@@ -0,0 +1,82 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        invite::repository::InviteRepository,
+        role::permission::Permission,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DeleteInviteService<I, A>
+where
+    I: InviteRepository,
+    A: AuditLog,
+{
+    invite_repository: I,
+    audit_log: A,
+}
+
+impl<I, A> DeleteInviteService<I, A>
+where
+    I: InviteRepository,
+    A: AuditLog,
+{
+    pub fn new(invite_repository: I, audit_log: A) -> Self {
+        Self {
+            invite_repository,
+            audit_log,
+        }
+    }
+
+    /// Withdraws an invite of the active organization of the actor, its code no longer works.
+    /// Users who signed up with it stay.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeleteInviteError> {
+        actor.require(Permission::InviteManage)?;
+
+        if !self
+            .invite_repository
+            .delete(&actor.organization_id, id)
+            .await?
+        {
+            return Err(DeleteInviteError::NotFound);
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::InviteDeleted).with_target(id),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum DeleteInviteError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeleteInviteError {
+    fn from(_: RepositoryError) -> Self {
+        DeleteInviteError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for DeleteInviteError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => DeleteInviteError::Forbidden,
+        }
+    }
+}
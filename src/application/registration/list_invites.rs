@@ -0,0 +1,57 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::{domain::DomainError, repository::RepositoryError},
+        invite::{entity::Invite, repository::InviteRepository},
+        role::permission::Permission,
+    },
+};
+
+#[derive(Clone)]
+pub struct ListInvitesService<I>
+where
+    I: InviteRepository,
+{
+    invite_repository: I,
+}
+
+impl<I> ListInvitesService<I>
+where
+    I: InviteRepository,
+{
+    pub fn new(invite_repository: I) -> Self {
+        Self { invite_repository }
+    }
+
+    /// Lists the invites of the active organization of the actor.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<Invite>, ListInvitesError> {
+        actor.require(Permission::InviteManage)?;
+
+        Ok(self
+            .invite_repository
+            .find_by_organization(&actor.organization_id)
+            .await?)
+    }
+}
+
+pub enum ListInvitesError {
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListInvitesError {
+    fn from(_: RepositoryError) -> Self {
+        ListInvitesError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for ListInvitesError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ListInvitesError::Forbidden,
+        }
+    }
+}
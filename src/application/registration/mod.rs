@@ -0,0 +1,5 @@
+pub mod captcha;
+pub mod create_invite;
+pub mod delete_invite;
+pub mod list_invites;
+pub mod register;
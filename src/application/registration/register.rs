@@ -0,0 +1,286 @@
+use super::captcha::{CaptchaError, CaptchaVerifier};
+use crate::{
+    application::{
+        audit::record::{anonymous, record},
+        security::{
+            login_attempt_store::LoginAttemptStore,
+            login_throttle::{LoginThrottle, ThrottleError, ThrottleKey},
+        },
+        user::create_user::{
+            CreateUserError, CreateUserInput, CreateUserOutput, CreateUserService,
+        },
+    },
+    domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
+        errors::repository::RepositoryError,
+        invite::{entity::Invite, repository::InviteRepository},
+        organization::entity::Organization,
+        user::{
+            error::UserError,
+            password_hasher::{HashError, PasswordHasher},
+            password_policy::BreachedPasswords,
+            repository::UserRepository,
+        },
+    },
+};
+use std::{
+    net::IpAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegistrationMode {
+    Closed,
+    InviteOnly,
+    Open,
+}
+
+#[derive(Clone)]
+pub struct RegisterService<R, H, B, I, C, A, L>
+where
+    R: UserRepository,
+    H: PasswordHasher,
+    B: BreachedPasswords,
+    I: InviteRepository,
+    C: CaptchaVerifier,
+    A: AuditLog,
+    L: LoginAttemptStore,
+{
+    create_user: CreateUserService<R, H, B, A>,
+    invite_repository: I,
+    hasher: H,
+    captcha: C,
+    mode: RegistrationMode,
+    audit_log: A,
+    throttle: LoginThrottle<L>,
+}
+
+impl<R, H, B, I, C, A, L> RegisterService<R, H, B, I, C, A, L>
+where
+    R: UserRepository,
+    H: PasswordHasher,
+    B: BreachedPasswords,
+    I: InviteRepository,
+    C: CaptchaVerifier,
+    A: AuditLog,
+    L: LoginAttemptStore,
+{
+    pub fn new(
+        create_user: CreateUserService<R, H, B, A>,
+        invite_repository: I,
+        hasher: H,
+        captcha: C,
+        mode: RegistrationMode,
+        audit_log: A,
+        throttle: LoginThrottle<L>,
+    ) -> Self {
+        Self {
+            create_user,
+            invite_repository,
+            hasher,
+            captcha,
+            mode,
+            audit_log,
+            throttle,
+        }
+    }
+
+    /// Signs a new regular user up. Invite-only registration uses up one use of an invite
+    /// code, open registration asks the CAPTCHA verifier instead.
+    ///
+    /// Users who sign up with an invite start in the organization of the invite, the others in
+    /// the default organization.
+    pub async fn execute(&self, input: RegisterInput) -> Result<CreateUserOutput, RegisterError> {
+        let invite: Option<(Uuid, Uuid)> = match self.mode {
+            RegistrationMode::Closed => return Err(RegisterError::Closed),
+            RegistrationMode::InviteOnly => Some(
+                self.redeem(
+                    input
+                        .invite_code
+                        .as_deref()
+                        .ok_or(RegisterError::InviteRequired)?,
+                    input.ip_address,
+                )
+                .await?,
+            ),
+            RegistrationMode::Open => {
+                let response: &str = input.captcha_response.as_deref().unwrap_or_default();
+
+                if !self.captcha.verify(response, input.ip_address).await? {
+                    return Err(RegisterError::CaptchaFailed);
+                }
+
+                None
+            }
+        };
+
+        let ip_address: Option<IpAddr> = input.ip_address;
+        let organization_id: Uuid = invite
+            .map(|(_, organization_id)| organization_id)
+            .unwrap_or(Organization::DEFAULT_ID);
+        let invite: Option<Uuid> = invite.map(|(id, _)| id);
+
+        let created: Result<CreateUserOutput, CreateUserError> = self
+            .create_user
+            .create(
+                CreateUserInput {
+                    username: input.username,
+                    name: input.name,
+                    password: input.password,
+                    email: input.email,
+                },
+                organization_id,
+            )
+            .await;
+
+        let user: CreateUserOutput = match (created, invite) {
+            (Ok(user), _) => user,
+            (Err(error), Some(invite)) => {
+                self.invite_repository.release(&invite).await?;
+
+                return Err(error.into());
+            }
+            (Err(error), None) => return Err(error.into()),
+        };
+
+        let mut entry: AuditEntry = anonymous(AuditAction::UserRegistered)
+            .with_target(user.id)
+            .with_change("invite", None::<Uuid>, invite);
+        entry.organization_id = Some(organization_id);
+        entry.actor_id = Some(user.id);
+        entry.actor_username = Some(user.username.clone());
+        entry.ip_address = ip_address;
+
+        record(&self.audit_log, entry).await;
+
+        Ok(user)
+    }
+
+    /// Takes one use of an invite code formatted as `<id>.<secret>`, returning the invite and
+    /// its organization.
+    ///
+    /// Wrong codes are throttled like failed logins, per invite and per client address.
+    async fn redeem(
+        &self,
+        code: &str,
+        ip_address: Option<IpAddr>,
+    ) -> Result<(Uuid, Uuid), RegisterError> {
+        let parsed: Option<(Uuid, &str)> = code
+            .split_once('.')
+            .and_then(|(id, secret)| Some((Uuid::try_parse(id).ok()?, secret)));
+        let throttle_keys: Vec<ThrottleKey> = parsed
+            .map(|(id, _)| ThrottleKey::Invite(id))
+            .into_iter()
+            .chain(ip_address.map(ThrottleKey::Ip))
+            .collect();
+
+        self.throttle.check(&throttle_keys).await?;
+
+        let invite: Option<(Invite, &str)> = match parsed {
+            Some((id, secret)) => self
+                .invite_repository
+                .find_by_id(&id)
+                .await?
+                .map(|invite| (invite, secret)),
+            None => None,
+        };
+        let matches: bool = match &invite {
+            Some((invite, secret)) => self
+                .hasher
+                .verify(secret, invite.secret_hash.as_str())?
+                .is_match(),
+            None => false,
+        };
+
+        let Some((invite, _)) = invite.filter(|_| matches) else {
+            self.throttle.record_failure(&throttle_keys).await?;
+
+            return Err(RegisterError::InvalidInvite);
+        };
+
+        self.throttle.record_success(&throttle_keys).await?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RegisterError::InfrastructureError)?
+            .as_secs();
+
+        if invite.is_expired(now)
+            || invite.is_used_up()
+            || !self.invite_repository.redeem(&invite.id, now).await?
+        {
+            return Err(RegisterError::InvalidInvite);
+        }
+
+        Ok((invite.id, invite.organization_id))
+    }
+}
+
+pub struct RegisterInput {
+    pub username: String,
+    pub name: String,
+    pub password: String,
+    pub email: Option<String>,
+    pub invite_code: Option<String>,
+    pub captcha_response: Option<String>,
+    pub ip_address: Option<IpAddr>,
+}
+
+pub enum RegisterError {
+    Closed,
+    InviteRequired,
+    /// The invite code is unknown, expired or used up.
+    InvalidInvite,
+    CaptchaFailed,
+    /// Too many wrong invite codes for the invite or from the client address, retry after the
+    /// given number of seconds.
+    Throttled(u64),
+    UserError(UserError),
+    AlreadyExists,
+    EmailAlreadyExists,
+    InfrastructureError,
+}
+
+impl From<CreateUserError> for RegisterError {
+    fn from(value: CreateUserError) -> Self {
+        match value {
+            CreateUserError::UserError(error) => RegisterError::UserError(error),
+            CreateUserError::AlreadyExists => RegisterError::AlreadyExists,
+            CreateUserError::EmailAlreadyExists => RegisterError::EmailAlreadyExists,
+            CreateUserError::Forbidden | CreateUserError::InfrastructureError => {
+                RegisterError::InfrastructureError
+            }
+        }
+    }
+}
+
+impl From<RepositoryError> for RegisterError {
+    fn from(_: RepositoryError) -> Self {
+        RegisterError::InfrastructureError
+    }
+}
+
+impl From<HashError> for RegisterError {
+    fn from(_: HashError) -> Self {
+        RegisterError::InfrastructureError
+    }
+}
+
+impl From<ThrottleError> for RegisterError {
+    fn from(value: ThrottleError) -> Self {
+        match value {
+            ThrottleError::Locked { retry_after } => RegisterError::Throttled(retry_after),
+            ThrottleError::InfrastructureError => RegisterError::InfrastructureError,
+        }
+    }
+}
+
+impl From<CaptchaError> for RegisterError {
+    fn from(_: CaptchaError) -> Self {
+        RegisterError::InfrastructureError
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::{
+            entity::{Role, RoleName},
+            error::RoleError,
+            permission::Permission,
+            repository::RoleRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateRoleService<L, A>
+where
+    L: RoleRepository,
+    A: AuditLog,
+{
+    role_repository: L,
+    audit_log: A,
+}
+
+impl<L, A> CreateRoleService<L, A>
+where
+    L: RoleRepository,
+    A: AuditLog,
+{
+    pub fn new(role_repository: L, audit_log: A) -> Self {
+        Self {
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// Creates a role. Nobody grants a role permissions they do not hold themselves.
+    pub async fn execute(
+        &self,
+        input: CreateRoleInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Role, CreateRoleError> {
+        actor.require(Permission::RoleManage)?;
+
+        let role: Role = Role::new(input.name, input.description, input.permissions)?;
+
+        if !Permission::all_granted(&role.permissions, &actor.permissions) {
+            return Err(CreateRoleError::Forbidden);
+        }
+
+        if self
+            .role_repository
+            .find_by_name(&role.name)
+            .await?
+            .is_some()
+        {
+            return Err(CreateRoleError::AlreadyExists);
+        }
+
+        let role: Role = self.role_repository.create(role).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::RoleCreated)
+                .with_target(role.name.as_str())
+                .with_change(
+                    "permissions",
+                    None::<String>,
+                    Some(permission_list(&role.permissions)),
+                ),
+        )
+        .await;
+
+        Ok(role)
+    }
+}
+
+/// Comma-separated permissions, for the audit log.
+pub(crate) fn permission_list(permissions: &[Permission]) -> String {
+    match permissions.is_empty() {
+        true => "no permissions".into(),
+        false => permissions
+            .iter()
+            .map(Permission::as_str)
+            .collect::<Vec<&str>>()
+            .join(", "),
+    }
+}
+
+pub struct CreateRoleInput {
+    pub name: RoleName,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+pub enum CreateRoleError {
+    InvalidRole(RoleError),
+    AlreadyExists,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RoleError> for CreateRoleError {
+    fn from(value: RoleError) -> Self {
+        CreateRoleError::InvalidRole(value)
+    }
+}
+
+impl From<RepositoryError> for CreateRoleError {
+    fn from(_: RepositoryError) -> Self {
+        CreateRoleError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for CreateRoleError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => CreateRoleError::Forbidden,
+        }
+    }
+}
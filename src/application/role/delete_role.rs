@@ -0,0 +1,246 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        organization::repository::OrganizationRepository,
+        role::{
+            entity::{Role, RoleName},
+            permission::Permission,
+            repository::RoleRepository,
+        },
+        user::repository::UserRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DeleteRoleService<L, U, O, A>
+where
+    L: RoleRepository,
+    U: UserRepository,
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    role_repository: L,
+    user_repository: U,
+    organization_repository: O,
+    audit_log: A,
+}
+
+impl<L, U, O, A> DeleteRoleService<L, U, O, A>
+where
+    L: RoleRepository,
+    U: UserRepository,
+    O: OrganizationRepository,
+    A: AuditLog,
+{
+    pub fn new(
+        role_repository: L,
+        user_repository: U,
+        organization_repository: O,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            role_repository,
+            user_repository,
+            organization_repository,
+            audit_log,
+        }
+    }
+
+    /// Deletes a role. Built-in roles cannot be deleted, and neither can roles users or
+    /// memberships still hold: they have to be moved to another role first.
+    pub async fn execute(
+        &self,
+        name: &RoleName,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeleteRoleError> {
+        actor.require(Permission::RoleManage)?;
+
+        let role: Role = self
+            .role_repository
+            .find_by_name(name)
+            .await?
+            .ok_or(DeleteRoleError::NotFound)?;
+
+        if role.builtin {
+            return Err(DeleteRoleError::Builtin);
+        }
+
+        // Deleted and purged users count too: their rows keep referencing the role.
+        if self.user_repository.has_users_with_role(&role.name).await?
+            || self
+                .organization_repository
+                .has_members_with_role(&role.name)
+                .await?
+        {
+            return Err(DeleteRoleError::InUse);
+        }
+
+        if !self.role_repository.delete(name).await? {
+            return Err(DeleteRoleError::NotFound);
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::RoleDeleted).with_target(role.name.as_str()),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum DeleteRoleError {
+    NotFound,
+    Builtin,
+    InUse,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeleteRoleError {
+    fn from(_: RepositoryError) -> Self {
+        DeleteRoleError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for DeleteRoleError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => DeleteRoleError::Forbidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::persistence::memory::{
+            InMemoryAuditLog, InMemoryOrganizationRepository, InMemoryRoleRepository,
+            InMemoryUserRepository,
+        },
+        domain::{
+            organization::{entity::Organization, membership::Membership},
+            user::{
+                entity::User,
+                value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+            },
+        },
+    };
+    use uuid::Uuid;
+
+    type TestService = DeleteRoleService<
+        InMemoryRoleRepository,
+        InMemoryUserRepository,
+        InMemoryOrganizationRepository,
+        InMemoryAuditLog,
+    >;
+
+    fn support() -> RoleName {
+        RoleName::new("support".into()).unwrap()
+    }
+
+    fn service(users: Vec<User>) -> TestService {
+        DeleteRoleService::new(
+            InMemoryRoleRepository::with(vec![
+                Role::new(support(), "Reads users".into(), vec![Permission::UserRead]).unwrap(),
+            ]),
+            InMemoryUserRepository::with(users),
+            InMemoryOrganizationRepository::default(),
+            InMemoryAuditLog::default(),
+        )
+    }
+
+    fn actor() -> AuthenticatedUser {
+        let mut actor: AuthenticatedUser = AuthenticatedUser::new(
+            Uuid::now_v7(),
+            "admin".into(),
+            Organization::DEFAULT_ID,
+            vec![RoleName::administrator()],
+            Vec::new(),
+            None,
+            None,
+            None,
+        );
+        actor.permissions = vec![Permission::RoleManage];
+
+        actor
+    }
+
+    #[actix_web::test]
+    async fn deletes_roles_nobody_holds() {
+        let service: TestService = service(Vec::new());
+
+        assert!(service.execute(&support(), &actor()).await.is_ok());
+        assert!(
+            service
+                .role_repository
+                .find_by_name(&support())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_delete_roles_users_hold() {
+        let user: User = User::new(
+            Uuid::now_v7(),
+            Name::new("Alice".into()).unwrap(),
+            Username::new("alice".into()).unwrap(),
+            PasswordHash::new("plain:secret".into()).unwrap(),
+            Some(support()),
+            None,
+        );
+        let service: TestService = service(vec![user]);
+
+        assert!(matches!(
+            service.execute(&support(), &actor()).await,
+            Err(DeleteRoleError::InUse)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_delete_roles_deleted_users_hold() {
+        let mut user: User = User::new(
+            Uuid::now_v7(),
+            Name::new("Alice".into()).unwrap(),
+            Username::new("alice".into()).unwrap(),
+            PasswordHash::new("plain:secret".into()).unwrap(),
+            Some(support()),
+            None,
+        );
+        user.deleted_at = Some(0);
+        let service: TestService = service(vec![user]);
+
+        assert!(matches!(
+            service.execute(&support(), &actor()).await,
+            Err(DeleteRoleError::InUse)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_delete_roles_memberships_hold() {
+        let service: TestService = service(Vec::new());
+        service
+            .organization_repository
+            .save_membership(Membership::new(
+                Uuid::now_v7(),
+                Uuid::now_v7(),
+                support(),
+                0,
+            ))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            service.execute(&support(), &actor()).await,
+            Err(DeleteRoleError::InUse)
+        ));
+    }
+}
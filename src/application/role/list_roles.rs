@@ -0,0 +1,52 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::{entity::Role, permission::Permission, repository::RoleRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct ListRolesService<L>
+where
+    L: RoleRepository,
+{
+    role_repository: L,
+}
+
+impl<L> ListRolesService<L>
+where
+    L: RoleRepository,
+{
+    pub fn new(role_repository: L) -> Self {
+        Self { role_repository }
+    }
+
+    /// Lists every role with the permissions it grants.
+    pub async fn execute(&self, actor: &AuthenticatedUser) -> Result<Vec<Role>, ListRolesError> {
+        if !actor.can(Permission::RoleAssign) {
+            actor.require(Permission::RoleManage)?;
+        }
+
+        Ok(self.role_repository.find_all().await?)
+    }
+}
+
+pub enum ListRolesError {
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListRolesError {
+    fn from(_: RepositoryError) -> Self {
+        ListRolesError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for ListRolesError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ListRolesError::Forbidden,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_role;
+pub mod delete_role;
+pub mod list_roles;
+pub mod permissions;
+pub mod update_role;
@@ -0,0 +1,123 @@
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser, group::permissions::group_permissions,
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        group::repository::GroupRepository,
+        organization::entity::Organization,
+        role::{
+            entity::{Role, RoleName},
+            permission::Permission,
+            repository::RoleRepository,
+        },
+        user::entity::User,
+    },
+};
+use uuid::Uuid;
+
+/// Everything the roles grant together. Roles that no longer exist grant nothing.
+pub async fn resolve_permissions<R>(
+    role_repository: &R,
+    roles: &[RoleName],
+) -> Result<Vec<Permission>, RepositoryError>
+where
+    R: RoleRepository,
+{
+    let mut permissions: Vec<Permission> = Vec::new();
+
+    for name in roles {
+        if let Some(role) = role_repository.find_by_name(name).await? {
+            permissions.extend(role.grants());
+        }
+    }
+
+    Ok(Permission::ALL
+        .into_iter()
+        .filter(|permission| permissions.contains(permission))
+        .collect())
+}
+
+/// Drops the deployment-wide permissions unless the organization acted in is the default one,
+/// so administrators of an organization cannot change what applies to every organization.
+pub fn within_organization(
+    mut permissions: Vec<Permission>,
+    organization_id: &Uuid,
+) -> Vec<Permission> {
+    if organization_id != &Organization::DEFAULT_ID {
+        permissions.retain(|permission| !permission.is_deployment_wide());
+    }
+
+    permissions
+}
+
+/// What the roles and the groups of the user grant them in the organization.
+pub async fn held_permissions<R, G>(
+    role_repository: &R,
+    group_repository: &G,
+    roles: &[RoleName],
+    organization_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<Vec<Permission>, RepositoryError>
+where
+    R: RoleRepository,
+    G: GroupRepository,
+{
+    let mut permissions: Vec<Permission> = resolve_permissions(role_repository, roles).await?;
+    permissions.extend(group_permissions(group_repository, organization_id, user_id).await?);
+
+    Ok(within_organization(
+        Role::dedup(permissions),
+        organization_id,
+    ))
+}
+
+/// What the role and the groups of the user grant them in the organization, for checking an
+/// actor holds everything a user they manage does.
+pub async fn user_permissions<R, G>(
+    role_repository: &R,
+    group_repository: &G,
+    user: &User,
+    organization_id: &Uuid,
+) -> Result<Vec<Permission>, RepositoryError>
+where
+    R: RoleRepository,
+    G: GroupRepository,
+{
+    held_permissions(
+        role_repository,
+        group_repository,
+        std::slice::from_ref(&user.role),
+        organization_id,
+        &user.id,
+    )
+    .await
+}
+
+/// Whether the actor holds every permission the user holds in the active organization of the
+/// actor, so nobody manages users more powerful than themselves. Actors always may act on
+/// their own account.
+pub async fn holds_permissions_of<R, G>(
+    role_repository: &R,
+    group_repository: &G,
+    actor: &AuthenticatedUser,
+    user: &User,
+) -> Result<bool, RepositoryError>
+where
+    R: RoleRepository,
+    G: GroupRepository,
+{
+    if user.id == actor.id {
+        return Ok(true);
+    }
+
+    let permissions: Vec<Permission> = user_permissions(
+        role_repository,
+        group_repository,
+        user,
+        &actor.organization_id,
+    )
+    .await?;
+
+    Ok(Permission::all_granted(&permissions, &actor.permissions))
+}
@@ -0,0 +1,141 @@
+use super::create_role::permission_list;
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::{
+            entity::{Role, RoleName},
+            error::RoleError,
+            permission::Permission,
+            repository::RoleRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct UpdateRoleService<L, A>
+where
+    L: RoleRepository,
+    A: AuditLog,
+{
+    role_repository: L,
+    audit_log: A,
+}
+
+impl<L, A> UpdateRoleService<L, A>
+where
+    L: RoleRepository,
+    A: AuditLog,
+{
+    pub fn new(role_repository: L, audit_log: A) -> Self {
+        Self {
+            role_repository,
+            audit_log,
+        }
+    }
+
+    /// Changes the description and the permissions of a role. Holders of the role get the new
+    /// permissions on their next request. The administrator role always holds every permission
+    /// and cannot be changed, and nobody adds or removes permissions they do not hold
+    /// themselves.
+    pub async fn execute(
+        &self,
+        name: &RoleName,
+        input: UpdateRoleInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<Role, UpdateRoleError> {
+        actor.require(Permission::RoleManage)?;
+
+        let role: Role = self
+            .role_repository
+            .find_by_name(name)
+            .await?
+            .ok_or(UpdateRoleError::NotFound)?;
+
+        if role.name.is_administrator() {
+            return Err(UpdateRoleError::Immutable);
+        }
+
+        let description: String = match input.description {
+            Some(description) => Role::valid_description(description)?,
+            None => role.description.clone(),
+        };
+        let permissions: Vec<Permission> = match input.permissions {
+            Some(permissions) => Role::dedup(permissions),
+            None => role.permissions.clone(),
+        };
+
+        let changed: Vec<Permission> = Permission::ALL
+            .into_iter()
+            .filter(|permission| {
+                role.permissions.contains(permission) != permissions.contains(permission)
+            })
+            .collect();
+
+        if !Permission::all_granted(&changed, &actor.permissions) {
+            return Err(UpdateRoleError::Forbidden);
+        }
+
+        let updated: Role = self
+            .role_repository
+            .update(name, description, permissions)
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::RoleUpdated)
+                .with_target(role.name.as_str())
+                .with_change(
+                    "description",
+                    Some(&role.description),
+                    Some(&updated.description),
+                )
+                .with_change(
+                    "permissions",
+                    Some(permission_list(&role.permissions)),
+                    Some(permission_list(&updated.permissions)),
+                ),
+        )
+        .await;
+
+        Ok(updated)
+    }
+}
+
+/// Fields left out stay unchanged.
+pub struct UpdateRoleInput {
+    pub description: Option<String>,
+    pub permissions: Option<Vec<Permission>>,
+}
+
+pub enum UpdateRoleError {
+    InvalidRole(RoleError),
+    NotFound,
+    Immutable,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RoleError> for UpdateRoleError {
+    fn from(value: RoleError) -> Self {
+        UpdateRoleError::InvalidRole(value)
+    }
+}
+
+impl From<RepositoryError> for UpdateRoleError {
+    fn from(_: RepositoryError) -> Self {
+        UpdateRoleError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for UpdateRoleError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => UpdateRoleError::Forbidden,
+        }
+    }
+}
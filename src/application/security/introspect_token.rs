@@ -0,0 +1,420 @@
+use crate::{
+    application::{
+        auth::{
+            authenticated_user::{Actor, AuthenticatedUser},
+            scope::Scope,
+        },
+        organization::tenant::activate,
+        role::permissions::held_permissions,
+        security::{
+            error::TokenError,
+            revocation_store::RevocationStore,
+            token::{AccessGrant, RefreshGrant, RefreshToken, Token, TokenKind},
+            token_service::TokenService,
+            verify_access::{VerifyAccessError, VerifyAccessService},
+        },
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        group::{
+            entity::{Group, GroupName},
+            repository::GroupRepository,
+        },
+        organization::repository::OrganizationRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct IntrospectTokenService<T, R, U, L, G, O>
+where
+    T: TokenService,
+    R: RevocationStore,
+    U: UserRepository,
+    L: RoleRepository,
+    G: GroupRepository,
+    O: OrganizationRepository,
+{
+    token_service: T,
+    revocation_store: R,
+    user_repository: U,
+    role_repository: L,
+    group_repository: G,
+    organization_repository: O,
+    verify_access: VerifyAccessService<T, R, L, G>,
+}
+
+impl<T, R, U, L, G, O> IntrospectTokenService<T, R, U, L, G, O>
+where
+    T: TokenService,
+    R: RevocationStore,
+    U: UserRepository,
+    L: RoleRepository,
+    G: GroupRepository,
+    O: OrganizationRepository,
+{
+    pub fn new(
+        token_service: T,
+        revocation_store: R,
+        user_repository: U,
+        role_repository: L,
+        group_repository: G,
+        organization_repository: O,
+        verify_access: VerifyAccessService<T, R, L, G>,
+    ) -> Self {
+        Self {
+            token_service,
+            revocation_store,
+            user_repository,
+            role_repository,
+            group_repository,
+            organization_repository,
+            verify_access,
+        }
+    }
+
+    /// Describes `input.token` following RFC 7662. Tokens that fail verification, were revoked
+    /// or belong to a user that can no longer log in are reported as inactive rather than as
+    /// errors. Active tokens come with the organization they act in, the groups of the user
+    /// there and the permissions their roles and groups grant, for resource servers to
+    /// authorize with. Scopes and permissions follow the same rules as for requests made with
+    /// the token, and group changes show in the next introspection. Impersonation tokens name
+    /// the administrator acting and are inactive once either of them was signed out.
+    pub async fn execute(
+        &self,
+        input: IntrospectTokenInput,
+    ) -> Result<IntrospectTokenOutput, IntrospectTokenError> {
+        let kinds: [TokenKind; 2] = match input.token_type_hint {
+            Some(TokenKind::Refresh) => [TokenKind::Refresh, TokenKind::Access],
+            _ => [TokenKind::Access, TokenKind::Refresh],
+        };
+
+        for kind in kinds {
+            let output: Option<IntrospectTokenOutput> = match kind {
+                TokenKind::Access => self.introspect_access(&input.token).await?,
+                TokenKind::Refresh => self.introspect_refresh(&input.token).await?,
+            };
+
+            if let Some(output) = output {
+                return Ok(output);
+            }
+        }
+
+        Ok(IntrospectTokenOutput::inactive())
+    }
+
+    async fn introspect_access(
+        &self,
+        token: &str,
+    ) -> Result<Option<IntrospectTokenOutput>, IntrospectTokenError> {
+        let grant: AccessGrant = match self.token_service.verify(&Token::new(token)) {
+            Ok(grant) => grant,
+            Err(TokenError::Internal) => return Err(IntrospectTokenError::InfrastructureError),
+            Err(_) => return Ok(None),
+        };
+
+        let expires_at: u64 = grant.expires_at;
+        let user: AuthenticatedUser = match self.verify_access.authorize(grant).await {
+            Ok(user) => user,
+            Err(VerifyAccessError::Revoked) => return Ok(Some(IntrospectTokenOutput::inactive())),
+            Err(VerifyAccessError::InvalidToken) => return Ok(None),
+            Err(VerifyAccessError::InfrastructureError) => {
+                return Err(IntrospectTokenError::InfrastructureError);
+            }
+        };
+
+        let groups: Vec<Group> = self
+            .group_repository
+            .find_by_member(&user.organization_id, &user.id)
+            .await?;
+
+        Ok(Some(IntrospectTokenOutput {
+            active: true,
+            subject: Some(user.id),
+            username: Some(user.username),
+            organization_id: Some(user.organization_id),
+            scopes: user.scopes,
+            groups: groups.into_iter().map(|group| group.name).collect(),
+            permissions: user.permissions,
+            expires_at: Some(expires_at),
+            kind: Some(TokenKind::Access),
+            actor: user.actor,
+        }))
+    }
+
+    async fn introspect_refresh(
+        &self,
+        token: &str,
+    ) -> Result<Option<IntrospectTokenOutput>, IntrospectTokenError> {
+        let grant: RefreshGrant = match self.token_service.verify_refresh(&RefreshToken::new(token))
+        {
+            Ok(grant) => grant,
+            Err(TokenError::Internal) => return Err(IntrospectTokenError::InfrastructureError),
+            Err(_) => return Ok(None),
+        };
+
+        if self.revocation_store.is_revoked(&grant.token_id).await?
+            || self
+                .revocation_store
+                .not_before(&grant.user_id)
+                .await?
+                .is_some_and(|not_before| grant.issued_at < not_before)
+        {
+            return Ok(Some(IntrospectTokenOutput::inactive()));
+        }
+
+        let user: User = match self.user_repository.find_by_id(&grant.user_id).await? {
+            Some(user) if user.is_active() => user,
+            _ => return Ok(Some(IntrospectTokenOutput::inactive())),
+        };
+
+        // The roles the next refresh would get, left out once the user is no longer a member.
+        let mut user: AuthenticatedUser = AuthenticatedUser::from(user);
+
+        if !activate(
+            &self.organization_repository,
+            &mut user,
+            &grant.organization_id,
+        )
+        .await?
+        {
+            return Ok(Some(IntrospectTokenOutput::inactive()));
+        }
+
+        let groups: Vec<Group> = self
+            .group_repository
+            .find_by_member(&grant.organization_id, &user.id)
+            .await?;
+        let permissions: Vec<Permission> = held_permissions(
+            &self.role_repository,
+            &self.group_repository,
+            &user.roles,
+            &grant.organization_id,
+            &user.id,
+        )
+        .await?;
+        user.scopes = grant.scopes;
+        user.grant_permissions(permissions);
+
+        Ok(Some(IntrospectTokenOutput {
+            active: true,
+            subject: Some(user.id),
+            username: Some(user.username),
+            organization_id: Some(grant.organization_id),
+            scopes: user.scopes,
+            groups: groups.into_iter().map(|group| group.name).collect(),
+            permissions: user.permissions,
+            expires_at: Some(grant.expires_at),
+            kind: Some(TokenKind::Refresh),
+            actor: None,
+        }))
+    }
+}
+
+pub struct IntrospectTokenInput {
+    pub token: String,
+    pub token_type_hint: Option<TokenKind>,
+}
+
+pub struct IntrospectTokenOutput {
+    pub active: bool,
+    pub subject: Option<Uuid>,
+    pub username: Option<String>,
+    pub organization_id: Option<Uuid>,
+    pub scopes: Vec<Scope>,
+    pub groups: Vec<GroupName>,
+    pub permissions: Vec<Permission>,
+    pub expires_at: Option<u64>,
+    pub kind: Option<TokenKind>,
+    /// The administrator impersonating the user, for impersonation tokens.
+    pub actor: Option<Actor>,
+}
+
+impl IntrospectTokenOutput {
+    pub fn inactive() -> Self {
+        Self {
+            active: false,
+            subject: None,
+            username: None,
+            organization_id: None,
+            scopes: Vec::new(),
+            groups: Vec::new(),
+            permissions: Vec::new(),
+            expires_at: None,
+            kind: None,
+            actor: None,
+        }
+    }
+}
+
+pub enum IntrospectTokenError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for IntrospectTokenError {
+    fn from(_: RepositoryError) -> Self {
+        IntrospectTokenError::InfrastructureError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::{
+            persistence::memory::{
+                InMemoryGroupRepository, InMemoryOrganizationRepository, InMemoryRevocationStore,
+                InMemoryRoleRepository, InMemoryUserRepository,
+            },
+            token::{id_token_key::IdTokenKey, jwt::JwtService},
+        },
+        application::security::token::IssuedToken,
+        domain::{
+            organization::entity::Organization,
+            role::entity::{Role, RoleName},
+        },
+    };
+
+    type TestService = IntrospectTokenService<
+        JwtService,
+        InMemoryRevocationStore,
+        InMemoryUserRepository,
+        InMemoryRoleRepository,
+        InMemoryGroupRepository,
+        InMemoryOrganizationRepository,
+    >;
+
+    fn support() -> RoleName {
+        RoleName::new("support".into()).unwrap()
+    }
+
+    fn service(
+        token_service: &JwtService,
+        revocation_store: &InMemoryRevocationStore,
+    ) -> TestService {
+        let roles: InMemoryRoleRepository = InMemoryRoleRepository::with(vec![
+            Role::new(support(), "Reads users".into(), vec![Permission::UserRead]).unwrap(),
+        ]);
+        let groups: InMemoryGroupRepository = InMemoryGroupRepository::default();
+
+        IntrospectTokenService::new(
+            token_service.clone(),
+            revocation_store.clone(),
+            InMemoryUserRepository::default(),
+            roles.clone(),
+            groups.clone(),
+            InMemoryOrganizationRepository::default(),
+            VerifyAccessService::new(
+                token_service.clone(),
+                revocation_store.clone(),
+                roles,
+                groups,
+            ),
+        )
+    }
+
+    fn token_service() -> JwtService {
+        JwtService::new(
+            "secret".into(),
+            60,
+            3600,
+            "http://localhost".into(),
+            IdTokenKey::generate().unwrap(),
+        )
+    }
+
+    fn user(scopes: Vec<Scope>, actor: Option<Actor>) -> AuthenticatedUser {
+        AuthenticatedUser::new(
+            Uuid::now_v7(),
+            "alice".into(),
+            Organization::DEFAULT_ID,
+            vec![support()],
+            scopes,
+            None,
+            None,
+            actor,
+        )
+    }
+
+    async fn introspect(
+        token_service: &JwtService,
+        revocation_store: &InMemoryRevocationStore,
+        user: &AuthenticatedUser,
+    ) -> IntrospectTokenOutput {
+        let issued: IssuedToken = token_service.issue(user).unwrap();
+
+        service(token_service, revocation_store)
+            .execute(IntrospectTokenInput {
+                token: issued.token.as_str().into(),
+                token_type_hint: None,
+            })
+            .await
+            .ok()
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn reports_permissions_of_tokens_with_the_admin_scope() {
+        let output: IntrospectTokenOutput = introspect(
+            &token_service(),
+            &InMemoryRevocationStore::default(),
+            &user(vec![Scope::Admin, Scope::Profile], None),
+        )
+        .await;
+
+        assert!(output.active);
+        assert_eq!(output.permissions, vec![Permission::UserRead]);
+    }
+
+    #[actix_web::test]
+    async fn reports_no_permissions_of_tokens_without_the_admin_scope() {
+        let output: IntrospectTokenOutput = introspect(
+            &token_service(),
+            &InMemoryRevocationStore::default(),
+            &user(vec![Scope::Profile], None),
+        )
+        .await;
+
+        assert!(output.active);
+        assert_eq!(output.scopes, vec![Scope::Profile]);
+        assert!(output.permissions.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn names_the_administrator_impersonating_the_user() {
+        let actor: Actor = Actor {
+            id: Uuid::now_v7(),
+            username: "admin".into(),
+        };
+        let output: IntrospectTokenOutput = introspect(
+            &token_service(),
+            &InMemoryRevocationStore::default(),
+            &user(vec![Scope::Profile], Some(actor.clone())),
+        )
+        .await;
+
+        assert!(output.active);
+        assert_eq!(output.actor.map(|actor| actor.id), Some(actor.id));
+    }
+
+    #[actix_web::test]
+    async fn deactivates_impersonation_tokens_once_the_administrator_is_signed_out() {
+        let actor: Actor = Actor {
+            id: Uuid::now_v7(),
+            username: "admin".into(),
+        };
+        let revocation_store: InMemoryRevocationStore = InMemoryRevocationStore::default();
+        revocation_store.revoke_all(&actor.id, u64::MAX).await.ok();
+
+        let output: IntrospectTokenOutput = introspect(
+            &token_service(),
+            &revocation_store,
+            &user(vec![Scope::Profile], Some(actor)),
+        )
+        .await;
+
+        assert!(!output.active);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::errors::repository::RepositoryError;
+
+/// Recent failed logins for a username or a client address.
+pub struct LoginAttempts {
+    pub failures: u32,
+    pub last_failure_at: u64,
+}
+
+/// Keeps count of failed logins, so guessing passwords can be slowed down.
+#[async_trait::async_trait]
+pub trait LoginAttemptStore {
+    async fn find(&self, key: &str) -> Result<Option<LoginAttempts>, RepositoryError>;
+    /// Counts a failure at `now`. The count starts over when the previous failure is older
+    /// than `window` seconds.
+    async fn record_failure(
+        &self,
+        key: &str,
+        now: u64,
+        window: u64,
+    ) -> Result<LoginAttempts, RepositoryError>;
+    async fn clear(&self, key: &str) -> Result<(), RepositoryError>;
+}
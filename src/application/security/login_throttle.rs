@@ -0,0 +1,301 @@
+use super::login_attempt_store::{LoginAttemptStore, LoginAttempts};
+use crate::domain::errors::repository::RepositoryError;
+use sha2::{Digest, Sha256};
+use std::{
+    net::IpAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+/// What failed logins, and other requests worth slowing down, are counted against.
+pub enum ThrottleKey {
+    /// A username, within the organization it is unique in.
+    Username(Uuid, String),
+    /// An email address, unique across organizations.
+    Email(String),
+    /// The user a second factor was attempted for.
+    User(Uuid),
+    /// The MFA token a second factor was attempted with.
+    MfaToken(String),
+    Ip(IpAddr),
+    /// A username password resets were requested for, within its organization. Every request
+    /// counts, whether it sends a token or not.
+    PasswordReset(Uuid, String),
+    /// A client address password resets were requested from.
+    PasswordResetIp(IpAddr),
+    /// An invite a registration was attempted with.
+    Invite(Uuid),
+}
+
+impl ThrottleKey {
+    fn as_key(&self) -> String {
+        match self {
+            ThrottleKey::Username(organization_id, username) => {
+                format!("username:{}:{}", organization_id, username)
+            }
+            ThrottleKey::Email(email) => format!("email:{}", email),
+            ThrottleKey::User(user_id) => format!("user:{}", user_id),
+            ThrottleKey::MfaToken(mfa_token) => {
+                format!("mfa:{}", hex::encode(Sha256::digest(mfa_token.as_bytes())))
+            }
+            ThrottleKey::Ip(ip) => format!("ip:{}", ip),
+            ThrottleKey::PasswordReset(organization_id, username) => {
+                format!("reset:{}:{}", organization_id, username)
+            }
+            ThrottleKey::PasswordResetIp(ip) => format!("reset-ip:{}", ip),
+            ThrottleKey::Invite(invite_id) => format!("invite:{}", invite_id),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ThrottlePolicy {
+    /// Failures after which a username, a user or an MFA token is locked out.
+    pub max_failures: u32,
+    /// Failures after which a client address is locked out. Addresses can be shared, so they
+    /// get no progressive delay and a higher threshold.
+    pub ip_max_failures: u32,
+    /// Seconds to wait after the first failure for a username or a user, doubled with each
+    /// failure.
+    pub base_delay: u64,
+    /// Seconds a lockout lasts; failures older than this are forgotten.
+    pub lockout: u64,
+}
+
+/// Slows down password guessing: each failure for a username delays the next attempt
+/// progressively, until the username is locked out. Second factors are slowed down the same
+/// way per user, and an MFA token is locked out once it has failed too often. Client
+/// addresses are locked out after more failures, whatever usernames they try. Password reset
+/// requests are limited the same way, apart from logins. Invite codes are guessed like
+/// passwords, per invite and per client address.
+#[derive(Clone)]
+pub struct LoginThrottle<S>
+where
+    S: LoginAttemptStore,
+{
+    store: S,
+    policy: ThrottlePolicy,
+}
+
+impl<S> LoginThrottle<S>
+where
+    S: LoginAttemptStore,
+{
+    pub fn new(store: S, policy: ThrottlePolicy) -> Self {
+        Self { store, policy }
+    }
+
+    /// Fails with the number of seconds to wait when any of `keys` may not attempt a login yet.
+    pub async fn check(&self, keys: &[ThrottleKey]) -> Result<(), ThrottleError> {
+        let now: u64 = now();
+        let mut retry_after: u64 = 0;
+
+        for key in keys {
+            if let Some(attempts) = self.store.find(&key.as_key()).await? {
+                retry_after = retry_after.max(self.wait(key, &attempts, now));
+            }
+        }
+
+        if retry_after > 0 {
+            return Err(ThrottleError::Locked { retry_after });
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_failure(&self, keys: &[ThrottleKey]) -> Result<(), ThrottleError> {
+        let now: u64 = now();
+
+        for key in keys {
+            self.store
+                .record_failure(&key.as_key(), now, self.policy.lockout)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Forgets the failures of the usernames, users and invites. Failures of client addresses are
+    /// kept, or logging into one's own account would reset the count while guessing others.
+    pub async fn record_success(&self, keys: &[ThrottleKey]) -> Result<(), ThrottleError> {
+        for key in keys {
+            if let ThrottleKey::Username(..)
+            | ThrottleKey::Email(_)
+            | ThrottleKey::User(_)
+            | ThrottleKey::Invite(_) = key
+            {
+                self.store.clear(&key.as_key()).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn wait(&self, key: &ThrottleKey, attempts: &LoginAttempts, now: u64) -> u64 {
+        if attempts.failures == 0
+            || attempts.last_failure_at.saturating_add(self.policy.lockout) <= now
+        {
+            return 0;
+        }
+
+        let delay: u64 = match key {
+            ThrottleKey::Username(..)
+            | ThrottleKey::Email(_)
+            | ThrottleKey::User(_)
+            | ThrottleKey::MfaToken(_)
+            | ThrottleKey::PasswordReset(..)
+            | ThrottleKey::Invite(_)
+                if attempts.failures >= self.policy.max_failures =>
+            {
+                self.policy.lockout
+            }
+            ThrottleKey::MfaToken(_) => 0,
+            ThrottleKey::Username(..)
+            | ThrottleKey::Email(_)
+            | ThrottleKey::User(_)
+            | ThrottleKey::PasswordReset(..)
+            | ThrottleKey::Invite(_) => self
+                .policy
+                .base_delay
+                .checked_shl(attempts.failures - 1)
+                .unwrap_or(u64::MAX)
+                .min(self.policy.lockout),
+            ThrottleKey::Ip(_) | ThrottleKey::PasswordResetIp(_)
+                if attempts.failures >= self.policy.ip_max_failures =>
+            {
+                self.policy.lockout
+            }
+            ThrottleKey::Ip(_) | ThrottleKey::PasswordResetIp(_) => 0,
+        };
+
+        attempts
+            .last_failure_at
+            .saturating_add(delay)
+            .saturating_sub(now)
+    }
+}
+
+#[derive(Debug)]
+pub enum ThrottleError {
+    Locked { retry_after: u64 },
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ThrottleError {
+    fn from(_: RepositoryError) -> Self {
+        ThrottleError::InfrastructureError
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::persistence::memory::InMemoryLoginAttemptStore;
+
+    fn throttle() -> LoginThrottle<InMemoryLoginAttemptStore> {
+        LoginThrottle::new(
+            InMemoryLoginAttemptStore::default(),
+            ThrottlePolicy {
+                max_failures: 3,
+                ip_max_failures: 10,
+                base_delay: 1,
+                lockout: 900,
+            },
+        )
+    }
+
+    #[actix_web::test]
+    async fn locks_an_mfa_token_after_too_many_failures() {
+        let throttle = throttle();
+        let keys: [ThrottleKey; 1] = [ThrottleKey::MfaToken("mfa-token".to_owned())];
+
+        for _ in 0..2 {
+            throttle.record_failure(&keys).await.unwrap();
+            throttle.check(&keys).await.unwrap();
+        }
+
+        throttle.record_failure(&keys).await.unwrap();
+
+        assert!(matches!(
+            throttle.check(&keys).await,
+            Err(ThrottleError::Locked { retry_after }) if retry_after > 0
+        ));
+        assert!(
+            throttle
+                .check(&[ThrottleKey::MfaToken("other-token".to_owned())])
+                .await
+                .is_ok()
+        );
+    }
+
+    #[actix_web::test]
+    async fn delays_second_factors_per_user() {
+        let throttle = throttle();
+        let user_id: Uuid = Uuid::now_v7();
+
+        throttle
+            .record_failure(&[
+                ThrottleKey::User(user_id),
+                ThrottleKey::MfaToken("first-token".to_owned()),
+            ])
+            .await
+            .unwrap();
+
+        let fresh_token: [ThrottleKey; 2] = [
+            ThrottleKey::User(user_id),
+            ThrottleKey::MfaToken("second-token".to_owned()),
+        ];
+
+        assert!(throttle.check(&fresh_token).await.is_err());
+
+        throttle.record_success(&fresh_token).await.unwrap();
+
+        assert!(throttle.check(&fresh_token).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn counts_password_resets_apart_from_logins() {
+        let throttle = throttle();
+        let organization_id: Uuid = Uuid::now_v7();
+        let reset: ThrottleKey = ThrottleKey::PasswordReset(organization_id, "alice".to_owned());
+
+        throttle
+            .record_failure(std::slice::from_ref(&reset))
+            .await
+            .unwrap();
+
+        assert!(throttle.check(&[reset]).await.is_err());
+        assert!(
+            throttle
+                .check(&[ThrottleKey::Username(organization_id, "alice".to_owned())])
+                .await
+                .is_ok()
+        );
+    }
+
+    #[actix_web::test]
+    async fn counts_usernames_per_organization() {
+        let throttle = throttle();
+        let alice: ThrottleKey = ThrottleKey::Username(Uuid::now_v7(), "alice".to_owned());
+
+        throttle
+            .record_failure(std::slice::from_ref(&alice))
+            .await
+            .unwrap();
+
+        assert!(throttle.check(&[alice]).await.is_err());
+        assert!(
+            throttle
+                .check(&[ThrottleKey::Username(Uuid::now_v7(), "alice".to_owned())])
+                .await
+                .is_ok()
+        );
+    }
+}
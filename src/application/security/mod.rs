@@ -1,3 +1,9 @@
 pub mod error;
+pub mod introspect_token;
+pub mod login_attempt_store;
+pub mod login_throttle;
+pub mod revocation_store;
+pub mod revoke_token;
 pub mod token;
 pub mod token_service;
+pub mod verify_access;
@@ -0,0 +1,14 @@
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+/// Keeps track of tokens revoked before their natural expiry.
+#[async_trait::async_trait]
+pub trait RevocationStore {
+    async fn revoke(&self, token_id: &Uuid, expires_at: u64) -> Result<(), RepositoryError>;
+    async fn is_revoked(&self, token_id: &Uuid) -> Result<bool, RepositoryError>;
+    /// Revokes every token of the user issued before `not_before`, in milliseconds since the
+    /// epoch. Tokens issued later in the same second stay valid.
+    async fn revoke_all(&self, user_id: &Uuid, not_before: u64) -> Result<(), RepositoryError>;
+    /// When tokens of the user issued earlier are revoked, in milliseconds, if ever.
+    async fn not_before(&self, user_id: &Uuid) -> Result<Option<u64>, RepositoryError>;
+}
@@ -0,0 +1,120 @@
+use crate::{
+    application::security::{
+        error::TokenError,
+        revocation_store::RevocationStore,
+        token::{RefreshToken, Token, TokenKind},
+        token_service::TokenService,
+    },
+    domain::{
+        client::entity::Client, errors::repository::RepositoryError,
+        session::repository::SessionRepository,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct RevokeTokenService<T, R, S>
+where
+    T: TokenService,
+    R: RevocationStore,
+    S: SessionRepository,
+{
+    token_service: T,
+    revocation_store: R,
+    session_repository: S,
+}
+
+impl<T, R, S> RevokeTokenService<T, R, S>
+where
+    T: TokenService,
+    R: RevocationStore,
+    S: SessionRepository,
+{
+    pub fn new(token_service: T, revocation_store: R, session_repository: S) -> Self {
+        Self {
+            token_service,
+            revocation_store,
+            session_repository,
+        }
+    }
+
+    /// Revokes `input.token` following RFC 7009. Invalid or already expired tokens are ignored,
+    /// since there is nothing left to revoke, and so are tokens issued to another client than
+    /// the calling `client`. Revoking a refresh token also ends its session.
+    pub async fn execute(
+        &self,
+        input: RevokeTokenInput,
+        client: &Client,
+    ) -> Result<(), RevokeTokenError> {
+        let kinds: [TokenKind; 2] = match input.token_type_hint {
+            Some(TokenKind::Refresh) => [TokenKind::Refresh, TokenKind::Access],
+            _ => [TokenKind::Access, TokenKind::Refresh],
+        };
+
+        for kind in kinds {
+            let verified: Result<Revocable, TokenError> = match kind {
+                TokenKind::Access => self
+                    .token_service
+                    .verify(&Token::new(input.token.as_str()))
+                    .map(|grant| Revocable {
+                        token_id: grant.token_id,
+                        expires_at: grant.expires_at,
+                        session_id: None,
+                        client_id: grant.user.client_id,
+                    }),
+                TokenKind::Refresh => self
+                    .token_service
+                    .verify_refresh(&RefreshToken::new(input.token.as_str()))
+                    .map(|grant| Revocable {
+                        token_id: grant.token_id,
+                        expires_at: grant.expires_at,
+                        session_id: grant.session_id,
+                        client_id: grant.client_id,
+                    }),
+            };
+
+            match verified {
+                Ok(token) if token.client_id != Some(client.id) => return Ok(()),
+                Ok(token) => {
+                    self.revocation_store
+                        .revoke(&token.token_id, token.expires_at)
+                        .await?;
+
+                    if let Some(session_id) = token.session_id {
+                        self.session_repository.delete(&session_id).await?;
+                    }
+
+                    return Ok(());
+                }
+                Err(TokenError::Internal) => return Err(RevokeTokenError::InfrastructureError),
+                Err(_) => continue,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What revoking a verified token of either kind takes.
+struct Revocable {
+    token_id: Uuid,
+    expires_at: u64,
+    session_id: Option<Uuid>,
+    /// The client the token was issued to.
+    client_id: Option<Uuid>,
+}
+
+pub struct RevokeTokenInput {
+    pub token: String,
+    pub token_type_hint: Option<TokenKind>,
+}
+
+pub enum RevokeTokenError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RevokeTokenError {
+    fn from(_: RepositoryError) -> Self {
+        RevokeTokenError::InfrastructureError
+    }
+}
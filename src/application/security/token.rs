@@ -1,3 +1,6 @@
+use crate::application::auth::{authenticated_user::AuthenticatedUser, scope::Scope};
+use uuid::Uuid;
+
 pub struct Token(String);
 
 impl Token {
@@ -10,6 +13,7 @@ impl Token {
     }
 }
 
+#[derive(Clone)]
 pub struct RefreshToken(String);
 
 impl RefreshToken {
@@ -26,14 +30,105 @@ pub struct IssuedToken {
     pub token: Token,
     pub expires_in: u64,
     pub refresh_token: Option<RefreshToken>,
+    pub refresh_expires_in: u64,
+    pub id_token: Option<Token>,
+    pub scopes: Vec<Scope>,
 }
 
 impl IssuedToken {
-    pub fn new(token: Token, expires_in: u64, refresh_token: Option<RefreshToken>) -> Self {
+    pub fn new(
+        token: Token,
+        expires_in: u64,
+        refresh_token: Option<RefreshToken>,
+        refresh_expires_in: u64,
+        id_token: Option<Token>,
+        scopes: Vec<Scope>,
+    ) -> Self {
         Self {
             token,
             expires_in,
             refresh_token,
+            refresh_expires_in,
+            id_token,
+            scopes,
         }
     }
 }
+
+/// Short-lived token proving the first authentication factor was accepted. It can only be
+/// exchanged, together with a second factor, for real tokens.
+#[derive(Debug, Clone)]
+pub struct MfaToken(String);
+
+impl MfaToken {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+pub struct AccessGrant {
+    pub token_id: Uuid,
+    pub user: AuthenticatedUser,
+    /// Milliseconds since the epoch, compared with the revocation cutoff of the user.
+    pub issued_at: u64,
+    pub expires_at: u64,
+}
+
+impl AccessGrant {
+    pub fn new(token_id: Uuid, user: AuthenticatedUser, issued_at: u64, expires_at: u64) -> Self {
+        Self {
+            token_id,
+            user,
+            issued_at,
+            expires_at,
+        }
+    }
+}
+
+pub struct RefreshGrant {
+    pub token_id: Uuid,
+    pub user_id: Uuid,
+    /// The organization the user acted in.
+    pub organization_id: Uuid,
+    pub scopes: Vec<Scope>,
+    pub client_id: Option<Uuid>,
+    pub session_id: Option<Uuid>,
+    /// Milliseconds since the epoch, compared with the revocation cutoff of the user.
+    pub issued_at: u64,
+    pub expires_at: u64,
+}
+
+impl RefreshGrant {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        token_id: Uuid,
+        user_id: Uuid,
+        organization_id: Uuid,
+        scopes: Vec<Scope>,
+        client_id: Option<Uuid>,
+        session_id: Option<Uuid>,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> Self {
+        Self {
+            token_id,
+            user_id,
+            organization_id,
+            scopes,
+            client_id,
+            session_id,
+            issued_at,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Access,
+    Refresh,
+}
@@ -1,13 +1,18 @@
+use super::{
+    error::TokenError,
+    token::{MfaToken, Token},
+};
 use uuid::Uuid;
 
-use super::{error::TokenError, token::Token};
 use crate::application::{
     auth::authenticated_user::AuthenticatedUser,
-    security::token::{IssuedToken, RefreshToken},
+    security::token::{AccessGrant, IssuedToken, RefreshGrant, RefreshToken},
 };
 
 pub trait TokenService {
     fn issue(&self, user: &AuthenticatedUser) -> Result<IssuedToken, TokenError>;
-    fn verify(&self, token: &Token) -> Result<AuthenticatedUser, TokenError>;
-    fn verify_refresh(&self, refresh_token: &RefreshToken) -> Result<Uuid, TokenError>;
+    fn verify(&self, token: &Token) -> Result<AccessGrant, TokenError>;
+    fn verify_refresh(&self, refresh_token: &RefreshToken) -> Result<RefreshGrant, TokenError>;
+    fn issue_mfa(&self, user_id: &Uuid) -> Result<MfaToken, TokenError>;
+    fn verify_mfa(&self, mfa_token: &MfaToken) -> Result<Uuid, TokenError>;
 }
@@ -0,0 +1,119 @@
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser,
+        role::permissions::held_permissions,
+        security::{
+            error::TokenError,
+            revocation_store::RevocationStore,
+            token::{AccessGrant, Token},
+            token_service::TokenService,
+        },
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+    },
+};
+
+#[derive(Clone)]
+pub struct VerifyAccessService<T, R, L, G>
+where
+    T: TokenService,
+    R: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    token_service: T,
+    revocation_store: R,
+    role_repository: L,
+    group_repository: G,
+}
+
+impl<T, R, L, G> VerifyAccessService<T, R, L, G>
+where
+    T: TokenService,
+    R: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+{
+    pub fn new(
+        token_service: T,
+        revocation_store: R,
+        role_repository: L,
+        group_repository: G,
+    ) -> Self {
+        Self {
+            token_service,
+            revocation_store,
+            role_repository,
+            group_repository,
+        }
+    }
+
+    /// Checks an access token presented with a request. Revoked tokens are rejected, and so are
+    /// tokens issued before the user's cutoff, which moves whenever their status, role or
+    /// password changes, even though they have not expired yet. Impersonation tokens also
+    /// follow the cutoff of the impersonating administrator.
+    ///
+    /// The permissions of the user are looked up from the roles named in the token and from
+    /// the groups they are in within the organization of the token, so changes to either
+    /// apply without signing in again. They only apply to tokens with the admin scope.
+    pub async fn execute(&self, token: &Token) -> Result<AuthenticatedUser, VerifyAccessError> {
+        let grant: AccessGrant = self.token_service.verify(token).map_err(|err| match err {
+            TokenError::Internal => VerifyAccessError::InfrastructureError,
+            _ => VerifyAccessError::InvalidToken,
+        })?;
+
+        self.authorize(grant).await
+    }
+
+    /// Checks an access token that was already verified, as `execute` does.
+    pub async fn authorize(
+        &self,
+        grant: AccessGrant,
+    ) -> Result<AuthenticatedUser, VerifyAccessError> {
+        if self.revocation_store.is_revoked(&grant.token_id).await? {
+            return Err(VerifyAccessError::Revoked);
+        }
+
+        let subjects =
+            std::iter::once(&grant.user.id).chain(grant.user.actor.as_ref().map(|actor| &actor.id));
+
+        for user_id in subjects {
+            if self
+                .revocation_store
+                .not_before(user_id)
+                .await?
+                .is_some_and(|not_before| grant.issued_at < not_before)
+            {
+                return Err(VerifyAccessError::Revoked);
+            }
+        }
+
+        let mut user: AuthenticatedUser = grant.user;
+        let permissions: Vec<Permission> = held_permissions(
+            &self.role_repository,
+            &self.group_repository,
+            &user.roles,
+            &user.organization_id,
+            &user.id,
+        )
+        .await?;
+        user.grant_permissions(permissions);
+
+        Ok(user)
+    }
+}
+
+pub enum VerifyAccessError {
+    InvalidToken,
+    Revoked,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for VerifyAccessError {
+    fn from(_: RepositoryError) -> Self {
+        VerifyAccessError::InfrastructureError
+    }
+}
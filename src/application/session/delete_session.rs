@@ -0,0 +1,55 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        session::{entity::Session, repository::SessionRepository},
+    },
+};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct DeleteSessionService<R>
+where
+    R: SessionRepository,
+{
+    session_repository: R,
+}
+
+impl<R> DeleteSessionService<R>
+where
+    R: SessionRepository,
+{
+    pub fn new(session_repository: R) -> Self {
+        Self { session_repository }
+    }
+
+    /// Signs one of the actor's devices out: its refresh tokens stop working. Sessions of
+    /// other users are reported as missing.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), DeleteSessionError> {
+        let session: Session = self
+            .session_repository
+            .find_by_id(id)
+            .await?
+            .filter(|session| session.user_id == actor.id)
+            .ok_or(DeleteSessionError::NotFound)?;
+
+        self.session_repository.delete(&session.id).await?;
+
+        Ok(())
+    }
+}
+
+pub enum DeleteSessionError {
+    NotFound,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for DeleteSessionError {
+    fn from(_: RepositoryError) -> Self {
+        DeleteSessionError::InfrastructureError
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+        security::revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        session::repository::SessionRepository,
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct EndSessionsService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    user_repository: U,
+    session_repository: R,
+    revocation_store: S,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<U, R, S, L, G, A> EndSessionsService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(
+        user_repository: U,
+        session_repository: R,
+        revocation_store: S,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            revocation_store,
+            role_repository,
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Signs a user out everywhere: all of their sessions end and every refresh token issued
+    /// to them so far is revoked. Users holding permissions the actor lacks stay signed in.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), EndSessionsError> {
+        actor.require(Permission::SessionRevoke)?;
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(EndSessionsError::NotFound)?;
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(EndSessionsError::Forbidden);
+        }
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| EndSessionsError::InfrastructureError)?
+            .as_millis() as u64;
+
+        self.session_repository
+            .delete_by_user_id(&user.id, None)
+            .await?;
+        self.revocation_store.revoke_all(&user.id, now).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::SessionsEnded).with_target(user.id),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum EndSessionsError {
+    NotFound,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for EndSessionsError {
+    fn from(_: RepositoryError) -> Self {
+        EndSessionsError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for EndSessionsError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => EndSessionsError::Forbidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::persistence::memory::{
+            InMemoryAuditLog, InMemoryGroupRepository, InMemoryRevocationStore,
+            InMemoryRoleRepository, InMemorySessionRepository, InMemoryUserRepository,
+        },
+        domain::{
+            audit::{entry::AuditEntry, query::AuditQuery},
+            role::entity::{Role, RoleName},
+            user::value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        },
+    };
+
+    type TestService = EndSessionsService<
+        InMemoryUserRepository,
+        InMemorySessionRepository,
+        InMemoryRevocationStore,
+        InMemoryRoleRepository,
+        InMemoryGroupRepository,
+        InMemoryAuditLog,
+    >;
+
+    fn user(username: &str, role: RoleName) -> User {
+        User::new(
+            Uuid::now_v7(),
+            Name::new(username.into()).unwrap(),
+            Username::new(username.into()).unwrap(),
+            PasswordHash::new("plain:secret".into()).unwrap(),
+            Some(role),
+            None,
+        )
+    }
+
+    fn service(users: Vec<User>) -> TestService {
+        let support: Role = Role::new(
+            RoleName::new("support".into()).unwrap(),
+            "Signs users out".into(),
+            vec![Permission::SessionRevoke],
+        )
+        .unwrap();
+        let administrator: Role = Role::new(
+            RoleName::administrator(),
+            "Administrators".into(),
+            Vec::new(),
+        )
+        .unwrap();
+
+        EndSessionsService::new(
+            InMemoryUserRepository::with(users),
+            InMemorySessionRepository::default(),
+            InMemoryRevocationStore::default(),
+            InMemoryRoleRepository::with(vec![support, administrator]),
+            InMemoryGroupRepository::default(),
+            InMemoryAuditLog::default(),
+        )
+    }
+
+    fn actor(user: &User) -> AuthenticatedUser {
+        let mut actor: AuthenticatedUser = AuthenticatedUser::from(user.clone());
+        actor.permissions = vec![Permission::SessionRevoke];
+
+        actor
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_sign_out_users_holding_more_permissions() {
+        let support: User = user("support", RoleName::new("support".into()).unwrap());
+        let admin: User = user("admin", RoleName::administrator());
+        let service: TestService = service(vec![support.clone(), admin.clone()]);
+
+        assert!(matches!(
+            service.execute(&admin.id, &actor(&support)).await,
+            Err(EndSessionsError::Forbidden)
+        ));
+        assert!(
+            service
+                .revocation_store
+                .not_before(&admin.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[actix_web::test]
+    async fn signs_out_users_holding_no_more_permissions() {
+        let support: User = user("support", RoleName::new("support".into()).unwrap());
+        let member: User = user("member", RoleName::user());
+        let service: TestService = service(vec![support.clone(), member.clone()]);
+
+        assert!(service.execute(&member.id, &actor(&support)).await.is_ok());
+        assert!(
+            service
+                .revocation_store
+                .not_before(&member.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        let entries: Vec<AuditEntry> = service
+            .audit_log
+            .list(&AuditQuery {
+                organization_id: support.organization_id,
+                actor_id: Some(support.id),
+                action: Some(AuditAction::SessionsEnded),
+                target: Some(member.id.to_string()),
+                since: None,
+                until: None,
+                before: None,
+                limit: 10,
+            })
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+    }
+}
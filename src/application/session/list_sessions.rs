@@ -0,0 +1,53 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        session::{entity::Session, repository::SessionRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone)]
+pub struct ListSessionsService<R>
+where
+    R: SessionRepository,
+{
+    session_repository: R,
+}
+
+impl<R> ListSessionsService<R>
+where
+    R: SessionRepository,
+{
+    pub fn new(session_repository: R) -> Self {
+        Self { session_repository }
+    }
+
+    /// Lists the actor's active sessions, most recently used first. Expired sessions are
+    /// cleaned up on the way.
+    pub async fn execute(
+        &self,
+        actor: &AuthenticatedUser,
+    ) -> Result<Vec<Session>, ListSessionsError> {
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ListSessionsError::InfrastructureError)?
+            .as_secs();
+
+        self.session_repository
+            .delete_expired(&actor.id, now)
+            .await?;
+
+        Ok(self.session_repository.find_by_user_id(&actor.id).await?)
+    }
+}
+
+pub enum ListSessionsError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListSessionsError {
+    fn from(_: RepositoryError) -> Self {
+        ListSessionsError::InfrastructureError
+    }
+}
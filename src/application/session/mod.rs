@@ -0,0 +1,3 @@
+pub mod delete_session;
+pub mod end_sessions;
+pub mod list_sessions;
@@ -0,0 +1,360 @@
+use crate::{
+    application::{
+        audit::record::{anonymous, entry, record},
+        auth::{authenticated_user::AuthenticatedUser, login::LoginContext},
+        security::{
+            error::TokenError,
+            login_attempt_store::LoginAttemptStore,
+            login_throttle::{LoginThrottle, ThrottleError, ThrottleKey},
+            revocation_store::RevocationStore,
+            token::IssuedToken,
+            token_service::TokenService,
+        },
+        user::password::{check_new_password, end_sessions, set_password},
+    },
+    domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
+        errors::repository::RepositoryError,
+        session::{entity::Session, repository::SessionRepository},
+        user::{
+            entity::User,
+            error::UserError,
+            password_hasher::{HashError, PasswordHasher},
+            password_history::PasswordHistoryRepository,
+            password_policy::{BreachedPasswords, PasswordPolicy},
+            repository::UserRepository,
+            value_objects::{email::Email, password_plain::PasswordPlain, username::Username},
+        },
+    },
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ChangePasswordService<U, P, H, S, T, A, B, R, L>
+where
+    U: UserRepository,
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+    T: TokenService,
+    A: LoginAttemptStore,
+    B: BreachedPasswords,
+    R: SessionRepository,
+    L: AuditLog,
+{
+    user_repository: U,
+    history_repository: P,
+    hasher: H,
+    revocation_store: S,
+    token_service: T,
+    throttle: LoginThrottle<A>,
+    policy: PasswordPolicy<B>,
+    session_repository: R,
+    audit_log: L,
+}
+
+impl<U, P, H, S, T, A, B, R, L> ChangePasswordService<U, P, H, S, T, A, B, R, L>
+where
+    U: UserRepository,
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    S: RevocationStore,
+    T: TokenService,
+    A: LoginAttemptStore,
+    B: BreachedPasswords,
+    R: SessionRepository,
+    L: AuditLog,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: U,
+        history_repository: P,
+        hasher: H,
+        revocation_store: S,
+        token_service: T,
+        throttle: LoginThrottle<A>,
+        policy: PasswordPolicy<B>,
+        session_repository: R,
+        audit_log: L,
+    ) -> Self {
+        Self {
+            user_repository,
+            history_repository,
+            hasher,
+            revocation_store,
+            token_service,
+            throttle,
+            policy,
+            session_repository,
+            audit_log,
+        }
+    }
+
+    /// Changes the password of `actor` after checking their current one. Every other session
+    /// ends: refresh tokens issued so far are revoked, and the caller gets fresh tokens instead.
+    ///
+    /// Wrong current passwords count towards the login lockout of the user.
+    pub async fn execute(
+        &self,
+        input: ChangePasswordInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<IssuedToken, ChangePasswordError> {
+        let user: User = self
+            .user_repository
+            .find_by_id(&actor.id)
+            .await?
+            .ok_or(ChangePasswordError::NotFound)?;
+        // The same key as password logins use, in the home organization of the user.
+        let throttle_keys: [ThrottleKey; 1] = [ThrottleKey::Username(
+            user.organization_id,
+            user.username.as_str().to_owned(),
+        )];
+
+        self.throttle.check(&throttle_keys).await?;
+
+        if !self
+            .hasher
+            .verify(&input.current_password, user.password_hash.as_str())?
+            .is_match()
+        {
+            self.throttle.record_failure(&throttle_keys).await?;
+
+            return Err(ChangePasswordError::InvalidCurrentPassword);
+        }
+
+        self.throttle.record_success(&throttle_keys).await?;
+
+        check_new_password::<_, _, _, ChangePasswordError>(
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+        set_password(
+            &self.user_repository,
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ChangePasswordError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        let current: Option<Session> = match actor.session_id {
+            Some(session_id) => self
+                .session_repository
+                .find_by_id(&session_id)
+                .await?
+                .filter(|session| session.user_id == user.id),
+            None => None,
+        };
+        let mut session: Session =
+            current.unwrap_or_else(|| Session::new(Uuid::now_v7(), user.id, actor.client_id, now));
+
+        end_sessions(
+            &self.revocation_store,
+            &self.session_repository,
+            &user.id,
+            Some(&session.id),
+            elapsed.as_millis() as u64,
+        )
+        .await?;
+
+        let mut actor: AuthenticatedUser = actor.clone();
+        actor.session_id = Some(session.id);
+        actor.auth_time = Some(session.created_at);
+
+        let token: IssuedToken = self.token_service.issue(&actor)?;
+
+        session.touch(None, None, now);
+        session.expires_at = now + token.refresh_expires_in;
+        self.session_repository.save(session).await?;
+
+        record(
+            &self.audit_log,
+            entry(&actor, AuditAction::PasswordChanged).with_target(user.id),
+        )
+        .await;
+
+        Ok(token)
+    }
+
+    /// Changes an expired password, which no longer signs the user in, given the current one.
+    /// The user then signs in with the new password, and with their second factor if they
+    /// enrolled one. Every session of the user ends.
+    ///
+    /// Wrong current passwords are throttled like failed logins, per username and per client
+    /// address.
+    pub async fn change_expired(
+        &self,
+        input: ChangeExpiredPasswordInput,
+        context: &LoginContext,
+    ) -> Result<(), ChangePasswordError> {
+        // Usernames cannot contain `@`, so the email address is told apart by it.
+        let (throttle_key, user): (ThrottleKey, Option<User>) = match input.username.contains('@') {
+            true => {
+                let email: Email = Email::new(input.username)
+                    .map_err(|_| ChangePasswordError::InvalidCurrentPassword)?;
+                let user: Option<User> = self.user_repository.find_by_email(&email).await?;
+
+                (ThrottleKey::Email(email.as_str().to_owned()), user)
+            }
+            false => {
+                let username: Username = Username::new(input.username)
+                    .map_err(|_| ChangePasswordError::InvalidCurrentPassword)?;
+                let user: Option<User> = self
+                    .user_repository
+                    .find_by_username(&input.organization_id, &username)
+                    .await?;
+
+                (
+                    ThrottleKey::Username(input.organization_id, username.as_str().to_owned()),
+                    user,
+                )
+            }
+        };
+        let mut throttle_keys: Vec<ThrottleKey> = vec![throttle_key];
+
+        if let Some(ip_address) = context.ip_address {
+            throttle_keys.push(ThrottleKey::Ip(ip_address));
+        }
+
+        self.throttle.check(&throttle_keys).await?;
+
+        let matches: bool = match &user {
+            Some(user) => self
+                .hasher
+                .verify(&input.current_password, user.password_hash.as_str())?
+                .is_match(),
+            None => false,
+        };
+
+        let Some(user) = user.filter(|_| matches) else {
+            self.throttle.record_failure(&throttle_keys).await?;
+
+            return Err(ChangePasswordError::InvalidCurrentPassword);
+        };
+
+        self.throttle.record_success(&throttle_keys).await?;
+
+        if !user.is_active() {
+            return Err(ChangePasswordError::UserInactive);
+        }
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ChangePasswordError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        if !user.is_password_expired(self.policy.max_age(), now) {
+            return Err(ChangePasswordError::NotExpired);
+        }
+
+        check_new_password::<_, _, _, ChangePasswordError>(
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+        set_password(
+            &self.user_repository,
+            &self.history_repository,
+            &self.hasher,
+            &self.policy,
+            &user,
+            &input.new_password,
+        )
+        .await?;
+        end_sessions(
+            &self.revocation_store,
+            &self.session_repository,
+            &user.id,
+            None,
+            elapsed.as_millis() as u64,
+        )
+        .await?;
+
+        let mut audit: AuditEntry = anonymous(AuditAction::PasswordChanged).with_target(user.id);
+        audit.organization_id = Some(user.organization_id);
+        audit.actor_id = Some(user.id);
+        audit.actor_username = Some(user.username.as_str().to_owned());
+        audit.ip_address = context.ip_address;
+
+        record(&self.audit_log, audit).await;
+
+        Ok(())
+    }
+}
+
+pub struct ChangePasswordInput {
+    pub current_password: String,
+    pub new_password: PasswordPlain,
+}
+
+pub struct ChangeExpiredPasswordInput {
+    /// The organization the username is unique in. Email addresses are unique everywhere.
+    pub organization_id: Uuid,
+    /// The username or email address the user signs in with.
+    pub username: String,
+    pub current_password: String,
+    pub new_password: PasswordPlain,
+}
+
+pub enum ChangePasswordError {
+    NotFound,
+    InvalidCurrentPassword,
+    UserInactive,
+    /// The password still signs the user in, so it is changed with their tokens instead.
+    NotExpired,
+    /// The new password breaks the password policy, or was used recently.
+    UserError(UserError),
+    /// Too many wrong current passwords, retry after this many seconds.
+    Throttled(u64),
+    InfrastructureError,
+}
+
+impl From<UserError> for ChangePasswordError {
+    fn from(value: UserError) -> Self {
+        ChangePasswordError::UserError(value)
+    }
+}
+
+impl From<HashError> for ChangePasswordError {
+    fn from(_: HashError) -> Self {
+        ChangePasswordError::InfrastructureError
+    }
+}
+
+impl From<RepositoryError> for ChangePasswordError {
+    fn from(_: RepositoryError) -> Self {
+        ChangePasswordError::InfrastructureError
+    }
+}
+
+impl From<TokenError> for ChangePasswordError {
+    fn from(_: TokenError) -> Self {
+        ChangePasswordError::InfrastructureError
+    }
+}
+
+impl From<ThrottleError> for ChangePasswordError {
+    fn from(value: ThrottleError) -> Self {
+        match value {
+            ThrottleError::Locked { retry_after } => ChangePasswordError::Throttled(retry_after),
+            ThrottleError::InfrastructureError => ChangePasswordError::InfrastructureError,
+        }
+    }
+}
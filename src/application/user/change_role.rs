@@ -0,0 +1,295 @@
+use super::change_status::valid_reason;
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+        security::revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{
+            entity::{Role, RoleName},
+            permission::Permission,
+            repository::RoleRepository,
+        },
+        session::repository::SessionRepository,
+        user::{entity::User, patch::UserPatch, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ChangeUserRoleService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    user_repository: U,
+    session_repository: R,
+    revocation_store: S,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<U, R, S, L, G, A> ChangeUserRoleService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(
+        user_repository: U,
+        session_repository: R,
+        revocation_store: S,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            revocation_store,
+            role_repository,
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Changes the role of a user. Tokens carry the role names they were issued with, so the
+    /// user is signed out everywhere and picks the new role up on the next sign in. Nobody
+    /// hands out or takes away permissions they do not hold themselves, and the last active
+    /// administrator of an organization cannot be demoted.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        input: ChangeUserRoleInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), ChangeUserRoleError> {
+        actor.require(Permission::RoleAssign)?;
+
+        let reason: String =
+            valid_reason(&input.reason).ok_or(ChangeUserRoleError::InvalidReason)?;
+
+        let role: Role = self
+            .role_repository
+            .find_by_name(&input.role)
+            .await?
+            .ok_or(ChangeUserRoleError::UnknownRole)?;
+
+        if !Permission::all_granted(&role.grants(), &actor.permissions) {
+            return Err(ChangeUserRoleError::Forbidden);
+        }
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(ChangeUserRoleError::NotFound)?;
+
+        if user.role == input.role {
+            return Ok(());
+        }
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(ChangeUserRoleError::Forbidden);
+        }
+
+        self.user_repository
+            .update_keeping_administrators(
+                &user.id,
+                UserPatch::new(None, None, None, Some(input.role.clone()), None, None),
+            )
+            .await?
+            .ok_or(ChangeUserRoleError::LastAdministrator)?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ChangeUserRoleError::InfrastructureError)?
+            .as_millis() as u64;
+
+        self.session_repository
+            .delete_by_user_id(&user.id, None)
+            .await?;
+        self.revocation_store.revoke_all(&user.id, now).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::UserRoleChanged)
+                .with_target(user.id)
+                .with_change("role", Some(user.role.as_str()), Some(input.role.as_str()))
+                .with_reason(reason),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub struct ChangeUserRoleInput {
+    pub role: RoleName,
+    /// Why the role changes, kept in the audit log.
+    pub reason: String,
+}
+
+pub enum ChangeUserRoleError {
+    InvalidReason,
+    NotFound,
+    UnknownRole,
+    Forbidden,
+    LastAdministrator,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ChangeUserRoleError {
+    fn from(_: RepositoryError) -> Self {
+        ChangeUserRoleError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for ChangeUserRoleError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ChangeUserRoleError::Forbidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::persistence::memory::{
+            InMemoryAuditLog, InMemoryGroupRepository, InMemoryRevocationStore,
+            InMemoryRoleRepository, InMemorySessionRepository, InMemoryUserRepository,
+        },
+        domain::{
+            organization::entity::Organization,
+            user::{
+                entity::UserStatus,
+                value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+            },
+        },
+    };
+
+    type TestService = ChangeUserRoleService<
+        InMemoryUserRepository,
+        InMemorySessionRepository,
+        InMemoryRevocationStore,
+        InMemoryRoleRepository,
+        InMemoryGroupRepository,
+        InMemoryAuditLog,
+    >;
+
+    fn administrator(username: &str) -> User {
+        User::new(
+            Uuid::now_v7(),
+            Name::new("Admin".into()).unwrap(),
+            Username::new(username.into()).unwrap(),
+            PasswordHash::new("plain:secret".into()).unwrap(),
+            Some(RoleName::administrator()),
+            None,
+        )
+    }
+
+    fn service(users: Vec<User>) -> TestService {
+        ChangeUserRoleService::new(
+            InMemoryUserRepository::with(users),
+            InMemorySessionRepository::default(),
+            InMemoryRevocationStore::default(),
+            InMemoryRoleRepository::with(vec![
+                Role::new(
+                    RoleName::administrator(),
+                    "Manages everything".into(),
+                    Vec::new(),
+                )
+                .unwrap(),
+                Role::new(RoleName::user(), "Signs in".into(), Vec::new()).unwrap(),
+            ]),
+            InMemoryGroupRepository::default(),
+            InMemoryAuditLog::default(),
+        )
+    }
+
+    fn actor(user: &User) -> AuthenticatedUser {
+        let mut actor: AuthenticatedUser = AuthenticatedUser::new(
+            user.id,
+            user.username.as_str().into(),
+            Organization::DEFAULT_ID,
+            vec![RoleName::administrator()],
+            Vec::new(),
+            None,
+            None,
+            None,
+        );
+        actor.permissions = Permission::ALL.to_vec();
+
+        actor
+    }
+
+    fn demotion() -> ChangeUserRoleInput {
+        ChangeUserRoleInput {
+            role: RoleName::user(),
+            reason: "Hands administration over".into(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_demote_the_last_administrator() {
+        let admin: User = administrator("admin");
+        let mut former: User = administrator("former");
+        former.status = UserStatus::Inactive;
+        let service: TestService = service(vec![admin.clone(), former]);
+
+        let result: Result<(), ChangeUserRoleError> =
+            service.execute(&admin.id, demotion(), &actor(&admin)).await;
+
+        assert!(matches!(
+            result,
+            Err(ChangeUserRoleError::LastAdministrator)
+        ));
+        assert!(
+            service
+                .user_repository
+                .all()
+                .iter()
+                .all(|user| user.role.is_administrator())
+        );
+    }
+
+    #[actix_web::test]
+    async fn demotes_an_administrator_another_one_remains_for() {
+        let admin: User = administrator("admin");
+        let other: User = administrator("other");
+        let service: TestService = service(vec![admin.clone(), other.clone()]);
+
+        assert!(
+            service
+                .execute(&admin.id, demotion(), &actor(&other))
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            service
+                .user_repository
+                .find_by_id(&admin.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .role,
+            RoleName::user()
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+        security::revocation_store::RevocationStore,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        session::repository::SessionRepository,
+        user::{
+            entity::{User, UserStatus},
+            patch::UserPatch,
+            repository::UserRepository,
+        },
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+pub const MAX_REASON_LENGTH: usize = 500;
+
+#[derive(Clone)]
+pub struct ChangeUserStatusService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    user_repository: U,
+    session_repository: R,
+    revocation_store: S,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
+}
+
+impl<U, R, S, L, G, A> ChangeUserStatusService<U, R, S, L, G, A>
+where
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
+{
+    pub fn new(
+        user_repository: U,
+        session_repository: R,
+        revocation_store: S,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            revocation_store,
+            role_repository,
+            group_repository,
+            audit_log,
+        }
+    }
+
+    /// Activates, deactivates or bans a user. Leaving the active status signs the user out
+    /// everywhere at once. Users holding permissions the actor lacks cannot be changed, and
+    /// the last active administrator of an organization cannot leave the active status.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        input: ChangeUserStatusInput,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), ChangeUserStatusError> {
+        actor.require(Permission::UserBan)?;
+
+        let reason: String =
+            valid_reason(&input.reason).ok_or(ChangeUserStatusError::InvalidReason)?;
+
+        let user: User = find_managed(&self.user_repository, id, actor)
+            .await?
+            .ok_or(ChangeUserStatusError::NotFound)?;
+
+        if user.status == input.status {
+            return Ok(());
+        }
+
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(ChangeUserStatusError::Forbidden);
+        }
+
+        self.user_repository
+            .update_keeping_administrators(
+                &user.id,
+                UserPatch::new(None, None, None, None, Some(input.status.clone()), None),
+            )
+            .await?
+            .ok_or(ChangeUserStatusError::LastAdministrator)?;
+
+        if input.status != UserStatus::Active {
+            let now: u64 = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|_| ChangeUserStatusError::InfrastructureError)?
+                .as_millis() as u64;
+
+            self.session_repository
+                .delete_by_user_id(&user.id, None)
+                .await?;
+            self.revocation_store.revoke_all(&user.id, now).await?;
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::UserStatusChanged)
+                .with_target(user.id)
+                .with_change(
+                    "status",
+                    Some(user.status.as_str()),
+                    Some(input.status.as_str()),
+                )
+                .with_reason(reason),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+/// Trims a reason given for an administrative change, which must not be blank or too long.
+pub(super) fn valid_reason(reason: &str) -> Option<String> {
+    let reason: &str = reason.trim();
+
+    if reason.is_empty() || reason.chars().count() > MAX_REASON_LENGTH {
+        return None;
+    }
+
+    Some(reason.to_owned())
+}
+
+pub struct ChangeUserStatusInput {
+    pub status: UserStatus,
+    /// Why the status changes, kept in the audit log.
+    pub reason: String,
+}
+
+pub enum ChangeUserStatusError {
+    InvalidReason,
+    NotFound,
+    Forbidden,
+    LastAdministrator,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ChangeUserStatusError {
+    fn from(_: RepositoryError) -> Self {
+        ChangeUserStatusError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for ChangeUserStatusError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => ChangeUserStatusError::Forbidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adapters::persistence::memory::{
+            InMemoryAuditLog, InMemoryGroupRepository, InMemoryRevocationStore,
+            InMemoryRoleRepository, InMemorySessionRepository, InMemoryUserRepository,
+        },
+        domain::{
+            organization::entity::Organization,
+            role::entity::{Role, RoleName},
+            user::value_objects::{name::Name, password_hash::PasswordHash, username::Username},
+        },
+    };
+
+    type TestService = ChangeUserStatusService<
+        InMemoryUserRepository,
+        InMemorySessionRepository,
+        InMemoryRevocationStore,
+        InMemoryRoleRepository,
+        InMemoryGroupRepository,
+        InMemoryAuditLog,
+    >;
+
+    fn administrator(username: &str) -> User {
+        User::new(
+            Uuid::now_v7(),
+            Name::new("Admin".into()).unwrap(),
+            Username::new(username.into()).unwrap(),
+            PasswordHash::new("plain:secret".into()).unwrap(),
+            Some(RoleName::administrator()),
+            None,
+        )
+    }
+
+    fn service(users: Vec<User>) -> TestService {
+        ChangeUserStatusService::new(
+            InMemoryUserRepository::with(users),
+            InMemorySessionRepository::default(),
+            InMemoryRevocationStore::default(),
+            InMemoryRoleRepository::with(vec![
+                Role::new(
+                    RoleName::administrator(),
+                    "Manages everything".into(),
+                    Vec::new(),
+                )
+                .unwrap(),
+            ]),
+            InMemoryGroupRepository::default(),
+            InMemoryAuditLog::default(),
+        )
+    }
+
+    fn actor(user: &User) -> AuthenticatedUser {
+        let mut actor: AuthenticatedUser = AuthenticatedUser::new(
+            user.id,
+            user.username.as_str().into(),
+            Organization::DEFAULT_ID,
+            vec![RoleName::administrator()],
+            Vec::new(),
+            None,
+            None,
+            None,
+        );
+        actor.permissions = Permission::ALL.to_vec();
+
+        actor
+    }
+
+    fn input(status: UserStatus) -> ChangeUserStatusInput {
+        ChangeUserStatusInput {
+            status,
+            reason: "Leaves the team".into(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn refuses_to_deactivate_the_last_administrator() {
+        let admin: User = administrator("admin");
+        let mut banned: User = administrator("banned");
+        banned.status = UserStatus::Banned;
+        let service: TestService = service(vec![admin.clone(), banned]);
+
+        for status in [UserStatus::Inactive, UserStatus::Banned] {
+            let result: Result<(), ChangeUserStatusError> = service
+                .execute(&admin.id, input(status), &actor(&admin))
+                .await;
+
+            assert!(matches!(
+                result,
+                Err(ChangeUserStatusError::LastAdministrator)
+            ));
+        }
+
+        assert!(
+            service
+                .user_repository
+                .find_by_id(&admin.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .is_active()
+        );
+    }
+
+    #[actix_web::test]
+    async fn deactivates_an_administrator_another_one_remains_for() {
+        let admin: User = administrator("admin");
+        let other: User = administrator("other");
+        let service: TestService = service(vec![admin.clone(), other.clone()]);
+
+        assert!(
+            service
+                .execute(&admin.id, input(UserStatus::Inactive), &actor(&other))
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            service
+                .user_repository
+                .find_by_id(&admin.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .status,
+            UserStatus::Inactive
+        );
+    }
+}
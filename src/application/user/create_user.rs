@@ -1,15 +1,21 @@
 use crate::{
-    application::auth::authenticated_user::AuthenticatedUser,
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
     domain::{
+        audit::{entry::AuditAction, log::AuditLog},
         errors::{domain::DomainError, repository::RepositoryError},
+        role::{entity::RoleName, permission::Permission},
         user::{
-            entity::{User, UserRole, UserStatus},
+            entity::{User, UserStatus},
             error::UserError,
             password_hasher::PasswordHasher,
+            password_policy::{BreachedPasswords, PasswordPolicy},
             repository::UserRepository,
             value_objects::{
-                name::Name, password_hash::PasswordHash, password_plain::PasswordPlain,
-                username::Username,
+                email::Email, name::Name, password_hash::PasswordHash,
+                password_plain::PasswordPlain, username::Username,
             },
         },
     },
@@ -17,24 +23,32 @@ use crate::{
 use uuid::Uuid;
 
 #[derive(Clone)]
-pub struct CreateUserService<R, H>
+pub struct CreateUserService<R, H, B, A>
 where
     R: UserRepository,
     H: PasswordHasher,
+    B: BreachedPasswords,
+    A: AuditLog,
 {
     user_repository: R,
     hasher: H,
+    policy: PasswordPolicy<B>,
+    audit_log: A,
 }
 
-impl<R, H> CreateUserService<R, H>
+impl<R, H, B, A> CreateUserService<R, H, B, A>
 where
     R: UserRepository,
     H: PasswordHasher,
+    B: BreachedPasswords,
+    A: AuditLog,
 {
-    pub fn new(user_repository: R, hasher: H) -> Self {
+    pub fn new(user_repository: R, hasher: H, policy: PasswordPolicy<B>, audit_log: A) -> Self {
         Self {
             user_repository,
             hasher,
+            policy,
+            audit_log,
         }
     }
 
@@ -43,27 +57,58 @@ where
         input: CreateUserInput,
         actor: &AuthenticatedUser,
     ) -> Result<CreateUserOutput, CreateUserError> {
-        actor.must_be_admin()?;
+        actor.require(Permission::UserCreate)?;
+
+        let output: CreateUserOutput = self.create(input, actor.organization_id).await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::UserCreated)
+                .with_target(output.id)
+                .with_change("username", None::<&str>, Some(&output.username))
+                .with_change("email", None::<&str>, output.email.as_deref()),
+        )
+        .await;
 
+        Ok(output)
+    }
+
+    /// Creates a regular user of an organization without checking who asks or recording it,
+    /// for callers that authorize and audit the creation themselves, like self-registration.
+    pub async fn create(
+        &self,
+        input: CreateUserInput,
+        organization_id: Uuid,
+    ) -> Result<CreateUserOutput, CreateUserError> {
         let username: Username = Username::new(input.username)?;
         let password: PasswordPlain = PasswordPlain::new(input.password)?;
+        self.policy.check(&password)?;
         let name: Name = Name::new(input.name)?;
-        let role: Option<UserRole> = None;
+        let email: Option<Email> = input.email.map(Email::new).transpose()?;
+        let role: Option<RoleName> = None;
         let status: Option<UserStatus> = None;
 
+        // Deleted users keep their username and address until they are purged.
         if self
             .user_repository
-            .find_by_username(&username)
+            .is_username_taken(&organization_id, &username)
             .await?
-            .is_some()
         {
             return Err(CreateUserError::AlreadyExists);
         }
 
+        if let Some(email) = &email
+            && self.user_repository.is_email_taken(email).await?
+        {
+            return Err(CreateUserError::EmailAlreadyExists);
+        }
+
         let password_hash_raw: String = self.hasher.hash(password.as_str());
         let password_hash: PasswordHash = PasswordHash::new(password_hash_raw)?;
 
-        let user: User = User::new(Uuid::now_v7(), name, username, password_hash, role, status);
+        let mut user: User = User::new(Uuid::now_v7(), name, username, password_hash, role, status);
+        user.email = email;
+        user.organization_id = organization_id;
 
         let user: User = self.user_repository.create(user).await?;
 
@@ -71,6 +116,7 @@ where
             id: user.id,
             username: user.username.as_str().into(),
             name: user.name.as_str().into(),
+            email: user.email.map(|email| email.as_str().into()),
         })
     }
 }
@@ -79,18 +125,21 @@ pub struct CreateUserInput {
     pub username: String,
     pub name: String,
     pub password: String,
+    pub email: Option<String>,
 }
 
 pub struct CreateUserOutput {
     pub id: Uuid,
     pub username: String,
     pub name: String,
+    pub email: Option<String>,
 }
 
 pub enum CreateUserError {
     UserError(UserError),
     Forbidden,
     AlreadyExists,
+    EmailAlreadyExists,
     InfrastructureError,
 }
 
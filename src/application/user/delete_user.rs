@@ -1,42 +1,112 @@
 use crate::{
-    application::auth::authenticated_user::AuthenticatedUser,
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+        security::revocation_store::RevocationStore,
+    },
     domain::{
+        audit::{entry::AuditAction, log::AuditLog},
         errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        session::repository::SessionRepository,
         user::{entity::User, repository::UserRepository},
     },
 };
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 #[derive(Clone)]
-pub struct DeleteUserService<R>
+pub struct DeleteUserService<U, R, S, L, G, A>
 where
-    R: UserRepository,
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
 {
-    user_repository: R,
+    user_repository: U,
+    session_repository: R,
+    revocation_store: S,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
 }
 
-impl<R> DeleteUserService<R>
+impl<U, R, S, L, G, A> DeleteUserService<U, R, S, L, G, A>
 where
-    R: UserRepository,
+    U: UserRepository,
+    R: SessionRepository,
+    S: RevocationStore,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
 {
-    pub fn new(user_repository: R) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: U,
+        session_repository: R,
+        revocation_store: S,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            revocation_store,
+            role_repository,
+            group_repository,
+            audit_log,
+        }
     }
 
+    /// Marks the user deleted and signs them out everywhere. The user can be restored until
+    /// the grace period ends, when the purge job anonymizes them. The last active
+    /// administrator of an organization cannot be deleted, not even by themselves, and nor can
+    /// users holding permissions the actor lacks.
     pub async fn execute(
         &self,
         id: &Uuid,
         actor: &AuthenticatedUser,
     ) -> Result<(), DeleteUserError> {
-        actor.must_be_admin_or_owner(&id)?;
+        actor.require_unless_owner(Permission::UserDelete, id)?;
 
-        let user: User = self
-            .user_repository
-            .find_by_id(id)
+        let user: User = find_managed(&self.user_repository, id, actor)
             .await?
             .ok_or(DeleteUserError::NotFound)?;
 
-        self.user_repository.delete(&user.id).await?;
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(DeleteUserError::Forbidden);
+        }
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| DeleteUserError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        if !self.user_repository.soft_delete(&user.id, now).await? {
+            return Err(DeleteUserError::LastAdministrator);
+        }
+
+        self.session_repository
+            .delete_by_user_id(&user.id, None)
+            .await?;
+        self.revocation_store
+            .revoke_all(&user.id, elapsed.as_millis() as u64)
+            .await?;
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::UserDeleted)
+                .with_target(user.id)
+                .with_change("username", Some(user.username.as_str()), None::<&str>),
+        )
+        .await;
 
         Ok(())
     }
@@ -44,6 +114,7 @@ where
 
 pub enum DeleteUserError {
     NotFound,
+    LastAdministrator,
     InfrastructureError,
     Forbidden,
 }
@@ -1,6 +1,11 @@
-use crate::domain::{
-    errors::repository::RepositoryError,
-    user::{entity::User, repository::UserRepository},
+use crate::{
+    application::{
+        auth::authenticated_user::AuthenticatedUser, organization::tenant::find_managed,
+    },
+    domain::{
+        errors::repository::RepositoryError,
+        user::{entity::User, repository::UserRepository},
+    },
 };
 use uuid::Uuid;
 
@@ -20,8 +25,13 @@ where
         Self { user_repository }
     }
 
-    pub async fn find_by_id(&self, id: &Uuid) -> Result<User, FindUserError> {
-        match self.user_repository.find_by_id(id).await? {
+    /// Finds a user the actor may see, see [`find_managed`].
+    pub async fn find_by_id(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<User, FindUserError> {
+        match find_managed(&self.user_repository, id, actor).await? {
             Some(user) => Ok(user),
             None => Err(FindUserError::NotFound),
         }
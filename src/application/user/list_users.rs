@@ -0,0 +1,98 @@
+use crate::{
+    application::auth::authenticated_user::AuthenticatedUser,
+    domain::{
+        errors::repository::RepositoryError,
+        role::permission::Permission,
+        user::{
+            entity::{User, UserStatus},
+            query::{Pagination, UserCursor, UserPage, UserQuery},
+            repository::UserRepository,
+        },
+    },
+};
+
+pub const DEFAULT_PAGE_SIZE: u64 = 20;
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone)]
+pub struct ListUsersService<R>
+where
+    R: UserRepository,
+{
+    user_repository: R,
+}
+
+impl<R> ListUsersService<R>
+where
+    R: UserRepository,
+{
+    pub fn new(user_repository: R) -> Self {
+        Self { user_repository }
+    }
+
+    /// Lists one page of the users of the active organization of the actor. Administrators
+    /// see every such user and how many match in total, and may list deleted users; other
+    /// users only see active users.
+    pub async fn execute(
+        &self,
+        mut query: UserQuery,
+        actor: &AuthenticatedUser,
+    ) -> Result<ListUsersOutput, ListUsersError> {
+        let limit: u64 = query.pagination.limit();
+
+        if limit == 0 || limit > MAX_PAGE_SIZE {
+            return Err(ListUsersError::InvalidLimit);
+        }
+
+        query.filter.organization_id = Some(actor.organization_id);
+
+        let is_admin: bool = actor.can(Permission::UserRead);
+
+        if !is_admin {
+            if query.filter.deleted {
+                return Err(ListUsersError::Forbidden);
+            }
+
+            match query.filter.status {
+                None | Some(UserStatus::Active) => query.filter.status = Some(UserStatus::Active),
+                Some(_) => return Err(ListUsersError::Forbidden),
+            }
+        }
+
+        let page: UserPage = self.user_repository.list(&query).await?;
+
+        let next_cursor: Option<UserCursor> = match query.pagination {
+            Pagination::Cursor { .. } if page.users.len() as u64 == limit => page
+                .users
+                .last()
+                .map(|user| UserCursor::after(user, &query.sort)),
+            _ => None,
+        };
+
+        Ok(ListUsersOutput {
+            users: page.users,
+            total: is_admin.then_some(page.total),
+            next_cursor,
+        })
+    }
+}
+
+pub struct ListUsersOutput {
+    pub users: Vec<User>,
+    /// How many users match the filters, only told to administrators.
+    pub total: Option<u64>,
+    /// Where the next page starts, when listing by cursor and more users may follow.
+    pub next_cursor: Option<UserCursor>,
+}
+
+pub enum ListUsersError {
+    InvalidLimit,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for ListUsersError {
+    fn from(_: RepositoryError) -> Self {
+        ListUsersError::InfrastructureError
+    }
+}
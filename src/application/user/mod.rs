@@ -1,4 +1,11 @@
+pub mod change_password;
+pub mod change_role;
+pub mod change_status;
 pub mod create_user;
 pub mod delete_user;
 pub mod find_user;
+pub mod list_users;
+pub mod password;
+pub mod purge_deleted_users;
+pub mod restore_user;
 pub mod update_user;
@@ -0,0 +1,93 @@
+use crate::{
+    application::security::revocation_store::RevocationStore,
+    domain::{
+        errors::repository::RepositoryError,
+        session::repository::SessionRepository,
+        user::{
+            entity::User,
+            error::UserError,
+            password_hasher::PasswordHasher,
+            password_history::PasswordHistoryRepository,
+            password_policy::{BreachedPasswords, PasswordPolicy},
+            patch::UserPatch,
+            repository::UserRepository,
+            value_objects::{password_hash::PasswordHash, password_plain::PasswordPlain},
+        },
+    },
+};
+use uuid::Uuid;
+
+/// Checks a new password of the user against the policy and the passwords they used recently.
+/// Every way of setting a password goes through here, and then through `set_password`.
+pub async fn check_new_password<P, H, B, E>(
+    history_repository: &P,
+    hasher: &H,
+    policy: &PasswordPolicy<B>,
+    user: &User,
+    new_password: &PasswordPlain,
+) -> Result<(), E>
+where
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    B: BreachedPasswords,
+    E: From<UserError> + From<RepositoryError>,
+{
+    policy.check(new_password)?;
+
+    let history: Vec<PasswordHash> = history_repository
+        .recent(&user.id, policy.history())
+        .await?;
+
+    policy.check_reuse(new_password, &user.password_hash, &history, hasher)?;
+
+    Ok(())
+}
+
+/// Replaces the password of the user with a checked one. The replaced password joins the
+/// history, so it cannot be picked again for a while.
+pub async fn set_password<U, P, H, B>(
+    user_repository: &U,
+    history_repository: &P,
+    hasher: &H,
+    policy: &PasswordPolicy<B>,
+    user: &User,
+    new_password: &PasswordPlain,
+) -> Result<User, RepositoryError>
+where
+    U: UserRepository,
+    P: PasswordHistoryRepository,
+    H: PasswordHasher,
+    B: BreachedPasswords,
+{
+    let password_hash: PasswordHash = PasswordHash::new(hasher.hash(new_password.as_str()))
+        .map_err(|_| RepositoryError::InvariantViolation)?;
+
+    let updated_user: User = user_repository
+        .update(
+            &user.id,
+            UserPatch::new(None, None, Some(password_hash), None, None, None),
+        )
+        .await?;
+    history_repository
+        .record(&user.id, &user.password_hash, policy.history())
+        .await?;
+
+    Ok(updated_user)
+}
+
+/// Signs the user out everywhere after a password change: every token issued before
+/// `not_before`, in milliseconds, is revoked, and every session ends apart from `keep`.
+pub async fn end_sessions<S, R>(
+    revocation_store: &S,
+    session_repository: &R,
+    user_id: &Uuid,
+    keep: Option<&Uuid>,
+    not_before: u64,
+) -> Result<(), RepositoryError>
+where
+    S: RevocationStore,
+    R: SessionRepository,
+{
+    revocation_store.revoke_all(user_id, not_before).await?;
+    session_repository.delete_by_user_id(user_id, keep).await
+}
@@ -0,0 +1,84 @@
+use crate::{
+    application::audit::record::{anonymous, record},
+    domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
+        errors::repository::RepositoryError,
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Users anonymized per batch, so one run does not hold on to too many rows at once.
+pub const PURGE_BATCH_SIZE: u64 = 100;
+
+/// Anonymizes the users whose grace period ended. Run periodically in the background.
+#[derive(Clone)]
+pub struct PurgeDeletedUsersService<R, A>
+where
+    R: UserRepository,
+    A: AuditLog,
+{
+    user_repository: R,
+    audit_log: A,
+    /// Seconds after the deletion during which the user can be restored.
+    grace_period: u64,
+}
+
+impl<R, A> PurgeDeletedUsersService<R, A>
+where
+    R: UserRepository,
+    A: AuditLog,
+{
+    pub fn new(user_repository: R, audit_log: A, grace_period: u64) -> Self {
+        Self {
+            user_repository,
+            audit_log,
+            grace_period,
+        }
+    }
+
+    /// Returns the number of users anonymized.
+    pub async fn execute(&self) -> Result<u64, PurgeDeletedUsersError> {
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| PurgeDeletedUsersError::InfrastructureError)?
+            .as_secs();
+        let deleted_before: u64 = now.saturating_sub(self.grace_period);
+        let mut purged: u64 = 0;
+
+        loop {
+            let users: Vec<User> = self
+                .user_repository
+                .find_purgeable(deleted_before, PURGE_BATCH_SIZE)
+                .await?;
+            let count: u64 = users.len() as u64;
+
+            for user in users {
+                self.user_repository.anonymize(&user.id, now).await?;
+
+                let mut entry: AuditEntry = anonymous(AuditAction::UserPurged).with_target(user.id);
+                entry.organization_id = Some(user.organization_id);
+                record(&self.audit_log, entry).await;
+            }
+
+            purged += count;
+
+            if count < PURGE_BATCH_SIZE {
+                return Ok(purged);
+            }
+        }
+    }
+}
+
+pub enum PurgeDeletedUsersError {
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for PurgeDeletedUsersError {
+    fn from(_: RepositoryError) -> Self {
+        PurgeDeletedUsersError::InfrastructureError
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+    },
+    domain::{
+        audit::{entry::AuditAction, log::AuditLog},
+        errors::{domain::DomainError, repository::RepositoryError},
+        role::permission::Permission,
+        user::{entity::User, repository::UserRepository},
+    },
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct RestoreUserService<R, A>
+where
+    R: UserRepository,
+    A: AuditLog,
+{
+    user_repository: R,
+    audit_log: A,
+    /// Seconds after the deletion during which the user can be restored.
+    grace_period: u64,
+}
+
+impl<R, A> RestoreUserService<R, A>
+where
+    R: UserRepository,
+    A: AuditLog,
+{
+    pub fn new(user_repository: R, audit_log: A, grace_period: u64) -> Self {
+        Self {
+            user_repository,
+            audit_log,
+            grace_period,
+        }
+    }
+
+    /// Undoes the deletion of a user of the actor's active organization. The user gets their
+    /// username and email address back, but has to sign in again.
+    pub async fn execute(
+        &self,
+        id: &Uuid,
+        actor: &AuthenticatedUser,
+    ) -> Result<(), RestoreUserError> {
+        actor.require(Permission::UserDelete)?;
+
+        let user: User = self
+            .user_repository
+            .find_deleted(&actor.organization_id, id)
+            .await?
+            .ok_or(RestoreUserError::NotFound)?;
+
+        let now: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| RestoreUserError::InfrastructureError)?
+            .as_secs();
+
+        // The purge job may not have run yet, the grace period is what counts.
+        if user.deleted_at.unwrap_or_default() + self.grace_period <= now {
+            return Err(RestoreUserError::GracePeriodEnded);
+        }
+
+        if !self.user_repository.restore(&user.id).await? {
+            return Err(RestoreUserError::NotFound);
+        }
+
+        record(
+            &self.audit_log,
+            entry(actor, AuditAction::UserRestored)
+                .with_target(user.id)
+                .with_change("username", None::<&str>, Some(user.username.as_str())),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+pub enum RestoreUserError {
+    NotFound,
+    GracePeriodEnded,
+    Forbidden,
+    InfrastructureError,
+}
+
+impl From<RepositoryError> for RestoreUserError {
+    fn from(_: RepositoryError) -> Self {
+        RestoreUserError::InfrastructureError
+    }
+}
+
+impl From<DomainError> for RestoreUserError {
+    fn from(value: DomainError) -> Self {
+        match value {
+            DomainError::Forbidden => RestoreUserError::Forbidden,
+        }
+    }
+}
@@ -1,68 +1,141 @@
 use crate::{
-    application::auth::authenticated_user::AuthenticatedUser,
+    application::{
+        audit::record::{entry, record},
+        auth::authenticated_user::AuthenticatedUser,
+        event::publisher::{Event, EventPublisher, ProfileUpdated},
+        organization::tenant::find_managed,
+        role::permissions::holds_permissions_of,
+        security::revocation_store::RevocationStore,
+        user::password::{check_new_password, end_sessions, set_password},
+    },
     domain::{
+        audit::{
+            entry::{AuditAction, AuditEntry},
+            log::AuditLog,
+        },
         errors::{domain::DomainError, repository::RepositoryError},
+        group::repository::GroupRepository,
+        role::{permission::Permission, repository::RoleRepository},
+        session::repository::SessionRepository,
         user::{
             entity::User,
             error::UserError,
             password_hasher::PasswordHasher,
+            password_history::PasswordHistoryRepository,
+            password_policy::{BreachedPasswords, PasswordPolicy},
             patch::UserPatch,
+            profile::{Profile, ProfilePatch},
             repository::UserRepository,
-            value_objects::{password_hash::PasswordHash, username::Username},
+            value_objects::{
+                bio::Bio, email::Email, locale::Locale, password_plain::PasswordPlain,
+                status_emoji::StatusEmoji, status_text::StatusText, timezone::Timezone,
+                username::Username,
+            },
         },
     },
 };
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 #[derive(Clone)]
-pub struct UpdateUserService<R, H>
+pub struct UpdateUserService<R, P, H, B, S, T, E, L, G, A>
 where
     R: UserRepository,
+    P: PasswordHistoryRepository,
     H: PasswordHasher,
+    B: BreachedPasswords,
+    S: RevocationStore,
+    T: SessionRepository,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
 {
     user_repository: R,
+    history_repository: P,
     hasher: H,
+    policy: PasswordPolicy<B>,
+    revocation_store: S,
+    session_repository: T,
+    publisher: E,
+    role_repository: L,
+    group_repository: G,
+    audit_log: A,
 }
 
-impl<R, H> UpdateUserService<R, H>
+impl<R, P, H, B, S, T, E, L, G, A> UpdateUserService<R, P, H, B, S, T, E, L, G, A>
 where
     R: UserRepository,
+    P: PasswordHistoryRepository,
     H: PasswordHasher,
+    B: BreachedPasswords,
+    S: RevocationStore,
+    T: SessionRepository,
+    E: EventPublisher,
+    L: RoleRepository,
+    G: GroupRepository,
+    A: AuditLog,
 {
-    pub fn new(user_repository: R, hasher: H) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: R,
+        history_repository: P,
+        hasher: H,
+        policy: PasswordPolicy<B>,
+        revocation_store: S,
+        session_repository: T,
+        publisher: E,
+        role_repository: L,
+        group_repository: G,
+        audit_log: A,
+    ) -> Self {
         Self {
             user_repository,
+            history_repository,
             hasher,
+            policy,
+            revocation_store,
+            session_repository,
+            publisher,
+            role_repository,
+            group_repository,
+            audit_log,
         }
     }
 
+    /// Updates a user. Setting their password goes through the same checks and history as
+    /// changing or resetting it, and signs them out everywhere. Changing how others see them
+    /// publishes a profile update, and changing who they are is audited. Users holding
+    /// permissions the actor lacks cannot be updated.
     pub async fn execute(
         &self,
         id: Uuid,
         input: UpdateUserInput,
         actor: &AuthenticatedUser,
     ) -> Result<UpdateUserOutput, UpdateUserError> {
-        actor.must_be_admin_or_owner(&id)?;
+        actor.require_unless_owner(Permission::UserUpdate, &id)?;
 
-        let user: User = self
-            .user_repository
-            .find_by_id(&id)
+        let user: User = find_managed(&self.user_repository, &id, actor)
             .await?
             .ok_or(UpdateUserError::NotFound)?;
 
+        if !holds_permissions_of(&self.role_repository, &self.group_repository, actor, &user)
+            .await?
+        {
+            return Err(UpdateUserError::Forbidden);
+        }
+
         let username: Option<Username> = match input.username {
             Some(raw) => {
                 let username: Username = Username::new(raw)?;
 
-                if username != user.username {
-                    if self
+                if username != user.username
+                    && self
                         .user_repository
-                        .find_by_username(&username)
+                        .is_username_taken(&user.organization_id, &username)
                         .await?
-                        .is_some()
-                    {
-                        return Err(UpdateUserError::AlreadyExists);
-                    }
+                {
+                    return Err(UpdateUserError::AlreadyExists);
                 }
 
                 Some(username)
@@ -70,16 +143,125 @@ where
             None => None,
         };
 
-        let password_hash: Option<PasswordHash> = if let Some(password) = input.password {
-            let hash: String = self.hasher.hash(&password);
-            Some(PasswordHash::new(hash)?)
-        } else {
-            None
+        // A new address replaces the verified one, and has to be verified in turn.
+        let email: Option<Email> = match input.email {
+            Some(raw) => {
+                let email: Email = Email::new(raw)?;
+
+                if user.email.as_ref() != Some(&email)
+                    && self.user_repository.is_email_taken(&email).await?
+                {
+                    return Err(UpdateUserError::EmailAlreadyExists);
+                }
+
+                Some(email)
+            }
+            None => None,
         };
 
-        let patch = UserPatch::new(input.name, username, password_hash);
+        // Users change their own password with the current one; only administrators set it
+        // directly.
+        let password: Option<PasswordPlain> = match input.password {
+            Some(raw) => {
+                actor
+                    .require(Permission::UserUpdate)
+                    .map_err(|_| UpdateUserError::PasswordChangeRequiresCurrent)?;
+
+                let password: PasswordPlain = PasswordPlain::new(raw)?;
+                check_new_password::<_, _, _, UpdateUserError>(
+                    &self.history_repository,
+                    &self.hasher,
+                    &self.policy,
+                    &user,
+                    &password,
+                )
+                .await?;
 
-        let updated_user = self.user_repository.update(&id, patch).await?;
+                Some(password)
+            }
+            None => None,
+        };
+
+        // An empty value clears a profile field.
+        let profile: ProfilePatch = ProfilePatch {
+            avatar_updated_at: None,
+            status_text: profile_field(input.status_text, StatusText::new)?,
+            status_emoji: profile_field(input.status_emoji, StatusEmoji::new)?,
+            bio: profile_field(input.bio, Bio::new)?,
+            timezone: profile_field(input.timezone, Timezone::new)?,
+            locale: profile_field(input.locale, Locale::new)?,
+        };
+
+        let password_changed: bool = password.is_some();
+        let patch =
+            UserPatch::new(input.name, username, None, None, None, email).with_profile(profile);
+
+        let mut updated_user = self.user_repository.update(&id, patch).await?;
+
+        let elapsed: Duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| UpdateUserError::InfrastructureError)?;
+        let now: u64 = elapsed.as_secs();
+
+        if let Some(password) = &password {
+            updated_user = set_password(
+                &self.user_repository,
+                &self.history_repository,
+                &self.hasher,
+                &self.policy,
+                &user,
+                password,
+            )
+            .await?;
+            end_sessions(
+                &self.revocation_store,
+                &self.session_repository,
+                &id,
+                None,
+                elapsed.as_millis() as u64,
+            )
+            .await?;
+        }
+
+        // Profile fields are the user's own business; the audit log keeps identity changes.
+        let audit: AuditEntry = entry(actor, AuditAction::UserUpdated)
+            .with_target(id)
+            .with_change(
+                "username",
+                Some(user.username.as_str()),
+                Some(updated_user.username.as_str()),
+            )
+            .with_change(
+                "name",
+                Some(user.name.as_str()),
+                Some(updated_user.name.as_str()),
+            )
+            .with_change(
+                "email",
+                user.email.as_ref().map(Email::as_str),
+                updated_user.email.as_ref().map(Email::as_str),
+            )
+            .with_change(
+                "password",
+                None::<&str>,
+                password_changed.then_some("changed"),
+            );
+
+        if !audit.changes.is_empty() {
+            record(&self.audit_log, audit).await;
+        }
+
+        if updated_user.name != user.name
+            || updated_user.username != user.username
+            || updated_user.profile != user.profile
+        {
+            self.publisher
+                .publish(Event::ProfileUpdated(ProfileUpdated::new(
+                    &updated_user,
+                    now,
+                )))
+                .await;
+        }
 
         Ok(UpdateUserOutput::from(updated_user))
     }
@@ -89,12 +271,32 @@ pub struct UpdateUserInput {
     pub username: Option<String>,
     pub name: Option<String>,
     pub password: Option<String>,
+    pub email: Option<String>,
+    pub status_text: Option<String>,
+    pub status_emoji: Option<String>,
+    pub bio: Option<String>,
+    pub timezone: Option<String>,
+    pub locale: Option<String>,
+}
+
+fn profile_field<T>(
+    raw: Option<String>,
+    parse: impl Fn(String) -> Result<T, UserError>,
+) -> Result<Option<Option<T>>, UserError> {
+    match raw {
+        Some(raw) if raw.trim().is_empty() => Ok(Some(None)),
+        Some(raw) => parse(raw).map(|value| Some(Some(value))),
+        None => Ok(None),
+    }
 }
 
 pub struct UpdateUserOutput {
     pub id: Uuid,
     pub username: String,
     pub name: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub profile: Profile,
 }
 
 impl From<User> for UpdateUserOutput {
@@ -103,6 +305,9 @@ impl From<User> for UpdateUserOutput {
             id: user.id,
             username: user.username.as_str().into(),
             name: user.name.as_str().into(),
+            email_verified: user.verified_email().is_some(),
+            email: user.email.map(|email| email.as_str().into()),
+            profile: user.profile,
         }
     }
 }
@@ -111,8 +316,11 @@ pub enum UpdateUserError {
     UserError(UserError),
     NotFound,
     AlreadyExists,
+    EmailAlreadyExists,
     InfrastructureError,
     Forbidden,
+    /// Only administrators set passwords directly, users go through the change-password flow.
+    PasswordChangeRequiresCurrent,
 }
 
 impl From<UserError> for UpdateUserError {
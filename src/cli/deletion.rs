@@ -0,0 +1,13 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "DELETION OPTIONS")]
+pub struct DeletionCli {
+    /// Days during which a deleted user can be restored before being anonymized
+    #[arg(long)]
+    pub deletion_grace_period: Option<String>,
+
+    /// Minutes between two runs of the job anonymizing deleted users
+    #[arg(long)]
+    pub deletion_purge_interval: Option<String>,
+}
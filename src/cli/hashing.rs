@@ -0,0 +1,21 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "PASSWORD HASHING OPTIONS")]
+pub struct HashingCli {
+    /// Argon2 memory cost in KiB
+    #[arg(long)]
+    pub argon2_memory: Option<String>,
+
+    /// Argon2 number of iterations
+    #[arg(long)]
+    pub argon2_iterations: Option<String>,
+
+    /// Argon2 degree of parallelism
+    #[arg(long)]
+    pub argon2_parallelism: Option<String>,
+
+    /// Secret mixed into every new password hash, kept out of the database
+    #[arg(long)]
+    pub password_pepper: Option<String>,
+}
@@ -22,4 +22,16 @@ pub struct HttpCli {
     /// Refresh token time to live in seconds
     #[arg(long)]
     pub refresh_token_ttl: Option<String>,
+
+    /// Seconds revoked tokens may still be accepted by other server instances [default: 30]
+    #[arg(long)]
+    pub revocation_cache_ttl: Option<String>,
+
+    /// Public base URL of the server, used as the OpenID Connect issuer
+    #[arg(long)]
+    pub public_url: Option<String>,
+
+    /// Path to a PKCS#8 PEM encoded P-256 key used to sign id_tokens
+    #[arg(long)]
+    pub id_token_key: Option<String>,
 }
@@ -0,0 +1,50 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "LDAP OPTIONS")]
+pub struct LdapCli {
+    /// Directory URL (ldap:// or ldaps://), enables LDAP authentication
+    #[arg(long)]
+    pub ldap_url: Option<String>,
+
+    /// DN users bind as, `{username}` is replaced with the escaped username
+    #[arg(long)]
+    pub ldap_bind_dn: Option<String>,
+
+    /// Base DN under which user entries are searched
+    #[arg(long)]
+    pub ldap_base_dn: Option<String>,
+
+    /// Attribute holding the username (e.g. uid, sAMAccountName)
+    #[arg(long)]
+    pub ldap_username_attribute: Option<String>,
+
+    /// Attribute holding the display name
+    #[arg(long)]
+    pub ldap_name_attribute: Option<String>,
+
+    /// Attribute listing the groups a user belongs to
+    #[arg(long)]
+    pub ldap_group_attribute: Option<String>,
+
+    /// DN of the group whose members are administrators
+    #[arg(long)]
+    pub ldap_admin_group: Option<String>,
+
+    /// DN of the group users must belong to in order to log in
+    #[arg(long)]
+    pub ldap_user_group: Option<String>,
+
+    /// Directory timeout in seconds
+    #[arg(long)]
+    pub ldap_timeout: Option<String>,
+
+    /// Comma-separated ids of the organizations directory users may sign in to, the default
+    /// organization when unset
+    #[arg(long)]
+    pub ldap_organizations: Option<String>,
+
+    /// Let directory logins take over local users with the same username (true or false)
+    #[arg(long)]
+    pub ldap_link_local_users: Option<String>,
+}
@@ -0,0 +1,21 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "LOCKOUT OPTIONS")]
+pub struct LockoutCli {
+    /// Failed password attempts after which a username is locked out
+    #[arg(long)]
+    pub lockout_max_failures: Option<String>,
+
+    /// Failed password attempts after which a client IP address is locked out
+    #[arg(long)]
+    pub lockout_ip_max_failures: Option<String>,
+
+    /// Delay in seconds after the first failed attempt, doubled with every further failure
+    #[arg(long)]
+    pub lockout_base_delay: Option<String>,
+
+    /// Lockout duration in minutes, failures older than this are forgotten
+    #[arg(long)]
+    pub lockout_duration: Option<String>,
+}
@@ -1,8 +1,17 @@
 use clap::Parser;
 
 pub mod database;
+pub mod deletion;
+pub mod hashing;
 pub mod http;
+pub mod ldap;
+pub mod lockout;
 pub mod logging;
+pub mod oidc;
+pub mod password;
+pub mod registration;
+pub mod smtp;
+pub mod webhook;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -20,4 +29,31 @@ pub struct Cli {
 
     #[command(flatten)]
     pub database: database::DatabaseCli,
+
+    #[command(flatten)]
+    pub deletion: deletion::DeletionCli,
+
+    #[command(flatten)]
+    pub hashing: hashing::HashingCli,
+
+    #[command(flatten)]
+    pub ldap: ldap::LdapCli,
+
+    #[command(flatten)]
+    pub lockout: lockout::LockoutCli,
+
+    #[command(flatten)]
+    pub oidc: oidc::OidcCli,
+
+    #[command(flatten)]
+    pub password: password::PasswordCli,
+
+    #[command(flatten)]
+    pub registration: registration::RegistrationCli,
+
+    #[command(flatten)]
+    pub smtp: smtp::SmtpCli,
+
+    #[command(flatten)]
+    pub webhook: webhook::WebhookCli,
 }
@@ -0,0 +1,11 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "OIDC FEDERATION OPTIONS")]
+pub struct OidcCli {
+    /// Upstream OpenID Connect provider users can sign in with, as
+    /// `name=<name>,issuer=<url>,client_id=<id>,client_secret=<secret>[,scope=<scopes>]`.
+    /// May be repeated
+    #[arg(long)]
+    pub oidc_provider: Vec<String>,
+}
@@ -0,0 +1,25 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "PASSWORD POLICY OPTIONS")]
+pub struct PasswordCli {
+    /// Minimum password length in characters
+    #[arg(long)]
+    pub password_min_length: Option<String>,
+
+    /// Character classes every password needs, comma-separated: lowercase, uppercase, digit, symbol
+    #[arg(long)]
+    pub password_required_classes: Option<String>,
+
+    /// Days after which passwords expire and must be reset, they never expire when unset
+    #[arg(long)]
+    pub password_max_age: Option<String>,
+
+    /// Number of former passwords that cannot be chosen again, 0 to allow reuse
+    #[arg(long)]
+    pub password_history: Option<String>,
+
+    /// File of breached passwords or SHA-1 hashes, or a directory of k-anonymity range files
+    #[arg(long)]
+    pub password_breach_list: Option<String>,
+}
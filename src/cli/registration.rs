@@ -0,0 +1,17 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "REGISTRATION OPTIONS")]
+pub struct RegistrationCli {
+    /// Who can sign up: closed, invite-only or open
+    #[arg(long)]
+    pub registration_mode: Option<String>,
+
+    /// Verification endpoint of the CAPTCHA provider, checked on open registration
+    #[arg(long)]
+    pub captcha_verify_url: Option<String>,
+
+    /// Secret key for the CAPTCHA provider
+    #[arg(long)]
+    pub captcha_secret: Option<String>,
+}
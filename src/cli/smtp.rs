@@ -0,0 +1,35 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "SMTP OPTIONS")]
+pub struct SmtpCli {
+    /// Mail server URL (smtp:// with STARTTLS, or smtps://), enables email
+    #[arg(long)]
+    pub smtp_url: Option<String>,
+
+    /// Sender address of the emails
+    #[arg(long)]
+    pub smtp_from: Option<String>,
+
+    /// Address of users without a verified email address, `{username}` is replaced with their
+    /// username
+    #[arg(long)]
+    pub smtp_recipient: Option<String>,
+
+    /// Username to authenticate to the mail server with
+    #[arg(long)]
+    pub smtp_username: Option<String>,
+
+    /// Password to authenticate to the mail server with
+    #[arg(long)]
+    pub smtp_password: Option<String>,
+
+    /// Mail server timeout in seconds
+    #[arg(long)]
+    pub smtp_timeout: Option<String>,
+
+    /// TLS for smtp:// URLs: required (default) or none to send in clear text when the server
+    /// does not offer STARTTLS
+    #[arg(long)]
+    pub smtp_tls: Option<String>,
+}
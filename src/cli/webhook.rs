@@ -0,0 +1,17 @@
+use clap::Args;
+
+#[derive(Args, Debug)]
+#[command(next_help_heading = "WEBHOOK OPTIONS")]
+pub struct WebhookCli {
+    /// Endpoint receiving user events such as profile updates, as JSON
+    #[arg(long)]
+    pub webhook_url: Option<String>,
+
+    /// Secret signing each event with HMAC-SHA256
+    #[arg(long)]
+    pub webhook_secret: Option<String>,
+
+    /// Seconds to wait for the endpoint
+    #[arg(long)]
+    pub webhook_timeout: Option<String>,
+}
@@ -19,7 +19,7 @@ impl DatabaseConfigProvider for CliDatabaseConfig {
             .ok_or(ConfigError::Missing("database-url"))?;
 
         if !is_valid_jdbc_url(&database_url) {
-            return Err(ConfigError::Invalid("database-url".into()));
+            return Err(ConfigError::Invalid("database-url"));
         }
 
         Ok(DatabaseConfig { database_url })
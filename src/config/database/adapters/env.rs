@@ -9,8 +9,8 @@ impl DatabaseConfigProvider for EnvDatabaseConfig {
     fn load() -> Result<DatabaseConfig, ConfigError> {
         dotenvy::dotenv().ok();
 
-        let database_url: String = std::env::var("DATABASE_URL")
-            .map_err(|_| ConfigError::Missing("DATABASE_URL".into()))?;
+        let database_url: String =
+            std::env::var("DATABASE_URL").map_err(|_| ConfigError::Missing("DATABASE_URL"))?;
 
         Ok(DatabaseConfig { database_url })
     }
@@ -0,0 +1,36 @@
+use crate::{
+    cli::{Cli, deletion::DeletionCli},
+    config::{
+        ConfigError,
+        deletion::ports::{
+            DEFAULT_GRACE_PERIOD, DEFAULT_PURGE_INTERVAL, DeletionConfig, DeletionConfigProvider,
+        },
+        lockout::ports::parse_setting,
+    },
+};
+use clap::Parser;
+
+pub struct CliDeletionConfig();
+
+impl DeletionConfigProvider for CliDeletionConfig {
+    fn load() -> Result<DeletionConfig, ConfigError> {
+        let args: DeletionCli = Cli::parse_from(std::env::args_os()).deletion;
+
+        if args.deletion_grace_period.is_none() && args.deletion_purge_interval.is_none() {
+            return Err(ConfigError::Missing("deletion-*"));
+        }
+
+        Ok(DeletionConfig {
+            grace_period: parse_setting(
+                args.deletion_grace_period,
+                DEFAULT_GRACE_PERIOD,
+                "deletion-grace-period",
+            )?,
+            purge_interval: parse_setting(
+                args.deletion_purge_interval,
+                DEFAULT_PURGE_INTERVAL,
+                "deletion-purge-interval",
+            )?,
+        })
+    }
+}
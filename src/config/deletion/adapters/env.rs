@@ -0,0 +1,35 @@
+use crate::config::{
+    ConfigError,
+    deletion::ports::{
+        DEFAULT_GRACE_PERIOD, DEFAULT_PURGE_INTERVAL, DeletionConfig, DeletionConfigProvider,
+    },
+    lockout::ports::parse_setting,
+};
+
+pub struct EnvDeletionConfig;
+
+impl DeletionConfigProvider for EnvDeletionConfig {
+    fn load() -> Result<DeletionConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let grace_period: Option<String> = std::env::var("DELETION_GRACE_PERIOD").ok();
+        let purge_interval: Option<String> = std::env::var("DELETION_PURGE_INTERVAL").ok();
+
+        if grace_period.is_none() && purge_interval.is_none() {
+            return Err(ConfigError::Missing("DELETION_*"));
+        }
+
+        Ok(DeletionConfig {
+            grace_period: parse_setting(
+                grace_period,
+                DEFAULT_GRACE_PERIOD,
+                "DELETION_GRACE_PERIOD",
+            )?,
+            purge_interval: parse_setting(
+                purge_interval,
+                DEFAULT_PURGE_INTERVAL,
+                "DELETION_PURGE_INTERVAL",
+            )?,
+        })
+    }
+}
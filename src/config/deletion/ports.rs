@@ -0,0 +1,25 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_GRACE_PERIOD: u64 = 30;
+pub const DEFAULT_PURGE_INTERVAL: u64 = 60;
+
+#[derive(Clone)]
+pub struct DeletionConfig {
+    /// Days during which a deleted user can be restored, before they are anonymized.
+    pub grace_period: u64,
+    /// Minutes between two runs of the purge job.
+    pub purge_interval: u64,
+}
+
+impl Default for DeletionConfig {
+    fn default() -> Self {
+        Self {
+            grace_period: DEFAULT_GRACE_PERIOD,
+            purge_interval: DEFAULT_PURGE_INTERVAL,
+        }
+    }
+}
+
+pub trait DeletionConfigProvider {
+    fn load() -> Result<DeletionConfig, ConfigError>;
+}
@@ -0,0 +1,46 @@
+use crate::{
+    cli::{Cli, hashing::HashingCli},
+    config::{
+        ConfigError,
+        hashing::ports::{
+            DEFAULT_ITERATIONS, DEFAULT_MEMORY, DEFAULT_PARALLELISM, HashingConfig,
+            HashingConfigProvider, parse_cost,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliHashingConfig();
+
+impl HashingConfigProvider for CliHashingConfig {
+    fn load() -> Result<HashingConfig, ConfigError> {
+        let args: HashingCli = Cli::parse_from(std::env::args_os()).hashing;
+
+        if args.argon2_memory.is_none()
+            && args.argon2_iterations.is_none()
+            && args.argon2_parallelism.is_none()
+            && args.password_pepper.is_none()
+        {
+            return Err(ConfigError::Missing("argon2-*"));
+        }
+
+        if args.password_pepper.as_ref().is_some_and(String::is_empty) {
+            return Err(ConfigError::Invalid("password-pepper"));
+        }
+
+        Ok(HashingConfig {
+            memory: parse_cost(args.argon2_memory, DEFAULT_MEMORY, "argon2-memory")?,
+            iterations: parse_cost(
+                args.argon2_iterations,
+                DEFAULT_ITERATIONS,
+                "argon2-iterations",
+            )?,
+            parallelism: parse_cost(
+                args.argon2_parallelism,
+                DEFAULT_PARALLELISM,
+                "argon2-parallelism",
+            )?,
+            pepper: args.password_pepper,
+        })
+    }
+}
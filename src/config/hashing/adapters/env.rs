@@ -0,0 +1,35 @@
+use crate::config::{
+    ConfigError,
+    hashing::ports::{
+        DEFAULT_ITERATIONS, DEFAULT_MEMORY, DEFAULT_PARALLELISM, HashingConfig,
+        HashingConfigProvider, parse_cost,
+    },
+};
+
+pub struct EnvHashingConfig;
+
+impl HashingConfigProvider for EnvHashingConfig {
+    fn load() -> Result<HashingConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let memory: Option<String> = std::env::var("ARGON2_MEMORY").ok();
+        let iterations: Option<String> = std::env::var("ARGON2_ITERATIONS").ok();
+        let parallelism: Option<String> = std::env::var("ARGON2_PARALLELISM").ok();
+        let pepper: Option<String> = std::env::var("PASSWORD_PEPPER").ok();
+
+        if memory.is_none() && iterations.is_none() && parallelism.is_none() && pepper.is_none() {
+            return Err(ConfigError::Missing("ARGON2_*"));
+        }
+
+        if pepper.as_ref().is_some_and(String::is_empty) {
+            return Err(ConfigError::Invalid("PASSWORD_PEPPER"));
+        }
+
+        Ok(HashingConfig {
+            memory: parse_cost(memory, DEFAULT_MEMORY, "ARGON2_MEMORY")?,
+            iterations: parse_cost(iterations, DEFAULT_ITERATIONS, "ARGON2_ITERATIONS")?,
+            parallelism: parse_cost(parallelism, DEFAULT_PARALLELISM, "ARGON2_PARALLELISM")?,
+            pepper,
+        })
+    }
+}
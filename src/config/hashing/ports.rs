@@ -0,0 +1,43 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_MEMORY: u32 = 19 * 1024;
+pub const DEFAULT_ITERATIONS: u32 = 2;
+pub const DEFAULT_PARALLELISM: u32 = 1;
+
+/// Stored hashes made with other parameters are upgraded as their users log in.
+#[derive(Clone)]
+pub struct HashingConfig {
+    /// Memory cost in KiB.
+    pub memory: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub pepper: Option<String>,
+}
+
+impl Default for HashingConfig {
+    fn default() -> Self {
+        Self {
+            memory: DEFAULT_MEMORY,
+            iterations: DEFAULT_ITERATIONS,
+            parallelism: DEFAULT_PARALLELISM,
+            pepper: None,
+        }
+    }
+}
+
+pub trait HashingConfigProvider {
+    fn load() -> Result<HashingConfig, ConfigError>;
+}
+
+/// Parses an optional cost, falling back to `default` when it is not set. Argon2 checks the
+/// ranges itself when the hasher is built.
+pub fn parse_cost(
+    value: Option<String>,
+    default: u32,
+    name: &'static str,
+) -> Result<u32, ConfigError> {
+    match value {
+        Some(value) => value.parse().map_err(|_| ConfigError::Invalid(name)),
+        None => Ok(default),
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     cli::{Cli, http::HttpCli},
     config::{
         ConfigError,
-        http::ports::{HttpConfig, HttpConfigProvider},
+        http::ports::{DEFAULT_REVOCATION_CACHE_TTL, HttpConfig, HttpConfigProvider},
     },
 };
 use clap::Parser;
@@ -30,6 +30,15 @@ impl HttpConfigProvider for CliHttpConfig {
             .ok_or(ConfigError::Missing("refresh-token-ttl"))?
             .parse()
             .map_err(|_| ConfigError::Invalid("refresh-token-ttl"))?;
+        let revocation_cache_ttl: u64 = args
+            .revocation_cache_ttl
+            .map(|ttl| ttl.parse())
+            .transpose()
+            .map_err(|_| ConfigError::Invalid("revocation-cache-ttl"))?
+            .unwrap_or(DEFAULT_REVOCATION_CACHE_TTL);
+
+        let public_url: Option<String> = args.public_url;
+        let id_token_key: Option<String> = args.id_token_key;
 
         if 1024 > port {
             return Err(ConfigError::Invalid("http-port"));
@@ -45,6 +54,9 @@ impl HttpConfigProvider for CliHttpConfig {
             token_secret,
             token_ttl,
             refresh_token_ttl,
+            revocation_cache_ttl,
+            public_url,
+            id_token_key,
         })
     }
 }
@@ -2,7 +2,7 @@ use std::net::IpAddr;
 
 use crate::config::{
     ConfigError,
-    http::ports::{HttpConfig, HttpConfigProvider},
+    http::ports::{DEFAULT_REVOCATION_CACHE_TTL, HttpConfig, HttpConfigProvider},
 };
 
 pub struct EnvHttpConfig;
@@ -28,6 +28,15 @@ impl HttpConfigProvider for EnvHttpConfig {
             .map_err(|_| ConfigError::Missing("REFRESH_TOKEN_TTL"))?
             .parse()
             .map_err(|_| ConfigError::Invalid("REFRESH_TOKEN_TTL"))?;
+        let revocation_cache_ttl: u64 = std::env::var("REVOCATION_CACHE_TTL")
+            .ok()
+            .map(|ttl| ttl.parse())
+            .transpose()
+            .map_err(|_| ConfigError::Invalid("REVOCATION_CACHE_TTL"))?
+            .unwrap_or(DEFAULT_REVOCATION_CACHE_TTL);
+
+        let public_url: Option<String> = std::env::var("PUBLIC_URL").ok();
+        let id_token_key: Option<String> = std::env::var("ID_TOKEN_KEY").ok();
 
         if !is_valid_host(&host) {
             return Err(ConfigError::Invalid("HTTP_HOST"));
@@ -39,6 +48,9 @@ impl HttpConfigProvider for EnvHttpConfig {
             token_secret,
             token_ttl,
             refresh_token_ttl,
+            revocation_cache_ttl,
+            public_url,
+            id_token_key,
         })
     }
 }
@@ -1,5 +1,8 @@
 use crate::config::ConfigError;
 
+/// How long, in seconds, per-user token cutoffs are cached by default.
+pub const DEFAULT_REVOCATION_CACHE_TTL: u64 = 30;
+
 #[derive(Clone)]
 pub struct HttpConfig {
     pub host: String,
@@ -7,6 +10,10 @@ pub struct HttpConfig {
     pub token_secret: String,
     pub token_ttl: u64,
     pub refresh_token_ttl: u64,
+    /// How long a server instance may take to notice tokens revoked by another one.
+    pub revocation_cache_ttl: u64,
+    pub public_url: Option<String>,
+    pub id_token_key: Option<String>,
 }
 
 pub trait HttpConfigProvider {
@@ -0,0 +1,62 @@
+use crate::{
+    cli::{Cli, ldap::LdapCli},
+    config::{
+        ConfigError,
+        ldap::ports::{
+            DEFAULT_GROUP_ATTRIBUTE, DEFAULT_NAME_ATTRIBUTE, DEFAULT_TIMEOUT,
+            DEFAULT_USERNAME_ATTRIBUTE, LdapConfig, LdapConfigProvider, USERNAME_PLACEHOLDER,
+            is_valid_url, parse_flag, parse_organizations,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliLdapConfig();
+
+impl LdapConfigProvider for CliLdapConfig {
+    fn load() -> Result<LdapConfig, ConfigError> {
+        let args: LdapCli = Cli::parse_from(std::env::args_os()).ldap;
+
+        let url: String = args.ldap_url.ok_or(ConfigError::Missing("ldap-url"))?;
+        let bind_dn: String = args
+            .ldap_bind_dn
+            .ok_or(ConfigError::Missing("ldap-bind-dn"))?;
+        let base_dn: String = args
+            .ldap_base_dn
+            .ok_or(ConfigError::Missing("ldap-base-dn"))?;
+        let timeout: u64 = match args.ldap_timeout {
+            Some(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("ldap-timeout"))?,
+            None => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("ldap-url"));
+        }
+
+        if !bind_dn.contains(USERNAME_PLACEHOLDER) {
+            return Err(ConfigError::Invalid("ldap-bind-dn"));
+        }
+
+        Ok(LdapConfig {
+            url,
+            bind_dn,
+            base_dn,
+            username_attribute: args
+                .ldap_username_attribute
+                .unwrap_or_else(|| DEFAULT_USERNAME_ATTRIBUTE.into()),
+            name_attribute: args
+                .ldap_name_attribute
+                .unwrap_or_else(|| DEFAULT_NAME_ATTRIBUTE.into()),
+            group_attribute: args
+                .ldap_group_attribute
+                .unwrap_or_else(|| DEFAULT_GROUP_ATTRIBUTE.into()),
+            admin_group: args.ldap_admin_group,
+            user_group: args.ldap_user_group,
+            timeout,
+            organizations: parse_organizations(args.ldap_organizations, "ldap-organizations")?,
+            link_local_users: parse_flag(args.ldap_link_local_users, "ldap-link-local-users")?,
+        })
+    }
+}
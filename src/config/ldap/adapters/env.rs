@@ -0,0 +1,60 @@
+use crate::config::{
+    ConfigError,
+    ldap::ports::{
+        DEFAULT_GROUP_ATTRIBUTE, DEFAULT_NAME_ATTRIBUTE, DEFAULT_TIMEOUT,
+        DEFAULT_USERNAME_ATTRIBUTE, LdapConfig, LdapConfigProvider, USERNAME_PLACEHOLDER,
+        is_valid_url, parse_flag, parse_organizations,
+    },
+};
+
+pub struct EnvLdapConfig;
+
+impl LdapConfigProvider for EnvLdapConfig {
+    fn load() -> Result<LdapConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let url: String =
+            std::env::var("LDAP_URL").map_err(|_| ConfigError::Missing("LDAP_URL"))?;
+        let bind_dn: String =
+            std::env::var("LDAP_BIND_DN").map_err(|_| ConfigError::Missing("LDAP_BIND_DN"))?;
+        let base_dn: String =
+            std::env::var("LDAP_BASE_DN").map_err(|_| ConfigError::Missing("LDAP_BASE_DN"))?;
+        let timeout: u64 = match std::env::var("LDAP_TIMEOUT") {
+            Ok(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("LDAP_TIMEOUT"))?,
+            Err(_) => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("LDAP_URL"));
+        }
+
+        if !bind_dn.contains(USERNAME_PLACEHOLDER) {
+            return Err(ConfigError::Invalid("LDAP_BIND_DN"));
+        }
+
+        Ok(LdapConfig {
+            url,
+            bind_dn,
+            base_dn,
+            username_attribute: std::env::var("LDAP_USERNAME_ATTRIBUTE")
+                .unwrap_or_else(|_| DEFAULT_USERNAME_ATTRIBUTE.into()),
+            name_attribute: std::env::var("LDAP_NAME_ATTRIBUTE")
+                .unwrap_or_else(|_| DEFAULT_NAME_ATTRIBUTE.into()),
+            group_attribute: std::env::var("LDAP_GROUP_ATTRIBUTE")
+                .unwrap_or_else(|_| DEFAULT_GROUP_ATTRIBUTE.into()),
+            admin_group: std::env::var("LDAP_ADMIN_GROUP").ok(),
+            user_group: std::env::var("LDAP_USER_GROUP").ok(),
+            timeout,
+            organizations: parse_organizations(
+                std::env::var("LDAP_ORGANIZATIONS").ok(),
+                "LDAP_ORGANIZATIONS",
+            )?,
+            link_local_users: parse_flag(
+                std::env::var("LDAP_LINK_LOCAL_USERS").ok(),
+                "LDAP_LINK_LOCAL_USERS",
+            )?,
+        })
+    }
+}
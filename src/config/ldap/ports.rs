@@ -0,0 +1,63 @@
+use crate::config::ConfigError;
+use uuid::Uuid;
+
+pub const DEFAULT_USERNAME_ATTRIBUTE: &str = "uid";
+pub const DEFAULT_NAME_ATTRIBUTE: &str = "cn";
+pub const DEFAULT_GROUP_ATTRIBUTE: &str = "memberOf";
+pub const DEFAULT_TIMEOUT: u64 = 5;
+
+/// Placeholder replaced with the username in `bind_dn`.
+pub const USERNAME_PLACEHOLDER: &str = "{username}";
+
+#[derive(Clone)]
+pub struct LdapConfig {
+    pub url: String,
+    pub bind_dn: String,
+    pub base_dn: String,
+    pub username_attribute: String,
+    pub name_attribute: String,
+    pub group_attribute: String,
+    pub admin_group: Option<String>,
+    pub user_group: Option<String>,
+    pub timeout: u64,
+    /// Organizations directory users may sign in to, by id. Only the default organization
+    /// when empty.
+    pub organizations: Vec<Uuid>,
+    /// Whether a directory login may take over the local user with the same username, who
+    /// then signs in through the directory only.
+    pub link_local_users: bool,
+}
+
+pub trait LdapConfigProvider {
+    fn load() -> Result<LdapConfig, ConfigError>;
+}
+
+pub fn parse_flag(value: Option<String>, name: &'static str) -> Result<bool, ConfigError> {
+    match value.as_deref() {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(_) => Err(ConfigError::Invalid(name)),
+    }
+}
+
+/// Parses a comma-separated list of organization ids.
+pub fn parse_organizations(
+    value: Option<String>,
+    name: &'static str,
+) -> Result<Vec<Uuid>, ConfigError> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| Uuid::try_parse(id).map_err(|_| ConfigError::Invalid(name)))
+        .collect()
+}
+
+pub fn is_valid_url(url: &str) -> bool {
+    url::Url::parse(url)
+        .is_ok_and(|url| matches!(url.scheme(), "ldap" | "ldaps") && url.host_str().is_some())
+}
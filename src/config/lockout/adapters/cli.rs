@@ -0,0 +1,46 @@
+use crate::{
+    cli::{Cli, lockout::LockoutCli},
+    config::{
+        ConfigError,
+        lockout::ports::{
+            DEFAULT_BASE_DELAY, DEFAULT_DURATION, DEFAULT_IP_MAX_FAILURES, DEFAULT_MAX_FAILURES,
+            LockoutConfig, LockoutConfigProvider, parse_setting,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliLockoutConfig();
+
+impl LockoutConfigProvider for CliLockoutConfig {
+    fn load() -> Result<LockoutConfig, ConfigError> {
+        let args: LockoutCli = Cli::parse_from(std::env::args_os()).lockout;
+
+        if args.lockout_max_failures.is_none()
+            && args.lockout_ip_max_failures.is_none()
+            && args.lockout_base_delay.is_none()
+            && args.lockout_duration.is_none()
+        {
+            return Err(ConfigError::Missing("lockout-*"));
+        }
+
+        Ok(LockoutConfig {
+            max_failures: parse_setting(
+                args.lockout_max_failures,
+                DEFAULT_MAX_FAILURES,
+                "lockout-max-failures",
+            )?,
+            ip_max_failures: parse_setting(
+                args.lockout_ip_max_failures,
+                DEFAULT_IP_MAX_FAILURES,
+                "lockout-ip-max-failures",
+            )?,
+            base_delay: parse_setting(
+                args.lockout_base_delay,
+                DEFAULT_BASE_DELAY,
+                "lockout-base-delay",
+            )?,
+            duration: parse_setting(args.lockout_duration, DEFAULT_DURATION, "lockout-duration")?,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use crate::config::{
+    ConfigError,
+    lockout::ports::{
+        DEFAULT_BASE_DELAY, DEFAULT_DURATION, DEFAULT_IP_MAX_FAILURES, DEFAULT_MAX_FAILURES,
+        LockoutConfig, LockoutConfigProvider, parse_setting,
+    },
+};
+
+pub struct EnvLockoutConfig;
+
+impl LockoutConfigProvider for EnvLockoutConfig {
+    fn load() -> Result<LockoutConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let max_failures: Option<String> = std::env::var("LOCKOUT_MAX_FAILURES").ok();
+        let ip_max_failures: Option<String> = std::env::var("LOCKOUT_IP_MAX_FAILURES").ok();
+        let base_delay: Option<String> = std::env::var("LOCKOUT_BASE_DELAY").ok();
+        let duration: Option<String> = std::env::var("LOCKOUT_DURATION").ok();
+
+        if max_failures.is_none()
+            && ip_max_failures.is_none()
+            && base_delay.is_none()
+            && duration.is_none()
+        {
+            return Err(ConfigError::Missing("LOCKOUT_*"));
+        }
+
+        Ok(LockoutConfig {
+            max_failures: parse_setting(
+                max_failures,
+                DEFAULT_MAX_FAILURES,
+                "LOCKOUT_MAX_FAILURES",
+            )?,
+            ip_max_failures: parse_setting(
+                ip_max_failures,
+                DEFAULT_IP_MAX_FAILURES,
+                "LOCKOUT_IP_MAX_FAILURES",
+            )?,
+            base_delay: parse_setting(base_delay, DEFAULT_BASE_DELAY, "LOCKOUT_BASE_DELAY")?,
+            duration: parse_setting(duration, DEFAULT_DURATION, "LOCKOUT_DURATION")?,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_MAX_FAILURES: u32 = 5;
+pub const DEFAULT_IP_MAX_FAILURES: u32 = 20;
+pub const DEFAULT_BASE_DELAY: u64 = 1;
+pub const DEFAULT_DURATION: u64 = 15;
+
+#[derive(Clone)]
+pub struct LockoutConfig {
+    pub max_failures: u32,
+    pub ip_max_failures: u32,
+    pub base_delay: u64,
+    pub duration: u64,
+}
+
+impl Default for LockoutConfig {
+    fn default() -> Self {
+        Self {
+            max_failures: DEFAULT_MAX_FAILURES,
+            ip_max_failures: DEFAULT_IP_MAX_FAILURES,
+            base_delay: DEFAULT_BASE_DELAY,
+            duration: DEFAULT_DURATION,
+        }
+    }
+}
+
+pub trait LockoutConfigProvider {
+    fn load() -> Result<LockoutConfig, ConfigError>;
+}
+
+/// Parses an optional setting, falling back to `default` when it is not set. Thresholds and
+/// durations of zero would disable the protection, so they are rejected.
+pub fn parse_setting<T>(
+    value: Option<String>,
+    default: T,
+    name: &'static str,
+) -> Result<T, ConfigError>
+where
+    T: std::str::FromStr + PartialEq + Default,
+{
+    match value {
+        Some(value) => match value.parse::<T>() {
+            Ok(parsed) if parsed != T::default() => Ok(parsed),
+            _ => Err(ConfigError::Invalid(name)),
+        },
+        None => Ok(default),
+    }
+}
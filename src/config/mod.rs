@@ -1,26 +1,80 @@
 pub mod database;
+pub mod deletion;
+pub mod hashing;
 pub mod http;
+pub mod ldap;
+pub mod lockout;
 pub mod logging;
+pub mod oidc;
+pub mod password;
+pub mod registration;
+pub mod smtp;
+pub mod webhook;
 
 use database::{
     adapters::{cli::CliDatabaseConfig, env::EnvDatabaseConfig},
     ports::{DatabaseConfig, DatabaseConfigProvider},
 };
+use deletion::{
+    adapters::{cli::CliDeletionConfig, env::EnvDeletionConfig},
+    ports::{DeletionConfig, DeletionConfigProvider},
+};
+use hashing::{
+    adapters::{cli::CliHashingConfig, env::EnvHashingConfig},
+    ports::{HashingConfig, HashingConfigProvider},
+};
 use http::{
     adapters::{cli::CliHttpConfig, env::EnvHttpConfig},
     ports::{HttpConfig, HttpConfigProvider},
 };
+use ldap::{
+    adapters::{cli::CliLdapConfig, env::EnvLdapConfig},
+    ports::{LdapConfig, LdapConfigProvider},
+};
+use lockout::{
+    adapters::{cli::CliLockoutConfig, env::EnvLockoutConfig},
+    ports::{LockoutConfig, LockoutConfigProvider},
+};
 use logging::{
     adapters::{cli::CliLoggingConfig, env::EnvLoggingConfig},
     ports::{LoggingConfig, LoggingConfigProvider},
 };
+use oidc::{
+    adapters::{cli::CliOidcConfig, env::EnvOidcConfig},
+    ports::{OidcConfigProvider, OidcProviderConfig},
+};
+use password::{
+    adapters::{cli::CliPasswordConfig, env::EnvPasswordConfig},
+    ports::{PasswordConfig, PasswordConfigProvider},
+};
+use registration::{
+    adapters::{cli::CliRegistrationConfig, env::EnvRegistrationConfig},
+    ports::{RegistrationConfig, RegistrationConfigProvider},
+};
+use smtp::{
+    adapters::{cli::CliSmtpConfig, env::EnvSmtpConfig},
+    ports::{SmtpConfig, SmtpConfigProvider},
+};
 use std::{error::Error, fmt::Display};
+use webhook::{
+    adapters::{cli::CliWebhookConfig, env::EnvWebhookConfig},
+    ports::{WebhookConfig, WebhookConfigProvider},
+};
 
 #[derive(Clone)]
 pub struct Config {
     pub http: HttpConfig,
     pub logging: LoggingConfig,
     pub database: DatabaseConfig,
+    pub deletion: DeletionConfig,
+    pub hashing: HashingConfig,
+    pub ldap: Option<LdapConfig>,
+    pub lockout: LockoutConfig,
+    pub oidc_providers: Vec<OidcProviderConfig>,
+    pub password: PasswordConfig,
+    pub registration: RegistrationConfig,
+    pub smtp: Option<SmtpConfig>,
+    pub webhook: Option<WebhookConfig>,
 }
 
 impl Config {
@@ -34,14 +88,60 @@ impl Config {
         let database: DatabaseConfig =
             merge_database(database_configs).expect("Failed to load database configuration");
 
+        let deletion_configs: Vec<Result<DeletionConfig, ConfigError>> =
+            vec![CliDeletionConfig::load(), EnvDeletionConfig::load()];
+        let deletion: DeletionConfig =
+            merge_deletion(deletion_configs).expect("Failed to load deletion configuration");
+
         let http: HttpConfig = merge_http(http_configs).expect("Failed to load HTTP configuration");
         let logging: LoggingConfig =
             merge_logging(logging_configs).expect("Failed to load logging configuration");
+        let hashing_configs: Vec<Result<HashingConfig, ConfigError>> =
+            vec![CliHashingConfig::load(), EnvHashingConfig::load()];
+        let hashing: HashingConfig =
+            merge_hashing(hashing_configs).expect("Failed to load password hashing configuration");
+        let ldap_configs: Vec<Result<LdapConfig, ConfigError>> =
+            vec![CliLdapConfig::load(), EnvLdapConfig::load()];
+        let ldap: Option<LdapConfig> =
+            merge_ldap(ldap_configs).expect("Failed to load LDAP configuration");
+        let lockout_configs: Vec<Result<LockoutConfig, ConfigError>> =
+            vec![CliLockoutConfig::load(), EnvLockoutConfig::load()];
+        let lockout: LockoutConfig =
+            merge_lockout(lockout_configs).expect("Failed to load lockout configuration");
+        let oidc_configs: Vec<Result<Vec<OidcProviderConfig>, ConfigError>> =
+            vec![CliOidcConfig::load(), EnvOidcConfig::load()];
+        let oidc_providers: Vec<OidcProviderConfig> =
+            merge_oidc(oidc_configs).expect("Failed to load OIDC federation configuration");
+        let password_configs: Vec<Result<PasswordConfig, ConfigError>> =
+            vec![CliPasswordConfig::load(), EnvPasswordConfig::load()];
+        let password: PasswordConfig =
+            merge_password(password_configs).expect("Failed to load password policy configuration");
+        let registration_configs: Vec<Result<RegistrationConfig, ConfigError>> =
+            vec![CliRegistrationConfig::load(), EnvRegistrationConfig::load()];
+        let registration: RegistrationConfig = merge_registration(registration_configs)
+            .expect("Failed to load registration configuration");
+        let smtp_configs: Vec<Result<SmtpConfig, ConfigError>> =
+            vec![CliSmtpConfig::load(), EnvSmtpConfig::load()];
+        let smtp: Option<SmtpConfig> =
+            merge_smtp(smtp_configs).expect("Failed to load SMTP configuration");
+        let webhook_configs: Vec<Result<WebhookConfig, ConfigError>> =
+            vec![CliWebhookConfig::load(), EnvWebhookConfig::load()];
+        let webhook: Option<WebhookConfig> =
+            merge_webhook(webhook_configs).expect("Failed to load webhook configuration");
 
         Ok(Self {
             http,
             logging,
             database,
+            deletion,
+            hashing,
+            ldap,
+            lockout,
+            oidc_providers,
+            password,
+            registration,
+            smtp,
+            webhook,
         })
     }
 }
@@ -60,19 +160,43 @@ fn merge_database(
     Ok(DatabaseConfig { database_url })
 }
 
+/// Like the lockout, deletion has defaults for every setting.
+fn merge_deletion(
+    configs: Vec<Result<DeletionConfig, ConfigError>>,
+) -> Result<DeletionConfig, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(DeletionConfig::default())
+}
+
 fn merge_http(configs: Vec<Result<HttpConfig, ConfigError>>) -> Result<HttpConfig, ConfigError> {
     let port: u16;
     let host: String;
     let token_secret: String;
     let token_ttl: u64;
     let refresh_token_ttl: u64;
+    let revocation_cache_ttl: u64;
+    let public_url: Option<String>;
+    let id_token_key: Option<String>;
 
     if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
         port = cfg.port;
         host = cfg.host.clone();
         token_secret = cfg.token_secret.clone();
-        token_ttl = cfg.token_ttl.clone();
-        refresh_token_ttl = cfg.refresh_token_ttl.clone();
+        token_ttl = cfg.token_ttl;
+        refresh_token_ttl = cfg.refresh_token_ttl;
+        revocation_cache_ttl = cfg.revocation_cache_ttl;
+        public_url = cfg.public_url.clone();
+        id_token_key = cfg.id_token_key.clone();
     } else {
         return Err(ConfigError::Missing("HTTP configuration"));
     }
@@ -83,6 +207,9 @@ fn merge_http(configs: Vec<Result<HttpConfig, ConfigError>>) -> Result<HttpConfi
         token_secret,
         token_ttl,
         refresh_token_ttl,
+        revocation_cache_ttl,
+        public_url,
+        id_token_key,
     })
 }
 
@@ -100,6 +227,151 @@ fn merge_logging(
     Ok(LoggingConfig { level })
 }
 
+/// Hashing has defaults for every setting, like the lockout.
+fn merge_hashing(
+    configs: Vec<Result<HashingConfig, ConfigError>>,
+) -> Result<HashingConfig, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(HashingConfig::default())
+}
+
+/// LDAP is optional: it is only enabled when one of the sources provides a complete
+/// configuration, but a source holding invalid values is still an error.
+fn merge_ldap(
+    configs: Vec<Result<LdapConfig, ConfigError>>,
+) -> Result<Option<LdapConfig>, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(Some(cfg.clone()));
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(None)
+}
+
+/// Every lockout setting has a default, so the defaults apply when no source sets any.
+fn merge_lockout(
+    configs: Vec<Result<LockoutConfig, ConfigError>>,
+) -> Result<LockoutConfig, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(LockoutConfig::default())
+}
+
+/// Federation is optional, like LDAP: no providers are configured unless a source lists some.
+fn merge_oidc(
+    configs: Vec<Result<Vec<OidcProviderConfig>, ConfigError>>,
+) -> Result<Vec<OidcProviderConfig>, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(Vec::new())
+}
+
+/// Like the lockout, the password policy has defaults for every setting.
+fn merge_password(
+    configs: Vec<Result<PasswordConfig, ConfigError>>,
+) -> Result<PasswordConfig, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(PasswordConfig::default())
+}
+
+/// Registration is closed unless a source opens it.
+fn merge_registration(
+    configs: Vec<Result<RegistrationConfig, ConfigError>>,
+) -> Result<RegistrationConfig, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(cfg.clone());
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(RegistrationConfig::default())
+}
+
+/// Email is optional, like LDAP: without it, notifications are only logged.
+fn merge_smtp(
+    configs: Vec<Result<SmtpConfig, ConfigError>>,
+) -> Result<Option<SmtpConfig>, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(Some(cfg.clone()));
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(None)
+}
+
+/// Events are optional too: without a webhook, they are only logged.
+fn merge_webhook(
+    configs: Vec<Result<WebhookConfig, ConfigError>>,
+) -> Result<Option<WebhookConfig>, ConfigError> {
+    if let Some(Ok(cfg)) = configs.iter().find(|r| r.is_ok()) {
+        return Ok(Some(cfg.clone()));
+    }
+
+    if let Some(Err(err)) = configs
+        .iter()
+        .find(|r| matches!(r, Err(ConfigError::Invalid(_))))
+    {
+        return Err(err.into());
+    }
+
+    Ok(None)
+}
+
 #[derive(Debug)]
 pub enum ConfigError {
     Missing(&'static str),
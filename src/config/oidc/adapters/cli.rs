@@ -0,0 +1,25 @@
+use crate::{
+    cli::{Cli, oidc::OidcCli},
+    config::{
+        ConfigError,
+        oidc::ports::{OidcConfigProvider, OidcProviderConfig, parse_provider},
+    },
+};
+use clap::Parser;
+
+pub struct CliOidcConfig();
+
+impl OidcConfigProvider for CliOidcConfig {
+    fn load() -> Result<Vec<OidcProviderConfig>, ConfigError> {
+        let args: OidcCli = Cli::parse_from(std::env::args_os()).oidc;
+
+        if args.oidc_provider.is_empty() {
+            return Err(ConfigError::Missing("oidc-provider"));
+        }
+
+        args.oidc_provider
+            .iter()
+            .map(|provider| parse_provider(provider, "oidc-provider"))
+            .collect()
+    }
+}
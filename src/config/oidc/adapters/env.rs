@@ -0,0 +1,22 @@
+use crate::config::{
+    ConfigError,
+    oidc::ports::{OidcConfigProvider, OidcProviderConfig, parse_provider},
+};
+
+pub struct EnvOidcConfig;
+
+impl OidcConfigProvider for EnvOidcConfig {
+    /// `OIDC_PROVIDERS` holds one or more provider definitions separated by `;`.
+    fn load() -> Result<Vec<OidcProviderConfig>, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let providers: String =
+            std::env::var("OIDC_PROVIDERS").map_err(|_| ConfigError::Missing("OIDC_PROVIDERS"))?;
+
+        providers
+            .split(';')
+            .filter(|provider| !provider.trim().is_empty())
+            .map(|provider| parse_provider(provider, "OIDC_PROVIDERS"))
+            .collect()
+    }
+}
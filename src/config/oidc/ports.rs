@@ -0,0 +1,67 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_SCOPE: &str = "openid profile email";
+
+#[derive(Clone)]
+pub struct OidcProviderConfig {
+    /// Name used in the `/auth/{provider}` routes.
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: String,
+}
+
+pub trait OidcConfigProvider {
+    fn load() -> Result<Vec<OidcProviderConfig>, ConfigError>;
+}
+
+/// Parses a `name=...,issuer=...,client_id=...,client_secret=...[,scope=...]` provider
+/// definition. Scopes are space-delimited.
+pub fn parse_provider(
+    value: &str,
+    source: &'static str,
+) -> Result<OidcProviderConfig, ConfigError> {
+    let mut name: Option<String> = None;
+    let mut issuer: Option<String> = None;
+    let mut client_id: Option<String> = None;
+    let mut client_secret: Option<String> = None;
+    let mut scope: Option<String> = None;
+
+    for pair in value.split(',') {
+        let (key, value) = pair.split_once('=').ok_or(ConfigError::Invalid(source))?;
+        let value: String = value.trim().to_owned();
+
+        match key.trim() {
+            "name" => name = Some(value),
+            "issuer" => issuer = Some(value),
+            "client_id" => client_id = Some(value),
+            "client_secret" => client_secret = Some(value),
+            "scope" => scope = Some(value),
+            _ => return Err(ConfigError::Invalid(source)),
+        }
+    }
+
+    let name: String = name.ok_or(ConfigError::Invalid(source))?;
+    let issuer: String = issuer.ok_or(ConfigError::Invalid(source))?;
+
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(ConfigError::Invalid(source));
+    }
+
+    if !url::Url::parse(&issuer).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+        return Err(ConfigError::Invalid(source));
+    }
+
+    Ok(OidcProviderConfig {
+        name,
+        issuer: issuer.trim_end_matches('/').to_owned(),
+        client_id: client_id.ok_or(ConfigError::Invalid(source))?,
+        client_secret: client_secret.ok_or(ConfigError::Invalid(source))?,
+        scope: scope.unwrap_or_else(|| DEFAULT_SCOPE.into()),
+    })
+}
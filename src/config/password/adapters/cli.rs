@@ -0,0 +1,46 @@
+use crate::{
+    cli::{Cli, password::PasswordCli},
+    config::{
+        ConfigError,
+        password::ports::{
+            PasswordConfig, PasswordConfigProvider, parse_classes, parse_history, parse_max_age,
+            parse_min_length,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliPasswordConfig();
+
+impl PasswordConfigProvider for CliPasswordConfig {
+    fn load() -> Result<PasswordConfig, ConfigError> {
+        let args: PasswordCli = Cli::parse_from(std::env::args_os()).password;
+
+        if args.password_min_length.is_none()
+            && args.password_required_classes.is_none()
+            && args.password_max_age.is_none()
+            && args.password_history.is_none()
+            && args.password_breach_list.is_none()
+        {
+            return Err(ConfigError::Missing("password-*"));
+        }
+
+        let [
+            require_lowercase,
+            require_uppercase,
+            require_digit,
+            require_symbol,
+        ] = parse_classes(args.password_required_classes, "password-required-classes")?;
+
+        Ok(PasswordConfig {
+            min_length: parse_min_length(args.password_min_length, "password-min-length")?,
+            require_lowercase,
+            require_uppercase,
+            require_digit,
+            require_symbol,
+            max_age: parse_max_age(args.password_max_age, "password-max-age")?,
+            history: parse_history(args.password_history, "password-history")?,
+            breach_list: args.password_breach_list,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::config::{
+    ConfigError,
+    password::ports::{
+        PasswordConfig, PasswordConfigProvider, parse_classes, parse_history, parse_max_age,
+        parse_min_length,
+    },
+};
+
+pub struct EnvPasswordConfig;
+
+impl PasswordConfigProvider for EnvPasswordConfig {
+    fn load() -> Result<PasswordConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let min_length: Option<String> = std::env::var("PASSWORD_MIN_LENGTH").ok();
+        let required_classes: Option<String> = std::env::var("PASSWORD_REQUIRED_CLASSES").ok();
+        let max_age: Option<String> = std::env::var("PASSWORD_MAX_AGE").ok();
+        let history: Option<String> = std::env::var("PASSWORD_HISTORY").ok();
+        let breach_list: Option<String> = std::env::var("PASSWORD_BREACH_LIST").ok();
+
+        if min_length.is_none()
+            && required_classes.is_none()
+            && max_age.is_none()
+            && history.is_none()
+            && breach_list.is_none()
+        {
+            return Err(ConfigError::Missing("PASSWORD_*"));
+        }
+
+        let [
+            require_lowercase,
+            require_uppercase,
+            require_digit,
+            require_symbol,
+        ] = parse_classes(required_classes, "PASSWORD_REQUIRED_CLASSES")?;
+
+        Ok(PasswordConfig {
+            min_length: parse_min_length(min_length, "PASSWORD_MIN_LENGTH")?,
+            require_lowercase,
+            require_uppercase,
+            require_digit,
+            require_symbol,
+            max_age: parse_max_age(max_age, "PASSWORD_MAX_AGE")?,
+            history: parse_history(history, "PASSWORD_HISTORY")?,
+            breach_list,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_MIN_LENGTH: usize = 8;
+pub const DEFAULT_HISTORY: u64 = 5;
+/// Longest password accepted at all, a policy cannot ask for more.
+pub const MAX_MIN_LENGTH: usize = 64;
+
+#[derive(Clone)]
+pub struct PasswordConfig {
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Days after which a password expires.
+    pub max_age: Option<u64>,
+    pub history: u64,
+    pub breach_list: Option<String>,
+}
+
+impl Default for PasswordConfig {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_MIN_LENGTH,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            max_age: None,
+            history: DEFAULT_HISTORY,
+            breach_list: None,
+        }
+    }
+}
+
+pub trait PasswordConfigProvider {
+    fn load() -> Result<PasswordConfig, ConfigError>;
+}
+
+pub fn parse_min_length(value: Option<String>, name: &'static str) -> Result<usize, ConfigError> {
+    match value {
+        Some(value) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|length| (1..=MAX_MIN_LENGTH).contains(length))
+            .ok_or(ConfigError::Invalid(name)),
+        None => Ok(DEFAULT_MIN_LENGTH),
+    }
+}
+
+/// Parses the required character classes into lowercase, uppercase, digit and symbol flags.
+pub fn parse_classes(value: Option<String>, name: &'static str) -> Result<[bool; 4], ConfigError> {
+    let mut classes: [bool; 4] = [false; 4];
+
+    for class in value.iter().flat_map(|value| value.split(',')) {
+        match class.trim() {
+            "lowercase" => classes[0] = true,
+            "uppercase" => classes[1] = true,
+            "digit" => classes[2] = true,
+            "symbol" => classes[3] = true,
+            "" => {}
+            _ => return Err(ConfigError::Invalid(name)),
+        }
+    }
+
+    Ok(classes)
+}
+
+pub fn parse_max_age(
+    value: Option<String>,
+    name: &'static str,
+) -> Result<Option<u64>, ConfigError> {
+    value
+        .map(|value| match value.parse::<u64>() {
+            Ok(days) if days > 0 => Ok(days),
+            _ => Err(ConfigError::Invalid(name)),
+        })
+        .transpose()
+}
+
+pub fn parse_history(value: Option<String>, name: &'static str) -> Result<u64, ConfigError> {
+    match value {
+        Some(value) => value.parse().map_err(|_| ConfigError::Invalid(name)),
+        None => Ok(DEFAULT_HISTORY),
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    cli::{Cli, registration::RegistrationCli},
+    config::{
+        ConfigError,
+        registration::ports::{
+            RegistrationConfig, RegistrationConfigProvider, parse_captcha, parse_mode,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliRegistrationConfig();
+
+impl RegistrationConfigProvider for CliRegistrationConfig {
+    fn load() -> Result<RegistrationConfig, ConfigError> {
+        let args: RegistrationCli = Cli::parse_from(std::env::args_os()).registration;
+
+        if args.registration_mode.is_none()
+            && args.captcha_verify_url.is_none()
+            && args.captcha_secret.is_none()
+        {
+            return Err(ConfigError::Missing("registration-mode"));
+        }
+
+        Ok(RegistrationConfig {
+            mode: parse_mode(args.registration_mode, "registration-mode")?,
+            captcha: parse_captcha(args.captcha_verify_url, args.captcha_secret, "captcha-*")?,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::{
+    ConfigError,
+    registration::ports::{
+        RegistrationConfig, RegistrationConfigProvider, parse_captcha, parse_mode,
+    },
+};
+
+pub struct EnvRegistrationConfig;
+
+impl RegistrationConfigProvider for EnvRegistrationConfig {
+    fn load() -> Result<RegistrationConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let mode: Option<String> = std::env::var("REGISTRATION_MODE").ok();
+        let captcha_verify_url: Option<String> = std::env::var("CAPTCHA_VERIFY_URL").ok();
+        let captcha_secret: Option<String> = std::env::var("CAPTCHA_SECRET").ok();
+
+        if mode.is_none() && captcha_verify_url.is_none() && captcha_secret.is_none() {
+            return Err(ConfigError::Missing("REGISTRATION_MODE"));
+        }
+
+        Ok(RegistrationConfig {
+            mode: parse_mode(mode, "REGISTRATION_MODE")?,
+            captcha: parse_captcha(captcha_verify_url, captcha_secret, "CAPTCHA_*")?,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::ConfigError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RegistrationMode {
+    /// Only administrators create users.
+    #[default]
+    Closed,
+    /// Anyone holding an invite code can sign up.
+    InviteOnly,
+    Open,
+}
+
+#[derive(Clone, Default)]
+pub struct RegistrationConfig {
+    pub mode: RegistrationMode,
+    pub captcha: Option<CaptchaConfig>,
+}
+
+#[derive(Clone)]
+pub struct CaptchaConfig {
+    pub verify_url: String,
+    pub secret: String,
+}
+
+pub trait RegistrationConfigProvider {
+    fn load() -> Result<RegistrationConfig, ConfigError>;
+}
+
+pub fn parse_mode(
+    value: Option<String>,
+    name: &'static str,
+) -> Result<RegistrationMode, ConfigError> {
+    match value.as_deref() {
+        Some("closed") => Ok(RegistrationMode::Closed),
+        Some("invite-only") => Ok(RegistrationMode::InviteOnly),
+        Some("open") => Ok(RegistrationMode::Open),
+        Some(_) => Err(ConfigError::Invalid(name)),
+        None => Ok(RegistrationMode::default()),
+    }
+}
+
+/// A CAPTCHA needs both the verification endpoint and the secret, one without the other is a
+/// mistake.
+pub fn parse_captcha(
+    verify_url: Option<String>,
+    secret: Option<String>,
+    name: &'static str,
+) -> Result<Option<CaptchaConfig>, ConfigError> {
+    match (verify_url, secret) {
+        (Some(verify_url), Some(secret)) if url::Url::parse(&verify_url).is_ok() => {
+            Ok(Some(CaptchaConfig { verify_url, secret }))
+        }
+        (None, None) => Ok(None),
+        _ => Err(ConfigError::Invalid(name)),
+    }
+}
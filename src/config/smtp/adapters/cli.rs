@@ -0,0 +1,56 @@
+use crate::{
+    cli::{Cli, smtp::SmtpCli},
+    config::{
+        ConfigError,
+        smtp::ports::{
+            DEFAULT_TIMEOUT, RECIPIENT_PLACEHOLDER, SmtpConfig, SmtpConfigProvider, SmtpTls,
+            is_valid_url, parse_tls,
+        },
+    },
+};
+use clap::Parser;
+
+pub struct CliSmtpConfig();
+
+impl SmtpConfigProvider for CliSmtpConfig {
+    fn load() -> Result<SmtpConfig, ConfigError> {
+        let args: SmtpCli = Cli::parse_from(std::env::args_os()).smtp;
+
+        let url: String = args.smtp_url.ok_or(ConfigError::Missing("smtp-url"))?;
+        let from: String = args.smtp_from.ok_or(ConfigError::Missing("smtp-from"))?;
+        let recipient: Option<String> = args.smtp_recipient;
+        let timeout: u64 = match args.smtp_timeout {
+            Some(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("smtp-timeout"))?,
+            None => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("smtp-url"));
+        }
+
+        let tls: SmtpTls = parse_tls(args.smtp_tls, &url, "smtp-tls")?;
+
+        if recipient
+            .as_ref()
+            .is_some_and(|recipient| !recipient.contains(RECIPIENT_PLACEHOLDER))
+        {
+            return Err(ConfigError::Invalid("smtp-recipient"));
+        }
+
+        if args.smtp_username.is_some() != args.smtp_password.is_some() {
+            return Err(ConfigError::Invalid("smtp-username"));
+        }
+
+        Ok(SmtpConfig {
+            url,
+            from,
+            recipient,
+            username: args.smtp_username,
+            password: args.smtp_password,
+            timeout,
+            tls,
+        })
+    }
+}
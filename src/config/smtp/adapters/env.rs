@@ -0,0 +1,56 @@
+use crate::config::{
+    ConfigError,
+    smtp::ports::{
+        DEFAULT_TIMEOUT, RECIPIENT_PLACEHOLDER, SmtpConfig, SmtpConfigProvider, SmtpTls,
+        is_valid_url, parse_tls,
+    },
+};
+
+pub struct EnvSmtpConfig;
+
+impl SmtpConfigProvider for EnvSmtpConfig {
+    fn load() -> Result<SmtpConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let url: String =
+            std::env::var("SMTP_URL").map_err(|_| ConfigError::Missing("SMTP_URL"))?;
+        let from: String =
+            std::env::var("SMTP_FROM").map_err(|_| ConfigError::Missing("SMTP_FROM"))?;
+        let recipient: Option<String> = std::env::var("SMTP_RECIPIENT").ok();
+        let username: Option<String> = std::env::var("SMTP_USERNAME").ok();
+        let password: Option<String> = std::env::var("SMTP_PASSWORD").ok();
+        let timeout: u64 = match std::env::var("SMTP_TIMEOUT") {
+            Ok(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("SMTP_TIMEOUT"))?,
+            Err(_) => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("SMTP_URL"));
+        }
+
+        let tls: SmtpTls = parse_tls(std::env::var("SMTP_TLS").ok(), &url, "SMTP_TLS")?;
+
+        if recipient
+            .as_ref()
+            .is_some_and(|recipient| !recipient.contains(RECIPIENT_PLACEHOLDER))
+        {
+            return Err(ConfigError::Invalid("SMTP_RECIPIENT"));
+        }
+
+        if username.is_some() != password.is_some() {
+            return Err(ConfigError::Invalid("SMTP_USERNAME"));
+        }
+
+        Ok(SmtpConfig {
+            url,
+            from,
+            recipient,
+            username,
+            password,
+            timeout,
+            tls,
+        })
+    }
+}
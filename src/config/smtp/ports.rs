@@ -0,0 +1,50 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_TIMEOUT: u64 = 10;
+
+/// Placeholder replaced with the username in `recipient`.
+pub const RECIPIENT_PLACEHOLDER: &str = "{username}";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// The server must offer STARTTLS, unless the URL already uses implicit TLS.
+    #[default]
+    Required,
+    /// Mail goes in clear text when the server does not offer STARTTLS.
+    Disabled,
+}
+
+#[derive(Clone)]
+pub struct SmtpConfig {
+    pub url: String,
+    pub from: String,
+    /// Address of users without a verified email address, built from their username.
+    pub recipient: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub timeout: u64,
+    pub tls: SmtpTls,
+}
+
+pub trait SmtpConfigProvider {
+    fn load() -> Result<SmtpConfig, ConfigError>;
+}
+
+pub fn is_valid_url(url: &str) -> bool {
+    url::Url::parse(url)
+        .is_ok_and(|url| matches!(url.scheme(), "smtp" | "smtps") && url.host_str().is_some())
+}
+
+/// Turning TLS off only makes sense for `smtp://`, an `smtps://` URL always uses it.
+pub fn parse_tls(
+    value: Option<String>,
+    url: &str,
+    name: &'static str,
+) -> Result<SmtpTls, ConfigError> {
+    match value.as_deref() {
+        Some("required") => Ok(SmtpTls::Required),
+        Some("none") if !url.starts_with("smtps:") => Ok(SmtpTls::Disabled),
+        Some(_) => Err(ConfigError::Invalid(name)),
+        None => Ok(SmtpTls::default()),
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    cli::{Cli, webhook::WebhookCli},
+    config::{
+        ConfigError,
+        webhook::ports::{DEFAULT_TIMEOUT, WebhookConfig, WebhookConfigProvider, is_valid_url},
+    },
+};
+use clap::Parser;
+
+pub struct CliWebhookConfig();
+
+impl WebhookConfigProvider for CliWebhookConfig {
+    fn load() -> Result<WebhookConfig, ConfigError> {
+        let args: WebhookCli = Cli::parse_from(std::env::args_os()).webhook;
+
+        let url: String = args
+            .webhook_url
+            .ok_or(ConfigError::Missing("webhook-url"))?;
+        let timeout: u64 = match args.webhook_timeout {
+            Some(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("webhook-timeout"))?,
+            None => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("webhook-url"));
+        }
+
+        Ok(WebhookConfig {
+            url,
+            secret: args.webhook_secret,
+            timeout,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::config::{
+    ConfigError,
+    webhook::ports::{DEFAULT_TIMEOUT, WebhookConfig, WebhookConfigProvider, is_valid_url},
+};
+
+pub struct EnvWebhookConfig;
+
+impl WebhookConfigProvider for EnvWebhookConfig {
+    fn load() -> Result<WebhookConfig, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let url: String =
+            std::env::var("WEBHOOK_URL").map_err(|_| ConfigError::Missing("WEBHOOK_URL"))?;
+        let secret: Option<String> = std::env::var("WEBHOOK_SECRET").ok();
+        let timeout: u64 = match std::env::var("WEBHOOK_TIMEOUT") {
+            Ok(timeout) => timeout
+                .parse()
+                .map_err(|_| ConfigError::Invalid("WEBHOOK_TIMEOUT"))?,
+            Err(_) => DEFAULT_TIMEOUT,
+        };
+
+        if !is_valid_url(&url) {
+            return Err(ConfigError::Invalid("WEBHOOK_URL"));
+        }
+
+        Ok(WebhookConfig {
+            url,
+            secret,
+            timeout,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use crate::config::ConfigError;
+
+pub const DEFAULT_TIMEOUT: u64 = 5;
+
+#[derive(Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub secret: Option<String>,
+    pub timeout: u64,
+}
+
+pub trait WebhookConfigProvider {
+    fn load() -> Result<WebhookConfig, ConfigError>;
+}
+
+pub fn is_valid_url(url: &str) -> bool {
+    url::Url::parse(url)
+        .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+}
@@ -0,0 +1,212 @@
+use super::error::AuditError;
+use std::{fmt::Display, net::IpAddr, str::FromStr};
+use uuid::Uuid;
+
+/// What was done. Security-relevant and administrative actions only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    LoginSucceeded,
+    /// Wrong password or unknown user.
+    LoginFailed,
+    UserRegistered,
+    UserCreated,
+    UserUpdated,
+    UserDeleted,
+    UserRestored,
+    /// A deleted user was anonymized once their grace period ended.
+    UserPurged,
+    UserStatusChanged,
+    UserRoleChanged,
+    UserImpersonated,
+    /// A request made with an impersonation token, put down to the administrator.
+    ImpersonatedRequest,
+    /// The user changed their own password.
+    PasswordChanged,
+    /// The password was set with a reset token.
+    PasswordReset,
+    /// An administrator removed the second factor of the user.
+    MfaReset,
+    /// An administrator signed the user out everywhere.
+    SessionsEnded,
+    ClientRegistered,
+    InviteCreated,
+    InviteDeleted,
+    RoleCreated,
+    RoleUpdated,
+    RoleDeleted,
+    OrganizationCreated,
+    MemberAdded,
+    MemberRoleChanged,
+    MemberRemoved,
+    GroupCreated,
+    GroupUpdated,
+    GroupDeleted,
+    GroupMemberAdded,
+    GroupMemberRemoved,
+}
+
+impl AuditAction {
+    pub const ALL: [AuditAction; 31] = [
+        AuditAction::LoginSucceeded,
+        AuditAction::LoginFailed,
+        AuditAction::UserRegistered,
+        AuditAction::UserCreated,
+        AuditAction::UserUpdated,
+        AuditAction::UserDeleted,
+        AuditAction::UserRestored,
+        AuditAction::UserPurged,
+        AuditAction::UserStatusChanged,
+        AuditAction::UserRoleChanged,
+        AuditAction::UserImpersonated,
+        AuditAction::ImpersonatedRequest,
+        AuditAction::PasswordChanged,
+        AuditAction::PasswordReset,
+        AuditAction::MfaReset,
+        AuditAction::SessionsEnded,
+        AuditAction::ClientRegistered,
+        AuditAction::InviteCreated,
+        AuditAction::InviteDeleted,
+        AuditAction::RoleCreated,
+        AuditAction::RoleUpdated,
+        AuditAction::RoleDeleted,
+        AuditAction::OrganizationCreated,
+        AuditAction::MemberAdded,
+        AuditAction::MemberRoleChanged,
+        AuditAction::MemberRemoved,
+        AuditAction::GroupCreated,
+        AuditAction::GroupUpdated,
+        AuditAction::GroupDeleted,
+        AuditAction::GroupMemberAdded,
+        AuditAction::GroupMemberRemoved,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::LoginSucceeded => "login.succeeded",
+            AuditAction::LoginFailed => "login.failed",
+            AuditAction::UserRegistered => "user.registered",
+            AuditAction::UserCreated => "user.created",
+            AuditAction::UserUpdated => "user.updated",
+            AuditAction::UserDeleted => "user.deleted",
+            AuditAction::UserRestored => "user.restored",
+            AuditAction::UserPurged => "user.purged",
+            AuditAction::UserStatusChanged => "user.status_changed",
+            AuditAction::UserRoleChanged => "user.role_changed",
+            AuditAction::UserImpersonated => "user.impersonated",
+            AuditAction::ImpersonatedRequest => "user.impersonated_request",
+            AuditAction::PasswordChanged => "user.password_changed",
+            AuditAction::PasswordReset => "user.password_reset",
+            AuditAction::MfaReset => "user.mfa_reset",
+            AuditAction::SessionsEnded => "user.sessions_ended",
+            AuditAction::ClientRegistered => "client.registered",
+            AuditAction::InviteCreated => "invite.created",
+            AuditAction::InviteDeleted => "invite.deleted",
+            AuditAction::RoleCreated => "role.created",
+            AuditAction::RoleUpdated => "role.updated",
+            AuditAction::RoleDeleted => "role.deleted",
+            AuditAction::OrganizationCreated => "organization.created",
+            AuditAction::MemberAdded => "member.added",
+            AuditAction::MemberRoleChanged => "member.role_changed",
+            AuditAction::MemberRemoved => "member.removed",
+            AuditAction::GroupCreated => "group.created",
+            AuditAction::GroupUpdated => "group.updated",
+            AuditAction::GroupDeleted => "group.deleted",
+            AuditAction::GroupMemberAdded => "group.member_added",
+            AuditAction::GroupMemberRemoved => "group.member_removed",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = AuditError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        AuditAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == value)
+            .ok_or_else(|| AuditError::UnknownAction(value.into()))
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One field of the target, before and after the action. Secrets are never recorded, only
+/// that they changed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A record of who did what to which target, when and from where. Entries are never changed
+/// or deleted once written.
+#[derive(Clone, Debug)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    /// The organization the action was taken in.
+    pub organization_id: Option<Uuid>,
+    /// Who acted, unknown for failed logins.
+    pub actor_id: Option<Uuid>,
+    pub actor_username: Option<String>,
+    pub action: AuditAction,
+    /// What was acted on, such as the identifier of a user or the name of a role.
+    pub target: Option<String>,
+    pub ip_address: Option<IpAddr>,
+    pub changes: Vec<AuditChange>,
+    /// Why, when the actor had to say.
+    pub reason: Option<String>,
+    pub occurred_at: u64,
+}
+
+impl AuditEntry {
+    pub fn new(id: Uuid, action: AuditAction, occurred_at: u64) -> Self {
+        Self {
+            id,
+            organization_id: None,
+            actor_id: None,
+            actor_username: None,
+            action,
+            target: None,
+            ip_address: None,
+            changes: Vec::new(),
+            reason: None,
+            occurred_at,
+        }
+    }
+
+    pub fn with_target(mut self, target: impl Display) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Records a changed field, unless it did not change.
+    pub fn with_change(
+        mut self,
+        field: &str,
+        before: Option<impl Display>,
+        after: Option<impl Display>,
+    ) -> Self {
+        let before: Option<String> = before.map(|value| value.to_string());
+        let after: Option<String> = after.map(|value| value.to_string());
+
+        if before != after {
+            self.changes.push(AuditChange {
+                field: field.into(),
+                before,
+                after,
+            });
+        }
+
+        self
+    }
+}
@@ -0,0 +1,9 @@
+use super::{entry::AuditEntry, query::AuditQuery};
+use crate::domain::errors::repository::RepositoryError;
+
+/// Append-only record of security and administrative actions.
+#[async_trait::async_trait]
+pub trait AuditLog {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryError>;
+    async fn list(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, RepositoryError>;
+}
@@ -0,0 +1,4 @@
+pub mod entry;
+pub mod error;
+pub mod log;
+pub mod query;
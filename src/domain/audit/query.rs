@@ -0,0 +1,17 @@
+use super::entry::AuditAction;
+use uuid::Uuid;
+
+/// Which entries to list, newest first. All filters must match.
+pub struct AuditQuery {
+    pub organization_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub target: Option<String>,
+    /// Only entries at or after this time, in seconds since the Unix epoch.
+    pub since: Option<u64>,
+    /// Only entries before this time, in seconds since the Unix epoch.
+    pub until: Option<u64>,
+    /// Only entries older than this one, to list the next page.
+    pub before: Option<Uuid>,
+    pub limit: u64,
+}
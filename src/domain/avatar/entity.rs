@@ -0,0 +1,50 @@
+use crate::domain::user::error::UserError;
+
+/// The fixed sizes avatars are resized to, in pixels per side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvatarSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl AvatarSize {
+    pub const ALL: [AvatarSize; 3] = [AvatarSize::Small, AvatarSize::Medium, AvatarSize::Large];
+
+    pub fn pixels(&self) -> u32 {
+        match self {
+            AvatarSize::Small => 64,
+            AvatarSize::Medium => 128,
+            AvatarSize::Large => 256,
+        }
+    }
+}
+
+/// An uploaded image, before it is decoded and resized.
+pub struct AvatarUpload(Vec<u8>);
+
+impl AvatarUpload {
+    pub const MAX_BYTES: usize = 5 * 1024 * 1024;
+
+    pub fn new(data: Vec<u8>) -> Result<Self, UserError> {
+        if data.is_empty() || data.len() > Self::MAX_BYTES {
+            return Err(UserError::InvalidProfile(format!(
+                "Avatar must be an image of at most {} MiB",
+                Self::MAX_BYTES / 1024 / 1024
+            )));
+        }
+
+        Ok(Self(data))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// An avatar resized to one of the fixed sizes, ready to be served.
+pub struct AvatarImage {
+    pub size: AvatarSize,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
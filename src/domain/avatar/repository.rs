@@ -0,0 +1,19 @@
+use super::entity::{AvatarImage, AvatarSize};
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait AvatarRepository {
+    async fn find(
+        &self,
+        user_id: &Uuid,
+        size: AvatarSize,
+    ) -> Result<Option<AvatarImage>, RepositoryError>;
+    /// Replaces every size of the avatar of the user at once.
+    async fn replace(
+        &self,
+        user_id: &Uuid,
+        images: Vec<AvatarImage>,
+    ) -> Result<(), RepositoryError>;
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,21 @@
+use crate::domain::user::value_objects::password_hash::PasswordHash;
+use uuid::Uuid;
+
+/// An application registered to call the OAuth endpoints on its own behalf.
+pub struct Client {
+    pub id: Uuid,
+    pub name: String,
+    pub secret_hash: PasswordHash,
+    pub scopes: Vec<String>,
+}
+
+impl Client {
+    pub fn new(id: Uuid, name: String, secret_hash: PasswordHash, scopes: Vec<String>) -> Self {
+        Self {
+            id,
+            name,
+            secret_hash,
+            scopes,
+        }
+    }
+}
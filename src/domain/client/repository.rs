@@ -0,0 +1,9 @@
+use super::entity::Client;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait ClientRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Client>, RepositoryError>;
+    async fn create(&self, client: Client) -> Result<Client, RepositoryError>;
+}
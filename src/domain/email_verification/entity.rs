@@ -0,0 +1,34 @@
+use crate::domain::user::value_objects::{email::Email, password_hash::PasswordHash};
+use uuid::Uuid;
+
+/// A pending verification of a user's email address. The token only verifies the address it
+/// was sent to, so it is worthless once the user changes their address.
+pub struct EmailVerificationToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub email: Email,
+    pub secret_hash: PasswordHash,
+    pub expires_at: u64,
+}
+
+impl EmailVerificationToken {
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        email: Email,
+        secret_hash: PasswordHash,
+        expires_at: u64,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            email,
+            secret_hash,
+            expires_at,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
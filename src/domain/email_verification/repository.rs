@@ -0,0 +1,18 @@
+use super::entity::EmailVerificationToken;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait EmailVerificationRepository {
+    async fn find_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<EmailVerificationToken>, RepositoryError>;
+    async fn create(
+        &self,
+        token: EmailVerificationToken,
+    ) -> Result<EmailVerificationToken, RepositoryError>;
+    /// Deletes the token, returning whether it still existed.
+    async fn consume(&self, id: &Uuid) -> Result<bool, RepositoryError>;
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError>;
+}
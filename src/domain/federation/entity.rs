@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+/// Links an account at an upstream identity provider to a local user.
+pub struct ExternalIdentity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Name of the provider, as configured.
+    pub provider: String,
+    /// The provider's stable identifier for the account (the `sub` claim).
+    pub subject: String,
+}
+
+impl ExternalIdentity {
+    pub fn new(id: Uuid, user_id: Uuid, provider: String, subject: String) -> Self {
+        Self {
+            id,
+            user_id,
+            provider,
+            subject,
+        }
+    }
+}
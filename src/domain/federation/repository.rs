@@ -0,0 +1,13 @@
+use super::entity::ExternalIdentity;
+use crate::domain::errors::repository::RepositoryError;
+
+#[async_trait::async_trait]
+pub trait ExternalIdentityRepository {
+    async fn find(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<ExternalIdentity>, RepositoryError>;
+    async fn create(&self, identity: ExternalIdentity)
+    -> Result<ExternalIdentity, RepositoryError>;
+}
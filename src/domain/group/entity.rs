@@ -0,0 +1,85 @@
+use super::error::GroupError;
+use crate::domain::role::{entity::Role, permission::Permission};
+use uuid::Uuid;
+
+/// The name a group is mentioned by, such as `backend` for `@backend`. Unique within an
+/// organization and unchangeable.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GroupName(String);
+
+impl GroupName {
+    /// Accepts the name with or without the leading `@` of a mention.
+    pub fn new(value: String) -> Result<Self, GroupError> {
+        let value: &str = value.trim();
+        let value: &str = value.strip_prefix('@').unwrap_or(value);
+
+        if !(2..=32).contains(&value.len())
+            || !value.starts_with(|c: char| c.is_ascii_lowercase())
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(GroupError::InvalidName(
+                "Group name must be 2 to 32 lowercase letters, digits, underscores or hyphens, starting with a letter"
+                    .into(),
+            ));
+        }
+
+        Ok(Self(value.to_owned()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn mention(&self) -> String {
+        format!("@{}", self.0)
+    }
+}
+
+/// Users of an organization that are mentioned together and share permissions on top of
+/// their roles.
+#[derive(Clone)]
+pub struct Group {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: GroupName,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+    pub created_at: u64,
+}
+
+impl Group {
+    pub const MAX_DESCRIPTION_LENGTH: usize = 255;
+
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        name: GroupName,
+        description: String,
+        permissions: Vec<Permission>,
+        now: u64,
+    ) -> Result<Self, GroupError> {
+        Ok(Self {
+            id,
+            organization_id,
+            name,
+            description: Self::valid_description(description)?,
+            permissions: Role::dedup(permissions),
+            created_at: now,
+        })
+    }
+
+    pub fn valid_description(description: String) -> Result<String, GroupError> {
+        let description: String = description.trim().to_owned();
+
+        if description.chars().count() > Self::MAX_DESCRIPTION_LENGTH {
+            return Err(GroupError::InvalidDescription(format!(
+                "Description must be at most {} characters long",
+                Self::MAX_DESCRIPTION_LENGTH
+            )));
+        }
+
+        Ok(description)
+    }
+}
@@ -0,0 +1,4 @@
+pub enum GroupError {
+    InvalidName(String),
+    InvalidDescription(String),
+}
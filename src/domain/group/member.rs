@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+/// A member of an organization added to one of its groups.
+#[derive(Clone)]
+pub struct GroupMember {
+    pub group_id: Uuid,
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub added_at: u64,
+}
+
+impl GroupMember {
+    pub fn new(group_id: Uuid, organization_id: Uuid, user_id: Uuid, now: u64) -> Self {
+        Self {
+            group_id,
+            organization_id,
+            user_id,
+            added_at: now,
+        }
+    }
+}
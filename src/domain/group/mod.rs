@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod error;
+pub mod member;
+pub mod repository;
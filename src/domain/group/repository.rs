@@ -0,0 +1,38 @@
+use super::{
+    entity::{Group, GroupName},
+    member::GroupMember,
+};
+use crate::domain::{errors::repository::RepositoryError, role::permission::Permission};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait GroupRepository {
+    async fn find_by_name(
+        &self,
+        organization_id: &Uuid,
+        name: &GroupName,
+    ) -> Result<Option<Group>, RepositoryError>;
+    async fn find_all(&self, organization_id: &Uuid) -> Result<Vec<Group>, RepositoryError>;
+    /// The groups of the organization the user is in.
+    async fn find_by_member(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Vec<Group>, RepositoryError>;
+    async fn create(&self, group: Group) -> Result<Group, RepositoryError>;
+    /// Replaces the description and the permissions of the group.
+    async fn update(
+        &self,
+        id: &Uuid,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Group, RepositoryError>;
+    /// Deletes the group, returning whether it still existed.
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError>;
+    async fn find_members(&self, group_id: &Uuid) -> Result<Vec<GroupMember>, RepositoryError>;
+    /// Adds the member, keeping the existing one if they are in the group already.
+    async fn add_member(&self, member: GroupMember) -> Result<GroupMember, RepositoryError>;
+    /// Removes the member, returning whether they were in the group.
+    async fn remove_member(&self, group_id: &Uuid, user_id: &Uuid)
+    -> Result<bool, RepositoryError>;
+}
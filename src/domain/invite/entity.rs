@@ -0,0 +1,48 @@
+use crate::domain::user::value_objects::password_hash::PasswordHash;
+use uuid::Uuid;
+
+/// A code that lets people sign up while registration is invite-only. Like password reset
+/// tokens, only a hash of the secret part of the code is kept.
+pub struct Invite {
+    pub id: Uuid,
+    /// The organization people who sign up with the invite join.
+    pub organization_id: Uuid,
+    pub secret_hash: PasswordHash,
+    /// The administrator who created the invite.
+    pub created_by: Uuid,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub expires_at: u64,
+    pub created_at: u64,
+}
+
+impl Invite {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        secret_hash: PasswordHash,
+        created_by: Uuid,
+        max_uses: u32,
+        expires_at: u64,
+        now: u64,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            secret_hash,
+            created_by,
+            max_uses,
+            uses: 0,
+            expires_at,
+            created_at: now,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_used_up(&self) -> bool {
+        self.uses >= self.max_uses
+    }
+}
@@ -0,0 +1,21 @@
+use super::entity::Invite;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait InviteRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Invite>, RepositoryError>;
+    /// All invites of the organization, newest first.
+    async fn find_by_organization(
+        &self,
+        organization_id: &Uuid,
+    ) -> Result<Vec<Invite>, RepositoryError>;
+    async fn create(&self, invite: Invite) -> Result<Invite, RepositoryError>;
+    /// Uses the invite once, returning whether it was still unexpired and had uses left. Two
+    /// callers cannot take the last use, even concurrently.
+    async fn redeem(&self, id: &Uuid, now: u64) -> Result<bool, RepositoryError>;
+    /// Gives back a use taken by `redeem` that did not lead to a registration.
+    async fn release(&self, id: &Uuid) -> Result<(), RepositoryError>;
+    /// Deletes the invite of the organization, returning whether it existed.
+    async fn delete(&self, organization_id: &Uuid, id: &Uuid) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,28 @@
+use crate::domain::user::value_objects::password_hash::PasswordHash;
+use uuid::Uuid;
+
+/// A user's TOTP (RFC 6238) second factor. Enrollments only take effect once confirmed with a
+/// first valid code.
+pub struct TotpEnrollment {
+    pub user_id: Uuid,
+    pub secret: String,
+    pub confirmed: bool,
+    pub recovery_codes: Vec<PasswordHash>,
+    pub last_used_step: Option<u64>,
+}
+
+impl TotpEnrollment {
+    pub fn new(user_id: Uuid, secret: String, recovery_codes: Vec<PasswordHash>) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed: false,
+            recovery_codes,
+            last_used_step: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.confirmed
+    }
+}
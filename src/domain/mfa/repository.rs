@@ -0,0 +1,13 @@
+use super::entity::TotpEnrollment;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait TotpRepository {
+    async fn find_by_user_id(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Option<TotpEnrollment>, RepositoryError>;
+    async fn save(&self, enrollment: TotpEnrollment) -> Result<TotpEnrollment, RepositoryError>;
+    async fn delete(&self, user_id: &Uuid) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,9 @@
+pub trait TotpProvider {
+    /// Generates a new base32 encoded shared secret.
+    fn generate_secret(&self) -> String;
+    /// Builds the `otpauth://` URI authenticator apps enroll from.
+    fn provisioning_uri(&self, secret: &str, account: &str) -> String;
+    /// Checks `code` against `secret`, returning the time step it matched. Steps at or before
+    /// `last_used_step` are rejected so a code cannot be replayed.
+    fn verify(&self, secret: &str, code: &str, last_used_step: Option<u64>) -> Option<u64>;
+}
@@ -1,2 +1,15 @@
+pub mod audit;
+pub mod avatar;
+pub mod client;
+pub mod email_verification;
 pub mod errors;
+pub mod federation;
+pub mod group;
+pub mod invite;
+pub mod mfa;
+pub mod organization;
+pub mod passkey;
+pub mod password_reset;
+pub mod role;
+pub mod session;
 pub mod user;
@@ -0,0 +1,72 @@
+use super::error::OrganizationError;
+use uuid::Uuid;
+
+/// The short, unique, unchangeable name users pick their organization with when signing in,
+/// such as `acme`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrganizationSlug(String);
+
+impl OrganizationSlug {
+    pub fn new(value: String) -> Result<Self, OrganizationError> {
+        let value: String = value.trim().to_ascii_lowercase();
+
+        if !(2..=32).contains(&value.len())
+            || !value.starts_with(|c: char| c.is_ascii_lowercase())
+            || value.ends_with('-')
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(OrganizationError::InvalidSlug(
+                "Slug must be 2 to 32 lowercase letters, digits or hyphens, starting with a letter"
+                    .into(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A tenant of the deployment. Every user has a home organization their username is unique
+/// in, and may be a member of others.
+#[derive(Clone)]
+pub struct Organization {
+    pub id: Uuid,
+    pub slug: OrganizationSlug,
+    pub name: String,
+    pub created_at: u64,
+}
+
+impl Organization {
+    /// The organization users existing before organizations were introduced belong to, and
+    /// the one people sign in to when they name none.
+    pub const DEFAULT_ID: Uuid = Uuid::nil();
+    pub const MAX_NAME_LENGTH: usize = 100;
+
+    pub fn new(
+        id: Uuid,
+        slug: OrganizationSlug,
+        name: String,
+        now: u64,
+    ) -> Result<Self, OrganizationError> {
+        let name: String = name.trim().to_owned();
+
+        if name.is_empty() || name.chars().count() > Self::MAX_NAME_LENGTH {
+            return Err(OrganizationError::InvalidName(format!(
+                "Name must be 1 to {} characters long",
+                Self::MAX_NAME_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            id,
+            slug,
+            name,
+            created_at: now,
+        })
+    }
+}
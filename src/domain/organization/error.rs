@@ -0,0 +1,4 @@
+pub enum OrganizationError {
+    InvalidSlug(String),
+    InvalidName(String),
+}
@@ -0,0 +1,23 @@
+use crate::domain::role::entity::RoleName;
+use uuid::Uuid;
+
+/// A user belonging to an organization. The role applies while the organization is the active
+/// one of the user, on top of the deployment-wide role of the user.
+#[derive(Clone)]
+pub struct Membership {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: RoleName,
+    pub joined_at: u64,
+}
+
+impl Membership {
+    pub fn new(organization_id: Uuid, user_id: Uuid, role: RoleName, now: u64) -> Self {
+        Self {
+            organization_id,
+            user_id,
+            role,
+            joined_at: now,
+        }
+    }
+}
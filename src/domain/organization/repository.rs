@@ -0,0 +1,45 @@
+use super::{
+    entity::{Organization, OrganizationSlug},
+    membership::Membership,
+};
+use crate::domain::{errors::repository::RepositoryError, role::entity::RoleName};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait OrganizationRepository {
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> Result<Option<Organization>, RepositoryError>;
+    /// Creates the organization along with the membership of its founder.
+    async fn create(
+        &self,
+        organization: Organization,
+        founder: Membership,
+    ) -> Result<Organization, RepositoryError>;
+    async fn find_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<Membership>, RepositoryError>;
+    /// The organizations the user is a member of, with the membership, by name.
+    async fn find_by_member(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<(Organization, Membership)>, RepositoryError>;
+    /// The members of the organization, earliest first.
+    async fn find_members(
+        &self,
+        organization_id: &Uuid,
+    ) -> Result<Vec<Membership>, RepositoryError>;
+    /// Whether any membership, in any organization, has the role.
+    async fn has_members_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError>;
+    /// Adds the membership, or changes the role of an existing one.
+    async fn save_membership(&self, membership: Membership) -> Result<Membership, RepositoryError>;
+    /// Removes the membership, returning whether it still existed.
+    async fn delete_membership(
+        &self,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<bool, RepositoryError>;
+}
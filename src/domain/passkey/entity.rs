@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+/// A WebAuthn public key credential registered by a user.
+#[derive(Clone)]
+pub struct Passkey {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub credential_id: Vec<u8>,
+    /// SEC1 encoded P-256 public key.
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+}
+
+impl Passkey {
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        name: String,
+        credential_id: Vec<u8>,
+        public_key: Vec<u8>,
+        sign_count: u32,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            credential_id,
+            public_key,
+            sign_count,
+        }
+    }
+}
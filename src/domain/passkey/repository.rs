@@ -0,0 +1,20 @@
+use super::{entity::Passkey, verifier::PasskeyChallenge};
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait PasskeyRepository {
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &[u8],
+    ) -> Result<Option<Passkey>, RepositoryError>;
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Passkey>, RepositoryError>;
+    async fn create(&self, passkey: Passkey) -> Result<Passkey, RepositoryError>;
+    async fn update_sign_count(&self, id: &Uuid, sign_count: u32) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError>;
+    /// Remembers an issued challenge until it is consumed or expires.
+    async fn create_challenge(&self, challenge: &PasskeyChallenge) -> Result<(), RepositoryError>;
+    /// Deletes the challenge, returning whether it was issued and not consumed yet. Only one
+    /// ceremony can consume a challenge, even concurrently.
+    async fn consume_challenge(&self, challenge: &str) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,61 @@
+use super::entity::Passkey;
+use uuid::Uuid;
+
+/// Relying party side of the WebAuthn registration and authentication ceremonies.
+pub trait PasskeyVerifier {
+    /// Identifier of the relying party, the domain passkeys are scoped to.
+    fn rp_id(&self) -> &str;
+    /// Human readable name of the relying party, shown by authenticators.
+    fn rp_name(&self) -> &str;
+    /// Issues a challenge for a ceremony. Registration challenges are bound to `user_id`.
+    fn challenge(&self, user_id: Option<&Uuid>) -> PasskeyChallenge;
+    /// Checks an attestation produced by `navigator.credentials.create()`.
+    fn verify_registration(
+        &self,
+        user_id: &Uuid,
+        client_data_json: &[u8],
+        attestation_object: &[u8],
+    ) -> Result<PasskeyRegistration, PasskeyError>;
+    /// Checks an assertion produced by `navigator.credentials.get()`.
+    fn verify_assertion(
+        &self,
+        passkey: &Passkey,
+        client_data_json: &[u8],
+        authenticator_data: &[u8],
+        signature: &[u8],
+    ) -> Result<PasskeyAssertion, PasskeyError>;
+}
+
+pub struct PasskeyChallenge {
+    pub value: String,
+    pub expires_at: u64,
+}
+
+/// A verified attestation. `challenge` still has to be consumed before the passkey is stored.
+pub struct PasskeyRegistration {
+    pub challenge: String,
+    pub credential_id: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+}
+
+/// A verified assertion. `challenge` still has to be consumed before the user is signed in.
+pub struct PasskeyAssertion {
+    pub challenge: String,
+    pub sign_count: u32,
+}
+
+#[derive(Debug)]
+pub enum PasskeyError {
+    /// The client data or authenticator data is malformed.
+    Malformed,
+    /// The challenge is unknown, expired or issued for another user.
+    InvalidChallenge,
+    /// The ceremony was performed for another origin or relying party.
+    InvalidOrigin,
+    /// The user was not present, or the key type is not supported.
+    Unsupported,
+    InvalidSignature,
+    /// The signature counter went backwards, the authenticator may have been cloned.
+    CounterRegression,
+}
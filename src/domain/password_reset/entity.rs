@@ -0,0 +1,26 @@
+use crate::domain::user::value_objects::password_hash::PasswordHash;
+use uuid::Uuid;
+
+/// A pending request to reset a user's password. Only a hash of the secret part of the
+/// token sent to the user is kept.
+pub struct PasswordResetToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub secret_hash: PasswordHash,
+    pub expires_at: u64,
+}
+
+impl PasswordResetToken {
+    pub fn new(id: Uuid, user_id: Uuid, secret_hash: PasswordHash, expires_at: u64) -> Self {
+        Self {
+            id,
+            user_id,
+            secret_hash,
+            expires_at,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
@@ -0,0 +1,16 @@
+use super::entity::PasswordResetToken;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait PasswordResetRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<PasswordResetToken>, RepositoryError>;
+    async fn create(
+        &self,
+        token: PasswordResetToken,
+    ) -> Result<PasswordResetToken, RepositoryError>;
+    /// Deletes the token, returning whether it still existed. Only one caller can consume a
+    /// token, even concurrently.
+    async fn consume(&self, id: &Uuid) -> Result<bool, RepositoryError>;
+    async fn delete_by_user_id(&self, user_id: &Uuid) -> Result<(), RepositoryError>;
+}
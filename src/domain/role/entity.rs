@@ -0,0 +1,104 @@
+use super::{error::RoleError, permission::Permission};
+
+/// The unique, unchangeable name of a role, such as `moderator`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RoleName(String);
+
+impl RoleName {
+    /// Holds every permission, including ones added later, and cannot be changed.
+    pub const ADMINISTRATOR: &str = "administrator";
+    /// Given to new users.
+    pub const USER: &str = "user";
+
+    pub fn new(value: String) -> Result<Self, RoleError> {
+        Self::validate_name(&value)?;
+
+        Ok(Self(value))
+    }
+
+    pub fn administrator() -> Self {
+        Self(Self::ADMINISTRATOR.into())
+    }
+
+    pub fn user() -> Self {
+        Self(Self::USER.into())
+    }
+
+    pub fn is_administrator(&self) -> bool {
+        self.0 == Self::ADMINISTRATOR
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn validate_name(name: &str) -> Result<(), RoleError> {
+        if !(2..=32).contains(&name.len())
+            || !name.starts_with(|c: char| c.is_ascii_lowercase())
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(RoleError::InvalidName(
+                "Role name must be 2 to 32 lowercase letters, digits, underscores or hyphens, starting with a letter"
+                    .into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Role {
+    pub name: RoleName,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+    /// Built-in roles cannot be deleted.
+    pub builtin: bool,
+}
+
+impl Role {
+    pub const MAX_DESCRIPTION_LENGTH: usize = 255;
+
+    pub fn new(
+        name: RoleName,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Self, RoleError> {
+        Ok(Self {
+            name,
+            description: Self::valid_description(description)?,
+            permissions: Self::dedup(permissions),
+            builtin: false,
+        })
+    }
+
+    /// The permissions the role grants. Administrators hold all of them, whatever is stored.
+    pub fn grants(&self) -> Vec<Permission> {
+        match self.name.is_administrator() {
+            true => Permission::ALL.to_vec(),
+            false => self.permissions.clone(),
+        }
+    }
+
+    pub fn valid_description(description: String) -> Result<String, RoleError> {
+        let description: String = description.trim().to_owned();
+
+        if description.chars().count() > Self::MAX_DESCRIPTION_LENGTH {
+            return Err(RoleError::InvalidDescription(format!(
+                "Description must be at most {} characters long",
+                Self::MAX_DESCRIPTION_LENGTH
+            )));
+        }
+
+        Ok(description)
+    }
+
+    pub fn dedup(permissions: Vec<Permission>) -> Vec<Permission> {
+        Permission::ALL
+            .into_iter()
+            .filter(|permission| permissions.contains(permission))
+            .collect()
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Debug)]
+pub enum RoleError {
+    InvalidName(String),
+    InvalidDescription(String),
+    UnknownPermission(String),
+}
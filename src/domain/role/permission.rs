@@ -0,0 +1,138 @@
+use super::error::RoleError;
+use std::{fmt::Display, str::FromStr};
+
+/// What a role allows beyond acting on one's own account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// See email addresses of others and users that are not active.
+    UserRead,
+    UserCreate,
+    /// Edit other users and set passwords directly.
+    UserUpdate,
+    UserDelete,
+    /// Change the status of users, e.g. to ban them.
+    UserBan,
+    UserImpersonate,
+    /// Reset the two-factor authentication of other users.
+    MfaReset,
+    /// End the sessions of other users.
+    SessionRevoke,
+    InviteManage,
+    ClientManage,
+    /// Give roles to users.
+    RoleAssign,
+    /// Define roles and their permissions.
+    RoleManage,
+    /// Create organizations.
+    OrganizationManage,
+    /// Add members to the active organization, change their role and remove them.
+    MemberManage,
+    /// Create groups in the active organization, change what they grant and who is in them.
+    GroupManage,
+    /// List and export the audit log of the active organization.
+    AuditRead,
+    /// Checked by chat servers, which read permissions through token introspection.
+    RoomModerate,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 17] = [
+        Permission::UserRead,
+        Permission::UserCreate,
+        Permission::UserUpdate,
+        Permission::UserDelete,
+        Permission::UserBan,
+        Permission::UserImpersonate,
+        Permission::MfaReset,
+        Permission::SessionRevoke,
+        Permission::InviteManage,
+        Permission::ClientManage,
+        Permission::RoleAssign,
+        Permission::RoleManage,
+        Permission::OrganizationManage,
+        Permission::MemberManage,
+        Permission::GroupManage,
+        Permission::AuditRead,
+        Permission::RoomModerate,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::UserRead => "user.read",
+            Permission::UserCreate => "user.create",
+            Permission::UserUpdate => "user.update",
+            Permission::UserDelete => "user.delete",
+            Permission::UserBan => "user.ban",
+            Permission::UserImpersonate => "user.impersonate",
+            Permission::MfaReset => "mfa.reset",
+            Permission::SessionRevoke => "session.revoke",
+            Permission::InviteManage => "invite.manage",
+            Permission::ClientManage => "client.manage",
+            Permission::RoleAssign => "role.assign",
+            Permission::RoleManage => "role.manage",
+            Permission::OrganizationManage => "organization.manage",
+            Permission::MemberManage => "member.manage",
+            Permission::GroupManage => "group.manage",
+            Permission::AuditRead => "audit.read",
+            Permission::RoomModerate => "room.moderate",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Permission::UserRead => "See email addresses and users that are not active",
+            Permission::UserCreate => "Create users",
+            Permission::UserUpdate => "Edit other users and set their passwords",
+            Permission::UserDelete => "Delete other users",
+            Permission::UserBan => "Activate, deactivate and ban users",
+            Permission::UserImpersonate => "Act as another user",
+            Permission::MfaReset => "Reset the two-factor authentication of other users",
+            Permission::SessionRevoke => "End the sessions of other users",
+            Permission::InviteManage => "Create, list and delete invites",
+            Permission::ClientManage => "Register OAuth clients",
+            Permission::RoleAssign => "Give roles to users",
+            Permission::RoleManage => "Create, edit and delete roles",
+            Permission::OrganizationManage => "Create organizations",
+            Permission::MemberManage => "Add, change and remove members of the organization",
+            Permission::GroupManage => "Create, edit and delete groups and manage their members",
+            Permission::AuditRead => "Read and export the audit log",
+            Permission::RoomModerate => "Moderate chat rooms",
+        }
+    }
+
+    /// Permissions over the whole deployment rather than one organization. Roles and groups
+    /// only grant them while the default organization is the active one.
+    pub const DEPLOYMENT_WIDE: [Permission; 3] = [
+        Permission::ClientManage,
+        Permission::RoleManage,
+        Permission::OrganizationManage,
+    ];
+
+    pub fn is_deployment_wide(&self) -> bool {
+        Permission::DEPLOYMENT_WIDE.contains(self)
+    }
+
+    /// Whether `granted` covers every permission of `required`.
+    pub fn all_granted(required: &[Permission], granted: &[Permission]) -> bool {
+        required
+            .iter()
+            .all(|permission| granted.contains(permission))
+    }
+}
+
+impl FromStr for Permission {
+    type Err = RoleError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Permission::ALL
+            .into_iter()
+            .find(|permission| permission.as_str() == value)
+            .ok_or_else(|| RoleError::UnknownPermission(value.into()))
+    }
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
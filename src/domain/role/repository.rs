@@ -0,0 +1,21 @@
+use super::{
+    entity::{Role, RoleName},
+    permission::Permission,
+};
+use crate::domain::errors::repository::RepositoryError;
+
+#[async_trait::async_trait]
+pub trait RoleRepository {
+    async fn find_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError>;
+    async fn find_all(&self) -> Result<Vec<Role>, RepositoryError>;
+    async fn create(&self, role: Role) -> Result<Role, RepositoryError>;
+    /// Replaces the description and the permissions of the role.
+    async fn update(
+        &self,
+        name: &RoleName,
+        description: String,
+        permissions: Vec<Permission>,
+    ) -> Result<Role, RepositoryError>;
+    /// Deletes the role, returning whether it still existed.
+    async fn delete(&self, name: &RoleName) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,46 @@
+use std::net::IpAddr;
+use uuid::Uuid;
+
+/// A signed-in device or browser. Every refresh token family belongs to one session, so
+/// ending the session stops its refresh tokens from being used.
+#[derive(Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub client_id: Option<Uuid>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<IpAddr>,
+    pub created_at: u64,
+    pub last_used_at: u64,
+    pub expires_at: u64,
+}
+
+impl Session {
+    pub fn new(id: Uuid, user_id: Uuid, client_id: Option<Uuid>, now: u64) -> Self {
+        Self {
+            id,
+            user_id,
+            client_id,
+            user_agent: None,
+            ip_address: None,
+            created_at: now,
+            last_used_at: now,
+            expires_at: now,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+
+    /// Records a use of the session from the given device.
+    pub fn touch(&mut self, user_agent: Option<String>, ip_address: Option<IpAddr>, now: u64) {
+        if user_agent.is_some() {
+            self.user_agent = user_agent;
+        }
+        if ip_address.is_some() {
+            self.ip_address = ip_address;
+        }
+        self.last_used_at = now;
+    }
+}
@@ -0,0 +1,21 @@
+use super::entity::Session;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait SessionRepository {
+    async fn find_by_id(&self, id: &Uuid) -> Result<Option<Session>, RepositoryError>;
+    /// Returns the user's sessions, most recently used first.
+    async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<Session>, RepositoryError>;
+    /// Inserts the session, or updates it when it already exists.
+    async fn save(&self, session: Session) -> Result<Session, RepositoryError>;
+    /// Deletes the session, returning whether it existed.
+    async fn delete(&self, id: &Uuid) -> Result<bool, RepositoryError>;
+    /// Deletes all of the user's sessions, apart from `except` when given.
+    async fn delete_by_user_id(
+        &self,
+        user_id: &Uuid,
+        except: Option<&Uuid>,
+    ) -> Result<(), RepositoryError>;
+    async fn delete_expired(&self, user_id: &Uuid, now: u64) -> Result<(), RepositoryError>;
+}
@@ -1,16 +1,11 @@
-use super::value_objects::{name::Name, password_hash::PasswordHash, username::Username};
+use super::{
+    profile::Profile,
+    value_objects::{email::Email, name::Name, password_hash::PasswordHash, username::Username},
+};
+use crate::domain::{organization::entity::Organization, role::entity::RoleName};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum UserRole {
-    #[serde(rename = "administrator")]
-    Administrator,
-    #[default]
-    #[serde(rename = "user")]
-    User,
-}
-
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum UserStatus {
     #[default]
@@ -22,13 +17,37 @@ pub enum UserStatus {
     Banned,
 }
 
+impl UserStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "active",
+            UserStatus::Inactive => "inactive",
+            UserStatus::Banned => "banned",
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct User {
     pub id: Uuid,
+    /// The home organization, which the username is unique in and whose administrators manage
+    /// the account.
+    pub organization_id: Uuid,
     pub name: Name,
     pub username: Username,
     pub password_hash: PasswordHash,
-    pub role: UserRole,
+    pub role: RoleName,
     pub status: UserStatus,
+    pub email: Option<Email>,
+    /// When the email address was verified, unset until the user proves they receive mail
+    /// there.
+    pub email_verified_at: Option<u64>,
+    /// When the password was last set, unknown until the user is stored.
+    pub password_changed_at: Option<u64>,
+    pub profile: Profile,
+    /// When the user was deleted. They can be restored until their grace period ends, and
+    /// keep their username and email address until then.
+    pub deleted_at: Option<u64>,
 }
 
 impl User {
@@ -37,23 +56,45 @@ impl User {
         name: Name,
         username: Username,
         password_hash: PasswordHash,
-        role: Option<UserRole>,
+        role: Option<RoleName>,
         status: Option<UserStatus>,
     ) -> Self {
-        let role: UserRole = role.unwrap_or_default();
+        let role: RoleName = role.unwrap_or_else(RoleName::user);
         let status: UserStatus = status.unwrap_or_default();
 
         Self {
             id,
+            organization_id: Organization::DEFAULT_ID,
             name,
             username,
             password_hash,
             role,
             status,
+            email: None,
+            email_verified_at: None,
+            password_changed_at: None,
+            profile: Profile::default(),
+            deleted_at: None,
         }
     }
 
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    /// The email address, once verified.
+    pub fn verified_email(&self) -> Option<&Email> {
+        self.email
+            .as_ref()
+            .filter(|_| self.email_verified_at.is_some())
+    }
+
+    /// Whether the password is older than `max_age` seconds. Passwords never expire without a
+    /// maximum age.
+    pub fn is_password_expired(&self, max_age: Option<u64>, now: u64) -> bool {
+        match (max_age, self.password_changed_at) {
+            (Some(max_age), Some(changed_at)) => changed_at.saturating_add(max_age) <= now,
+            _ => false,
+        }
+    }
 }
@@ -1,4 +1,12 @@
+use super::password_policy::PasswordViolation;
+
+#[derive(Debug)]
 pub enum UserError {
     InvalidUsername(String),
     InvalidPassword(String),
+    InvalidEmail(String),
+    /// A profile field, such as the bio or the time zone, is out of its limits.
+    InvalidProfile(String),
+    /// The password is well-formed but breaks the password policy.
+    WeakPassword(Vec<PasswordViolation>),
 }
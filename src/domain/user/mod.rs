@@ -1,6 +1,10 @@
 pub mod entity;
 pub mod error;
 pub mod password_hasher;
+pub mod password_history;
+pub mod password_policy;
 pub mod patch;
+pub mod profile;
+pub mod query;
 pub mod repository;
 pub mod value_objects;
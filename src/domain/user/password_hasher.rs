@@ -1,5 +1,27 @@
+/// Outcome of checking a password against a stored hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordMatch {
+    Mismatch,
+    Match,
+    /// The password matches, but the hash was made with outdated parameters and should be
+    /// replaced by a fresh one.
+    Outdated,
+}
+
+impl PasswordMatch {
+    pub fn is_match(&self) -> bool {
+        !matches!(self, PasswordMatch::Mismatch)
+    }
+}
+
+#[derive(Debug)]
+pub enum HashError {
+    /// The stored hash cannot be parsed, or was made with a key that is not configured.
+    Malformed,
+}
+
 #[async_trait::async_trait]
 pub trait PasswordHasher {
     fn hash(&self, plain: &str) -> String;
-    fn verify(&self, plain: &str, hash: &str) -> bool;
+    fn verify(&self, plain: &str, hash: &str) -> Result<PasswordMatch, HashError>;
 }
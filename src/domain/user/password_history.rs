@@ -0,0 +1,21 @@
+use super::value_objects::password_hash::PasswordHash;
+use crate::domain::errors::repository::RepositoryError;
+use uuid::Uuid;
+
+/// Passwords a user had before, so they cannot be chosen again.
+#[async_trait::async_trait]
+pub trait PasswordHistoryRepository {
+    /// The `limit` most recently retired password hashes of the user, newest first.
+    async fn recent(
+        &self,
+        user_id: &Uuid,
+        limit: u64,
+    ) -> Result<Vec<PasswordHash>, RepositoryError>;
+    /// Stores a retired password hash, forgetting all but the `keep` most recent ones.
+    async fn record(
+        &self,
+        user_id: &Uuid,
+        hash: &PasswordHash,
+        keep: u64,
+    ) -> Result<(), RepositoryError>;
+}
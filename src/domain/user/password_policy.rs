@@ -0,0 +1,255 @@
+use super::{
+    error::UserError,
+    password_hasher::PasswordHasher,
+    value_objects::{password_hash::PasswordHash, password_plain::PasswordPlain},
+};
+
+/// Passwords known to have leaked, which must not be chosen.
+pub trait BreachedPasswords {
+    fn contains(&self, password: &str) -> bool;
+}
+
+/// A rule a new password breaks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PasswordViolation {
+    TooShort { min_length: usize },
+    MissingLowercase,
+    MissingUppercase,
+    MissingDigit,
+    MissingSymbol,
+    Breached,
+    Reused,
+}
+
+impl PasswordViolation {
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordViolation::TooShort { .. } => "too_short",
+            PasswordViolation::MissingLowercase => "missing_lowercase",
+            PasswordViolation::MissingUppercase => "missing_uppercase",
+            PasswordViolation::MissingDigit => "missing_digit",
+            PasswordViolation::MissingSymbol => "missing_symbol",
+            PasswordViolation::Breached => "breached",
+            PasswordViolation::Reused => "reused",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            PasswordViolation::TooShort { min_length } => {
+                format!("Password must be at least {} characters long", min_length)
+            }
+            PasswordViolation::MissingLowercase => {
+                "Password must contain a lowercase letter".into()
+            }
+            PasswordViolation::MissingUppercase => {
+                "Password must contain an uppercase letter".into()
+            }
+            PasswordViolation::MissingDigit => "Password must contain a digit".into(),
+            PasswordViolation::MissingSymbol => {
+                "Password must contain a character that is neither a letter nor a digit".into()
+            }
+            PasswordViolation::Breached => {
+                "Password appears in a list of breached passwords".into()
+            }
+            PasswordViolation::Reused => "Password must differ from the recently used ones".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PasswordRules {
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Seconds after which a password must be changed, if it expires at all.
+    pub max_age: Option<u64>,
+    /// How many former passwords, besides the current one, cannot be chosen again.
+    pub history: u64,
+}
+
+/// Decides which passwords users may choose.
+#[derive(Clone)]
+pub struct PasswordPolicy<B>
+where
+    B: BreachedPasswords,
+{
+    rules: PasswordRules,
+    breached: B,
+}
+
+impl<B> PasswordPolicy<B>
+where
+    B: BreachedPasswords,
+{
+    pub fn new(rules: PasswordRules, breached: B) -> Self {
+        Self { rules, breached }
+    }
+
+    pub fn history(&self) -> u64 {
+        self.rules.history
+    }
+
+    pub fn max_age(&self) -> Option<u64> {
+        self.rules.max_age
+    }
+
+    /// Reports every rule the password breaks at once, so they can all be fixed in one go.
+    pub fn check(&self, password: &PasswordPlain) -> Result<(), UserError> {
+        let password: &str = password.as_str();
+        let mut violations: Vec<PasswordViolation> = Vec::new();
+
+        if password.chars().count() < self.rules.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min_length: self.rules.min_length,
+            });
+        }
+
+        let lacks = |matches: fn(char) -> bool| !password.chars().any(matches);
+
+        if self.rules.require_lowercase && lacks(char::is_lowercase) {
+            violations.push(PasswordViolation::MissingLowercase);
+        }
+
+        if self.rules.require_uppercase && lacks(char::is_uppercase) {
+            violations.push(PasswordViolation::MissingUppercase);
+        }
+
+        if self.rules.require_digit && lacks(char::is_numeric) {
+            violations.push(PasswordViolation::MissingDigit);
+        }
+
+        if self.rules.require_symbol && lacks(|c| !c.is_alphanumeric()) {
+            violations.push(PasswordViolation::MissingSymbol);
+        }
+
+        if self.breached.contains(password) {
+            violations.push(PasswordViolation::Breached);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(UserError::WeakPassword(violations))
+        }
+    }
+
+    /// Rejects a password matching the current hash or one of the `former` ones. A hash that
+    /// cannot be read matches nothing.
+    pub fn check_reuse<H>(
+        &self,
+        password: &PasswordPlain,
+        current: &PasswordHash,
+        former: &[PasswordHash],
+        hasher: &H,
+    ) -> Result<(), UserError>
+    where
+        H: PasswordHasher,
+    {
+        if std::iter::once(current).chain(former).any(|hash| {
+            hasher
+                .verify(password.as_str(), hash.as_str())
+                .is_ok_and(|matched| matched.is_match())
+        }) {
+            return Err(UserError::WeakPassword(vec![PasswordViolation::Reused]));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Breached passwords given as plain text.
+    struct BreachList(&'static [&'static str]);
+
+    impl BreachedPasswords for BreachList {
+        fn contains(&self, password: &str) -> bool {
+            self.0.contains(&password)
+        }
+    }
+
+    fn policy() -> PasswordPolicy<BreachList> {
+        PasswordPolicy::new(
+            PasswordRules {
+                min_length: 10,
+                require_lowercase: true,
+                require_uppercase: true,
+                require_digit: true,
+                require_symbol: true,
+                max_age: None,
+                history: 0,
+            },
+            BreachList(&["Password123!"]),
+        )
+    }
+
+    fn violations(password: &str) -> Vec<PasswordViolation> {
+        match policy().check(&PasswordPlain::new(password.into()).unwrap()) {
+            Ok(()) => Vec::new(),
+            Err(UserError::WeakPassword(violations)) => violations,
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn reports_every_rule_a_password_breaks() {
+        use PasswordViolation::*;
+
+        let cases: [(&str, Vec<PasswordViolation>); 10] = [
+            ("Correct-Horse-42", vec![]),
+            ("Short-4", vec![TooShort { min_length: 10 }]),
+            // Length counts characters, not bytes.
+            ("Pâté-Été-42", vec![]),
+            ("Éé-Éé-Éé-4", vec![]),
+            ("CORRECT-HORSE-42", vec![MissingLowercase]),
+            ("correct-horse-42", vec![MissingUppercase]),
+            ("Correct-Horse-XL", vec![MissingDigit]),
+            ("CorrectHorse42", vec![MissingSymbol]),
+            ("Password123!", vec![Breached]),
+            (
+                "abc",
+                vec![
+                    TooShort { min_length: 10 },
+                    MissingUppercase,
+                    MissingDigit,
+                    MissingSymbol,
+                ],
+            ),
+        ];
+
+        for (password, expected) in cases {
+            assert_eq!(violations(password), expected, "password {:?}", password);
+        }
+    }
+
+    #[test]
+    fn checks_only_the_required_character_classes() {
+        let policy: PasswordPolicy<BreachList> = PasswordPolicy::new(
+            PasswordRules {
+                min_length: 4,
+                require_lowercase: false,
+                require_uppercase: false,
+                require_digit: false,
+                require_symbol: false,
+                max_age: None,
+                history: 0,
+            },
+            BreachList(&[]),
+        );
+
+        for password in ["abcd", "ABCD", "1234", "!!!!"] {
+            assert!(
+                policy
+                    .check(&PasswordPlain::new(password.into()).unwrap())
+                    .is_ok(),
+                "password {:?}",
+                password
+            );
+        }
+    }
+}
@@ -1,9 +1,19 @@
-use super::value_objects::{password_hash::PasswordHash, username::Username};
+use super::{
+    entity::UserStatus,
+    profile::ProfilePatch,
+    value_objects::{email::Email, password_hash::PasswordHash, username::Username},
+};
+use crate::domain::role::entity::RoleName;
 
 pub struct UserPatch {
     pub name: Option<String>,
     pub username: Option<Username>,
     pub password_hash: Option<PasswordHash>,
+    pub role: Option<RoleName>,
+    pub status: Option<UserStatus>,
+    /// A new email address, which then needs to be verified again.
+    pub email: Option<Email>,
+    pub profile: ProfilePatch,
 }
 
 impl UserPatch {
@@ -11,11 +21,23 @@ impl UserPatch {
         name: Option<String>,
         username: Option<Username>,
         password_hash: Option<PasswordHash>,
+        role: Option<RoleName>,
+        status: Option<UserStatus>,
+        email: Option<Email>,
     ) -> Self {
         Self {
             name,
             username,
             password_hash,
+            role,
+            status,
+            email,
+            profile: ProfilePatch::default(),
         }
     }
+
+    pub fn with_profile(mut self, profile: ProfilePatch) -> Self {
+        self.profile = profile;
+        self
+    }
 }
@@ -0,0 +1,27 @@
+use super::value_objects::{
+    bio::Bio, locale::Locale, status_emoji::StatusEmoji, status_text::StatusText,
+    timezone::Timezone,
+};
+
+/// How users present themselves to others, beyond their name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// When the avatar was last uploaded, unset without one. It versions the avatar images.
+    pub avatar_updated_at: Option<u64>,
+    pub status_text: Option<StatusText>,
+    pub status_emoji: Option<StatusEmoji>,
+    pub bio: Option<Bio>,
+    pub timezone: Option<Timezone>,
+    pub locale: Option<Locale>,
+}
+
+/// Changes to a profile: `None` keeps a field, `Some(None)` clears it.
+#[derive(Default)]
+pub struct ProfilePatch {
+    pub avatar_updated_at: Option<Option<u64>>,
+    pub status_text: Option<Option<StatusText>>,
+    pub status_emoji: Option<Option<StatusEmoji>>,
+    pub bio: Option<Option<Bio>>,
+    pub timezone: Option<Option<Timezone>>,
+    pub locale: Option<Option<Locale>>,
+}
@@ -0,0 +1,89 @@
+use super::entity::{User, UserStatus};
+use crate::domain::role::entity::RoleName;
+use uuid::Uuid;
+
+/// Which users to list. All filters must match.
+#[derive(Default)]
+pub struct UserFilter {
+    /// Only users whose home organization this is.
+    pub organization_id: Option<Uuid>,
+    pub status: Option<UserStatus>,
+    pub role: Option<RoleName>,
+    /// Case-insensitive prefix of the username or the name.
+    pub search: Option<String>,
+    /// List deleted users that can still be restored, instead of the others.
+    pub deleted: bool,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum UserSortField {
+    #[default]
+    Username,
+    Name,
+    /// Creation order, which identifiers follow.
+    CreatedAt,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct UserSort {
+    pub field: UserSortField,
+    pub direction: SortDirection,
+}
+
+/// Position right after a listed user, for the same sort. Ties on the sort field are broken by
+/// identifier, so pages neither overlap nor skip users while the list changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserCursor {
+    pub value: String,
+    pub id: Uuid,
+}
+
+impl UserCursor {
+    pub fn after(user: &User, sort: &UserSort) -> Self {
+        let value: String = match sort.field {
+            UserSortField::Username => user.username.as_str().to_owned(),
+            UserSortField::Name => user.name.as_str().to_owned(),
+            UserSortField::CreatedAt => String::new(),
+        };
+
+        Self { value, id: user.id }
+    }
+}
+
+pub enum Pagination {
+    Offset {
+        offset: u64,
+        limit: u64,
+    },
+    Cursor {
+        after: Option<UserCursor>,
+        limit: u64,
+    },
+}
+
+impl Pagination {
+    pub fn limit(&self) -> u64 {
+        match self {
+            Pagination::Offset { limit, .. } | Pagination::Cursor { limit, .. } => *limit,
+        }
+    }
+}
+
+pub struct UserQuery {
+    pub filter: UserFilter,
+    pub sort: UserSort,
+    pub pagination: Pagination,
+}
+
+/// One page of users, along with how many users match the filter in total.
+pub struct UserPage {
+    pub users: Vec<User>,
+    pub total: u64,
+}
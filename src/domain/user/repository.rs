@@ -1,12 +1,95 @@
-use super::{entity::User, value_objects::username::Username};
-use crate::domain::{errors::repository::RepositoryError, user::patch::UserPatch};
+use super::{
+    entity::User,
+    value_objects::{email::Email, password_hash::PasswordHash, username::Username},
+};
+use crate::domain::{
+    errors::repository::RepositoryError,
+    role::entity::RoleName,
+    user::{
+        patch::UserPatch,
+        query::{UserPage, UserQuery},
+    },
+};
 use uuid::Uuid;
 
+/// Deleted users are left out of every lookup and listing, unless a method says otherwise.
 #[async_trait::async_trait]
 pub trait UserRepository {
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<User>, RepositoryError>;
-    async fn find_by_username(&self, username: &Username) -> Result<Option<User>, RepositoryError>;
+    /// Finds the user only if the organization is their home organization.
+    async fn find_in_organization(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError>;
+    async fn find_by_username(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<Option<User>, RepositoryError>;
+    async fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError>;
+    /// Whether a user of the organization holds the username, deleted users included.
+    async fn is_username_taken(
+        &self,
+        organization_id: &Uuid,
+        username: &Username,
+    ) -> Result<bool, RepositoryError>;
+    /// Whether a user holds the email address, deleted users included.
+    async fn is_email_taken(&self, email: &Email) -> Result<bool, RepositoryError>;
+    /// Whether a user holds the role, deleted and purged users included.
+    async fn has_users_with_role(&self, role: &RoleName) -> Result<bool, RepositoryError>;
+    /// Finds a deleted user of the organization that was not purged yet.
+    async fn find_deleted(
+        &self,
+        organization_id: &Uuid,
+        id: &Uuid,
+    ) -> Result<Option<User>, RepositoryError>;
+    /// Deleted users that were not purged yet and were deleted before `deleted_before`, oldest
+    /// first.
+    async fn find_purgeable(
+        &self,
+        deleted_before: u64,
+        limit: u64,
+    ) -> Result<Vec<User>, RepositoryError>;
+    async fn list(&self, query: &UserQuery) -> Result<UserPage, RepositoryError>;
+    /// Creates the user along with their membership of their home organization.
     async fn create(&self, user: User) -> Result<User, RepositoryError>;
+    /// Changes a user, unless they were deleted.
     async fn update(&self, id: &Uuid, user: UserPatch) -> Result<User, RepositoryError>;
+    /// Updates the user unless that leaves an organization they administer without another
+    /// active administrator, returning `None` then. Users administer their home organization
+    /// through the administrator role, and any organization through an administrator
+    /// membership. Counting the administrators and updating happen at once, so two
+    /// administrators cannot demote each other concurrently.
+    async fn update_keeping_administrators(
+        &self,
+        id: &Uuid,
+        user: UserPatch,
+    ) -> Result<Option<User>, RepositoryError>;
+    /// Replaces the password hash with another hash of the same password, so unlike an update
+    /// it does not count as a password change.
+    async fn rehash_password(
+        &self,
+        id: &Uuid,
+        password_hash: &PasswordHash,
+    ) -> Result<(), RepositoryError>;
+    /// Marks the email address as verified, returning whether it was still the address of the
+    /// user.
+    async fn mark_email_verified(
+        &self,
+        id: &Uuid,
+        email: &Email,
+        verified_at: u64,
+    ) -> Result<bool, RepositoryError>;
+    /// Marks the user deleted, keeping the row and everything referring to it. Like
+    /// `update_keeping_administrators`, the last active administrator of an organization is
+    /// not deleted, returning whether the user was.
+    async fn soft_delete(&self, id: &Uuid, deleted_at: u64) -> Result<bool, RepositoryError>;
+    /// Undoes a deletion, returning whether the user was deleted and not purged yet.
+    async fn restore(&self, id: &Uuid) -> Result<bool, RepositoryError>;
+    /// Strips a deleted user of everything identifying them, such as their names, email
+    /// address, credentials and profile, keeping the row for what still refers to it.
+    async fn anonymize(&self, id: &Uuid, purged_at: u64) -> Result<(), RepositoryError>;
+    /// Removes a user for good, only for undoing a creation that could not be completed.
     async fn delete(&self, id: &Uuid) -> Result<(), RepositoryError>;
 }
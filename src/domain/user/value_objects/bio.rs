@@ -0,0 +1,29 @@
+use crate::domain::user::error::UserError;
+
+/// A few paragraphs about the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bio(String);
+
+impl Bio {
+    pub const MAX_LENGTH: usize = 1000;
+
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let value: String = value.trim().to_owned();
+
+        if value.is_empty()
+            || value.chars().count() > Self::MAX_LENGTH
+            || value.chars().any(|c| c.is_control() && c != '\n')
+        {
+            return Err(UserError::InvalidProfile(format!(
+                "Bio must be between 1 and {} characters long",
+                Self::MAX_LENGTH
+            )));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
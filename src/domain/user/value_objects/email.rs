@@ -0,0 +1,135 @@
+use crate::domain::user::error::UserError;
+
+/// An email address, kept in lowercase so that each address belongs to one user at most.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Email(String);
+
+impl Email {
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let value: String = value.trim().to_lowercase();
+
+        Self::validate_email(&value)?;
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// A practical subset of RFC 5321 addresses: no quoted local parts or address literals,
+    /// which mail providers do not hand out anyway.
+    fn validate_email(email: &str) -> Result<(), UserError> {
+        let invalid = || UserError::InvalidEmail("Email address is not valid".into());
+
+        if email.len() > 254 {
+            return Err(UserError::InvalidEmail(
+                "Email address must be at most 254 characters long".into(),
+            ));
+        }
+
+        let (local, domain) = email.rsplit_once('@').ok_or_else(invalid)?;
+
+        if local.is_empty()
+            || local.len() > 64
+            || local.starts_with('.')
+            || local.ends_with('.')
+            || local.contains("..")
+            || !local
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+        {
+            return Err(invalid());
+        }
+
+        let labels: Vec<&str> = domain.split('.').collect();
+
+        if labels.len() < 2
+            || labels.iter().any(|label| {
+                label.is_empty()
+                    || label.len() > 63
+                    || label.starts_with('-')
+                    || label.ends_with('-')
+                    || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            })
+        {
+            return Err(invalid());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_common_addresses() {
+        for address in [
+            "alice@example.org",
+            "alice.smith+news@mail.example.co.uk",
+            "a@b.io",
+            "o'brien@example-mail.org",
+        ] {
+            assert_eq!(Email::new(address.into()).unwrap().as_str(), address);
+        }
+    }
+
+    #[test]
+    fn folds_case_and_trims_whitespace() {
+        let email: Email = Email::new("  Alice.Smith@Example.ORG\n".into()).unwrap();
+
+        assert_eq!(email.as_str(), "alice.smith@example.org");
+        assert_eq!(email, Email::new("alice.smith@example.org".into()).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_addresses() {
+        for address in [
+            "",
+            "   ",
+            "alice",
+            "alice.example.org",
+            "@example.org",
+            "alice@",
+            "alice@localhost",
+            "alice@example..org",
+            "alice@-example.org",
+            ".alice@example.org",
+            "alice..smith@example.org",
+            "alice smith@example.org",
+            "\"alice\"@example.org",
+            "alice@[192.0.2.1]",
+        ] {
+            assert!(
+                matches!(Email::new(address.into()), Err(UserError::InvalidEmail(_))),
+                "address {:?}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_overlong_addresses() {
+        let label: String = "a".repeat(63);
+        let domain: String = format!("{label}.{label}.{}.org", "b".repeat(57));
+
+        // At the limits: 64 characters before the @ and 254 in total.
+        let longest: String = format!("{}@{}", "a".repeat(64), domain);
+        assert_eq!(longest.len(), 254);
+        assert!(Email::new(longest.clone()).is_ok());
+
+        for address in [
+            format!("b{}", longest),
+            format!("{}@example.org", "a".repeat(65)),
+            format!("alice@{}.org", "a".repeat(64)),
+        ] {
+            assert!(
+                matches!(Email::new(address.clone()), Err(UserError::InvalidEmail(_))),
+                "address {:?}",
+                address
+            );
+        }
+    }
+}
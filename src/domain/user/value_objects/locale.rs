@@ -0,0 +1,61 @@
+use crate::domain::user::error::UserError;
+
+/// A BCP 47 language tag limited to language, script and region, such as `en`, `pt-BR` or
+/// `zh-Hant-TW`, kept in its canonical casing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale(String);
+
+impl Locale {
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let invalid =
+            || UserError::InvalidProfile("Locale must be a language tag, like en or pt-BR".into());
+
+        let value: String = value.trim().replace('_', "-");
+        let mut subtags = value.split('-');
+
+        let language: &str = subtags.next().ok_or_else(invalid)?;
+
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+
+        let mut locale: String = language.to_ascii_lowercase();
+        let mut subtag: Option<&str> = subtags.next();
+
+        // Script, e.g. `Hant`.
+        if let Some(script) = subtag
+            && script.len() == 4
+            && script.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            locale.push('-');
+            locale.push_str(&script[..1].to_ascii_uppercase());
+            locale.push_str(&script[1..].to_ascii_lowercase());
+            subtag = subtags.next();
+        }
+
+        // Region, e.g. `BR` or `419`.
+        if let Some(region) = subtag {
+            let is_region: bool = (region.len() == 2
+                && region.chars().all(|c| c.is_ascii_alphabetic()))
+                || (region.len() == 3 && region.chars().all(|c| c.is_ascii_digit()));
+
+            if !is_region {
+                return Err(invalid());
+            }
+
+            locale.push('-');
+            locale.push_str(&region.to_ascii_uppercase());
+        }
+
+        if subtags.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self(locale))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
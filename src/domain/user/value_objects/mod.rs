@@ -1,4 +1,10 @@
+pub mod bio;
+pub mod email;
+pub mod locale;
 pub mod name;
 pub mod password_hash;
 pub mod password_plain;
+pub mod status_emoji;
+pub mod status_text;
+pub mod timezone;
 pub mod username;
@@ -15,9 +15,10 @@ impl PasswordPlain {
     }
 
     fn validate_password(password: &str) -> Result<(), UserError> {
-        if password.is_empty() || password.chars().count() < 8 {
+        // How long a password must be is up to the password policy.
+        if password.is_empty() {
             return Err(UserError::InvalidPassword(
-                "Password must be at least 8 characters long".into(),
+                "Password must not be empty".into(),
             ));
         }
 
@@ -0,0 +1,49 @@
+use crate::domain::user::error::UserError;
+
+/// The emoji shown next to the status text, either the emoji itself or a `:shortcode:`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusEmoji(String);
+
+impl StatusEmoji {
+    /// Enough code points for flags, skin tones and joined sequences like families.
+    const MAX_CODE_POINTS: usize = 10;
+
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let value: String = value.trim().to_owned();
+
+        if !Self::is_shortcode(&value) && !Self::is_emoji(&value) {
+            return Err(UserError::InvalidProfile(
+                "Status emoji must be a single emoji or a :shortcode:".into(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn is_shortcode(value: &str) -> bool {
+        match value.strip_prefix(':').and_then(|s| s.strip_suffix(':')) {
+            Some(name) => {
+                (1..=32).contains(&name.len())
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_+-".contains(c))
+            }
+            None => false,
+        }
+    }
+
+    /// Without the Unicode emoji tables this only rules out text: no ASCII at all, and no
+    /// control characters or whitespace.
+    fn is_emoji(value: &str) -> bool {
+        let count: usize = value.chars().count();
+
+        (1..=Self::MAX_CODE_POINTS).contains(&count)
+            && value
+                .chars()
+                .all(|c| !c.is_ascii() && !c.is_control() && !c.is_whitespace())
+    }
+}
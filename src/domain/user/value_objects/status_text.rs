@@ -0,0 +1,29 @@
+use crate::domain::user::error::UserError;
+
+/// A short line telling others what the user is up to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusText(String);
+
+impl StatusText {
+    pub const MAX_LENGTH: usize = 100;
+
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let value: String = value.trim().to_owned();
+
+        if value.is_empty()
+            || value.chars().count() > Self::MAX_LENGTH
+            || value.chars().any(char::is_control)
+        {
+            return Err(UserError::InvalidProfile(format!(
+                "Status text must be a single line of 1 to {} characters",
+                Self::MAX_LENGTH
+            )));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
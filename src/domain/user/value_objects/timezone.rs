@@ -0,0 +1,61 @@
+use crate::domain::user::error::UserError;
+
+const AREAS: [&str; 11] = [
+    "Africa",
+    "America",
+    "Antarctica",
+    "Arctic",
+    "Asia",
+    "Atlantic",
+    "Australia",
+    "Etc",
+    "Europe",
+    "Indian",
+    "Pacific",
+];
+
+/// An IANA time zone name such as `Europe/Amsterdam`, or `UTC`.
+///
+/// Names are checked for their shape and area, not against a time zone database, so clients
+/// should offer the zones they know rather than free text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timezone(String);
+
+impl Timezone {
+    pub fn new(value: String) -> Result<Self, UserError> {
+        let value: String = value.trim().to_owned();
+
+        Self::validate_timezone(&value)?;
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn validate_timezone(timezone: &str) -> Result<(), UserError> {
+        if timezone == "UTC" {
+            return Ok(());
+        }
+
+        let parts: Vec<&str> = timezone.split('/').collect();
+
+        if !(2..=3).contains(&parts.len())
+            || !AREAS.contains(&parts[0])
+            || parts[1..].iter().any(|part| {
+                part.is_empty()
+                    || part.len() > 30
+                    || !part
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "_+-".contains(c))
+            })
+        {
+            return Err(UserError::InvalidProfile(
+                "Timezone must be an IANA time zone name, like Europe/Amsterdam".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
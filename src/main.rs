@@ -14,21 +14,17 @@ use std::io::Error;
 
 #[actix_web::main]
 async fn main() -> Result<(), Error> {
-    let Config {
-        http: http_config,
-        logging: logging_config,
-        database: database_config,
-    } = Config::load().expect("Failed to load configuration");
+    let config: Config = Config::load().expect("Failed to load configuration");
 
     env_logger::Builder::from_env(
-        env_logger::Env::default().filter_or("RUST_LOG", &logging_config.level),
+        env_logger::Env::default().filter_or("RUST_LOG", &config.logging.level),
     )
     .format_timestamp_secs()
     .init();
 
-    let db: DatabaseConnection = connect_to_db(&database_config, &logging_config).await;
+    let db: DatabaseConnection = connect_to_db(&config.database, &config.logging).await;
 
     info!("Starting application");
 
-    build_app(http_config, db).await
+    build_app(config, db).await
 }